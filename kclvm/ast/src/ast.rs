@@ -36,7 +36,7 @@
 //! todo: remove type_str fields after python frontend removed.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use compiler_base_span::{Loc, Span};
 
@@ -285,6 +285,30 @@ impl Program {
         }
         None
     }
+    /// Serialize the program into a pretty JSON string with the positions of all
+    /// AST nodes. The packages are sorted by the package path, so that the same
+    /// program is always serialized into the same string.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        #[derive(Serialize)]
+        struct SortedProgram<'a> {
+            root: &'a str,
+            main: &'a str,
+            pkgs: BTreeMap<&'a str, &'a [Module]>,
+        }
+        serde_json::to_string_pretty(&SortedProgram {
+            root: &self.root,
+            main: &self.main,
+            pkgs: self
+                .pkgs
+                .iter()
+                .map(|(pkgpath, modules)| (pkgpath.as_str(), modules.as_slice()))
+                .collect(),
+        })
+    }
+    /// Deserialize the program from a JSON string serialized by [`Program::to_json`].
+    pub fn from_json(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
 }
 
 /// Module is an abstract syntax tree for a single KCL file.
@@ -344,10 +368,13 @@ pub enum Stmt {
 /// TypeAliasStmt represents a type alias statement, e.g.
 /// ```kcl
 /// type StrOrInt = str | int
+/// type Pairs[T] = {str:T}
 /// ```
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct TypeAliasStmt {
     pub type_name: NodeRef<Identifier>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<NodeRef<String>>,
     pub type_value: NodeRef<String>,
 
     pub ty: NodeRef<Type>,
@@ -470,6 +497,8 @@ pub struct SchemaStmt {
     pub for_host_name: Option<NodeRef<Identifier>>,
     pub is_mixin: bool,
     pub is_protocol: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<NodeRef<String>>,
     pub args: Option<NodeRef<Arguments>>,
     pub mixins: Vec<NodeRef<Identifier>>,
     pub body: Vec<NodeRef<Stmt>>,
@@ -1472,6 +1501,8 @@ pub enum Type {
     Dict(DictType),
     Union(UnionType),
     Literal(LiteralType),
+    Function(FunctionType),
+    Generic(GenericType),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1498,6 +1529,26 @@ pub struct UnionType {
     pub type_elements: Vec<NodeRef<Type>>,
 }
 
+/// FunctionType, e.g.
+/// ```kcl
+/// (int, str) -> bool
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunctionType {
+    pub params_ty: Vec<NodeRef<Type>>,
+    pub ret_ty: NodeRef<Type>,
+}
+
+/// GenericType is the instantiation of a generic schema or type alias, e.g.
+/// ```kcl
+/// Page[Person]
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GenericType {
+    pub name: Identifier,
+    pub args: Vec<NodeRef<Type>>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum LiteralType {
     Bool(bool),
@@ -1579,6 +1630,28 @@ impl ToString for Type {
                         w.push_str(&format!("\"{}\"", v.replace('"', "\\\"")));
                     }
                 },
+                Type::Function(x) => {
+                    w.push('(');
+                    for (i, t) in x.params_ty.iter().enumerate() {
+                        if i > 0 {
+                            w.push_str(", ");
+                        }
+                        to_str(&t.node, w);
+                    }
+                    w.push_str(") -> ");
+                    to_str(&x.ret_ty.node, w);
+                }
+                Type::Generic(x) => {
+                    w.push_str(&x.name.names.join("."));
+                    w.push('[');
+                    for (i, t) in x.args.iter().enumerate() {
+                        if i > 0 {
+                            w.push_str(", ");
+                        }
+                        to_str(&t.node, w);
+                    }
+                    w.push(']');
+                }
             }
         }
 
@@ -1,6 +1,7 @@
 use crate::node_ref;
-use crate::walker::MutSelfMutWalker;
+use crate::walker::{MutSelfMutWalker, MutSelfWalker};
 use crate::{ast, ast::*};
+use std::collections::HashMap;
 
 /// Construct an AssignStmt node with assign_value as value
 fn build_assign_node(attr_name: &str, assign_value: NodeRef<Expr>) -> NodeRef<Stmt> {
@@ -142,6 +143,50 @@ fn test_ast_print_assign_binary() {
     println!("{:?}", json_str);
 }
 
+#[test]
+fn test_program_to_json() {
+    let assign_stmt = get_dummy_assign_ast();
+    let stmt = Box::new(ast::Node::new(
+        ast::Stmt::Assign(assign_stmt.node),
+        assign_stmt.filename,
+        assign_stmt.line,
+        assign_stmt.column,
+        assign_stmt.end_line,
+        assign_stmt.end_column,
+    ));
+    let mut pkgs = HashMap::new();
+    for pkgpath in ["pkg", "__main__", "a.b"] {
+        pkgs.insert(
+            pkgpath.to_string(),
+            vec![ast::Module {
+                filename: "main.k".to_string(),
+                pkg: pkgpath.to_string(),
+                doc: "".to_string(),
+                name: "main".to_string(),
+                body: vec![stmt.clone()],
+                comments: vec![],
+            }],
+        );
+    }
+    let program = ast::Program {
+        root: ".".to_string(),
+        main: "__main__".to_string(),
+        pkgs,
+    };
+    let json_str = program.to_json().unwrap();
+    assert_eq!(json_str, program.clone().to_json().unwrap());
+    // The packages are sorted and the node positions are kept.
+    let main_index = json_str.find("\"__main__\": [").unwrap();
+    let a_index = json_str.find("\"a.b\": [").unwrap();
+    let pkg_index = json_str.find("\"pkg\": [").unwrap();
+    assert!(main_index < a_index && a_index < pkg_index);
+    assert!(json_str.contains("\"end_column\": 2"));
+
+    let program = ast::Program::from_json(&json_str).unwrap();
+    assert_eq!(program.pkgs.len(), 3);
+    assert_eq!(program.to_json().unwrap(), json_str);
+}
+
 #[test]
 fn test_mut_walker() {
     pub struct VarMutSelfMutWalker;
@@ -158,6 +203,41 @@ fn test_mut_walker() {
     assert_eq!(assign_stmt.node.targets[0].node.names[0], "x")
 }
 
+#[test]
+fn test_mut_self_walker_node() {
+    #[derive(Default)]
+    struct IdentifierPosCollector {
+        positions: Vec<(String, u64, u64)>,
+    }
+    impl MutSelfWalker for IdentifierPosCollector {
+        fn walk_expr_node(&mut self, expr: &ast::Node<ast::Expr>) {
+            if let ast::Expr::Identifier(identifier) = &expr.node {
+                self.positions
+                    .push((identifier.get_name(), expr.line, expr.column));
+            }
+            self.walk_expr(&expr.node)
+        }
+        fn walk_identifier_node(&mut self, identifier: &ast::Node<ast::Identifier>) {
+            self.positions.push((
+                identifier.node.get_name(),
+                identifier.line,
+                identifier.column,
+            ));
+        }
+    }
+    let assign_stmt = get_dummy_assign_binary_ast();
+    let mut collector = IdentifierPosCollector::default();
+    collector.walk_assign_stmt(&assign_stmt.node);
+    assert_eq!(
+        collector.positions,
+        vec![
+            ("a".to_string(), 1, 1),
+            ("a".to_string(), 1, 1),
+            ("a".to_string(), 1, 1)
+        ]
+    );
+}
+
 #[test]
 fn test_try_from_for_stringlit() {
     let str_lit = ast::StringLit::try_from("test_str".to_string()).unwrap();
@@ -271,6 +351,7 @@ fn gen_schema_stmt(count: i32) -> Vec<NodeRef<ast::Stmt>> {
             for_host_name: None,
             is_mixin: false,
             is_protocol: false,
+            type_params: vec![],
             args: None,
             mixins: vec![],
             body: vec![],
@@ -281,3 +362,18 @@ fn gen_schema_stmt(count: i32) -> Vec<NodeRef<ast::Stmt>> {
     }
     schema_stmts
 }
+
+#[test]
+fn test_keywords() {
+    kclvm_span::create_session_globals_then(|| {
+        for (i, keyword) in crate::token::KEYWORDS.iter().enumerate() {
+            // The first keyword symbol is the empty string.
+            assert_eq!(
+                kclvm_span::Symbol::intern(keyword).as_u32(),
+                i as u32 + 1,
+                "{}",
+                keyword
+            );
+        }
+    });
+}
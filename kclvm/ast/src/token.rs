@@ -14,6 +14,38 @@ pub use UnaryOpToken::*;
 use compiler_base_span::{Span, DUMMY_SP};
 use kclvm_span::symbol::{Ident, Symbol};
 
+/// The KCL keywords which can't be used as identifiers, keep them in sync with the
+/// `Keywords` in `kclvm_span::symbol`.
+pub const KEYWORDS: [&str; 27] = [
+    "as",
+    "import",
+    "rule",
+    "schema",
+    "mixin",
+    "protocol",
+    "check",
+    "for",
+    "assert",
+    "if",
+    "elif",
+    "else",
+    "or",
+    "and",
+    "not",
+    "in",
+    "is",
+    "lambda",
+    "all",
+    "any",
+    "filter",
+    "map",
+    "type",
+    "True",
+    "False",
+    "None",
+    "Undefined",
+];
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum CommentKind {
     /// "#"
@@ -69,6 +69,11 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
         self.write("type");
         self.write_space();
         self.walk_identifier(&type_alias_stmt.type_name.node);
+        if !type_alias_stmt.type_params.is_empty() {
+            self.write("[");
+            self.write_type_params(&type_alias_stmt.type_params);
+            self.write("]");
+        }
         self.write(" = ");
         self.write(&type_alias_stmt.type_value.node);
         self.write_newline_without_fill();
@@ -173,9 +178,22 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
             self.write("schema ");
         }
         self.write(&schema_stmt.name.node);
-        if let Some(args) = &schema_stmt.args {
+        if !schema_stmt.type_params.is_empty() || schema_stmt.args.is_some() {
             self.write("[");
-            self.walk_arguments(&args.node);
+            interleave!(
+                || self.write(COMMA_WHITESPACE),
+                |param: &ast::NodeRef<String>| {
+                    self.write("type ");
+                    self.write(&param.node)
+                },
+                &schema_stmt.type_params
+            );
+            if let Some(args) = &schema_stmt.args {
+                if !schema_stmt.type_params.is_empty() {
+                    self.write(COMMA_WHITESPACE);
+                }
+                self.walk_arguments(&args.node);
+            }
             self.write("]");
         }
         if let Some(parent_name) = &schema_stmt.parent_name {
@@ -819,6 +837,14 @@ impl<'p> Printer<'p> {
         );
     }
 
+    pub fn write_type_params(&mut self, type_params: &[ast::NodeRef<String>]) {
+        interleave!(
+            || self.write(COMMA_WHITESPACE),
+            |param: &ast::NodeRef<String>| self.write(&param.node),
+            type_params
+        );
+    }
+
     pub fn write_entry(&mut self, item: &ast::NodeRef<ast::ConfigEntry>) {
         match &item.node.key {
             Some(key) => {
@@ -6,7 +6,7 @@ use pretty_assertions::assert_eq;
 
 const FILE_INPUT_SUFFIX: &str = ".input";
 const FILE_OUTPUT_SUFFIX: &str = ".output";
-const TEST_CASES: &[&'static str; 16] = &[
+const TEST_CASES: &[&'static str; 18] = &[
     "arguments",
     "empty",
     "if_stmt",
@@ -23,6 +23,8 @@ const TEST_CASES: &[&'static str; 16] = &[
     "str",
     "type_alias",
     "unification",
+    "function_type",
+    "generic",
 ];
 
 fn read_data(data_name: &str) -> (String, String) {
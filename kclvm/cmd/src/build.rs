@@ -0,0 +1,34 @@
+use anyhow::Result;
+use clap::ArgMatches;
+use kclvm_error::StringError;
+use kclvm_parser::ParseSession;
+use kclvm_runner::{artifact::ArtifactKind, build_program, ExecProgramArgs};
+use std::io::Write;
+use std::sync::Arc;
+
+use crate::settings::must_build_settings;
+
+/// Run the KCL build command, which compiles the KCL program into a shared library
+/// or an executable that can be run with `kclvm_cli run --artifact`.
+pub fn build_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    // Config settings building
+    let settings = must_build_settings(matches);
+    let output = settings.output().unwrap_or_default();
+    let args: ExecProgramArgs = settings.try_into()?;
+    let kind = if matches.get_flag("executable") {
+        ArtifactKind::Exe
+    } else {
+        ArtifactKind::Lib
+    };
+    let sess = Arc::new(ParseSession::default());
+    match build_program(sess.clone(), &args, &output, kind) {
+        Ok(artifact) => writeln!(writer, "{}", artifact)?,
+        Err(msg) => {
+            if !sess.0.diag_handler.has_errors()? {
+                sess.0.add_err(StringError(msg))?;
+            }
+            sess.0.emit_stashed_diagnostics_and_abort()?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use anyhow::Result;
+use clap::ArgMatches;
+use kclvm_config::{cache::clean_cache, modfile::get_pkg_root};
+
+/// Run the KCL clean command, which removes the package caches and the
+/// lib caches of the KCL package.
+pub fn clean_command(matches: &ArgMatches) -> Result<()> {
+    let input = matches
+        .get_one::<String>("input")
+        .map(|f| f.as_str())
+        .unwrap_or(".");
+    // The caches are stored in the package root with the `kcl.mod` file, or in the
+    // input directory if there is no `kcl.mod` file.
+    let root = match get_pkg_root(input) {
+        Some(root) => root,
+        None => Path::new(input).canonicalize()?.display().to_string(),
+    };
+    clean_cache(&root)
+}
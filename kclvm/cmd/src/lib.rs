@@ -3,6 +3,8 @@
 #[macro_use]
 extern crate clap;
 
+pub mod build;
+pub mod clean;
 pub mod fmt;
 pub mod lint;
 pub mod run;
@@ -18,6 +20,8 @@ use clap::{ArgAction, Command};
 use std::io;
 
 use anyhow::Result;
+use build::build_command;
+use clean::clean_command;
 use fmt::fmt_command;
 use lint::lint_command;
 use run::run_command;
@@ -29,9 +33,11 @@ pub fn main(args: &[&str]) -> Result<()> {
     // Sub commands
     match matches.subcommand() {
         Some(("run", sub_matches)) => run_command(sub_matches, &mut io::stdout()),
+        Some(("build", sub_matches)) => build_command(sub_matches, &mut io::stdout()),
         Some(("lint", sub_matches)) => lint_command(sub_matches),
         Some(("fmt", sub_matches)) => fmt_command(sub_matches),
         Some(("vet", sub_matches)) => vet_command(sub_matches),
+        Some(("clean", sub_matches)) => clean_command(sub_matches),
         Some(("server", _)) => kclvm_api::service::jsonrpc::start_stdio_server(),
         Some(("version", _)) => {
             println!("{}", kclvm_version::get_version_info());
@@ -55,12 +61,28 @@ pub fn app() -> Command {
             .arg(arg!(verbose: -v --verbose "Print test information verbosely").action(ArgAction::Count))
             .arg(arg!(disable_none: -n --disable_none "Disable dumping None values"))
             .arg(arg!(strict_range_check: -r --strict_range_check "Do perform strict numeric range checks"))
+            .arg(arg!(strict_type_check: --strict_type_check "Report the implicit any types in the type checking"))
             .arg(arg!(debug: -d --debug "Run in debug mode (for developers only)"))
             .arg(arg!(sort_keys: -k --sort_keys "Sort result keys"))
             .arg(arg!(arguments: -D --argument <arguments> ... "Specify the top-level argument").num_args(1..))
             .arg(arg!(path_selector: -S --path_selector <path_selector> ... "Specify the path selector").num_args(1..))
             .arg(arg!(overrides: -O --overrides <overrides> ... "Specify the configuration override path and value").num_args(1..))
             .arg(arg!(target: --target <target> "Specify the target type"))
+            .arg(arg!(backend: --backend <backend> "Specify the backend to run the program, default is llvm").value_parser(["llvm", "evaluator"]))
+            .arg(arg!(artifact: --artifact <artifact> "Run the artifact built by the build command instead of the input files"))
+            .arg(arg!(emit: --emit <emit> ... "Emit the outputs of each package for debugging").num_args(1..).value_parser(["ast", "llvm-ir", "llvm-bc", "obj"]))
+            .arg(arg!(emit_dir: --emit_dir <emit_dir> "Specify the directory of the emitted outputs, default is the current directory"))
+            .arg(arg!(package_map: -E --external <package_map> ... "Mapping of package name and path where the package is located").num_args(1..)),
+        )
+        .subcommand(
+            Command::new("build")
+            .about("Build the KCL program into a shared library or an executable")
+            .arg(arg!([input] ... "Specify the input files to build").num_args(0..))
+            .arg(arg!(output: -o --output <output> "Specify the output artifact path").required(true))
+            .arg(arg!(setting: -Y --setting <setting> ... "Specify the input setting file").num_args(1..))
+            .arg(arg!(executable: --executable "Build an executable instead of a shared library"))
+            .arg(arg!(strict_type_check: --strict_type_check "Report the implicit any types in the type checking"))
+            .arg(arg!(overrides: -O --overrides <overrides> ... "Specify the configuration override path and value").num_args(1..))
             .arg(arg!(package_map: -E --external <package_map> ... "Mapping of package name and path where the package is located").num_args(1..)),
         )
         .subcommand(
@@ -69,7 +91,8 @@ pub fn app() -> Command {
             .arg(arg!([input] ... "Sets the input file to use").num_args(0..))
             .arg(arg!(setting: -Y --setting <setting> ... "Sets the input file to use").num_args(1..))
             .arg(arg!(verbose: -v --verbose "Print test information verbosely").action(ArgAction::Count))
-            .arg(arg!(emit_warning: --emit_warning "Emit warning message")),
+            .arg(arg!(emit_warning: --emit_warning "Emit warning message"))
+            .arg(arg!(lint_config: --"lint-config" <lint_config> "Specify the lint config file to set the lint levels")),
         )
         .subcommand(
             Command::new("fmt")
@@ -87,6 +110,11 @@ pub fn app() -> Command {
                 .arg(arg!(attribute_name: -n --attribute_name <attribute_name> "The attribute name for the data loading"))
                 .arg(arg!(format: --format <format> "Validation data file format, support YAML and JSON, default is JSON")),
         )
+        .subcommand(
+            Command::new("clean")
+                .about("Remove the package and lib caches")
+                .arg(arg!([input] "The KCL package path, default is the current directory")),
+        )
     .subcommand(Command::new("server").about("Start a rpc server for APIs"))
     .subcommand(Command::new("version").about("Show the KCL version"))
 }
@@ -1,8 +1,10 @@
 use anyhow::Result;
 use clap::ArgMatches;
+use kclvm_config::modfile::load_lint_config_file;
 use kclvm_error::Handler;
 use kclvm_runner::ExecProgramArgs;
-use kclvm_tools::lint::lint_files;
+use kclvm_tools::lint::lint_files_with_levels;
+use std::collections::HashMap;
 
 use crate::settings::must_build_settings;
 
@@ -21,9 +23,14 @@ pub fn lint_command(matches: &ArgMatches) -> Result<()> {
     } else {
         args.get_files()
     };
+    // The lint levels in the lint config file override the ones in `kcl.mod`.
+    let lint_levels = match matches.get_one::<String>("lint_config") {
+        Some(path) => load_lint_config_file(path)?,
+        None => HashMap::new(),
+    };
     let (mut err_handler, mut warning_handler) = (Handler::default(), Handler::default());
     (err_handler.diagnostics, warning_handler.diagnostics) =
-        lint_files(&files, Some(args.get_load_program_options()));
+        lint_files_with_levels(&files, Some(args.get_load_program_options()), &lint_levels);
     if matches.get_count("emit_warning") > 0 {
         warning_handler.emit()?;
     }
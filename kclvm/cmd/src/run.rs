@@ -2,19 +2,31 @@ use anyhow::Result;
 use clap::ArgMatches;
 use kclvm_error::StringError;
 use kclvm_parser::ParseSession;
-use kclvm_runner::exec_program;
+use kclvm_runner::{emit::EmitKind, exec_program, ExecProgramArgs};
 use std::io::Write;
 use std::sync::Arc;
 
 use crate::settings::must_build_settings;
+use crate::util::{string_from_matches, strings_from_matches};
 
 /// Run the KCL run command.
 pub fn run_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
     // Config settings building
     let settings = must_build_settings(matches);
     let output = settings.output();
+    let mut args: ExecProgramArgs = settings.try_into()?;
+    if let Some(backend) = string_from_matches(matches, "backend") {
+        args.backend = backend.parse()?;
+    }
+    args.artifact = string_from_matches(matches, "artifact");
+    args.emit = strings_from_matches(matches, "emit")
+        .unwrap_or_default()
+        .iter()
+        .map(|kind| kind.parse())
+        .collect::<Result<Vec<EmitKind>, _>>()?;
+    args.emit_dir = string_from_matches(matches, "emit_dir");
     let sess = Arc::new(ParseSession::default());
-    match exec_program(sess.clone(), &settings.try_into()?) {
+    match exec_program(sess.clone(), &args) {
         Ok(result) => match output {
             Some(o) => {
                 std::fs::write(o, result.yaml_result)?;
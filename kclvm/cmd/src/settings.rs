@@ -26,14 +26,13 @@ pub(crate) fn must_build_settings(matches: &ArgMatches) -> SettingsPathBuf {
 
 /// Build settings from arg matches.
 pub(crate) fn build_settings(matches: &ArgMatches) -> Result<SettingsPathBuf> {
-    let files: Vec<&str> = match matches.get_many::<String>("input") {
-        Some(files) => files.into_iter().map(|f| f.as_str()).collect::<Vec<&str>>(),
-        None => vec![],
-    };
+    let files = strings_from_matches(matches, "input").unwrap_or_default();
+    let files = files.iter().map(|f| f.as_str()).collect::<Vec<&str>>();
 
-    let setting_files = matches
-        .get_many::<String>("setting")
-        .map(|files| files.into_iter().map(|f| f.as_str()).collect::<Vec<&str>>());
+    let setting_files = strings_from_matches(matches, "setting");
+    let setting_files = setting_files
+        .as_ref()
+        .map(|files| files.iter().map(|f| f.as_str()).collect::<Vec<&str>>());
 
     let arguments = strings_from_matches(matches, "arguments");
 
@@ -44,10 +43,11 @@ pub(crate) fn build_settings(matches: &ArgMatches) -> Result<SettingsPathBuf> {
         setting_files,
         Some(SettingsFile {
             kcl_cli_configs: Some(Config {
-                output: matches.get_one::<String>("output").map(|v| v.to_string()),
+                output: string_from_matches(matches, "output"),
                 overrides: strings_from_matches(matches, "overrides"),
                 path_selector: strings_from_matches(matches, "path_selector"),
                 strict_range_check: bool_from_matches(matches, "strict_range_check"),
+                strict_type_check: bool_from_matches(matches, "strict_type_check"),
                 disable_none: bool_from_matches(matches, "disable_none"),
                 verbose: u32_from_matches(matches, "verbose"),
                 debug: bool_from_matches(matches, "debug"),
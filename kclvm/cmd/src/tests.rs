@@ -7,8 +7,8 @@ use std::{
 use kclvm_config::modfile::KCL_PKG_PATH;
 
 use crate::{
-    app, fmt::fmt_command, run::run_command, settings::build_settings, util::hashmaps_from_matches,
-    vet::vet_command,
+    app, clean::clean_command, fmt::fmt_command, run::run_command, settings::build_settings,
+    util::hashmaps_from_matches, vet::vet_command,
 };
 
 #[cfg(unix)]
@@ -61,6 +61,41 @@ fn test_build_settings_fail() {
     assert!(build_settings(matches).is_err());
 }
 
+#[test]
+fn test_build_cmd_settings() {
+    let matches =
+        app().get_matches_from(&[ROOT_CMD, "build", "hello.k", "-o", "app.so", "--executable"]);
+    let matches = matches.subcommand_matches("build").unwrap();
+    assert!(matches.get_flag("executable"));
+    let s = build_settings(matches).unwrap();
+    assert_eq!(s.output(), Some("app.so".to_string()));
+    assert_eq!(s.settings().input(), vec!["hello.k".to_string()]);
+
+    let matches =
+        app().get_matches_from(&[ROOT_CMD, "run", "--artifact", "app.so", "-D", "name=app"]);
+    let matches = matches.subcommand_matches("run").unwrap();
+    assert_eq!(
+        matches.get_one::<String>("artifact"),
+        Some(&"app.so".to_string())
+    );
+    let s = build_settings(matches).unwrap();
+    assert!(s.settings().input().is_empty());
+    assert_eq!(s.settings().kcl_options.as_ref().unwrap().len(), 1);
+}
+
+#[test]
+fn test_run_cmd_backend() {
+    let matches = app().get_matches_from(&[ROOT_CMD, "run", "hello.k", "--backend", "evaluator"]);
+    let matches = matches.subcommand_matches("run").unwrap();
+    assert_eq!(
+        matches.get_one::<String>("backend"),
+        Some(&"evaluator".to_string())
+    );
+    assert!(app()
+        .try_get_matches_from(&[ROOT_CMD, "run", "hello.k", "--backend", "python"])
+        .is_err());
+}
+
 #[test]
 fn test_fmt_cmd() {
     let input = std::path::Path::new(".")
@@ -73,6 +108,19 @@ fn test_fmt_cmd() {
     assert!(fmt_command(&matches).is_ok())
 }
 
+#[test]
+fn test_clean_cmd() {
+    let root = env::temp_dir().join(format!("kclvm_test_clean_cmd_{}", std::process::id()));
+    let cache_dir = root.join(".kclvm").join("cache");
+    fs::create_dir_all(&cache_dir).unwrap();
+    fs::write(root.join("kcl.mod"), "").unwrap();
+    let matches = app().get_matches_from(&[ROOT_CMD, "clean", root.to_str().unwrap()]);
+    let matches = matches.subcommand_matches("clean").unwrap();
+    assert!(clean_command(&matches).is_ok());
+    assert!(!cache_dir.exists());
+    fs::remove_dir_all(root).unwrap();
+}
+
 fn test_vet_cmd() {
     let test_path = std::path::Path::new(".")
         .join("src")
@@ -203,6 +251,7 @@ fn test_run_command() {
     test_run_command_with_konfig();
     test_load_cache_with_different_pkg();
     test_kcl_path_is_sym_link();
+    test_run_command_with_emit();
 }
 
 fn test_run_command_with_import() {
@@ -237,6 +286,36 @@ fn test_run_command_with_konfig() {
     }
 }
 
+fn test_run_command_with_emit() {
+    let temp_dir = env::temp_dir().join(format!("kclvm_test_run_emit_{}", std::process::id()));
+    let input = Path::new(".")
+        .join("src")
+        .join("test_data")
+        .join("fmt")
+        .join("test.k");
+    let matches = app().get_matches_from(&[
+        ROOT_CMD,
+        "run",
+        input.to_str().unwrap(),
+        "--emit",
+        "ast",
+        "--emit_dir",
+        temp_dir.to_str().unwrap(),
+    ]);
+    let matches = matches.subcommand_matches("run").unwrap();
+    let mut buf = Vec::new();
+    run_command(matches, &mut buf).unwrap();
+    assert!(temp_dir.join("__main__.ast.json").exists());
+    fs::remove_dir_all(temp_dir).unwrap();
+
+    assert!(app()
+        .try_get_matches_from(&[ROOT_CMD, "run", "--emit", "llvm"])
+        .is_err());
+    assert!(app()
+        .try_get_matches_from(&[ROOT_CMD, "run", "--emit", "resolved-ast"])
+        .is_err());
+}
+
 fn test_load_cache_with_different_pkg() {
     let main_path = PathBuf::from("./src/test_data/cache/main/main.k");
     let main_v1_path = PathBuf::from("./src/test_data/cache/main/main.k.v1");
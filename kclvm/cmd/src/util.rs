@@ -2,14 +2,21 @@ use anyhow::Result;
 use clap::ArgMatches;
 use std::collections::HashMap;
 
+// The arguments not defined in the subcommand are treated as unset, so that the
+// subcommands can share the settings building with a subset of the `run` arguments.
+
 #[inline]
 pub(crate) fn strings_from_matches(matches: &ArgMatches, key: &str) -> Option<Vec<String>> {
-    matches.get_many::<String>(key).map(|files| {
-        files
-            .into_iter()
-            .map(|v| v.to_string())
-            .collect::<Vec<String>>()
-    })
+    matches
+        .try_get_many::<String>(key)
+        .ok()
+        .flatten()
+        .map(|files| {
+            files
+                .into_iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<String>>()
+        })
 }
 
 #[inline]
@@ -17,32 +24,40 @@ pub(crate) fn hashmaps_from_matches(
     matches: &ArgMatches,
     key: &str,
 ) -> Option<Result<HashMap<String, String>>> {
-    matches.get_many::<String>(key).map(|files| {
-        files
-            .into_iter()
-            .map(|s| {
-                let split_values = s.split('=').collect::<Vec<&str>>();
-                if split_values.len() == 2
-                    && !split_values[0].trim().is_empty()
-                    && !split_values[1].trim().is_empty()
-                {
-                    Ok((split_values[0].to_string(), split_values[1].to_string()))
-                } else {
-                    Err(anyhow::anyhow!("Invalid value for top level arguments"))
-                }
-            })
-            .collect::<Result<HashMap<String, String>>>()
-    })
+    matches
+        .try_get_many::<String>(key)
+        .ok()
+        .flatten()
+        .map(|files| {
+            files
+                .into_iter()
+                .map(|s| {
+                    let split_values = s.split('=').collect::<Vec<&str>>();
+                    if split_values.len() == 2
+                        && !split_values[0].trim().is_empty()
+                        && !split_values[1].trim().is_empty()
+                    {
+                        Ok((split_values[0].to_string(), split_values[1].to_string()))
+                    } else {
+                        Err(anyhow::anyhow!("Invalid value for top level arguments"))
+                    }
+                })
+                .collect::<Result<HashMap<String, String>>>()
+        })
 }
 
 #[inline]
 pub(crate) fn string_from_matches(matches: &ArgMatches, key: &str) -> Option<String> {
-    matches.get_one::<String>(key).map(|v| v.to_string())
+    matches
+        .try_get_one::<String>(key)
+        .ok()
+        .flatten()
+        .map(|v| v.to_string())
 }
 
 #[inline]
 pub(crate) fn bool_from_matches(matches: &ArgMatches, key: &str) -> Option<bool> {
-    if matches.get_flag(key) == true {
+    if matches.try_get_one::<bool>(key).ok().flatten() == Some(&true) {
         Some(true)
     } else {
         None
@@ -51,7 +66,12 @@ pub(crate) fn bool_from_matches(matches: &ArgMatches, key: &str) -> Option<bool>
 
 #[inline]
 pub(crate) fn u32_from_matches(matches: &ArgMatches, key: &str) -> Option<u32> {
-    let occurrences = matches.get_count(key);
+    let occurrences = matches
+        .try_get_one::<u8>(key)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or_default();
     if occurrences > 0 {
         Some(occurrences as u32)
     } else {
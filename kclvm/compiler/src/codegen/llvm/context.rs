@@ -29,7 +29,7 @@ use kclvm_sema::builtin;
 use kclvm_sema::plugin;
 
 use crate::codegen::abi::Align;
-use crate::codegen::{error as kcl_error, EmitOptions, INNER_LEVEL};
+use crate::codegen::{error as kcl_error, EmitFileType, EmitOptions, INNER_LEVEL};
 use crate::codegen::{
     traits::*, ENTRY_NAME, GLOBAL_VAL_ALIGNMENT, KCL_CONTEXT_VAR_NAME, MODULE_NAME,
     PKG_INIT_FUNCTION_SUFFIX,
@@ -38,7 +38,7 @@ use crate::codegen::{CodeGenContext, GLOBAL_LEVEL};
 use crate::pkgpath_without_prefix;
 use crate::value;
 
-use super::OBJECT_FILE_SUFFIX;
+use super::emit_file_suffix;
 
 /// SCALAR_KEY denotes the temp scalar key for the global variable json plan process.
 const SCALAR_KEY: &str = "";
@@ -1246,20 +1246,43 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
         if let Some(path_str) = &opt.emit_path {
             let path = std::path::Path::new(&path_str);
             if opt.no_link {
+                let suffix = emit_file_suffix(opt.file_type);
                 let modules = self.modules.borrow_mut();
                 for (index, (_, module)) in modules.iter().enumerate() {
                     let path = if modules.len() == 1 {
-                        format!("{}{}", path_str, OBJECT_FILE_SUFFIX)
+                        format!("{}{}", path_str, suffix)
                     } else {
-                        format!("{}_{}{}", path_str, index, OBJECT_FILE_SUFFIX)
+                        format!("{}_{}{}", path_str, index, suffix)
                     };
                     let path = std::path::Path::new(&path);
-                    // Build LLVM module to a `.o` object file.
-                    self.build_object_file(&module.borrow(), path)?;
+                    self.emit_module_file(&module.borrow(), path, opt.file_type)?;
                 }
             } else {
-                // Build LLVM module to a `.o` object file.
-                self.build_object_file(&self.module, path)?;
+                self.emit_module_file(&self.module, path, opt.file_type)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Emit LLVM module to the file of the file type.
+    fn emit_module_file(
+        self: &LLVMCodeGenContext<'ctx>,
+        module: &Module,
+        path: &Path,
+        file_type: EmitFileType,
+    ) -> Result<(), Box<dyn Error>> {
+        match file_type {
+            // Build LLVM module to a `.o` object file.
+            EmitFileType::Object => self.build_object_file(module, path)?,
+            // Print LLVM module to a `.ll` IR text file.
+            EmitFileType::LLVMIR => module.print_to_file(path)?,
+            // Write LLVM module to a `.bc` bitcode file.
+            EmitFileType::LLVMBitcode => {
+                if !module.write_bitcode_to_path(path) {
+                    return Err(
+                        format!("failed to write the bitcode file {}", path.display()).into(),
+                    );
+                }
             }
         }
         Ok(())
@@ -14,6 +14,8 @@ mod utils;
 
 pub use emit::emit_code;
 
+use super::EmitFileType;
+
 /// Object file type format suffix.
 #[cfg(target_os = "windows")]
 pub const OBJECT_FILE_SUFFIX: &str = ".obj";
@@ -21,3 +23,14 @@ pub const OBJECT_FILE_SUFFIX: &str = ".obj";
 pub const OBJECT_FILE_SUFFIX: &str = ".o";
 /// LLVM IR text format suffix .ll
 pub const LL_FILE_SUFFIX: &str = ".ll";
+/// LLVM bitcode format suffix .bc
+pub const BC_FILE_SUFFIX: &str = ".bc";
+
+/// Returns the file suffix of the emitted file type.
+pub fn emit_file_suffix(file_type: EmitFileType) -> &'static str {
+    match file_type {
+        EmitFileType::Object => OBJECT_FILE_SUFFIX,
+        EmitFileType::LLVMIR => LL_FILE_SUFFIX,
+        EmitFileType::LLVMBitcode => BC_FILE_SUFFIX,
+    }
+}
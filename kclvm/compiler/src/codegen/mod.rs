@@ -38,6 +38,20 @@ pub struct EmitOptions<'a> {
     pub emit_path: Option<&'a str>,
     /// no_link indicates whether to link the generated code of different KCL packages to the same module.
     pub no_link: bool,
+    /// The type of the emitted file, which is the native object file by default.
+    pub file_type: EmitFileType,
+}
+
+/// EmitFileType represents the file types which the code generator can emit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EmitFileType {
+    /// Native object file.
+    #[default]
+    Object,
+    /// LLVM IR text file.
+    LLVMIR,
+    /// LLVM bitcode file.
+    LLVMBitcode,
 }
 
 /// Emit code with the options using CodeGenContext.
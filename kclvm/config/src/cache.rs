@@ -1,16 +1,17 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
 extern crate chrono;
-use super::modfile::KCL_FILE_SUFFIX;
+use super::modfile::{KCLModFile, KCL_FILE_SUFFIX, KCL_MOD_FILE};
 use crypto::digest::Digest;
 use crypto::md5::Md5;
 use fslock::LockFile;
 use kclvm_utils::pkgpath::parse_external_pkg_name;
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
 use std::error;
 use std::fs::{create_dir_all, File};
-use std::io::{Read, Write};
-use std::path::Path;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
 
 use kclvm_version as version;
 
@@ -18,6 +19,10 @@ const LOCK_SUFFIX: &str = ".lock";
 const DEFAULT_CACHE_DIR: &str = ".kclvm/cache";
 const CACHE_INFO_FILENAME: &str = "info";
 const KCL_SUFFIX_PATTERN: &str = "*.k";
+const PKG_CACHE_DIR: &str = "pkg";
+const TMP_SUFFIX: &str = ".tmp";
+/// The default size limit of the package cache in megabytes.
+pub const DEFAULT_PKG_CACHE_SIZE_LIMIT: u64 = 512;
 
 pub type CacheInfo = String;
 pub type Cache = HashMap<String, CacheInfo>;
@@ -35,6 +40,308 @@ impl Default for CacheOption {
     }
 }
 
+/// The cache of the parsed and resolved packages, which is stored in
+/// `<root>/.kclvm/cache/<version>/pkg` and enabled by `enable_pkg_cache = true` in the
+/// `[build]` section of `kcl.mod`.
+///
+/// Different from [`load_pkg_cache`] and [`save_pkg_cache`] which check the modified time
+/// of the package files, there is one entry for each kind of the package data, which stores
+/// the hash of the compiler version, the build settings and the package source files in the
+/// [`PkgCacheKey`] it is saved with. An entry is removed when it is loaded with a different
+/// hash or its content does not match the checksum, and the least recently written entries
+/// are removed when the size of the cache exceeds `pkg_cache_size_limit`.
+#[derive(Debug, Clone)]
+pub struct PkgCache {
+    dir: PathBuf,
+    build_settings: String,
+    cached_pkg_prefix: Option<String>,
+    size_limit: u64,
+}
+
+/// The key of the package data in the [`PkgCache`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PkgCacheKey {
+    /// The entry name, which is the hash of the data kind and the package path.
+    name: String,
+    /// The hash of all the inputs the package data depends on.
+    pub hash: String,
+}
+
+/// The first line of a package cache entry, which is followed by the JSON package data.
+#[derive(Debug, Serialize, Deserialize)]
+struct PkgCacheHeader {
+    /// The hash of the key the entry is saved with.
+    hash: String,
+    /// The md5 checksum of the package data.
+    checksum: String,
+}
+
+impl PkgCache {
+    /// Returns the package cache of the program root, or `None` if the package cache is
+    /// not enabled in the `kcl.mod` file of the root.
+    pub fn new(root: &str) -> Option<Self> {
+        if root.is_empty() {
+            return None;
+        }
+        // An invalid `kcl.mod` file only disables the package cache here.
+        let content = std::fs::read_to_string(Path::new(root).join(KCL_MOD_FILE)).ok()?;
+        let build = toml::from_str::<KCLModFile>(&content).ok()?.build?;
+        if !build.enable_pkg_cache.unwrap_or_default() {
+            return None;
+        }
+        Some(Self {
+            dir: Path::new(&get_cache_dir(root, None)).join(PKG_CACHE_DIR),
+            build_settings: serde_json::to_string(&build).ok()?,
+            cached_pkg_prefix: build.cached_pkg_prefix,
+            size_limit: build
+                .pkg_cache_size_limit
+                .unwrap_or(DEFAULT_PKG_CACHE_SIZE_LIMIT)
+                * 1024
+                * 1024,
+        })
+    }
+
+    /// Whether the package can be cached, only the packages with `cached_pkg_prefix` in
+    /// `kcl.mod` are cached if it is set.
+    pub fn is_cached_pkg(&self, pkgpath: &str) -> bool {
+        match &self.cached_pkg_prefix {
+            Some(prefix) => pkgpath.starts_with(prefix.as_str()),
+            None => true,
+        }
+    }
+
+    /// Returns the cache key of the package data of the kind, e.g., the AST or the scope.
+    /// `sources` are the filenames and the source code of the package files, and `deps`
+    /// are the other inputs the data depends on, e.g., the key hashes of the imported packages.
+    pub fn key(
+        &self,
+        kind: &str,
+        pkgpath: &str,
+        sources: &[(String, String)],
+        deps: &[String],
+    ) -> PkgCacheKey {
+        pkg_cache_key(&self.build_settings, kind, pkgpath, sources, deps)
+    }
+
+    /// Load the package data of the key, `None` is returned if the entry does not exist.
+    /// The entry is removed if it is saved with a different key hash, or its content is
+    /// corrupted or can not be deserialized, e.g., it is too deeply nested.
+    pub fn load<T>(&self, key: &PkgCacheKey) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        let path = self.entry_path(key);
+        let content = std::fs::read_to_string(&path).ok()?;
+        let data = content.split_once('\n').and_then(|(header, data)| {
+            let header = serde_json::from_str::<PkgCacheHeader>(header).ok()?;
+            if header.hash != key.hash || header.checksum != md5_of(&[data]) {
+                return None;
+            }
+            serde_json::from_str(data).ok()
+        });
+        if data.is_none() {
+            // The entry is saved again after the package is parsed or resolved, and a
+            // failure to remove it only makes it checked again.
+            let _ = self.remove_entry(&path);
+        }
+        data
+    }
+
+    /// Save the package data with the key, which replaces the former entry of the package,
+    /// and remove the least recently written entries if the size of the cache exceeds the limit.
+    pub fn save<T>(&self, key: &PkgCacheKey, data: &T) -> anyhow::Result<()>
+    where
+        T: Serialize,
+    {
+        create_dir_all(&self.dir)?;
+        let dst_filename = self.entry_path(key);
+        let tmp_filename = temp_file(&self.dir.display().to_string(), &key.name);
+        let mut lock_file = LockFile::open(&format!("{}{}", dst_filename.display(), LOCK_SUFFIX))?;
+        lock_file.lock()?;
+        let result = save_pkg_cache_entry(&dst_filename, Path::new(&tmp_filename), key, data);
+        lock_file.unlock()?;
+        if result.is_err() {
+            let _ = std::fs::remove_file(&tmp_filename);
+        }
+        result?;
+        self.evict()
+    }
+
+    /// Returns the path of the package cache entry of the key.
+    pub(crate) fn entry_path(&self, key: &PkgCacheKey) -> PathBuf {
+        self.dir.join(&key.name)
+    }
+
+    /// Remove the package cache entry and its lock file.
+    fn remove_entry(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::remove_file(path)?;
+        let _ = std::fs::remove_file(format!("{}{}", path.display(), LOCK_SUFFIX));
+        Ok(())
+    }
+
+    /// Remove the least recently written entries until the size of the cache is
+    /// not greater than the limit.
+    fn evict(&self) -> anyhow::Result<()> {
+        let mut entries = vec![];
+        let mut size = 0;
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let name = path.display().to_string();
+            if name.ends_with(LOCK_SUFFIX) || name.ends_with(TMP_SUFFIX) {
+                continue;
+            }
+            let metadata = std::fs::metadata(&path)?;
+            size += metadata.len();
+            entries.push((metadata.modified()?, metadata.len(), path));
+        }
+        if size <= self.size_limit {
+            return Ok(());
+        }
+        entries.sort_by_key(|entry| entry.0);
+        for (_, len, path) in entries {
+            if size <= self.size_limit {
+                break;
+            }
+            self.remove_entry(&path)?;
+            size -= len;
+        }
+        Ok(())
+    }
+}
+
+/// The package data cached in memory, which can be shared by multiple compiles in one
+/// process, e.g., the requests of the language server, and is always enabled.
+///
+/// There is one entry for each kind of the package data like the [`PkgCache`], and the data
+/// is stored as JSON, so the data built from `Rc`, e.g., the package scopes, can be loaded
+/// by the other threads. An entry is replaced when the package is saved with a different key
+/// hash, e.g., after one of the package files or the imported packages changes.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryPkgCache {
+    /// The key hashes and the JSON package data by the entry names.
+    entries: Arc<RwLock<HashMap<String, (String, String)>>>,
+}
+
+impl MemoryPkgCache {
+    /// Returns the cache key of the package data of the kind, which is the same as
+    /// [`PkgCache::key`] without the build settings of `kcl.mod`.
+    pub fn key(
+        &self,
+        kind: &str,
+        pkgpath: &str,
+        sources: &[(String, String)],
+        deps: &[String],
+    ) -> PkgCacheKey {
+        pkg_cache_key("", kind, pkgpath, sources, deps)
+    }
+
+    /// Load the package data of the key, `None` is returned if the entry does not exist
+    /// or it is saved with a different key hash.
+    pub fn load<T>(&self, key: &PkgCacheKey) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        let entries = self.entries.read().ok()?;
+        let (hash, data) = entries.get(&key.name)?;
+        if *hash != key.hash {
+            return None;
+        }
+        serde_json::from_str(data).ok()
+    }
+
+    /// Save the package data with the key, which replaces the former entry of the package.
+    pub fn save<T>(&self, key: &PkgCacheKey, data: &T) -> anyhow::Result<()>
+    where
+        T: Serialize,
+    {
+        let data = serde_json::to_string(data)?;
+        self.entries
+            .write()
+            .map_err(|e| anyhow::anyhow!("{e}"))?
+            .insert(key.name.clone(), (key.hash.clone(), data));
+        Ok(())
+    }
+
+    /// Remove all the entries, e.g., when the packages may be loaded from other files.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        }
+    }
+}
+
+/// Returns the key of the package data, whose hash depends on the compiler version, the
+/// build settings, the package sources and the other inputs of the package data.
+fn pkg_cache_key(
+    build_settings: &str,
+    kind: &str,
+    pkgpath: &str,
+    sources: &[(String, String)],
+    deps: &[String],
+) -> PkgCacheKey {
+    let name = md5_of(&[kind, pkgpath]);
+    let mut inputs = vec![
+        version::VERSION,
+        version::CHECK_SUM,
+        build_settings,
+        kind,
+        pkgpath,
+    ];
+    for (filename, code) in sources {
+        inputs.push(filename);
+        inputs.push(code);
+    }
+    inputs.extend(deps.iter().map(|dep| dep.as_str()));
+    PkgCacheKey {
+        name,
+        hash: md5_of(&inputs),
+    }
+}
+
+/// Returns the md5 hash of the inputs.
+fn md5_of(inputs: &[&str]) -> String {
+    let mut md5 = Md5::new();
+    for input in inputs {
+        // Prefix the length to avoid the ambiguous concatenations.
+        md5.input_str(&format!("{}:", input.len()));
+        md5.input_str(input);
+    }
+    md5.result_str()
+}
+
+fn save_pkg_cache_entry<T>(
+    dst_filename: &Path,
+    tmp_filename: &Path,
+    key: &PkgCacheKey,
+    data: &T,
+) -> anyhow::Result<()>
+where
+    T: Serialize,
+{
+    let data = serde_json::to_string(data)?;
+    let header = PkgCacheHeader {
+        hash: key.hash.clone(),
+        checksum: md5_of(&[&data]),
+    };
+    let mut writer = BufWriter::new(File::create(tmp_filename)?);
+    serde_json::to_writer(&mut writer, &header)?;
+    writer.write_all(b"\n")?;
+    writer.write_all(data.as_bytes())?;
+    writer.flush()?;
+    std::fs::rename(tmp_filename, dst_filename)?;
+    Ok(())
+}
+
+/// Remove all the caches of the program root, including the package caches
+/// and the lib caches.
+pub fn clean_cache(root: &str) -> anyhow::Result<()> {
+    let cache_dir = Path::new(root).join(DEFAULT_CACHE_DIR);
+    if cache_dir.exists() {
+        std::fs::remove_dir_all(cache_dir)?;
+    }
+    Ok(())
+}
+
 /// Load pkg cache.
 pub fn load_pkg_cache<T>(
     root: &str,
@@ -159,9 +466,11 @@ pub fn write_info_cache(
     create_dir_all(path).unwrap();
     let relative_path = filepath.replacen(root, ".", 1);
     let cache_info = get_cache_info(filepath);
-    let tmp_filename = temp_file(&cache_dir, "");
     let mut lock_file = LockFile::open(&format!("{}{}", dst_filename, LOCK_SUFFIX)).unwrap();
     lock_file.lock().unwrap();
+    // Create the temp file name after locking, because the info cache file
+    // may be written by multiple threads of the same process concurrently.
+    let tmp_filename = temp_file(&cache_dir, "");
     let mut cache = read_info_cache(root, target, cache_name);
     cache.insert(relative_path, cache_info);
     let mut file = File::create(&tmp_filename).unwrap();
@@ -235,7 +544,7 @@ fn temp_file(cache_dir: &str, pkgpath: &str) -> String {
     let timestamp = chrono::Local::now().timestamp_nanos();
     let id = std::process::id();
     Path::new(cache_dir)
-        .join(format!("{}.{}.{}.tmp", pkgpath, id, timestamp))
+        .join(format!("{}.{}.{}{}", pkgpath, id, timestamp, TMP_SUFFIX))
         .display()
         .to_string()
 }
@@ -1,8 +1,8 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
 
 use kclvm_utils::path::PathPrefix;
-use serde::Deserialize;
-use std::{env, fs, io::Read, path::PathBuf};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, env, fs, io::Read, path::PathBuf};
 use toml;
 
 pub const KCL_MOD_FILE: &str = "kcl.mod";
@@ -61,13 +61,19 @@ pub struct KCLModFile {
     pub root_pkg: Option<String>,
     pub build: Option<KCLModFileBuildSection>,
     pub expected: Option<KCLModFileExpectedSection>,
+    /// The lint levels by the lint names, e.g., `unused_import = "allow"`.
+    pub lint: Option<HashMap<String, String>>,
 }
 
 #[allow(dead_code)]
-#[derive(Default, Deserialize)]
+#[derive(Default, Deserialize, Serialize)]
 pub struct KCLModFileBuildSection {
+    /// Whether to cache the parsed and resolved packages, see [`crate::cache::PkgCache`].
     pub enable_pkg_cache: Option<bool>,
+    /// Only the packages with the package path prefix are cached if it is set.
     pub cached_pkg_prefix: Option<String>,
+    /// The size limit of the package cache in megabytes.
+    pub pkg_cache_size_limit: Option<u64>,
     pub target: Option<String>,
 }
 
@@ -147,6 +153,30 @@ pub fn load_mod_file(root: &str) -> KCLModFile {
     toml::from_slice(buffer.as_slice()).unwrap()
 }
 
+/// Load the lint levels in the `[lint]` section of a TOML file, e.g., the lint config file
+/// of the `kclvm_cli lint --lint-config` flag.
+pub fn load_lint_config_file(path: &str) -> anyhow::Result<HashMap<String, String>> {
+    let content = std::fs::read_to_string(path)?;
+    let mod_file: KCLModFile = toml::from_str(&content)?;
+    Ok(mod_file.lint.unwrap_or_default())
+}
+
+/// Load the lint levels in the `[lint]` section of the `kcl.mod` file in the package root `root`,
+/// the lint levels are empty when the `kcl.mod` file does not exist.
+pub fn load_mod_lint_levels(root: &str) -> anyhow::Result<HashMap<String, String>> {
+    let k_mod_file_path = std::path::Path::new(root).join(KCL_MOD_FILE);
+    if !k_mod_file_path.exists() {
+        return Ok(HashMap::new());
+    }
+    load_lint_config_file(&k_mod_file_path.to_string_lossy()).map_err(|err| {
+        anyhow::anyhow!(
+            "failed to load the lint levels of {}: {}",
+            k_mod_file_path.display(),
+            err
+        )
+    })
+}
+
 #[cfg(test)]
 mod modfile_test {
     use crate::modfile::*;
@@ -215,5 +245,28 @@ mod modfile_test {
                 .unwrap(),
             "v0.2.0"
         );
+        let lint = kcl_mod.lint.as_ref().unwrap();
+        assert_eq!(lint.get("unused_import").unwrap(), "allow");
+        assert_eq!(lint.get("mutable_default").unwrap(), "deny");
+    }
+
+    #[test]
+    fn test_load_lint_config_file() {
+        let lint = load_lint_config_file(SETTINGS_FILE).unwrap();
+        assert_eq!(lint.len(), 2);
+        assert!(load_lint_config_file("./src/testdata/not_exist.toml").is_err());
+    }
+
+    #[test]
+    fn test_load_mod_lint_levels() {
+        let lint = load_mod_lint_levels(TEST_ROOT).unwrap();
+        assert_eq!(lint.get("mutable_default").unwrap(), "deny");
+        assert!(load_mod_lint_levels("./src/testdata/not_exist")
+            .unwrap()
+            .is_empty());
+        let err = load_mod_lint_levels("./src/testdata/invalid_lint").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("failed to load the lint levels of"));
     }
 }
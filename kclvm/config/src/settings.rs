@@ -56,6 +56,7 @@ pub struct Config {
     pub overrides: Option<Vec<String>>,
     pub path_selector: Option<Vec<String>>,
     pub strict_range_check: Option<bool>,
+    pub strict_type_check: Option<bool>,
     pub disable_none: Option<bool>,
     pub verbose: Option<u32>,
     pub debug: Option<bool>,
@@ -75,6 +76,7 @@ impl SettingsFile {
                 overrides: Some(vec![]),
                 path_selector: Some(vec![]),
                 strict_range_check: Some(false),
+                strict_type_check: Some(false),
                 disable_none: Some(false),
                 verbose: Some(0),
                 debug: Some(false),
@@ -374,6 +376,7 @@ pub fn merge_settings(settings: &[SettingsFile]) -> SettingsFile {
                 set_if!(result_kcl_cli_configs, overrides, kcl_cli_configs);
                 set_if!(result_kcl_cli_configs, path_selector, kcl_cli_configs);
                 set_if!(result_kcl_cli_configs, strict_range_check, kcl_cli_configs);
+                set_if!(result_kcl_cli_configs, strict_type_check, kcl_cli_configs);
                 set_if!(result_kcl_cli_configs, disable_none, kcl_cli_configs);
                 set_if!(result_kcl_cli_configs, verbose, kcl_cli_configs);
                 set_if!(result_kcl_cli_configs, debug, kcl_cli_configs);
@@ -0,0 +1,2 @@
+[lint
+unused_import = "allow"
@@ -1,6 +1,9 @@
-use std::{env, path::PathBuf};
+use std::{env, fs, path::PathBuf, time::Duration};
 
-use crate::modfile::{get_vendor_home, KCL_PKG_PATH};
+use crate::{
+    cache::{clean_cache, MemoryPkgCache, PkgCache},
+    modfile::{get_vendor_home, KCL_MOD_FILE, KCL_PKG_PATH},
+};
 
 #[test]
 fn test_vendor_home() {
@@ -20,3 +23,90 @@ fn test_vendor_home() {
         .unwrap();
     assert_eq!(get_vendor_home(), kpm_home.display().to_string())
 }
+
+#[test]
+fn test_pkg_cache() {
+    let root = env::temp_dir().join(format!("kclvm_test_pkg_cache_{}", std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+    let root_str = root.display().to_string();
+    // The package cache is not enabled without `kcl.mod`.
+    assert!(PkgCache::new(&root_str).is_none());
+    fs::write(
+        root.join(KCL_MOD_FILE),
+        "[build]\nenable_pkg_cache = true\ncached_pkg_prefix = \"vendor\"\npkg_cache_size_limit = 1\n",
+    )
+    .unwrap();
+    let cache = PkgCache::new(&root_str).unwrap();
+    assert!(cache.is_cached_pkg("vendor.pkg"));
+    assert!(!cache.is_cached_pkg("pkg"));
+
+    let sources = vec![("pkg/main.k".to_string(), "a = 1".to_string())];
+    let key = cache.key("ast", "vendor.pkg", &sources, &[]);
+    assert_eq!(key, cache.key("ast", "vendor.pkg", &sources, &[]));
+    assert_ne!(key, cache.key("scope", "vendor.pkg", &sources, &[]));
+    assert_ne!(
+        key,
+        cache.key("ast", "vendor.pkg", &sources, &[key.hash.clone()])
+    );
+    let changed_sources = vec![("pkg/main.k".to_string(), "a = 2".to_string())];
+    assert_ne!(key, cache.key("ast", "vendor.pkg", &changed_sources, &[]));
+
+    assert_eq!(cache.load::<String>(&key), None);
+    cache.save(&key, &"a".to_string()).unwrap();
+    assert_eq!(cache.load::<String>(&key), Some("a".to_string()));
+
+    // The entry saved with the former sources is removed when the package changes.
+    let changed_key = cache.key("ast", "vendor.pkg", &changed_sources, &[]);
+    assert_eq!(cache.entry_path(&key), cache.entry_path(&changed_key));
+    assert_eq!(cache.load::<String>(&changed_key), None);
+    assert!(!cache.entry_path(&key).exists());
+    assert_eq!(cache.load::<String>(&key), None);
+
+    // The corrupted entry is removed.
+    cache.save(&key, &"a".to_string()).unwrap();
+    let content = fs::read_to_string(cache.entry_path(&key)).unwrap();
+    fs::write(cache.entry_path(&key), content.replace("\"a\"", "\"b\"")).unwrap();
+    assert_eq!(cache.load::<String>(&key), None);
+    assert!(!cache.entry_path(&key).exists());
+    cache.save(&key, &"a".to_string()).unwrap();
+
+    // The least recently written entries are removed when the cache exceeds the size limit.
+    let large_keys = ["vendor.large1", "vendor.large2"]
+        .iter()
+        .map(|pkgpath| cache.key("ast", pkgpath, &sources, &[]))
+        .collect::<Vec<_>>();
+    for large_key in &large_keys {
+        std::thread::sleep(Duration::from_millis(10));
+        cache.save(large_key, &"a".repeat(600 * 1024)).unwrap();
+    }
+    assert_eq!(cache.load::<String>(&key), None);
+    assert_eq!(cache.load::<String>(&large_keys[0]), None);
+    assert!(cache.load::<String>(&large_keys[1]).is_some());
+
+    clean_cache(&root_str).unwrap();
+    assert_eq!(cache.load::<String>(&large_keys[1]), None);
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn test_memory_pkg_cache() {
+    let cache = MemoryPkgCache::default();
+    let sources = vec![("pkg/main.k".to_string(), "a = 1".to_string())];
+    let key = cache.key("scope", "pkg", &sources, &[]);
+    assert_eq!(cache.load::<String>(&key), None);
+    cache.save(&key, &"a".to_string()).unwrap();
+    assert_eq!(cache.load::<String>(&key), Some("a".to_string()));
+    // The clones share the entries, e.g., the requests of the language server.
+    assert_eq!(cache.clone().load::<String>(&key), Some("a".to_string()));
+
+    // The entry saved with the former sources is not loaded when the package changes.
+    let changed_sources = vec![("pkg/main.k".to_string(), "a = 2".to_string())];
+    let changed_key = cache.key("scope", "pkg", &changed_sources, &[]);
+    assert_eq!(cache.load::<String>(&changed_key), None);
+    cache.save(&changed_key, &"b".to_string()).unwrap();
+    assert_eq!(cache.load::<String>(&key), None);
+    assert_eq!(cache.load::<String>(&changed_key), Some("b".to_string()));
+
+    cache.clear();
+    assert_eq!(cache.load::<String>(&changed_key), None);
+}
@@ -1,11 +1,12 @@
 use kclvm_span::Loc;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::Hash;
 
 use crate::{ErrorKind, WarningKind};
 
 /// Diagnostic structure.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Diagnostic {
     pub level: Level,
     pub messages: Vec<Message>,
@@ -17,7 +18,7 @@ pub struct Diagnostic {
 ///
 /// A Position is valid if the line number is > 0.
 /// The line is 1-based and the column is 0-based.
-#[derive(PartialEq, Clone, Eq, Hash, Debug, Default)]
+#[derive(PartialEq, Clone, Eq, Hash, Debug, Default, Serialize, Deserialize)]
 pub struct Position {
     pub filename: String,
     pub line: u64,
@@ -125,7 +126,7 @@ impl Diagnostic {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Message {
     pub pos: Position,
     pub style: Style,
@@ -133,13 +134,13 @@ pub struct Message {
     pub note: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DiagnosticId {
     Error(ErrorKind),
     Warning(WarningKind),
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Level {
     Error,
     Warning,
@@ -165,7 +166,7 @@ impl fmt::Display for Level {
 /// Style indicates the style of error message:
 /// - `LineAndColumn` is <filename>:<line>:<column>
 /// - `Line` is <filename>:<line>
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Style {
     Empty,
     LineAndColumn,
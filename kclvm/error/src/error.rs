@@ -1,6 +1,8 @@
 //! This module is used to gather all error codes into one place,
 //! the goal being to make their maintenance easier.
 
+use serde::{Deserialize, Serialize};
+
 macro_rules! register_errors {
     ($($ecode:ident: $kind:expr, $message:expr,)*) => (
         pub static ERRORS: &[(&str, Error)] = &[
@@ -45,6 +47,7 @@ register_errors! {
     E2A31: ErrorKind::IllegalAttributeError, include_str!("./error_codes/E2A31.md"),
     E2L28: ErrorKind::UniqueKeyError, include_str!("./error_codes/E2L28.md"),
     E2D34: ErrorKind::IllegalInheritError, include_str!("./error_codes/E2D34.md"),
+    E2G23: ErrorKind::VarianceError, include_str!("./error_codes/E2G23.md"),
     E3M38: ErrorKind::EvaluationError, include_str!("./error_codes/E2D34.md"),
 }
 
@@ -52,6 +55,16 @@ register_errors! {
 // new line.
 register_warnings! {
     W1001: WarningKind::CompilerWarning, include_str!("./warning_codes/W1001.md"),
+    W1002: WarningKind::UnusedPrivateVariableWarning, include_str!("./warning_codes/W1002.md"),
+    W1003: WarningKind::UnusedLambdaParameterWarning, include_str!("./warning_codes/W1003.md"),
+    W1004: WarningKind::UnusedSchemaLocalVariableWarning, include_str!("./warning_codes/W1004.md"),
+    W1005: WarningKind::UnusedTypeAliasWarning, include_str!("./warning_codes/W1005.md"),
+    W1006: WarningKind::UnusedSchemaWarning, include_str!("./warning_codes/W1006.md"),
+    W1007: WarningKind::NameShadowingWarning, include_str!("./warning_codes/W1007.md"),
+    W1008: WarningKind::SelfComparisonWarning, include_str!("./warning_codes/W1008.md"),
+    W1009: WarningKind::ConstantConditionWarning, include_str!("./warning_codes/W1009.md"),
+    W1010: WarningKind::DuplicateConfigKeyWarning, include_str!("./warning_codes/W1010.md"),
+    W1011: WarningKind::MutableDefaultWarning, include_str!("./warning_codes/W1011.md"),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -61,7 +74,7 @@ pub struct Error {
     pub message: Option<&'static str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ErrorKind {
     InvalidSyntax,
     TabError,
@@ -96,6 +109,7 @@ pub enum ErrorKind {
     CompileError,
     EvaluationError,
     UniqueKeyError,
+    VarianceError,
 }
 
 impl std::fmt::Display for ErrorKind {
@@ -127,12 +141,22 @@ pub struct Warning {
 }
 
 // Kind of KCL warning.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum WarningKind {
     CompilerWarning,
     UnusedImportWarning,
     ReimportWarning,
     ImportPositionWarning,
+    UnusedPrivateVariableWarning,
+    UnusedLambdaParameterWarning,
+    UnusedSchemaLocalVariableWarning,
+    UnusedTypeAliasWarning,
+    UnusedSchemaWarning,
+    NameShadowingWarning,
+    SelfComparisonWarning,
+    ConstantConditionWarning,
+    DuplicateConfigKeyWarning,
+    MutableDefaultWarning,
 }
 
 /// Test warning `fmt`
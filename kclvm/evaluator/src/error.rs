@@ -0,0 +1,75 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use std::error;
+use std::fmt;
+
+use kclvm_runtime::{ErrType, ValueRef};
+
+pub(crate) const INVALID_OPERATOR_MSG: &str = "Invalid operator";
+pub(crate) const INVALID_JOINED_STR_MSG: &str = "Invalid AST JoinedString value";
+pub(crate) const INVALID_STR_INTERPOLATION_SPEC_MSG: &str =
+    "Invalid string interpolation format specification";
+pub(crate) const INVALID_LOOP_VARIABLES_MSG: &str =
+    "The number of loop variables can only be 1 or 2";
+pub(crate) const INVALID_DECORATOR_NAME_MSG: &str =
+    "Invalid decorator name, expect single identifier";
+pub(crate) const INVALID_SCHEMA_CONFIG_MSG: &str = "Invalid schema config expression";
+pub(crate) const MISSING_EXPR_MSG: &str = "Missing expression";
+
+/// The evaluation result of the AST nodes.
+pub type EvalResult = Result<ValueRef, EvalError>;
+
+/// The error returned on the invalid AST nodes and the names or packages which
+/// can not be found, the KCL runtime errors are still reported by the runtime.
+#[derive(Clone)]
+pub struct EvalError {
+    pub message: String,
+    pub ty: ErrType,
+}
+
+impl EvalError {
+    pub fn new(message: &str) -> Self {
+        Self::with_type(ErrType::EvaluationError_TYPE, message)
+    }
+
+    pub fn with_type(ty: ErrType, message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            ty,
+        }
+    }
+
+    /// The error of a name which is not defined in the package.
+    pub fn name_not_defined(name: &str) -> Self {
+        Self::with_type(
+            ErrType::NameError_TYPE,
+            &format!("name '{}' is not defined", name),
+        )
+    }
+
+    /// The error of a package which is not found in the program.
+    pub fn pkg_not_found(pkgpath: &str) -> Self {
+        Self::with_type(
+            ErrType::CannotFindModule_TYPE,
+            &format!("pkgpath {} is not found", pkgpath),
+        )
+    }
+}
+
+impl fmt::Debug for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EvalError({}, {:?})", self.ty as i32, self.message)
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for EvalError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
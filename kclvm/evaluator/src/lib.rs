@@ -0,0 +1,264 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+//! The tree-walking evaluator runs a resolved KCL program on the KCL runtime
+//! values directly, without the LLVM code generation, the assembler and the linker.
+//!
+//! The evaluator follows the semantics of the LLVM code generator. Schema, rule,
+//! check block, schema attribute and lambda functions are registered as function
+//! proxies which are called back by the runtime, see [`kclvm_runtime::FUNC_PROXY_TAG`].
+
+mod error;
+mod module;
+mod node;
+mod proxy;
+mod schema;
+mod scope;
+mod utils;
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::os::raw::c_char;
+use std::sync::Once;
+
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_ast::walker::TypedResultWalker;
+use kclvm_runtime::{Context, ValueRef, MAIN_PKG_PATH};
+
+use proxy::Proxy;
+use scope::Scope;
+
+pub use error::{EvalError, EvalResult};
+
+pub(crate) const SCHEMA_SELF_NAME: &str = "$schema_self";
+pub(crate) const SCHEMA_CONFIG_NAME: &str = "$schema_config";
+pub(crate) const SCHEMA_CONFIG_META_NAME: &str = "$schema_config_meta";
+pub(crate) const SCHEMA_CAL_MAP: &str = "$schema_cal_map";
+pub(crate) const SCHEMA_ARGS: &str = "$schema_args";
+pub(crate) const SCHEMA_KWARGS: &str = "$schema_kwargs";
+pub(crate) const SCHEMA_RUNTIME_TYPE: &str = "$schema_runtime_type";
+pub(crate) const BACKTRACK_LEVEL_MAP: &str = "$backtrack_level_map";
+pub(crate) const BACKTRACK_CACHE: &str = "$backtrack_cache";
+pub(crate) const LAMBDA_CLOSURE: &str = "$lambda_closure";
+pub(crate) const LAMBDA_NAME: &str = "kclvm_lambda";
+pub(crate) const SCHEMA_VARIABLE_LIST: &[&str] = &[
+    BACKTRACK_CACHE,
+    BACKTRACK_LEVEL_MAP,
+    SCHEMA_CAL_MAP,
+    SCHEMA_CONFIG_NAME,
+    SCHEMA_CONFIG_META_NAME,
+    SCHEMA_SELF_NAME,
+    SCHEMA_ARGS,
+    SCHEMA_KWARGS,
+    SCHEMA_RUNTIME_TYPE,
+];
+/// Scope 0 is the builtin scope and scope 1 is the global scope.
+pub(crate) const GLOBAL_LEVEL: usize = 1;
+pub(crate) const INNER_LEVEL: usize = 2;
+pub(crate) const SCALAR_KEY: &str = "";
+pub(crate) const INTERNAL_ERROR_MSG: &str = "Internal error, please report a bug to us";
+
+macro_rules! check_backtrack_stop {
+    ($evaluator: expr) => {
+        if let Some(backtrack_meta) = $evaluator.backtrack_meta.borrow().as_ref() {
+            if backtrack_meta.stop {
+                return Ok(kclvm_runtime::ValueRef::undefined());
+            }
+        }
+    };
+}
+pub(crate) use check_backtrack_stop;
+
+/// The evaluator runtime options, which are the same as the arguments of `_kcl_run`.
+#[derive(Debug, Default, Clone)]
+pub struct EvalOptions {
+    /// Top level arguments, e.g., `-D key=value`.
+    pub args: Vec<(String, String)>,
+    pub strict_range_check: bool,
+    pub disable_none: bool,
+    pub debug_mode: bool,
+    /// The plugin agent function address, 0 denotes no plugin agent.
+    pub plugin_agent: u64,
+}
+
+/// The backtrack meta of a schema attribute function.
+#[derive(Debug, Clone)]
+pub(crate) struct BacktrackMeta {
+    pub target: String,
+    pub level: usize,
+    pub count: usize,
+    pub stop: bool,
+}
+
+/// The evaluator context which walks the resolved AST.
+pub struct Evaluator<'ctx> {
+    pub program: &'ctx ast::Program,
+    pub import_names: IndexMap<String, IndexMap<String, String>>,
+    pub(crate) pkg_scopes: RefCell<HashMap<String, Vec<Scope>>>,
+    pub(crate) pkgpath_stack: RefCell<Vec<String>>,
+    pub(crate) filename_stack: RefCell<Vec<String>>,
+    /// Runtime types of the schemas being evaluated.
+    pub(crate) schema_stack: RefCell<Vec<String>>,
+    pub(crate) schema_expr_depth: Cell<usize>,
+    pub(crate) lambda_stack: RefCell<Vec<bool>>,
+    pub(crate) local_vars: RefCell<HashSet<String>>,
+    pub(crate) target_vars: RefCell<Vec<String>>,
+    pub(crate) imported: RefCell<HashSet<String>>,
+    pub(crate) backtrack_meta: RefCell<Option<BacktrackMeta>>,
+    pub(crate) current_line: Cell<u64>,
+    pub(crate) proxies: RefCell<Vec<Proxy<'ctx>>>,
+    /// The function pointers of the schema, rule and lambda nodes keyed by the node address.
+    pub(crate) function_cache: RefCell<HashMap<usize, (u64, u64)>>,
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    /// New an evaluator using the resolved program and its import names.
+    pub fn new(
+        program: &'ctx ast::Program,
+        import_names: IndexMap<String, IndexMap<String, String>>,
+    ) -> Self {
+        Self {
+            program,
+            import_names,
+            pkg_scopes: RefCell::new(HashMap::new()),
+            pkgpath_stack: RefCell::new(vec![MAIN_PKG_PATH.to_string()]),
+            filename_stack: RefCell::new(vec!["".to_string()]),
+            schema_stack: RefCell::new(vec![]),
+            schema_expr_depth: Cell::new(0),
+            lambda_stack: RefCell::new(vec![false]),
+            local_vars: RefCell::new(HashSet::new()),
+            target_vars: RefCell::new(vec!["".to_string()]),
+            imported: RefCell::new(HashSet::new()),
+            backtrack_meta: RefCell::new(None),
+            current_line: Cell::new(0),
+            proxies: RefCell::new(vec![]),
+            function_cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Evaluate the main package and return the JSON plan string of the global values.
+    pub fn run(&self) -> EvalResult {
+        if !self.import_names.is_empty() {
+            let mut import_names = ValueRef::dict(None);
+            for (file, names) in &self.import_names {
+                let mut names_value = ValueRef::dict(None);
+                for (name, pkgpath) in names {
+                    names_value
+                        .dict_update_key_value(name, ValueRef::str(&format!("@{}", pkgpath)));
+                }
+                import_names.dict_update_key_value(file, names_value);
+            }
+            utils::context_set_import_names(&import_names);
+        }
+        self.init_scope(MAIN_PKG_PATH)?;
+        if let Some(modules) = self.program.pkgs.get(MAIN_PKG_PATH) {
+            for module in modules {
+                self.push_filename(&module.filename);
+                self.walk_module(module)?;
+            }
+        }
+        self.globals_to_json_str()
+    }
+
+    /// Plan the global variables and scalars of the current package to a JSON string.
+    pub(crate) fn globals_to_json_str(&self) -> EvalResult {
+        let current_pkgpath = self.current_pkgpath();
+        let pkg_scopes = self.pkg_scopes.borrow();
+        let scopes = pkg_scopes
+            .get(&current_pkgpath)
+            .ok_or_else(|| EvalError::pkg_not_found(&current_pkgpath))?;
+        let scope = scopes.last().expect(INTERNAL_ERROR_MSG);
+        let mut global_dict = ValueRef::dict(None);
+        for scalar in &scope.scalars {
+            global_dict.dict_insert(SCALAR_KEY, scalar, Default::default(), -1);
+        }
+        for (name, value) in &scope.variables {
+            // Omit private variables and function variables
+            if name.starts_with(kclvm_runtime::KCL_PRIVATE_VAR_PREFIX) {
+                continue;
+            }
+            let mut value_dict = ValueRef::dict(None);
+            value_dict.dict_insert(name, value, Default::default(), -1);
+            global_dict.dict_insert(SCALAR_KEY, &value_dict, Default::default(), -1);
+        }
+        let value = global_dict
+            .dict_get_value(SCALAR_KEY)
+            .unwrap_or_else(ValueRef::undefined);
+        Ok(ValueRef::str(&value.plan_to_json_string()))
+    }
+}
+
+/// Install the panic hook once, which records the panic information into the runtime
+/// context of the evaluator running on the current thread, and the panics out of the
+/// evaluators are handled by the previous hook.
+fn init_panic_hook() {
+    static PANIC_HOOK: Once = Once::new();
+    PANIC_HOOK.call_once(|| {
+        let prev_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if proxy::is_evaluating() {
+                Context::current_context_mut().set_panic_info(info);
+            } else {
+                prev_hook(info);
+            }
+        }));
+    });
+}
+
+/// Evaluate the resolved program and return the JSON plan of the main package,
+/// or the JSON panic information when the evaluation fails, which is the same
+/// as the result buffer of `_kcl_run`.
+pub fn run(
+    program: &ast::Program,
+    import_names: IndexMap<String, IndexMap<String, String>>,
+    opts: &EvalOptions,
+) -> String {
+    init_panic_hook();
+    if opts.plugin_agent > 0 {
+        let plugin_method: extern "C" fn(
+            method: *const i8,
+            args: *const c_char,
+            kwargs: *const c_char,
+        ) -> *const c_char = unsafe { std::mem::transmute(opts.plugin_agent as usize) };
+        kclvm_runtime::kclvm_plugin_init(plugin_method);
+    }
+    let evaluator = Evaluator::new(program, import_names);
+    let ctx = unsafe { kclvm_runtime::kclvm_context_new() };
+    let _guard = proxy::EvaluatorGuard::new(&evaluator, ctx);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+        kclvm_runtime::kclvm_context_set_strict_range_check(ctx, opts.strict_range_check as i8);
+        kclvm_runtime::kclvm_context_set_disable_none(ctx, opts.disable_none as i8);
+        kclvm_runtime::kclvm_context_set_disable_schema_check(ctx, 0);
+        kclvm_runtime::kclvm_context_set_list_option_mode(ctx, 0);
+        kclvm_runtime::kclvm_context_set_debug_mode(ctx, opts.debug_mode as i8);
+        for (key, value) in &opts.args {
+            let key = utils::cstring(key);
+            let value = utils::cstring(value);
+            kclvm_runtime::kclvm_builtin_option_init(
+                ctx,
+                key.as_ptr().cast(),
+                value.as_ptr().cast(),
+            );
+        }
+        kclvm_runtime::kclvm_context_main_begin_hook(ctx);
+        let value = evaluator.run()?;
+        let value = kclvm_runtime::kclvm_context_main_end_hook(ctx, value.into_raw());
+        Ok::<_, EvalError>(kclvm_runtime::ptr_as_ref(value).as_str())
+    }));
+    let ctx_ref = unsafe { kclvm_runtime::mut_ptr_as_ref(ctx) };
+    let result = match result {
+        Ok(Ok(result)) => result,
+        Ok(Err(err)) => {
+            ctx_ref.set_err_type(&err.ty);
+            ctx_ref.panic_info.__kcl_PanicInfo__ = true;
+            ctx_ref.panic_info.message = err.message;
+            ctx_ref.get_panic_info_json_string()
+        }
+        Err(_) => ctx_ref.get_panic_info_json_string(),
+    };
+    unsafe { kclvm_runtime::kclvm_context_delete(ctx) };
+    result
+}
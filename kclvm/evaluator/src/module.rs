@@ -0,0 +1,74 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use kclvm_ast::ast;
+use kclvm_ast::walker::TypedResultWalker;
+use kclvm_runtime::ValueRef;
+
+use crate::{EvalError, Evaluator};
+
+impl<'ctx> Evaluator<'ctx> {
+    pub(crate) fn compile_module_import_and_types(
+        &self,
+        module: &'ctx ast::Module,
+    ) -> Result<(), EvalError> {
+        self.predefine_global_vars(module);
+        for stmt in &module.body {
+            match &stmt.node {
+                ast::Stmt::Import(import_stmt) => {
+                    self.walk_import_stmt(import_stmt)?;
+                }
+                ast::Stmt::Schema(schema_stmt) => {
+                    self.predefine_global_types(&schema_stmt.name.node);
+                    self.walk_schema_stmt(schema_stmt)?;
+                }
+                ast::Stmt::Rule(rule_stmt) => {
+                    self.predefine_global_types(&rule_stmt.name.node);
+                    self.walk_rule_stmt(rule_stmt)?;
+                }
+                _ => {}
+            };
+        }
+        Ok(())
+    }
+
+    pub(crate) fn predefine_global_types(&self, name: &str) {
+        // Store or add the variable in the scope
+        if !self.store_variable(name, ValueRef::undefined()) {
+            self.add_variable(name, ValueRef::undefined());
+        }
+    }
+
+    /// Predefine all global variables.
+    pub(crate) fn predefine_global_vars(&self, module: &'ctx ast::Module) {
+        for stmt in &module.body {
+            if let ast::Stmt::Assign(assign_stmt) = &stmt.node {
+                for target in &assign_stmt.targets {
+                    let names = &target.node.names;
+                    if names.len() == 1 {
+                        self.add_or_update_global_variable(&names[0], ValueRef::undefined());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Predefine all global or lambda local variables in the branches of the if statement,
+    /// because the variables defined in an unreached branch are still visible after the
+    /// if statement.
+    pub(crate) fn predefine_if_stmt_vars(&self, if_stmt: &'ctx ast::IfStmt) {
+        for stmt in if_stmt.body.iter().chain(&if_stmt.orelse) {
+            match &stmt.node {
+                ast::Stmt::Assign(assign_stmt) => {
+                    for target in &assign_stmt.targets {
+                        let names = &target.node.names;
+                        if names.len() == 1 {
+                            self.add_variable(&names[0], ValueRef::undefined());
+                        }
+                    }
+                }
+                ast::Stmt::If(if_stmt) => self.predefine_if_stmt_vars(if_stmt),
+                _ => {}
+            }
+        }
+    }
+}
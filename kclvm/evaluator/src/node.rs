@@ -0,0 +1,1361 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use std::rc::Rc;
+
+use kclvm_ast::ast::{self, ConfigEntry, NodeRef};
+use kclvm_ast::walker::TypedResultWalker;
+use kclvm_runtime::*;
+use kclvm_sema::{builtin, plugin};
+
+use crate::error::*;
+use crate::proxy::{LambdaProxy, Proxy};
+use crate::utils::{self, BinaryFunc};
+use crate::{check_backtrack_stop, Evaluator, GLOBAL_LEVEL, INNER_LEVEL, INTERNAL_ERROR_MSG};
+use crate::{
+    BACKTRACK_CACHE, LAMBDA_CLOSURE, LAMBDA_NAME, SCHEMA_CAL_MAP, SCHEMA_CONFIG_META_NAME,
+    SCHEMA_CONFIG_NAME, SCHEMA_RUNTIME_TYPE, SCHEMA_SELF_NAME, SCHEMA_VARIABLE_LIST,
+};
+
+/// Impl TypedResultWalker for Evaluator to visit AST nodes to evaluate the values.
+impl<'ctx> TypedResultWalker<'ctx> for Evaluator<'ctx> {
+    type Result = EvalResult;
+
+    /*
+     * Stmt
+     */
+
+    fn walk_stmt(&self, stmt: &'ctx ast::Node<ast::Stmt>) -> Self::Result {
+        check_backtrack_stop!(self);
+        utils::update_ctx_filename(stmt);
+        utils::update_ctx_line_col(self, stmt);
+        self.target_vars.borrow_mut().clear();
+        self.target_vars.borrow_mut().push("".to_string());
+        match &stmt.node {
+            ast::Stmt::TypeAlias(type_alias) => self.walk_type_alias_stmt(type_alias),
+            ast::Stmt::Expr(expr_stmt) => self.walk_expr_stmt(expr_stmt),
+            ast::Stmt::Unification(unification_stmt) => {
+                self.walk_unification_stmt(unification_stmt)
+            }
+            ast::Stmt::Assign(assign_stmt) => self.walk_assign_stmt(assign_stmt),
+            ast::Stmt::AugAssign(aug_assign_stmt) => self.walk_aug_assign_stmt(aug_assign_stmt),
+            ast::Stmt::Assert(assert_stmt) => self.walk_assert_stmt(assert_stmt),
+            ast::Stmt::If(if_stmt) => self.walk_if_stmt(if_stmt),
+            ast::Stmt::Import(import_stmt) => self.walk_import_stmt(import_stmt),
+            ast::Stmt::SchemaAttr(schema_attr) => self.walk_schema_attr(schema_attr),
+            ast::Stmt::Schema(schema_stmt) => self.walk_schema_stmt(schema_stmt),
+            ast::Stmt::Rule(rule_stmt) => self.walk_rule_stmt(rule_stmt),
+        }
+    }
+
+    fn walk_expr_stmt(&self, expr_stmt: &'ctx ast::ExprStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut result = ValueRef::none();
+        for expr in &expr_stmt.exprs {
+            let scalar = self.walk_expr(expr)?;
+            // Only non-call expressions are allowed to emit values bacause of the function void return type.
+            if !matches!(expr.node, ast::Expr::Call(_)) {
+                self.add_scalar(scalar.clone(), matches!(expr.node, ast::Expr::Schema(_)));
+            }
+            result = scalar;
+        }
+        Ok(result)
+    }
+
+    fn walk_unification_stmt(&self, unification_stmt: &'ctx ast::UnificationStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.local_vars.borrow_mut().clear();
+        let name = &unification_stmt.target.node.names[0];
+        self.target_vars.borrow_mut().push(name.clone());
+        // The right value of the unification_stmt is a schema_expr.
+        let value = self.walk_schema_expr(&unification_stmt.value.node)?;
+        if self.scope_level() == GLOBAL_LEVEL || self.is_in_lambda() {
+            if self.resolve_variable(name) {
+                let org_value = self.walk_identifier_with_ctx(
+                    &unification_stmt.target.node,
+                    &ast::ExprContext::Load,
+                    None,
+                )?;
+                let value = utils::aug_op(kclvm_value_op_aug_bit_or, &org_value, &value);
+                // Store the identifier value
+                self.walk_identifier_with_ctx(
+                    &unification_stmt.target.node,
+                    &ast::ExprContext::Store,
+                    Some(value.clone()),
+                )?;
+                Ok(value)
+            } else {
+                self.walk_identifier_with_ctx(
+                    &unification_stmt.target.node,
+                    &unification_stmt.target.node.ctx,
+                    Some(value.clone()),
+                )?;
+                Ok(value)
+            }
+        // Local variables including schema/rule/lambda
+        } else if !self.schema_stack.borrow().is_empty() {
+            // Load the identifier value
+            let org_value = self.walk_identifier_with_ctx(
+                &unification_stmt.target.node,
+                &ast::ExprContext::Load,
+                None,
+            )?;
+            let value = utils::binary_op(kclvm_value_op_bit_or, &org_value, &value);
+            // Store the identifier value
+            self.walk_identifier_with_ctx(
+                &unification_stmt.target.node,
+                &ast::ExprContext::Store,
+                Some(value.clone()),
+            )?;
+            Ok(value)
+        } else {
+            Ok(value)
+        }
+    }
+
+    fn walk_type_alias_stmt(&self, _type_alias_stmt: &'ctx ast::TypeAliasStmt) -> Self::Result {
+        // Nothing to do, because all type aliases have been replaced at compile time
+        Ok(ValueRef::none())
+    }
+
+    fn walk_assign_stmt(&self, assign_stmt: &'ctx ast::AssignStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.local_vars.borrow_mut().clear();
+        for name in &assign_stmt.targets {
+            self.target_vars
+                .borrow_mut()
+                .push(name.node.names[0].clone());
+        }
+        // Load the right value
+        let mut value = self.walk_expr(&assign_stmt.value)?;
+        if let Some(type_annotation) = &assign_stmt.type_annotation {
+            value =
+                utils::convert_collection_value(&value, &type_annotation.node, self.is_in_schema());
+        }
+        if assign_stmt.targets.len() == 1 {
+            let name = &assign_stmt.targets[0];
+            self.walk_identifier_with_ctx(&name.node, &name.node.ctx, Some(value.clone()))?;
+        } else {
+            // Store targets
+            for name in &assign_stmt.targets {
+                self.walk_identifier_with_ctx(&name.node, &name.node.ctx, Some(value.deep_copy()))?;
+            }
+        }
+        Ok(value)
+    }
+
+    fn walk_aug_assign_stmt(&self, aug_assign_stmt: &'ctx ast::AugAssignStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.target_vars
+            .borrow_mut()
+            .push(aug_assign_stmt.target.node.names[0].clone());
+        // Load the right value
+        let right_value = self.walk_expr(&aug_assign_stmt.value)?;
+        // Load the identifier value
+        let org_value = self.walk_identifier_with_ctx(
+            &aug_assign_stmt.target.node,
+            &ast::ExprContext::Load,
+            None,
+        )?;
+        let value = match aug_assign_stmt.op {
+            ast::AugOp::Add => utils::aug_op(kclvm_value_op_aug_add, &org_value, &right_value),
+            ast::AugOp::Sub => utils::aug_op(kclvm_value_op_aug_sub, &org_value, &right_value),
+            ast::AugOp::Mul => utils::aug_op(kclvm_value_op_aug_mul, &org_value, &right_value),
+            ast::AugOp::Div => utils::aug_op(kclvm_value_op_aug_div, &org_value, &right_value),
+            ast::AugOp::Mod => utils::aug_op(kclvm_value_op_aug_mod, &org_value, &right_value),
+            ast::AugOp::Pow => utils::aug_op(kclvm_value_op_aug_pow, &org_value, &right_value),
+            ast::AugOp::LShift => {
+                utils::aug_op(kclvm_value_op_aug_bit_lshift, &org_value, &right_value)
+            }
+            ast::AugOp::RShift => {
+                utils::aug_op(kclvm_value_op_aug_bit_rshift, &org_value, &right_value)
+            }
+            ast::AugOp::BitOr => utils::binary_op(kclvm_value_op_bit_or, &org_value, &right_value),
+            ast::AugOp::BitXor => {
+                utils::aug_op(kclvm_value_op_aug_bit_xor, &org_value, &right_value)
+            }
+            ast::AugOp::BitAnd => {
+                utils::aug_op(kclvm_value_op_aug_bit_and, &org_value, &right_value)
+            }
+            ast::AugOp::FloorDiv => {
+                utils::aug_op(kclvm_value_op_aug_floor_div, &org_value, &right_value)
+            }
+            ast::AugOp::Assign => return Err(EvalError::new(INVALID_OPERATOR_MSG)),
+        };
+        // Store the identifier value
+        self.walk_identifier_with_ctx(
+            &aug_assign_stmt.target.node,
+            &ast::ExprContext::Store,
+            Some(value.clone()),
+        )?;
+        Ok(value)
+    }
+
+    fn walk_assert_stmt(&self, assert_stmt: &'ctx ast::AssertStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        if let Some(if_cond) = &assert_stmt.if_cond {
+            if !self.walk_expr(if_cond)?.is_truthy() {
+                return Ok(ValueRef::none());
+            }
+        }
+        let assert_result = self.walk_expr(&assert_stmt.test)?;
+        let msg = match &assert_stmt.msg {
+            Some(msg) => self.walk_expr(msg)?,
+            None => ValueRef::str(""),
+        };
+        utils::assert(&assert_result, &msg);
+        Ok(ValueRef::none())
+    }
+
+    fn walk_if_stmt(&self, if_stmt: &'ctx ast::IfStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        if self.scope_level() == GLOBAL_LEVEL || self.is_in_lambda() {
+            self.predefine_if_stmt_vars(if_stmt);
+        }
+        let cond = self.walk_expr(&if_stmt.cond)?;
+        if cond.is_truthy() {
+            self.walk_stmts(&if_stmt.body)?;
+        } else {
+            self.walk_stmts(&if_stmt.orelse)?;
+        }
+        Ok(ValueRef::none())
+    }
+
+    fn walk_import_stmt(&self, import_stmt: &'ctx ast::ImportStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let pkgpath = import_stmt.path.as_str();
+        if self.imported.borrow().contains(pkgpath) {
+            return Ok(ValueRef::none());
+        }
+        // Nothing to do on the builtin system module import because the check has been done.
+        if !builtin::STANDARD_SYSTEM_MODULES.contains(&pkgpath)
+            && !pkgpath.starts_with(plugin::PLUGIN_MODULE_PREFIX)
+        {
+            let pkgpath_with_prefix = format!("{}{}", PKG_PATH_PREFIX, import_stmt.path);
+            self.push_pkgpath(&pkgpath_with_prefix);
+            if let Some(modules) = self.program.pkgs.get(&import_stmt.path) {
+                // Init all builtin functions.
+                self.init_scope(&pkgpath_with_prefix)?;
+                // Evaluate all schema and rule types in the pkgpath firstly.
+                for module in modules {
+                    self.push_filename(&module.filename);
+                    self.compile_module_import_and_types(module)?;
+                    self.pop_filename();
+                }
+                for module in modules {
+                    self.push_filename(&module.filename);
+                    self.walk_stmts_except_import(&module.body)?;
+                    self.pop_filename();
+                }
+            }
+            self.pop_pkgpath();
+        }
+        self.imported.borrow_mut().insert(pkgpath.to_string());
+        Ok(ValueRef::none())
+    }
+
+    fn walk_schema_stmt(&self, schema_stmt: &'ctx ast::SchemaStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let function = self.emit_schema(schema_stmt);
+        // Store or add the variable in the scope
+        let name = &schema_stmt.name.node;
+        if !self.store_variable(name, function.clone()) {
+            self.add_variable(name, function.clone());
+        }
+        Ok(function)
+    }
+
+    fn walk_rule_stmt(&self, rule_stmt: &'ctx ast::RuleStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let function = self.emit_rule(rule_stmt);
+        // Store or add the variable in the scope
+        let name = &rule_stmt.name.node;
+        if !self.store_variable(name, function.clone()) {
+            self.add_variable(name, function.clone());
+        }
+        Ok(function)
+    }
+
+    /*
+     * Expr
+     */
+
+    fn walk_expr(&self, expr: &'ctx ast::Node<ast::Expr>) -> Self::Result {
+        check_backtrack_stop!(self);
+        utils::update_ctx_filename(expr);
+        utils::update_ctx_line_col(self, expr);
+        match &expr.node {
+            ast::Expr::Identifier(identifier) => self.walk_identifier(identifier),
+            ast::Expr::Unary(unary_expr) => self.walk_unary_expr(unary_expr),
+            ast::Expr::Binary(binary_expr) => self.walk_binary_expr(binary_expr),
+            ast::Expr::If(if_expr) => self.walk_if_expr(if_expr),
+            ast::Expr::Selector(selector_expr) => self.walk_selector_expr(selector_expr),
+            ast::Expr::Call(call_expr) => self.walk_call_expr(call_expr),
+            ast::Expr::Paren(paren_expr) => self.walk_paren_expr(paren_expr),
+            ast::Expr::Quant(quant_expr) => self.walk_quant_expr(quant_expr),
+            ast::Expr::List(list_expr) => self.walk_list_expr(list_expr),
+            ast::Expr::ListIfItem(list_if_item_expr) => {
+                self.walk_list_if_item_expr(list_if_item_expr)
+            }
+            ast::Expr::ListComp(list_comp) => self.walk_list_comp(list_comp),
+            ast::Expr::Starred(starred_expr) => self.walk_starred_expr(starred_expr),
+            ast::Expr::DictComp(dict_comp) => self.walk_dict_comp(dict_comp),
+            ast::Expr::ConfigIfEntry(config_if_entry_expr) => {
+                self.walk_config_if_entry_expr(config_if_entry_expr)
+            }
+            ast::Expr::CompClause(comp_clause) => self.walk_comp_clause(comp_clause),
+            ast::Expr::Schema(schema_expr) => self.walk_schema_expr(schema_expr),
+            ast::Expr::Config(config_expr) => self.walk_config_expr(config_expr),
+            ast::Expr::Check(check) => self.walk_check_expr(check),
+            ast::Expr::Lambda(lambda) => self.walk_lambda_expr(lambda),
+            ast::Expr::Subscript(subscript) => self.walk_subscript(subscript),
+            ast::Expr::Keyword(keyword) => self.walk_keyword(keyword),
+            ast::Expr::Arguments(..) => Ok(ValueRef::none()),
+            ast::Expr::Compare(compare) => self.walk_compare(compare),
+            ast::Expr::NumberLit(number_lit) => self.walk_number_lit(number_lit),
+            ast::Expr::StringLit(string_lit) => self.walk_string_lit(string_lit),
+            ast::Expr::NameConstantLit(name_constant_lit) => {
+                self.walk_name_constant_lit(name_constant_lit)
+            }
+            ast::Expr::JoinedString(joined_string) => self.walk_joined_string(joined_string),
+            ast::Expr::FormattedValue(formatted_value) => {
+                self.walk_formatted_value(formatted_value)
+            }
+            ast::Expr::Missing(missing_expr) => self.walk_missing_expr(missing_expr),
+        }
+    }
+
+    fn walk_quant_expr(&self, quant_expr: &'ctx ast::QuantExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut result = match quant_expr.op {
+            ast::QuantOperation::All => ValueRef::bool(true),
+            ast::QuantOperation::Any => ValueRef::bool(false),
+            ast::QuantOperation::Map => ValueRef::list(None),
+            ast::QuantOperation::Filter => self.walk_expr(&quant_expr.target)?.deep_copy(),
+        };
+        // Iterator
+        let iter_host_value = if let ast::QuantOperation::Filter = quant_expr.op {
+            result.deep_copy()
+        } else {
+            self.walk_expr(&quant_expr.target)?
+        };
+        let mut iter_value = iter_host_value.iter();
+        self.enter_scope();
+        while !iter_value.is_end() {
+            let next_value = iter_value
+                .next(&iter_host_value)
+                .cloned()
+                .unwrap_or_else(ValueRef::undefined);
+            let key = iter_value
+                .key()
+                .cloned()
+                .unwrap_or_else(ValueRef::undefined);
+            let variables = &quant_expr.variables;
+            {
+                let mut local_vars = self.local_vars.borrow_mut();
+                for v in variables {
+                    let name = &v.node.names[0];
+                    local_vars.insert(name.clone());
+                }
+            }
+            if variables.len() == 1 {
+                // Store the target
+                self.walk_identifier_with_ctx(
+                    &variables[0].node,
+                    &ast::ExprContext::Store,
+                    Some(next_value.clone()),
+                )?;
+            } else if variables.len() == 2 {
+                let value = iter_value
+                    .value()
+                    .cloned()
+                    .unwrap_or_else(ValueRef::undefined);
+                // Store the target
+                self.walk_identifier_with_ctx(
+                    &variables[0].node,
+                    &ast::ExprContext::Store,
+                    Some(key),
+                )?;
+                self.walk_identifier_with_ctx(
+                    &variables[1].node,
+                    &ast::ExprContext::Store,
+                    Some(value),
+                )?;
+            } else {
+                return Err(EvalError::new(INVALID_LOOP_VARIABLES_MSG));
+            }
+            if let Some(if_expr) = &quant_expr.if_cond {
+                if !self.walk_expr(if_expr)?.is_truthy() {
+                    continue;
+                }
+            }
+            let value = self.walk_expr(&quant_expr.test)?;
+            let is_truth = value.is_truthy();
+            match quant_expr.op {
+                ast::QuantOperation::All => {
+                    if !is_truth {
+                        result = ValueRef::bool(false);
+                        break;
+                    }
+                }
+                ast::QuantOperation::Any => {
+                    if is_truth {
+                        result = ValueRef::bool(true);
+                        break;
+                    }
+                }
+                ast::QuantOperation::Filter => {
+                    if !is_truth {
+                        utils::remove_item(&result, &next_value);
+                    }
+                }
+                ast::QuantOperation::Map => {
+                    result.list_append(&value);
+                }
+            }
+        }
+        self.leave_scope();
+        self.local_vars.borrow_mut().clear();
+        Ok(result)
+    }
+
+    fn walk_schema_attr(&self, schema_attr: &'ctx ast::SchemaAttr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.local_vars.borrow_mut().clear();
+        let name = schema_attr.name.node.as_str();
+        self.target_vars.borrow_mut().push(name.to_string());
+        for decorator in &schema_attr.decorators {
+            self.walk_decorator_with_name(&decorator.node, Some(name), false)?;
+        }
+        let value = match &schema_attr.value {
+            Some(value) => self.walk_expr(value)?,
+            None => ValueRef::undefined(),
+        };
+        let config_value = self.get_variable(SCHEMA_CONFIG_NAME)?;
+        let schema_value = self.get_variable(SCHEMA_SELF_NAME)?;
+        utils::config_attr_map(&schema_value, name, &schema_attr.type_str.node);
+        if let Some(op) = &schema_attr.op {
+            match op {
+                // Union
+                ast::BinOrAugOp::Aug(ast::AugOp::BitOr) => {
+                    let org_value = schema_value
+                        .dict_get_value(name)
+                        .unwrap_or_else(ValueRef::undefined);
+                    let value = utils::binary_op(kclvm_value_op_bit_or, &org_value, &value);
+                    utils::dict_merge(&schema_value, name, &value, 1, -1);
+                }
+                // Assign
+                _ => utils::dict_merge(&schema_value, name, &value, 1, -1),
+            }
+        }
+        if let Some(config_attr_value) = config_value.dict_get_entry(name) {
+            utils::value_union(&schema_value, &config_attr_value);
+            let cal_map = self.get_variable(SCHEMA_CAL_MAP)?;
+            let backtrack_cache = self.get_variable(BACKTRACK_CACHE)?;
+            let runtime_type = self.get_variable(SCHEMA_RUNTIME_TYPE)?;
+            utils::schema_backtrack_cache(
+                &schema_value,
+                &backtrack_cache,
+                &cal_map,
+                name,
+                &runtime_type,
+            );
+            // Update backtrack meta
+            if let Some(backtrack_meta) = self.backtrack_meta.borrow_mut().as_mut() {
+                if name == backtrack_meta.target {
+                    backtrack_meta.count += 1;
+                    if backtrack_meta.count >= backtrack_meta.level {
+                        backtrack_meta.stop = true;
+                        return Ok(schema_value);
+                    }
+                }
+            }
+        }
+        Ok(schema_value)
+    }
+
+    fn walk_if_expr(&self, if_expr: &'ctx ast::IfExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let cond = self.walk_expr(&if_expr.cond)?;
+        if cond.is_truthy() {
+            self.walk_expr(&if_expr.body)
+        } else {
+            self.walk_expr(&if_expr.orelse)
+        }
+    }
+
+    fn walk_unary_expr(&self, unary_expr: &'ctx ast::UnaryExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let value = self.walk_expr(&unary_expr.operand)?;
+        let f = match unary_expr.op {
+            ast::UnaryOp::UAdd => kclvm_value_unary_plus,
+            ast::UnaryOp::USub => kclvm_value_unary_minus,
+            ast::UnaryOp::Invert => kclvm_value_unary_not,
+            ast::UnaryOp::Not => kclvm_value_unary_l_not,
+        };
+        Ok(utils::unary_op(f, &value))
+    }
+
+    fn walk_binary_expr(&self, binary_expr: &'ctx ast::BinaryExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let is_logic_op = matches!(
+            binary_expr.op,
+            ast::BinOrCmpOp::Bin(ast::BinOp::And) | ast::BinOrCmpOp::Bin(ast::BinOp::Or)
+        );
+        let is_membership_as_op = matches!(binary_expr.op, ast::BinOrCmpOp::Bin(ast::BinOp::As));
+        if !is_logic_op {
+            let left_value = self.walk_expr(&binary_expr.left)?;
+            let right_value = if is_membership_as_op {
+                match &binary_expr.right.node {
+                    ast::Expr::Identifier(id) => ValueRef::str(&id.names.join(".")),
+                    _ => ValueRef::none(),
+                }
+            } else {
+                self.walk_expr(&binary_expr.right)?
+            };
+            let f: BinaryFunc = match binary_expr.op {
+                ast::BinOrCmpOp::Bin(ast::BinOp::Add) => kclvm_value_op_add,
+                ast::BinOrCmpOp::Bin(ast::BinOp::Sub) => kclvm_value_op_sub,
+                ast::BinOrCmpOp::Bin(ast::BinOp::Mul) => kclvm_value_op_mul,
+                ast::BinOrCmpOp::Bin(ast::BinOp::Div) => kclvm_value_op_div,
+                ast::BinOrCmpOp::Bin(ast::BinOp::FloorDiv) => kclvm_value_op_floor_div,
+                ast::BinOrCmpOp::Bin(ast::BinOp::Mod) => kclvm_value_op_mod,
+                ast::BinOrCmpOp::Bin(ast::BinOp::Pow) => kclvm_value_op_pow,
+                ast::BinOrCmpOp::Bin(ast::BinOp::LShift) => kclvm_value_op_bit_lshift,
+                ast::BinOrCmpOp::Bin(ast::BinOp::RShift) => kclvm_value_op_bit_rshift,
+                ast::BinOrCmpOp::Bin(ast::BinOp::BitAnd) => kclvm_value_op_bit_and,
+                ast::BinOrCmpOp::Bin(ast::BinOp::BitOr) => kclvm_value_op_bit_or,
+                ast::BinOrCmpOp::Bin(ast::BinOp::BitXor) => kclvm_value_op_bit_xor,
+                ast::BinOrCmpOp::Bin(ast::BinOp::And) => kclvm_value_logic_and,
+                ast::BinOrCmpOp::Bin(ast::BinOp::Or) => kclvm_value_logic_or,
+                ast::BinOrCmpOp::Bin(ast::BinOp::As) => kclvm_value_as,
+                ast::BinOrCmpOp::Cmp(ref op) => cmp_func(op),
+            };
+            Ok(utils::binary_op(f, &left_value, &right_value))
+        } else {
+            let jump_if_false = matches!(binary_expr.op, ast::BinOrCmpOp::Bin(ast::BinOp::And));
+            let left_value = self.walk_expr(&binary_expr.left)?;
+            // Jump if false on logic and, jump if true on logic or.
+            if left_value.is_truthy() != jump_if_false {
+                Ok(left_value)
+            } else {
+                self.walk_expr(&binary_expr.right)
+            }
+        }
+    }
+
+    fn walk_selector_expr(&self, selector_expr: &'ctx ast::SelectorExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let value = self.walk_expr(&selector_expr.value)?;
+        let names = &selector_expr.attr.node.names;
+        let mut value = if selector_expr.has_question {
+            utils::load_attr_option(&value, &names[0])
+        } else {
+            utils::load_attr(&value, &names[0])
+        };
+        for name in &names[1..] {
+            value = utils::load_attr(&value, name);
+        }
+        Ok(value)
+    }
+
+    fn walk_call_expr(&self, call_expr: &'ctx ast::CallExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let func = self.walk_expr(&call_expr.func)?;
+        // args
+        let mut list_value = ValueRef::list(None);
+        for arg in &call_expr.args {
+            let value = self.walk_expr(arg)?;
+            list_value.list_append(&value);
+        }
+        // kwargs
+        let mut dict_value = ValueRef::dict(None);
+        for keyword in &call_expr.keywords {
+            let name = &keyword.node.arg.node.names[0];
+            let value = match &keyword.node.value {
+                Some(value) => self.walk_expr(value)?,
+                None => ValueRef::none(),
+            };
+            dict_value.dict_insert(name, &value, Default::default(), -1);
+        }
+        Ok(utils::value_function_invoke(
+            &func,
+            &list_value,
+            &dict_value,
+            &self.current_pkgpath(),
+            self.is_in_schema(),
+        ))
+    }
+
+    fn walk_subscript(&self, subscript: &'ctx ast::Subscript) -> Self::Result {
+        check_backtrack_stop!(self);
+        let value = self.walk_expr(&subscript.value)?;
+        if let Some(index) = &subscript.index {
+            // index
+            let index = self.walk_expr(index)?;
+            let f: BinaryFunc = if subscript.has_question {
+                kclvm_value_subscr_option
+            } else {
+                kclvm_value_subscr
+            };
+            Ok(utils::binary_op(f, &value, &index))
+        } else {
+            let walk_option = |expr: &'ctx Option<NodeRef<ast::Expr>>| match expr {
+                Some(expr) => self.walk_expr(expr),
+                None => Ok(ValueRef::none()),
+            };
+            let lower = walk_option(&subscript.lower)?;
+            let upper = walk_option(&subscript.upper)?;
+            let step = walk_option(&subscript.step)?;
+            let f: utils::SliceFunc = if subscript.has_question {
+                kclvm_value_slice_option
+            } else {
+                kclvm_value_slice
+            };
+            Ok(utils::slice_op(f, &value, &lower, &upper, &step))
+        }
+    }
+
+    fn walk_paren_expr(&self, paren_expr: &'ctx ast::ParenExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_expr(&paren_expr.expr)
+    }
+
+    fn walk_list_expr(&self, list_expr: &'ctx ast::ListExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut list_value = ValueRef::list(None);
+        for item in &list_expr.elts {
+            let value = self.walk_expr(item)?;
+            match &item.node {
+                ast::Expr::Starred(_) | ast::Expr::ListIfItem(_) => {
+                    utils::list_append_unpack(&list_value, &value)
+                }
+                _ => list_value.list_append(&value),
+            };
+        }
+        Ok(list_value)
+    }
+
+    fn walk_list_if_item_expr(&self, list_if_item_expr: &'ctx ast::ListIfItemExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let cond = self.walk_expr(&list_if_item_expr.if_cond)?;
+        if cond.is_truthy() {
+            let mut then_value = ValueRef::list(None);
+            for expr in &list_if_item_expr.exprs {
+                let value = self.walk_expr(expr)?;
+                match &expr.node {
+                    ast::Expr::Starred(_) | ast::Expr::ListIfItem(_) => {
+                        utils::list_append_unpack(&then_value, &value)
+                    }
+                    _ => then_value.list_append(&value),
+                };
+            }
+            Ok(then_value)
+        } else {
+            match &list_if_item_expr.orelse {
+                Some(orelse) => self.walk_expr(orelse),
+                None => Ok(ValueRef::none()),
+            }
+        }
+    }
+
+    fn walk_starred_expr(&self, starred_expr: &'ctx ast::StarredExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_expr(&starred_expr.value)
+    }
+
+    fn walk_list_comp(&self, list_comp: &'ctx ast::ListComp) -> Self::Result {
+        check_backtrack_stop!(self);
+        let collection_value = ValueRef::list(None);
+        self.enter_scope();
+        self.walk_generator(
+            &list_comp.generators,
+            &list_comp.elt,
+            None,
+            None,
+            0,
+            &collection_value,
+            &ast::CompType::List,
+        )?;
+        self.leave_scope();
+        Ok(collection_value)
+    }
+
+    fn walk_dict_comp(&self, dict_comp: &'ctx ast::DictComp) -> Self::Result {
+        check_backtrack_stop!(self);
+        let collection_value = ValueRef::dict(None);
+        self.enter_scope();
+        let key = dict_comp.entry.key.as_ref().expect(INTERNAL_ERROR_MSG);
+        self.walk_generator(
+            &dict_comp.generators,
+            key,
+            Some(&dict_comp.entry.value),
+            Some(&dict_comp.entry.operation),
+            0,
+            &collection_value,
+            &ast::CompType::Dict,
+        )?;
+        self.leave_scope();
+        Ok(collection_value)
+    }
+
+    fn walk_config_if_entry_expr(
+        &self,
+        config_if_entry_expr: &'ctx ast::ConfigIfEntryExpr,
+    ) -> Self::Result {
+        check_backtrack_stop!(self);
+        let cond = self.walk_expr(&config_if_entry_expr.if_cond)?;
+        if cond.is_truthy() {
+            self.walk_config_entries(&config_if_entry_expr.items)
+        } else {
+            match &config_if_entry_expr.orelse {
+                Some(orelse) => self.walk_expr(orelse),
+                None => Ok(ValueRef::none()),
+            }
+        }
+    }
+
+    fn walk_comp_clause(&self, _comp_clause: &'ctx ast::CompClause) -> Self::Result {
+        // Nothing to do on this AST node
+        Ok(ValueRef::none())
+    }
+
+    fn walk_schema_expr(&self, schema_expr: &'ctx ast::SchemaExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        // Check the required attributes only when the values of all attributes
+        // in the final schema are solved.
+        let is_in_schema = self.is_in_schema();
+        self.schema_expr_depth.set(self.schema_expr_depth.get() + 1);
+        let config_value = self.walk_expr(&schema_expr.config)?;
+        let schema_type = self.walk_identifier_with_ctx(
+            &schema_expr.name.node,
+            &schema_expr.name.node.ctx,
+            None,
+        )?;
+        let config_expr = match &schema_expr.config.node {
+            ast::Expr::Config(config_expr) => config_expr,
+            _ => return Err(EvalError::new(INVALID_SCHEMA_CONFIG_MSG)),
+        };
+        let config_meta = self.get_schema_config_meta(Some(&schema_expr.name), config_expr);
+        let mut list_value = ValueRef::list(None);
+        for arg in &schema_expr.args {
+            let value = self.walk_expr(arg)?;
+            list_value.list_append(&value);
+        }
+        let mut dict_value = ValueRef::dict(None);
+        for keyword in &schema_expr.kwargs {
+            let name = &keyword.node.arg.node.names[0];
+            let value = match &keyword.node.value {
+                Some(value) => self.walk_expr(value)?,
+                None => ValueRef::none(),
+            };
+            dict_value.dict_insert(name, &value, Default::default(), -1);
+        }
+        let schema = utils::schema_value_new(
+            &list_value,
+            &dict_value,
+            &schema_type,
+            &config_value,
+            &config_meta,
+            &self.current_pkgpath(),
+        );
+        if !is_in_schema {
+            utils::schema_optional_check(&schema);
+        }
+        utils::update_ctx_filename(&schema_expr.config);
+        self.schema_expr_depth.set(self.schema_expr_depth.get() - 1);
+        Ok(schema)
+    }
+
+    fn walk_config_expr(&self, config_expr: &'ctx ast::ConfigExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_config_entries(&config_expr.items)
+    }
+
+    fn walk_check_expr(&self, check_expr: &'ctx ast::CheckExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        if let Some(if_cond) = &check_expr.if_cond {
+            if !self.walk_expr(if_cond)?.is_truthy() {
+                return Ok(ValueRef::none());
+            }
+        }
+        let check_result = self.walk_expr(&check_expr.test)?;
+        let msg = match &check_expr.msg {
+            Some(msg) => self.walk_expr(msg)?,
+            None => ValueRef::str(""),
+        };
+        let schema_config_meta = self.get_variable(SCHEMA_CONFIG_META_NAME)?;
+        utils::update_ctx_current_line(self);
+        utils::schema_assert(&check_result, &msg, &schema_config_meta);
+        Ok(ValueRef::none())
+    }
+
+    fn walk_lambda_expr(&self, lambda_expr: &'ctx ast::LambdaExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let pkgpath = self.current_pkgpath();
+        let key = lambda_expr as *const ast::LambdaExpr as usize;
+        let cached = self.function_cache.borrow().get(&key).cloned();
+        let fn_ptr = match cached {
+            Some((fn_ptr, _)) => fn_ptr,
+            None => {
+                let fn_ptr = self.add_proxy(Proxy::Lambda(Rc::new(LambdaProxy {
+                    expr: lambda_expr,
+                    pkgpath: pkgpath.clone(),
+                    filename: self.current_filename(),
+                    schema_stack: self.schema_stack.borrow().clone(),
+                    schema_expr_depth: self.schema_expr_depth.get(),
+                })));
+                self.function_cache.borrow_mut().insert(key, (fn_ptr, 0));
+                fn_ptr
+            }
+        };
+        // Use the closure map in the current scope to construct the lambda closure.
+        // The default value of the closure map is `{}`.
+        let closure = ValueRef::list_value(Some(&[self.get_closure_map()?]));
+        // Use "pkgpath"+"kclvm_lambda" to name the function to prevent conflicts
+        // between lambdas with the same name in different packages
+        let name = format!(
+            "{}.{}",
+            pkgpath.strip_prefix(PKG_PATH_PREFIX).unwrap_or(&pkgpath),
+            LAMBDA_NAME
+        );
+        Ok(utils::lambda_function_value(fn_ptr, &closure, &name))
+    }
+
+    fn walk_keyword(&self, _keyword: &'ctx ast::Keyword) -> Self::Result {
+        // Nothing to do
+        Ok(ValueRef::none())
+    }
+
+    fn walk_arguments(&self, _arguments: &'ctx ast::Arguments) -> Self::Result {
+        // Nothing to do
+        Ok(ValueRef::none())
+    }
+
+    fn walk_compare(&self, compare: &'ctx ast::Compare) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut left_value = self.walk_expr(&compare.left)?;
+        let mut result = ValueRef::undefined();
+        for (i, op) in compare.ops.iter().enumerate() {
+            let right_value = self.walk_expr(&compare.comparators[i])?;
+            result = utils::binary_op(cmp_func(op), &left_value, &right_value);
+            // Short-circuit the chained comparison on the first false result.
+            if !result.is_truthy() {
+                break;
+            }
+            left_value = right_value;
+        }
+        Ok(result)
+    }
+
+    fn walk_identifier(&self, identifier: &'ctx ast::Identifier) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_identifier_with_ctx(identifier, &identifier.ctx, None)
+    }
+
+    fn walk_number_lit(&self, number_lit: &'ctx ast::NumberLit) -> Self::Result {
+        check_backtrack_stop!(self);
+        Ok(match number_lit.value {
+            ast::NumberLitValue::Int(int_value) => match &number_lit.binary_suffix {
+                Some(binary_suffix) => {
+                    let unit = binary_suffix.value();
+                    let value = cal_num(int_value, unit.as_str());
+                    ValueRef::unit(value, int_value, &unit)
+                }
+                None => ValueRef::int(int_value),
+            },
+            ast::NumberLitValue::Float(float_value) => ValueRef::float(float_value),
+        })
+    }
+
+    fn walk_string_lit(&self, string_lit: &'ctx ast::StringLit) -> Self::Result {
+        check_backtrack_stop!(self);
+        Ok(ValueRef::str(string_lit.value.as_str()))
+    }
+
+    fn walk_name_constant_lit(
+        &self,
+        name_constant_lit: &'ctx ast::NameConstantLit,
+    ) -> Self::Result {
+        check_backtrack_stop!(self);
+        Ok(match name_constant_lit.value {
+            ast::NameConstant::True => ValueRef::bool(true),
+            ast::NameConstant::False => ValueRef::bool(false),
+            ast::NameConstant::None => ValueRef::none(),
+            ast::NameConstant::Undefined => ValueRef::undefined(),
+        })
+    }
+
+    fn walk_joined_string(&self, joined_string: &'ctx ast::JoinedString) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut result_value = ValueRef::str("");
+        for value in &joined_string.values {
+            let value = match &value.node {
+                ast::Expr::FormattedValue(formatted_value) => {
+                    self.walk_formatted_value(formatted_value)?
+                }
+                ast::Expr::StringLit(string_lit) => self.walk_string_lit(string_lit)?,
+                _ => return Err(EvalError::new(INVALID_JOINED_STR_MSG)),
+            };
+            result_value = utils::binary_op(kclvm_value_op_add, &result_value, &value);
+        }
+        Ok(result_value)
+    }
+
+    fn walk_formatted_value(&self, formatted_value: &'ctx ast::FormattedValue) -> Self::Result {
+        check_backtrack_stop!(self);
+        let formatted_expr_value = self.walk_expr(&formatted_value.value)?;
+        let mut f: utils::UnaryFunc = kclvm_value_to_str_value;
+        if let Some(spec) = &formatted_value.format_spec {
+            f = match spec.to_lowercase().as_str() {
+                "#json" => kclvm_value_to_json_value,
+                "#yaml" => kclvm_value_to_yaml_value,
+                _ => return Err(EvalError::new(INVALID_STR_INTERPOLATION_SPEC_MSG)),
+            };
+        }
+        Ok(utils::unary_op(f, &formatted_expr_value))
+    }
+
+    fn walk_comment(&self, _comment: &'ctx ast::Comment) -> Self::Result {
+        // Nothing to do
+        Ok(ValueRef::none())
+    }
+
+    fn walk_missing_expr(&self, _missing_expr: &'ctx ast::MissingExpr) -> Self::Result {
+        Err(EvalError::new(MISSING_EXPR_MSG))
+    }
+
+    fn walk_module(&self, module: &'ctx ast::Module) -> Self::Result {
+        check_backtrack_stop!(self);
+        if !module.body.is_empty() {
+            utils::update_ctx_filename(&module.body[0]);
+        }
+        // Evaluate all schema and rule types firstly
+        self.compile_module_import_and_types(module)?;
+        // Evaluate all statements of the module
+        self.walk_stmts_except_import(&module.body)
+    }
+}
+
+/// Get the runtime function of the comparison operator.
+fn cmp_func(op: &ast::CmpOp) -> BinaryFunc {
+    match op {
+        ast::CmpOp::Eq => kclvm_value_cmp_equal_to,
+        ast::CmpOp::NotEq => kclvm_value_cmp_not_equal_to,
+        ast::CmpOp::Gt => kclvm_value_cmp_greater_than,
+        ast::CmpOp::GtE => kclvm_value_cmp_greater_than_or_equal,
+        ast::CmpOp::Lt => kclvm_value_cmp_less_than,
+        ast::CmpOp::LtE => kclvm_value_cmp_less_than_or_equal,
+        ast::CmpOp::Is => kclvm_value_is,
+        ast::CmpOp::IsNot => kclvm_value_is_not,
+        ast::CmpOp::Not => kclvm_value_is_not,
+        ast::CmpOp::NotIn => kclvm_value_not_in,
+        ast::CmpOp::In => kclvm_value_in,
+    }
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    pub(crate) fn walk_stmts_except_import(
+        &self,
+        stmts: &'ctx [Box<ast::Node<ast::Stmt>>],
+    ) -> EvalResult {
+        check_backtrack_stop!(self);
+        let mut result = ValueRef::none();
+        for stmt in stmts {
+            if !matches!(&stmt.node, ast::Stmt::Import(..)) {
+                result = self.walk_stmt(stmt)?;
+            }
+        }
+        Ok(result)
+    }
+
+    pub(crate) fn walk_stmts(&self, stmts: &'ctx [Box<ast::Node<ast::Stmt>>]) -> EvalResult {
+        check_backtrack_stop!(self);
+        // Empty statements return None value
+        let mut result = ValueRef::none();
+        for stmt in stmts {
+            result = self.walk_stmt(stmt)?;
+        }
+        Ok(result)
+    }
+
+    pub(crate) fn walk_identifier_with_ctx(
+        &self,
+        identifier: &'ctx ast::Identifier,
+        identifier_ctx: &ast::ExprContext,
+        right_value: Option<ValueRef>,
+    ) -> EvalResult {
+        check_backtrack_stop!(self);
+        let is_in_schema = !self.schema_stack.borrow().is_empty();
+        match identifier_ctx {
+            ast::ExprContext::Store => {
+                let right_value = right_value.expect(INTERNAL_ERROR_MSG);
+                if identifier.names.len() == 1 {
+                    let name = identifier.names[0].as_str();
+                    // Global variables
+                    if self.scope_level() == GLOBAL_LEVEL {
+                        self.add_or_update_global_variable(name, right_value.clone());
+                    // Local variables including schema/rule/lambda
+                    } else if self.is_in_lambda() {
+                        // If variable exists in the scope and update it, if not, add it to the scope.
+                        self.store_variable_in_current_scope(name, right_value.clone());
+                    } else {
+                        let is_local_var = self.local_vars.borrow().contains(name);
+                        if is_in_schema && self.scope_level() >= INNER_LEVEL && !is_local_var {
+                            let schema_value = self.get_variable(SCHEMA_SELF_NAME)?;
+                            let config_value = self.get_variable(SCHEMA_CONFIG_NAME)?;
+                            let config_entry = config_value
+                                .dict_get_entry(name)
+                                .unwrap_or_else(ValueRef::none);
+                            utils::dict_merge(&schema_value, name, &right_value, 1, -1);
+                            utils::value_union(&schema_value, &config_entry);
+                            let cal_map = self.get_variable(SCHEMA_CAL_MAP)?;
+                            let backtrack_cache = self.get_variable(BACKTRACK_CACHE)?;
+                            let runtime_type = self.get_variable(SCHEMA_RUNTIME_TYPE)?;
+                            utils::schema_backtrack_cache(
+                                &schema_value,
+                                &backtrack_cache,
+                                &cal_map,
+                                name,
+                                &runtime_type,
+                            );
+                            // Update backtrack meta
+                            if let Some(backtrack_meta) = self.backtrack_meta.borrow_mut().as_mut()
+                            {
+                                if name == backtrack_meta.target {
+                                    backtrack_meta.count += 1;
+                                    if backtrack_meta.count == backtrack_meta.level {
+                                        backtrack_meta.stop = true;
+                                        return Ok(schema_value);
+                                    }
+                                }
+                            }
+                        }
+                        if is_local_var || !is_in_schema {
+                            self.store_variable_in_current_scope(name, right_value.clone());
+                        }
+                    }
+                } else {
+                    let names = &identifier.names;
+                    let name = names[0].as_str();
+                    let mut value = if is_in_schema {
+                        self.get_variable_in_schema(name)?
+                    } else {
+                        self.get_variable(name)?
+                    };
+                    for (i, attr) in names.iter().enumerate().skip(1) {
+                        if i < names.len() - 1 {
+                            value = utils::load_attr(&value, attr);
+                        } else {
+                            utils::dict_set_value(&value, attr, &right_value);
+                        }
+                    }
+                }
+                Ok(right_value)
+            }
+            ast::ExprContext::Load => {
+                let name = identifier.names[0].as_str();
+                let is_local_var = self.local_vars.borrow().contains(name);
+                let load_first_name = || {
+                    if is_in_schema && !is_local_var {
+                        self.get_variable_in_schema(name)
+                    } else {
+                        self.get_variable(name)
+                    }
+                };
+                if identifier.names.len() == 1 {
+                    load_first_name()
+                } else {
+                    let names = &identifier.names;
+                    let mut value = if identifier.pkgpath.is_empty() {
+                        load_first_name()?
+                    } else {
+                        ValueRef::none()
+                    };
+                    for (i, attr) in names.iter().enumerate().skip(1) {
+                        value = if i == 1 && !identifier.pkgpath.is_empty() {
+                            self.get_variable_in_pkgpath(attr, &identifier.pkgpath)?
+                        } else {
+                            utils::load_attr(&value, attr)
+                        };
+                    }
+                    Ok(value)
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn walk_generator(
+        &self,
+        generators: &'ctx [Box<ast::Node<ast::CompClause>>],
+        elt: &'ctx ast::Node<ast::Expr>,
+        val: Option<&'ctx ast::Node<ast::Expr>>,
+        op: Option<&'ctx ast::ConfigEntryOperation>,
+        gen_index: usize,
+        collection_value: &ValueRef,
+        comp_type: &ast::CompType,
+    ) -> Result<(), EvalError> {
+        let generator = &generators[gen_index];
+        let iter_host_value = self.walk_expr(&generator.node.iter)?;
+        let mut iter_value = iter_host_value.iter();
+        let targets = &generator.node.targets;
+        while !iter_value.is_end() {
+            let next_value = iter_value
+                .next(&iter_host_value)
+                .cloned()
+                .unwrap_or_else(ValueRef::undefined);
+            {
+                let mut local_vars = self.local_vars.borrow_mut();
+                for v in targets {
+                    let name = &v.node.names[0];
+                    local_vars.insert(name.clone());
+                }
+            }
+            if targets.len() == 1 {
+                // Store the target
+                self.walk_identifier_with_ctx(
+                    &targets[0].node,
+                    &ast::ExprContext::Store,
+                    Some(next_value),
+                )?;
+            } else if targets.len() == 2 {
+                let key = iter_value
+                    .key()
+                    .cloned()
+                    .unwrap_or_else(ValueRef::undefined);
+                let value = iter_value
+                    .value()
+                    .cloned()
+                    .unwrap_or_else(ValueRef::undefined);
+                // Store the target
+                self.walk_identifier_with_ctx(
+                    &targets[0].node,
+                    &ast::ExprContext::Store,
+                    Some(key),
+                )?;
+                self.walk_identifier_with_ctx(
+                    &targets[1].node,
+                    &ast::ExprContext::Store,
+                    Some(value),
+                )?;
+            } else {
+                return Err(EvalError::new(INVALID_LOOP_VARIABLES_MSG));
+            }
+            let mut is_truthy = true;
+            for if_expr in &generator.node.ifs {
+                if !self.walk_expr(if_expr)?.is_truthy() {
+                    is_truthy = false;
+                    break;
+                }
+            }
+            if !is_truthy {
+                continue;
+            }
+            let next_gen_index = gen_index + 1;
+            if next_gen_index >= generators.len() {
+                match comp_type {
+                    ast::CompType::List => {
+                        let item = self.walk_expr(elt)?;
+                        let mut collection_value = collection_value.clone();
+                        collection_value.list_append(&item);
+                    }
+                    ast::CompType::Dict => {
+                        let value = self.walk_expr(val.expect(INTERNAL_ERROR_MSG))?;
+                        let key = self.walk_expr(elt)?;
+                        let op = op.expect(INTERNAL_ERROR_MSG);
+                        utils::dict_insert_value(collection_value, &key, &value, op.value(), -1);
+                    }
+                }
+            } else {
+                self.walk_generator(
+                    generators,
+                    elt,
+                    val,
+                    op,
+                    next_gen_index,
+                    collection_value,
+                    comp_type,
+                )?;
+            }
+        }
+        let mut local_vars = self.local_vars.borrow_mut();
+        for v in targets {
+            let name = &v.node.names[0];
+            local_vars.remove(name);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn walk_config_entries(&self, items: &'ctx [NodeRef<ConfigEntry>]) -> EvalResult {
+        let config_value = ValueRef::dict(None);
+        self.enter_scope();
+        for item in items {
+            let value = self.walk_expr(&item.node.value)?;
+            if let Some(key) = &item.node.key {
+                let mut insert_index = -1;
+                let optional_name = match &key.node {
+                    ast::Expr::Identifier(identifier) => Some(identifier.names[0].clone()),
+                    ast::Expr::StringLit(string_lit) => Some(string_lit.value.clone()),
+                    ast::Expr::Subscript(subscript) => {
+                        let mut name = None;
+                        if let ast::Expr::Identifier(identifier) = &subscript.value.node {
+                            if let Some(index_node) = &subscript.index {
+                                if let ast::Expr::NumberLit(number) = &index_node.node {
+                                    if let ast::NumberLitValue::Int(v) = number.value {
+                                        insert_index = v;
+                                        name = Some(identifier.names[0].clone())
+                                    }
+                                }
+                            }
+                        }
+                        name
+                    }
+                    _ => None,
+                };
+                // Store a local variable for every entry key.
+                let key = match optional_name {
+                    Some(name) => {
+                        self.add_or_update_local_variable(&name, value.clone());
+                        ValueRef::str(&name)
+                    }
+                    None => self.walk_expr(key)?,
+                };
+                utils::dict_insert_value(
+                    &config_value,
+                    &key,
+                    &value,
+                    item.node.operation.value(),
+                    insert_index as i32,
+                );
+            } else {
+                // If the key does not exist, execute the logic of unpacking expression `**expr` here.
+                utils::dict_insert_unpack(&config_value, &value);
+            }
+        }
+        self.leave_scope();
+        Ok(config_value)
+    }
+
+    /// Get the config meta of the schema expression, which records the position
+    /// of the schema name and all config entry keys.
+    pub(crate) fn get_schema_config_meta(
+        &self,
+        n: Option<&'ctx ast::Node<ast::Identifier>>,
+        t: &'ctx ast::ConfigExpr,
+    ) -> ValueRef {
+        let config_meta = ValueRef::dict(None);
+        if let Some(n) = n {
+            utils::dict_insert_override_item(
+                &config_meta,
+                CONFIG_META_FILENAME,
+                &ValueRef::str(&n.filename),
+            );
+            utils::dict_insert_override_item(
+                &config_meta,
+                CONFIG_META_LINE,
+                &ValueRef::int(n.line as i64),
+            );
+            utils::dict_insert_override_item(
+                &config_meta,
+                CONFIG_META_COLUMN,
+                &ValueRef::int(n.column as i64),
+            );
+        }
+        for item in &t.items {
+            if let Some(key) = &item.node.key {
+                let name = match &key.node {
+                    ast::Expr::Identifier(t) => t.names[0].clone(),
+                    ast::Expr::NumberLit(t) => match t.value {
+                        ast::NumberLitValue::Int(i) => i.to_string(),
+                        ast::NumberLitValue::Float(f) => f.to_string(),
+                    },
+                    ast::Expr::StringLit(t) => t.value.clone(),
+                    ast::Expr::NameConstantLit(t) => match t.value {
+                        ast::NameConstant::True => KCL_NAME_CONSTANT_TRUE.to_string(),
+                        ast::NameConstant::False => KCL_NAME_CONSTANT_FALSE.to_string(),
+                        ast::NameConstant::None => KCL_NAME_CONSTANT_NONE.to_string(),
+                        ast::NameConstant::Undefined => KCL_NAME_CONSTANT_UNDEFINED.to_string(),
+                    },
+                    _ => format!("{:?}", key.node),
+                };
+                let config_item_meta = ValueRef::dict(None);
+                utils::dict_insert_override_item(
+                    &config_item_meta,
+                    CONFIG_ITEM_META_FILENAME,
+                    &ValueRef::str(&key.filename),
+                );
+                utils::dict_insert_override_item(
+                    &config_item_meta,
+                    CONFIG_ITEM_META_LINE,
+                    &ValueRef::int(key.line as i64),
+                );
+                utils::dict_insert_override_item(
+                    &config_item_meta,
+                    CONFIG_ITEM_META_COLUMN,
+                    &ValueRef::int(key.column as i64),
+                );
+                let value = match &item.node.value.node {
+                    ast::Expr::Config(config_expr) => {
+                        self.get_schema_config_meta(None, config_expr)
+                    }
+                    _ => ValueRef::dict(None),
+                };
+                utils::dict_insert_override_item(&config_item_meta, CONFIG_ITEM_META, &value);
+                utils::dict_insert_override_item(&config_meta, &name, &config_item_meta)
+            }
+        }
+        config_meta
+    }
+
+    /// Get the closure map of the current scope, which captures the variables
+    /// of the local scopes and the schema variables.
+    pub(crate) fn get_closure_map(&self) -> EvalResult {
+        let closure_map = ValueRef::dict(None);
+        {
+            let pkgpath = self.current_pkgpath();
+            let pkg_scopes = self.pkg_scopes.borrow();
+            let scopes = pkg_scopes
+                .get(&pkgpath)
+                .ok_or_else(|| EvalError::pkg_not_found(&pkgpath))?;
+            // Closure variables must be inner of the global scope.
+            for scope in scopes.iter().skip(INNER_LEVEL) {
+                for (name, value) in &scope.variables {
+                    if name != LAMBDA_CLOSURE {
+                        utils::dict_insert_override_item(&closure_map, name, value);
+                    }
+                }
+            }
+        }
+        // Capture schema `self` closure.
+        if !self.schema_stack.borrow().is_empty() {
+            for schema_closure_name in SCHEMA_VARIABLE_LIST {
+                let value = self.get_variable(schema_closure_name)?;
+                utils::dict_insert_override_item(&closure_map, schema_closure_name, &value);
+            }
+        }
+        Ok(closure_map)
+    }
+
+    /// Execute the lambda function body.
+    pub(crate) fn execute_lambda(
+        &self,
+        lambda: &LambdaProxy<'ctx>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> EvalResult {
+        utils::set_ctx_filename(&lambda.filename);
+        let mut args = args.clone();
+        let closure_map = args
+            .list_pop_first()
+            .unwrap_or_else(|| ValueRef::dict(None));
+        self.add_variable(LAMBDA_CLOSURE, closure_map.clone());
+        // Closure variables are captured when the lambda is defined.
+        if closure_map.is_config() {
+            for (name, value) in closure_map.as_dict_ref().values.iter() {
+                self.add_variable(name, value.clone());
+            }
+        }
+        self.walk_arguments(&lambda.expr.args, &args, kwargs)?;
+        self.walk_stmts(&lambda.expr.body)
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+//! Function proxies of the evaluator.
+//!
+//! The LLVM code generator emits native functions for schemas, rules, check blocks,
+//! schema attributes and lambdas which are called by the runtime through function
+//! pointers. The evaluator registers these nodes as proxies instead, and the proxy
+//! index tagged with [`FUNC_PROXY_TAG`] is used as the function pointer. When the
+//! runtime calls a tagged function pointer, the call is dispatched to the
+//! [`proxy_handler`] which walks the proxy node in a new evaluation frame.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use kclvm_ast::ast;
+use kclvm_runtime::{
+    mut_ptr_as_ref, ptr_as_ref, Context, ErrType, FuncProxyHandler, ValueRef, FUNC_PROXY_TAG,
+};
+
+use crate::scope::Scope;
+use crate::{BacktrackMeta, EvalError, EvalResult, Evaluator, INNER_LEVEL, INTERNAL_ERROR_MSG};
+
+/// A function proxy registered in the runtime.
+#[derive(Clone)]
+pub(crate) enum Proxy<'ctx> {
+    Lambda(Rc<LambdaProxy<'ctx>>),
+    Schema(Rc<SchemaProxy<'ctx>>),
+    SchemaCheck(Rc<SchemaProxy<'ctx>>),
+    SchemaAttr(Rc<SchemaAttrProxy<'ctx>>),
+    Rule(Rc<RuleProxy<'ctx>>),
+    RuleCheck(Rc<RuleProxy<'ctx>>),
+}
+
+/// The left identifier operations of a schema body, which are emitted before
+/// the schema body statements to build the schema cal map.
+pub(crate) enum SchemaLeftIdentifier {
+    /// Merge an undefined attribute into the schema value.
+    Undefined(String),
+    /// Register the attribute function and the statement line in the cal map.
+    AttrFunc {
+        name: String,
+        fn_ptr: u64,
+        line: u64,
+    },
+}
+
+pub(crate) struct SchemaProxy<'ctx> {
+    pub stmt: &'ctx ast::SchemaStmt,
+    pub pkgpath: String,
+    pub filename: String,
+    pub runtime_type: String,
+    pub check_fn_ptr: u64,
+    pub left_identifiers: Vec<SchemaLeftIdentifier>,
+}
+
+pub(crate) struct SchemaAttrProxy<'ctx> {
+    pub stmt: &'ctx ast::SchemaStmt,
+    pub pkgpath: String,
+    pub filename: String,
+    pub runtime_type: String,
+    /// The attribute name which is used for the backtrack.
+    pub name: String,
+    pub attr_stmt: &'ctx ast::Node<ast::Stmt>,
+    pub if_level: usize,
+}
+
+pub(crate) struct RuleProxy<'ctx> {
+    pub stmt: &'ctx ast::RuleStmt,
+    pub pkgpath: String,
+    pub filename: String,
+    pub runtime_type: String,
+    pub check_fn_ptr: u64,
+}
+
+pub(crate) struct LambdaProxy<'ctx> {
+    pub expr: &'ctx ast::LambdaExpr,
+    pub pkgpath: String,
+    pub filename: String,
+    /// The schema stack where the lambda is defined.
+    pub schema_stack: Vec<String>,
+    pub schema_expr_depth: usize,
+}
+
+thread_local! {
+    /// The evaluator which handles the function proxy calls of the current thread.
+    static EVALUATOR: Cell<*const ()> = const { Cell::new(std::ptr::null()) };
+}
+
+/// Sets the proxy handler of the runtime context and the current evaluator,
+/// the previous evaluator is restored on drop.
+pub(crate) struct EvaluatorGuard {
+    prev: *const (),
+}
+
+impl EvaluatorGuard {
+    pub(crate) fn new(evaluator: &Evaluator, ctx: *mut Context) -> Self {
+        let handler: FuncProxyHandler = proxy_handler;
+        unsafe { mut_ptr_as_ref(ctx).func_proxy_handler = handler as usize as u64 };
+        let prev = EVALUATOR.with(|e| e.replace(evaluator as *const Evaluator as *const ()));
+        Self { prev }
+    }
+}
+
+impl Drop for EvaluatorGuard {
+    fn drop(&mut self) {
+        EVALUATOR.with(|e| e.set(self.prev));
+    }
+}
+
+/// Whether an evaluator is running on the current thread.
+pub(crate) fn is_evaluating() -> bool {
+    EVALUATOR.with(|e| !e.get().is_null())
+}
+
+/// The function proxy handler called by the runtime with the proxy index.
+unsafe extern "C-unwind" fn proxy_handler(
+    index: u64,
+    ctx: *mut Context,
+    args: *const ValueRef,
+    kwargs: *const ValueRef,
+) -> *const ValueRef {
+    let evaluator = EVALUATOR.with(|e| e.get()) as *const Evaluator;
+    if evaluator.is_null() {
+        mut_ptr_as_ref(ctx).set_err_type(&ErrType::EvaluationError_TYPE);
+        panic!("{}: the evaluator is not found", INTERNAL_ERROR_MSG);
+    }
+    let evaluator = &*evaluator;
+    let args = ptr_as_ref(args).clone();
+    let kwargs = ptr_as_ref(kwargs).clone();
+    // The runtime reports errors by panics, so the evaluation error in the function
+    // proxy is raised to the runtime and recorded into the runtime context.
+    match evaluator.call_proxy(index, &args, &kwargs) {
+        Ok(value) => value.into_raw(),
+        Err(err) => {
+            mut_ptr_as_ref(ctx).set_err_type(&err.ty);
+            panic!("{}", err.message)
+        }
+    }
+}
+
+/// The evaluation frame state which is saved when calling a function proxy.
+struct Frame {
+    scopes: Vec<Scope>,
+    schema_stack: Vec<String>,
+    schema_expr_depth: usize,
+    lambda_stack: Vec<bool>,
+    local_vars: std::collections::HashSet<String>,
+    target_vars: Vec<String>,
+    backtrack_meta: Option<BacktrackMeta>,
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    /// Register a function proxy and return its tagged function pointer.
+    pub(crate) fn add_proxy(&self, proxy: Proxy<'ctx>) -> u64 {
+        let mut proxies = self.proxies.borrow_mut();
+        proxies.push(proxy);
+        FUNC_PROXY_TAG | (proxies.len() - 1) as u64
+    }
+
+    /// Get the tagged function pointer of the next registered proxy.
+    pub(crate) fn next_proxy_ptr(&self) -> u64 {
+        FUNC_PROXY_TAG | self.proxies.borrow().len() as u64
+    }
+
+    /// Call the function proxy at `index` in a new evaluation frame.
+    pub(crate) fn call_proxy(&self, index: u64, args: &ValueRef, kwargs: &ValueRef) -> EvalResult {
+        let proxy = self
+            .proxies
+            .borrow()
+            .get(index as usize)
+            .cloned()
+            .ok_or_else(|| {
+                EvalError::new(&format!(
+                    "{}: invalid function proxy {}",
+                    INTERNAL_ERROR_MSG, index
+                ))
+            })?;
+        match proxy {
+            Proxy::Lambda(lambda) => {
+                let frame = self.enter_frame(&lambda.pkgpath, &lambda.filename)?;
+                *self.schema_stack.borrow_mut() = lambda.schema_stack.clone();
+                self.schema_expr_depth.set(lambda.schema_expr_depth);
+                *self.lambda_stack.borrow_mut() = vec![true];
+                let value = self.execute_lambda(&lambda, args, kwargs);
+                self.leave_frame(frame);
+                value
+            }
+            Proxy::Schema(schema) => {
+                let frame = self.enter_frame(&schema.pkgpath, &schema.filename)?;
+                let value = self.execute_schema_body(&schema, args, kwargs);
+                self.leave_frame(frame);
+                value
+            }
+            Proxy::SchemaCheck(schema) => {
+                let frame = self.enter_frame(&schema.pkgpath, &schema.filename)?;
+                let value = self.execute_schema_check(&schema, args, kwargs);
+                self.leave_frame(frame);
+                value
+            }
+            Proxy::SchemaAttr(attr) => {
+                let frame = self.enter_frame(&attr.pkgpath, &attr.filename)?;
+                let value = self.execute_schema_attr(&attr, args, kwargs);
+                self.leave_frame(frame);
+                value
+            }
+            Proxy::Rule(rule) => {
+                let frame = self.enter_frame(&rule.pkgpath, &rule.filename)?;
+                let value = self.execute_rule_body(&rule, args, kwargs);
+                self.leave_frame(frame);
+                value
+            }
+            Proxy::RuleCheck(rule) => {
+                let frame = self.enter_frame(&rule.pkgpath, &rule.filename)?;
+                let value = self.execute_rule_check(&rule, args, kwargs);
+                self.leave_frame(frame);
+                value
+            }
+        }
+    }
+
+    /// Enter a new function frame of the package `pkgpath`, which only keeps the builtin
+    /// and the global scopes of the package and enters a new function scope.
+    fn enter_frame(&self, pkgpath: &str, filename: &str) -> Result<Frame, EvalError> {
+        self.init_scope(pkgpath)?;
+        self.push_pkgpath(pkgpath);
+        self.push_filename(filename);
+        let scopes = {
+            let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+            let scopes = pkg_scopes.get_mut(pkgpath).expect(INTERNAL_ERROR_MSG);
+            let saved = scopes.split_off(INNER_LEVEL);
+            scopes.push(Scope::default());
+            saved
+        };
+        Ok(Frame {
+            scopes,
+            schema_stack: std::mem::take(&mut *self.schema_stack.borrow_mut()),
+            schema_expr_depth: self.schema_expr_depth.replace(0),
+            lambda_stack: self.lambda_stack.replace(vec![false]),
+            local_vars: std::mem::take(&mut *self.local_vars.borrow_mut()),
+            target_vars: self.target_vars.replace(vec!["".to_string()]),
+            backtrack_meta: self.backtrack_meta.replace(None),
+        })
+    }
+
+    /// Leave the function frame and restore the evaluation state of the caller.
+    fn leave_frame(&self, frame: Frame) {
+        let pkgpath = self.current_pkgpath();
+        {
+            let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+            let scopes = pkg_scopes.get_mut(&pkgpath).expect(INTERNAL_ERROR_MSG);
+            scopes.truncate(INNER_LEVEL);
+            scopes.extend(frame.scopes);
+        }
+        self.pop_pkgpath();
+        self.pop_filename();
+        *self.schema_stack.borrow_mut() = frame.schema_stack;
+        self.schema_expr_depth.set(frame.schema_expr_depth);
+        *self.lambda_stack.borrow_mut() = frame.lambda_stack;
+        *self.local_vars.borrow_mut() = frame.local_vars;
+        *self.target_vars.borrow_mut() = frame.target_vars;
+        *self.backtrack_meta.borrow_mut() = frame.backtrack_meta;
+    }
+}
@@ -0,0 +1,744 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use std::rc::Rc;
+
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_ast::walker::TypedResultWalker;
+use kclvm_runtime::{schema_runtime_type, ValueRef, CAL_MAP_META_LINE, CAL_MAP_RUNTIME_TYPE};
+
+use crate::error::{EvalError, EvalResult, INVALID_DECORATOR_NAME_MSG};
+use crate::proxy::{Proxy, RuleProxy, SchemaAttrProxy, SchemaLeftIdentifier, SchemaProxy};
+use crate::{utils, BacktrackMeta, Evaluator};
+use crate::{
+    BACKTRACK_CACHE, BACKTRACK_LEVEL_MAP, SCHEMA_ARGS, SCHEMA_CAL_MAP, SCHEMA_CONFIG_META_NAME,
+    SCHEMA_CONFIG_NAME, SCHEMA_KWARGS, SCHEMA_RUNTIME_TYPE, SCHEMA_SELF_NAME,
+};
+
+/// The left identifier of a schema body statement collected before the
+/// schema attribute functions are registered.
+enum LeftIdentifier<'ctx> {
+    Undefined(String),
+    Stmt(String, &'ctx ast::Node<ast::Stmt>),
+}
+
+/// The schema closure values popped from the schema function arguments.
+struct SchemaClosure {
+    instance_pkgpath: ValueRef,
+    record_instance: ValueRef,
+    backtrack_cache: ValueRef,
+    backtrack_level_map: ValueRef,
+    cal_map: ValueRef,
+    attr_optional_mapping: ValueRef,
+    schema_value: ValueRef,
+    config: ValueRef,
+    config_meta: ValueRef,
+    is_sub_schema: ValueRef,
+}
+
+impl SchemaClosure {
+    /// Pop the schema closure values from the schema function arguments.
+    fn pop_from(args: &mut ValueRef) -> Self {
+        let mut pop = || args.list_pop().unwrap_or_else(ValueRef::undefined);
+        Self {
+            instance_pkgpath: pop(),
+            record_instance: pop(),
+            backtrack_cache: pop(),
+            backtrack_level_map: pop(),
+            cal_map: pop(),
+            attr_optional_mapping: pop(),
+            schema_value: pop(),
+            config: pop(),
+            config_meta: pop(),
+            is_sub_schema: pop(),
+        }
+    }
+
+    /// The arguments of the parent, mixin and protocol schema functions.
+    fn to_args(&self, schema_value: &ValueRef) -> ValueRef {
+        ValueRef::list_value(Some(&[
+            // is_sub_schema
+            ValueRef::bool(false),
+            self.config_meta.clone(),
+            self.config.clone(),
+            schema_value.clone(),
+            self.attr_optional_mapping.clone(),
+            self.cal_map.clone(),
+            self.backtrack_level_map.clone(),
+            self.backtrack_cache.clone(),
+            self.record_instance.clone(),
+            self.instance_pkgpath.clone(),
+        ]))
+    }
+}
+
+/// The schema check closure values popped from the check function arguments.
+struct SchemaCheckClosure {
+    backtrack_cache: ValueRef,
+    backtrack_level_map: ValueRef,
+    cal_map: ValueRef,
+    schema_value: ValueRef,
+    config: ValueRef,
+    config_meta: ValueRef,
+}
+
+impl SchemaCheckClosure {
+    /// Pop the schema check closure values from the check or attribute function arguments.
+    fn pop_from(args: &mut ValueRef) -> Self {
+        let mut pop = || args.list_pop().unwrap_or_else(ValueRef::undefined);
+        Self {
+            backtrack_cache: pop(),
+            backtrack_level_map: pop(),
+            cal_map: pop(),
+            schema_value: pop(),
+            config: pop(),
+            config_meta: pop(),
+        }
+    }
+
+    /// The arguments of the parent and mixin schema check functions.
+    fn to_args(&self) -> ValueRef {
+        ValueRef::list_value(Some(&[
+            self.config_meta.clone(),
+            self.config.clone(),
+            self.schema_value.clone(),
+            self.cal_map.clone(),
+            self.backtrack_level_map.clone(),
+            self.backtrack_cache.clone(),
+        ]))
+    }
+
+    /// Append the check closure values into the schema arguments.
+    fn append_to(&self, args: &mut ValueRef) {
+        args.list_append(&self.config_meta);
+        args.list_append(&self.config);
+        args.list_append(&self.schema_value);
+        args.list_append(&self.cal_map);
+        args.list_append(&self.backtrack_level_map);
+        args.list_append(&self.backtrack_cache);
+    }
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    /// Define the schema function value of the schema statement.
+    pub(crate) fn emit_schema(&self, schema_stmt: &'ctx ast::SchemaStmt) -> ValueRef {
+        let pkgpath = self.current_pkgpath();
+        let runtime_type = schema_runtime_type(&schema_stmt.name.node, &pkgpath);
+        let key = schema_stmt as *const ast::SchemaStmt as usize;
+        let cached = self.function_cache.borrow().get(&key).cloned();
+        let (fn_ptr, check_fn_ptr) = match cached {
+            Some(fn_ptrs) => fn_ptrs,
+            None => {
+                let filename = self.current_filename();
+                let mut identifiers = vec![];
+                self.collect_schema_left_identifiers(
+                    &schema_stmt.body,
+                    false,
+                    &mut identifiers,
+                    &mut vec![],
+                );
+                // Register schema attribute functions.
+                let mut if_levels: IndexMap<String, usize> = IndexMap::default();
+                let mut left_identifiers = vec![];
+                for identifier in identifiers {
+                    match identifier {
+                        LeftIdentifier::Undefined(name) => {
+                            left_identifiers.push(SchemaLeftIdentifier::Undefined(name))
+                        }
+                        LeftIdentifier::Stmt(name, stmt) => {
+                            let if_level = if_levels.entry(name.clone()).or_default();
+                            if matches!(&stmt.node, ast::Stmt::If(..)) {
+                                *if_level += 1;
+                            } else {
+                                *if_level = 0;
+                            }
+                            let fn_ptr =
+                                self.add_proxy(Proxy::SchemaAttr(Rc::new(SchemaAttrProxy {
+                                    stmt: schema_stmt,
+                                    pkgpath: pkgpath.clone(),
+                                    filename: filename.clone(),
+                                    runtime_type: runtime_type.clone(),
+                                    name: name.clone(),
+                                    attr_stmt: stmt,
+                                    if_level: *if_level,
+                                })));
+                            left_identifiers.push(SchemaLeftIdentifier::AttrFunc {
+                                name,
+                                fn_ptr,
+                                line: stmt.line,
+                            });
+                        }
+                    }
+                }
+                // The check function is registered just after the body function.
+                let fn_ptr = self.next_proxy_ptr();
+                let schema = Rc::new(SchemaProxy {
+                    stmt: schema_stmt,
+                    pkgpath,
+                    filename,
+                    runtime_type: runtime_type.clone(),
+                    check_fn_ptr: fn_ptr + 1,
+                    left_identifiers,
+                });
+                self.add_proxy(Proxy::Schema(schema.clone()));
+                let check_fn_ptr = self.add_proxy(Proxy::SchemaCheck(schema));
+                self.function_cache
+                    .borrow_mut()
+                    .insert(key, (fn_ptr, check_fn_ptr));
+                (fn_ptr, check_fn_ptr)
+            }
+        };
+        utils::schema_function_value(fn_ptr, check_fn_ptr, &runtime_type)
+    }
+
+    /// Define the rule function value of the rule statement.
+    pub(crate) fn emit_rule(&self, rule_stmt: &'ctx ast::RuleStmt) -> ValueRef {
+        let pkgpath = self.current_pkgpath();
+        let runtime_type = schema_runtime_type(&rule_stmt.name.node, &pkgpath);
+        let key = rule_stmt as *const ast::RuleStmt as usize;
+        let cached = self.function_cache.borrow().get(&key).cloned();
+        let (fn_ptr, check_fn_ptr) = match cached {
+            Some(fn_ptrs) => fn_ptrs,
+            None => {
+                let fn_ptr = self.next_proxy_ptr();
+                let rule = Rc::new(RuleProxy {
+                    stmt: rule_stmt,
+                    pkgpath,
+                    filename: self.current_filename(),
+                    runtime_type: runtime_type.clone(),
+                    check_fn_ptr: fn_ptr + 1,
+                });
+                self.add_proxy(Proxy::Rule(rule.clone()));
+                let check_fn_ptr = self.add_proxy(Proxy::RuleCheck(rule));
+                self.function_cache
+                    .borrow_mut()
+                    .insert(key, (fn_ptr, check_fn_ptr));
+                (fn_ptr, check_fn_ptr)
+            }
+        };
+        utils::schema_function_value(fn_ptr, check_fn_ptr, &runtime_type)
+    }
+
+    /// Collect the left identifiers of the schema body statements, the names in
+    /// the if statements are registered with the outermost if statement.
+    fn collect_schema_left_identifiers(
+        &self,
+        body: &'ctx [Box<ast::Node<ast::Stmt>>],
+        is_in_if: bool,
+        identifiers: &mut Vec<LeftIdentifier<'ctx>>,
+        in_if_names: &mut Vec<String>,
+    ) {
+        fn add_name<'ctx>(
+            name: &str,
+            stmt: &'ctx ast::Node<ast::Stmt>,
+            is_in_if: bool,
+            identifiers: &mut Vec<LeftIdentifier<'ctx>>,
+            in_if_names: &mut Vec<String>,
+        ) {
+            identifiers.push(LeftIdentifier::Undefined(name.to_string()));
+            if is_in_if {
+                in_if_names.push(name.to_string());
+            } else {
+                identifiers.push(LeftIdentifier::Stmt(name.to_string(), stmt));
+            }
+        }
+        for stmt in body {
+            match &stmt.node {
+                ast::Stmt::Unification(unification_stmt) => {
+                    add_name(
+                        &unification_stmt.target.node.names[0],
+                        stmt,
+                        is_in_if,
+                        identifiers,
+                        in_if_names,
+                    );
+                }
+                ast::Stmt::Assign(assign_stmt) => {
+                    for target in &assign_stmt.targets {
+                        add_name(
+                            &target.node.names[0],
+                            stmt,
+                            is_in_if,
+                            identifiers,
+                            in_if_names,
+                        );
+                    }
+                }
+                ast::Stmt::AugAssign(aug_assign_stmt) => {
+                    add_name(
+                        &aug_assign_stmt.target.node.names[0],
+                        stmt,
+                        is_in_if,
+                        identifiers,
+                        in_if_names,
+                    );
+                }
+                ast::Stmt::SchemaAttr(schema_attr) => {
+                    add_name(
+                        &schema_attr.name.node,
+                        stmt,
+                        is_in_if,
+                        identifiers,
+                        in_if_names,
+                    );
+                }
+                ast::Stmt::If(if_stmt) => {
+                    for branch in [&if_stmt.body, &if_stmt.orelse] {
+                        let mut names: Vec<String> = vec![];
+                        self.collect_schema_left_identifiers(branch, true, identifiers, &mut names);
+                        if is_in_if {
+                            in_if_names.extend(names);
+                        } else {
+                            for name in names {
+                                identifiers.push(LeftIdentifier::Stmt(name, stmt));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Add the schema magic variables into the current scope.
+    fn add_schema_variables(
+        &self,
+        closure: &SchemaCheckClosure,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+        runtime_type: &str,
+    ) {
+        self.add_variable(BACKTRACK_CACHE, closure.backtrack_cache.clone());
+        self.add_variable(BACKTRACK_LEVEL_MAP, closure.backtrack_level_map.clone());
+        self.add_variable(SCHEMA_CAL_MAP, closure.cal_map.clone());
+        self.add_variable(SCHEMA_CONFIG_NAME, closure.config.clone());
+        self.add_variable(SCHEMA_CONFIG_META_NAME, closure.config_meta.clone());
+        self.add_variable(SCHEMA_SELF_NAME, closure.schema_value.clone());
+        self.add_variable(SCHEMA_ARGS, args.clone());
+        self.add_variable(SCHEMA_KWARGS, kwargs.clone());
+        self.add_variable(SCHEMA_RUNTIME_TYPE, ValueRef::str(runtime_type));
+    }
+
+    /// Walk the schema or rule arguments out of the schema context.
+    fn walk_schema_arguments(
+        &self,
+        arguments: &'ctx Option<ast::NodeRef<ast::Arguments>>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> Result<(), EvalError> {
+        let schema_stack = std::mem::take(&mut *self.schema_stack.borrow_mut());
+        self.walk_arguments(arguments, args, kwargs)?;
+        *self.schema_stack.borrow_mut() = schema_stack;
+        Ok(())
+    }
+
+    /// Call the schema or rule function of the parent, mixin or protocol `name`.
+    fn call_schema_function(
+        &self,
+        name: &'ctx ast::NodeRef<ast::Identifier>,
+        args: &ValueRef,
+    ) -> EvalResult {
+        let func = self.walk_identifier_with_ctx(&name.node, &ast::ExprContext::Load, None)?;
+        let fn_ptr = func.as_function().fn_ptr;
+        Ok(utils::call_function_ptr(
+            fn_ptr,
+            args,
+            &ValueRef::dict(None),
+        ))
+    }
+
+    /// Call the schema or rule check function of the parent, mixin or protocol `name`.
+    fn call_schema_check_function(
+        &self,
+        name: &'ctx ast::NodeRef<ast::Identifier>,
+        args: &ValueRef,
+    ) -> Result<(), EvalError> {
+        let func = self.walk_identifier_with_ctx(&name.node, &ast::ExprContext::Load, None)?;
+        let check_fn_ptr = func.as_function().check_fn_ptr;
+        utils::call_function_ptr(check_fn_ptr, args, &ValueRef::dict(None));
+        Ok(())
+    }
+
+    /// Execute the schema body function.
+    pub(crate) fn execute_schema_body(
+        &self,
+        schema: &SchemaProxy<'ctx>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> EvalResult {
+        let schema_stmt = schema.stmt;
+        let schema_name = &schema_stmt.name.node;
+        let runtime_type = &schema.runtime_type;
+        utils::set_ctx_filename(&schema.filename);
+        utils::update_ctx_pkgpath(&schema.pkgpath);
+        let mut args = args.clone();
+        let closure = SchemaClosure::pop_from(&mut args);
+        self.add_variable(BACKTRACK_CACHE, closure.backtrack_cache.clone());
+        self.add_variable(BACKTRACK_LEVEL_MAP, closure.backtrack_level_map.clone());
+        self.add_variable(SCHEMA_CAL_MAP, closure.cal_map.clone());
+        self.add_variable(SCHEMA_CONFIG_NAME, closure.config.clone());
+        self.add_variable(SCHEMA_CONFIG_META_NAME, closure.config_meta.clone());
+        self.add_variable(SCHEMA_ARGS, args.clone());
+        self.add_variable(SCHEMA_KWARGS, kwargs.clone());
+        self.add_variable(SCHEMA_RUNTIME_TYPE, ValueRef::str(runtime_type));
+        self.walk_arguments(&schema_stmt.args, &args, kwargs)?;
+        let schema_value = match &schema_stmt.parent_name {
+            Some(parent_name) => {
+                let schema_value = self
+                    .call_schema_function(parent_name, &closure.to_args(&closure.schema_value))?;
+                utils::set_ctx_filename(&schema.filename);
+                schema_value
+            }
+            None => closure.schema_value.clone(),
+        };
+        self.schema_stack.borrow_mut().push(runtime_type.clone());
+        self.add_variable(SCHEMA_SELF_NAME, schema_value.clone());
+        // Schema attribute placeholders and backtrack functions
+        let undefined = ValueRef::undefined();
+        let runtime_type_value = ValueRef::str(runtime_type);
+        for identifier in &schema.left_identifiers {
+            match identifier {
+                SchemaLeftIdentifier::Undefined(name) => {
+                    utils::dict_merge(&schema_value, name, &undefined, 0, -1)
+                }
+                SchemaLeftIdentifier::AttrFunc { name, fn_ptr, line } => {
+                    utils::default_collection_insert_int_pointer(&closure.cal_map, name, *fn_ptr);
+                    utils::default_collection_insert_value(
+                        &closure.cal_map,
+                        &format!("{}_{}", name, CAL_MAP_RUNTIME_TYPE),
+                        &runtime_type_value,
+                    );
+                    utils::default_collection_insert_value(
+                        &closure.cal_map,
+                        &format!("{}_{}", name, CAL_MAP_META_LINE),
+                        &ValueRef::int(*line as i64),
+                    );
+                }
+            }
+        }
+        if closure.record_instance.is_truthy() {
+            // Run schema body statements
+            for stmt in &schema_stmt.body {
+                self.walk_stmt(stmt)?;
+            }
+            // Schema decorators check
+            for decorator in &schema_stmt.decorators {
+                self.walk_decorator_with_name(&decorator.node, Some(schema_name), true)?;
+            }
+            // Append schema default settings
+            utils::schema_default_settings(&schema_value, &closure.config, runtime_type);
+        }
+        // Schema mixin
+        for mixin in &schema_stmt.mixins {
+            self.call_schema_function(mixin, &closure.to_args(&schema_value))?;
+            utils::set_ctx_filename(&schema.filename);
+        }
+        // Schema attribute optional check
+        for stmt in &schema_stmt.body {
+            if let ast::Stmt::SchemaAttr(schema_attr) = &stmt.node {
+                utils::dict_insert_override_item(
+                    &closure.attr_optional_mapping,
+                    &schema_attr.name.node,
+                    &ValueRef::bool(schema_attr.is_optional),
+                )
+            }
+        }
+        if closure.is_sub_schema.is_truthy() {
+            let index_sign_key_name = match &schema_stmt.index_signature {
+                Some(index_signature) => index_signature.node.key_name.as_deref().unwrap_or(""),
+                None => "",
+            };
+            let mut check_args = args.deep_copy();
+            let check_kwargs = kwargs.deep_copy();
+            SchemaCheckClosure {
+                backtrack_cache: closure.backtrack_cache.clone(),
+                backtrack_level_map: closure.backtrack_level_map.clone(),
+                cal_map: closure.cal_map.clone(),
+                schema_value: schema_value.clone(),
+                config: closure.config.clone(),
+                config_meta: closure.config_meta.clone(),
+            }
+            .append_to(&mut check_args);
+            if index_sign_key_name.is_empty() {
+                // Call schema check block function
+                utils::call_function_ptr(schema.check_fn_ptr, &check_args, &check_kwargs);
+            } else {
+                // Call schema check block function with index sign attribute name loop set
+                utils::schema_do_check_with_index_sign_attr(
+                    &check_args,
+                    &check_kwargs,
+                    schema.check_fn_ptr,
+                    index_sign_key_name,
+                );
+            }
+        }
+        // Build a schema value and record instance
+        Ok(utils::schema_with_config(
+            &schema_value,
+            &closure.config,
+            &closure.config_meta,
+            schema_name,
+            &self.current_pkgpath(),
+            &closure.is_sub_schema,
+            &closure.record_instance,
+            &closure.instance_pkgpath,
+            &closure.attr_optional_mapping,
+        ))
+    }
+
+    /// Execute the schema check function.
+    pub(crate) fn execute_schema_check(
+        &self,
+        schema: &SchemaProxy<'ctx>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> EvalResult {
+        let schema_stmt = schema.stmt;
+        let mut args = args.clone();
+        let closure = SchemaCheckClosure::pop_from(&mut args);
+        self.add_schema_variables(&closure, &args, kwargs, &schema.runtime_type);
+        self.walk_schema_arguments(&schema_stmt.args, &args, kwargs)?;
+        self.schema_stack
+            .borrow_mut()
+            .push(schema.runtime_type.clone());
+        // Schema runtime index signature and relaxed check
+        match &schema_stmt.index_signature {
+            Some(index_signature) => {
+                let index_sign_value = match &index_signature.node.value {
+                    Some(value) => self.walk_expr(value)?,
+                    None => ValueRef::undefined(),
+                };
+                utils::schema_value_check(
+                    &closure.schema_value,
+                    &closure.config,
+                    &closure.config_meta,
+                    &schema_stmt.name.node,
+                    &index_sign_value,
+                    index_signature.node.key_name.as_deref().unwrap_or(""),
+                    &index_signature.node.key_type.node,
+                    &index_signature.node.value_type.node,
+                    index_signature.node.any_other,
+                )
+            }
+            None => utils::schema_value_check(
+                &closure.schema_value,
+                &closure.config,
+                &closure.config_meta,
+                &schema_stmt.name.node,
+                &ValueRef::none(),
+                "",
+                "",
+                "",
+                false,
+            ),
+        }
+        // Call base check function
+        if let Some(parent_name) = &schema_stmt.parent_name {
+            self.call_schema_check_function(parent_name, &closure.to_args())?;
+            utils::set_ctx_filename(&schema.filename);
+        }
+        // Call self check function
+        for check_expr in &schema_stmt.checks {
+            self.walk_check_expr(&check_expr.node)?;
+        }
+        // Call mixin check functions
+        for mixin in &schema_stmt.mixins {
+            self.call_schema_check_function(mixin, &closure.to_args())?;
+            utils::set_ctx_filename(&schema.filename);
+        }
+        Ok(closure.schema_value)
+    }
+
+    /// Execute the schema attribute backtrack function.
+    pub(crate) fn execute_schema_attr(
+        &self,
+        attr: &SchemaAttrProxy<'ctx>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> EvalResult {
+        let mut args = args.clone();
+        let closure = SchemaCheckClosure::pop_from(&mut args);
+        self.add_schema_variables(&closure, &args, kwargs, &attr.runtime_type);
+        utils::set_ctx_filename(&attr.filename);
+        self.walk_schema_arguments(&attr.stmt.args, &args, kwargs)?;
+        self.schema_stack
+            .borrow_mut()
+            .push(attr.runtime_type.clone());
+        // Backtrack meta begin
+        if matches!(&attr.attr_stmt.node, ast::Stmt::If(..)) {
+            *self.backtrack_meta.borrow_mut() = Some(BacktrackMeta {
+                target: attr.name.clone(),
+                level: attr.if_level,
+                count: 0,
+                stop: false,
+            });
+        }
+        self.walk_stmt(attr.attr_stmt)?;
+        // Backtrack meta end
+        *self.backtrack_meta.borrow_mut() = None;
+        Ok(closure.schema_value)
+    }
+
+    /// Execute the rule body function.
+    pub(crate) fn execute_rule_body(
+        &self,
+        rule: &RuleProxy<'ctx>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> EvalResult {
+        let rule_stmt = rule.stmt;
+        utils::set_ctx_filename(&rule.filename);
+        let mut args = args.clone();
+        let closure = SchemaClosure::pop_from(&mut args);
+        self.add_variable(BACKTRACK_CACHE, closure.backtrack_cache.clone());
+        self.add_variable(BACKTRACK_LEVEL_MAP, closure.backtrack_level_map.clone());
+        self.add_variable(SCHEMA_CAL_MAP, closure.cal_map.clone());
+        self.add_variable(SCHEMA_CONFIG_NAME, closure.config.clone());
+        self.add_variable(SCHEMA_CONFIG_META_NAME, closure.config_meta.clone());
+        self.add_variable(SCHEMA_ARGS, args.clone());
+        self.add_variable(SCHEMA_KWARGS, kwargs.clone());
+        self.add_variable(SCHEMA_RUNTIME_TYPE, ValueRef::str(&rule.runtime_type));
+        self.walk_arguments(&rule_stmt.args, &args, kwargs)?;
+        self.schema_stack
+            .borrow_mut()
+            .push(rule.runtime_type.clone());
+        self.add_variable(SCHEMA_SELF_NAME, closure.schema_value.clone());
+        // Construct for protocol
+        let schema_value = match &rule_stmt.for_host_name {
+            Some(for_host_name) => {
+                self.call_schema_function(for_host_name, &closure.to_args(&closure.schema_value))?
+            }
+            None => closure.schema_value.clone(),
+        };
+        if closure.record_instance.is_truthy() {
+            // Rule decorators check
+            for decorator in &rule_stmt.decorators {
+                self.walk_decorator_with_name(&decorator.node, Some(&rule_stmt.name.node), true)?;
+            }
+        }
+        if closure.is_sub_schema.is_truthy() {
+            let check_args = SchemaCheckClosure {
+                backtrack_cache: closure.backtrack_cache.clone(),
+                backtrack_level_map: closure.backtrack_level_map.clone(),
+                cal_map: closure.cal_map.clone(),
+                schema_value: schema_value.clone(),
+                config: closure.config.clone(),
+                config_meta: closure.config_meta.clone(),
+            }
+            .to_args();
+            utils::call_function_ptr(rule.check_fn_ptr, &check_args, &ValueRef::dict(None));
+        }
+        Ok(schema_value)
+    }
+
+    /// Execute the rule check function.
+    pub(crate) fn execute_rule_check(
+        &self,
+        rule: &RuleProxy<'ctx>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> EvalResult {
+        let rule_stmt = rule.stmt;
+        let mut args = args.clone();
+        let closure = SchemaCheckClosure::pop_from(&mut args);
+        self.add_schema_variables(&closure, &args, kwargs, &rule.runtime_type);
+        self.walk_schema_arguments(&rule_stmt.args, &args, kwargs)?;
+        self.schema_stack
+            .borrow_mut()
+            .push(rule.runtime_type.clone());
+        // Call base check function
+        for parent_name in &rule_stmt.parent_rules {
+            self.call_schema_check_function(parent_name, &closure.to_args())?;
+        }
+        // Call self rule check expressions
+        for check_expr in &rule_stmt.checks {
+            self.walk_check_expr(&check_expr.node)?;
+        }
+        Ok(closure.schema_value)
+    }
+
+    /// Walk the schema or rule decorator with the target attribute name.
+    pub(crate) fn walk_decorator_with_name(
+        &self,
+        decorator: &'ctx ast::CallExpr,
+        attr_name: Option<&str>,
+        is_schema_target: bool,
+    ) -> EvalResult {
+        crate::check_backtrack_stop!(self);
+        let mut list_value = ValueRef::list(None);
+        let mut dict_value = ValueRef::dict(None);
+        let config_meta = self.get_variable(SCHEMA_CONFIG_META_NAME)?;
+        let config_value = self.get_variable(SCHEMA_CONFIG_NAME)?;
+        for arg in &decorator.args {
+            let value = self.walk_expr(arg)?;
+            list_value.list_append(&value);
+        }
+        for keyword in &decorator.keywords {
+            let name = &keyword.node.arg.node.names[0];
+            let value = match &keyword.node.value {
+                Some(value) => self.walk_expr(value)?,
+                None => ValueRef::none(),
+            };
+            dict_value.dict_insert(name, &value, Default::default(), -1);
+        }
+        let name = match &decorator.func.node {
+            ast::Expr::Identifier(ident) if ident.names.len() == 1 => ident.names[0].clone(),
+            _ => return Err(EvalError::new(INVALID_DECORATOR_NAME_MSG)),
+        };
+        utils::decorator(
+            &name,
+            &list_value,
+            &dict_value,
+            &config_meta,
+            attr_name.unwrap_or(""),
+            &config_value,
+            is_schema_target,
+        );
+        Ok(ValueRef::undefined())
+    }
+
+    /// Walk the function arguments and store the argument values into the current scope.
+    pub(crate) fn walk_arguments(
+        &self,
+        arguments: &'ctx Option<ast::NodeRef<ast::Arguments>>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> Result<(), EvalError> {
+        let arguments = match arguments {
+            Some(arguments) => &arguments.node,
+            None => return Ok(()),
+        };
+        // Default parameter values
+        for (arg_name, default) in arguments.args.iter().zip(&arguments.defaults) {
+            let arg_value = match default {
+                Some(value) => self.walk_expr(value)?,
+                None => ValueRef::none(),
+            };
+            self.store_argument_in_current_scope(&arg_name.node.get_name());
+            self.walk_identifier_with_ctx(
+                &arg_name.node,
+                &ast::ExprContext::Store,
+                Some(arg_value),
+            )?;
+        }
+        // Positional arguments
+        let argument_len = args.len();
+        for (i, arg_name) in arguments.args.iter().enumerate() {
+            if i >= argument_len {
+                break;
+            }
+            let arg_value = args
+                .list_get_option(i as isize)
+                .unwrap_or_else(ValueRef::undefined);
+            self.store_variable(&arg_name.node.names[0], arg_value);
+        }
+        // Keyword arguments
+        for arg_name in &arguments.args {
+            let name = &arg_name.node.names[0];
+            if let Some(arg) = kwargs.dict_get_value(name) {
+                self.store_variable(name, arg);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::ast;
+use kclvm_runtime::{ValueRef, PKG_PATH_PREFIX};
+use kclvm_sema::{builtin, plugin};
+
+use crate::{utils, EvalError, EvalResult, Evaluator, GLOBAL_LEVEL, INTERNAL_ERROR_MSG};
+use crate::{
+    BACKTRACK_CACHE, BACKTRACK_LEVEL_MAP, SCHEMA_ARGS, SCHEMA_CAL_MAP, SCHEMA_CONFIG_META_NAME,
+    SCHEMA_CONFIG_NAME, SCHEMA_KWARGS, SCHEMA_SELF_NAME,
+};
+
+/// The evaluator scope.
+#[derive(Debug, Default)]
+pub struct Scope {
+    /// Scalars denotes the expression statement values without attribute.
+    pub scalars: Vec<ValueRef>,
+    /// schema_scalar_idx denotes whether a schema exists in the scalar list.
+    pub schema_scalar_idx: usize,
+    /// Scope normal variables
+    pub variables: IndexMap<String, ValueRef>,
+    /// Function or schema arguments which can not be updated by the local variables.
+    pub arguments: IndexSet<String>,
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    /// Init a scope named `pkgpath` with all builtin functions
+    pub(crate) fn init_scope(&self, pkgpath: &str) -> Result<(), EvalError> {
+        let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+        if pkg_scopes.contains_key(pkgpath) {
+            return Ok(());
+        }
+        // Init all global types including schema and rule
+        let module_list: &Vec<ast::Module> = match self.program.pkgs.get(pkgpath) {
+            Some(module_list) => module_list,
+            None => pkgpath
+                .strip_prefix(PKG_PATH_PREFIX)
+                .and_then(|pkgpath| self.program.pkgs.get(pkgpath))
+                .ok_or_else(|| EvalError::pkg_not_found(pkgpath))?,
+        };
+        let mut builtin_scope = Scope::default();
+        for module in module_list {
+            for stmt in &module.body {
+                let name = match &stmt.node {
+                    ast::Stmt::Schema(schema_stmt) => &schema_stmt.name.node,
+                    ast::Stmt::Rule(rule_stmt) => &rule_stmt.name.node,
+                    _ => continue,
+                };
+                builtin_scope
+                    .variables
+                    .entry(name.to_string())
+                    .or_insert_with(ValueRef::undefined);
+            }
+        }
+        // Init all builtin functions
+        for symbol in builtin::BUILTIN_FUNCTION_NAMES {
+            let function_name =
+                format!("{}_{}", builtin::KCL_BUILTIN_FUNCTION_MANGLE_PREFIX, symbol);
+            builtin_scope
+                .variables
+                .entry(symbol.to_string())
+                .or_insert_with(|| utils::builtin_function_value(&function_name));
+        }
+        pkg_scopes.insert(pkgpath.to_string(), vec![builtin_scope, Scope::default()]);
+        Ok(())
+    }
+
+    /// Get the scope level
+    pub(crate) fn scope_level(&self) -> usize {
+        let current_pkgpath = self.current_pkgpath();
+        let pkg_scopes = self.pkg_scopes.borrow();
+        let msg = format!("pkgpath {} is not found", current_pkgpath);
+        let scopes = pkg_scopes.get(&current_pkgpath).expect(&msg);
+        // Sub the builtin global scope
+        scopes.len() - 1
+    }
+
+    /// Enter scope
+    pub(crate) fn enter_scope(&self) {
+        let current_pkgpath = self.current_pkgpath();
+        let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+        let msg = format!("pkgpath {} is not found", current_pkgpath);
+        let scopes = pkg_scopes.get_mut(&current_pkgpath).expect(&msg);
+        scopes.push(Scope::default());
+    }
+
+    /// Leave scope
+    pub(crate) fn leave_scope(&self) {
+        let current_pkgpath = self.current_pkgpath();
+        let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+        let msg = format!("pkgpath {} is not found", current_pkgpath);
+        let scopes = pkg_scopes.get_mut(&current_pkgpath).expect(&msg);
+        scopes.pop();
+    }
+
+    /// Run `f` with the mutable scopes of the current package.
+    fn with_scopes<T>(&self, f: impl FnOnce(&mut Vec<Scope>) -> T) -> T {
+        let current_pkgpath = self.current_pkgpath();
+        let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+        let msg = format!("pkgpath {} is not found", current_pkgpath);
+        let scopes = pkg_scopes.get_mut(&current_pkgpath).expect(&msg);
+        f(scopes)
+    }
+
+    /// Append a scalar value into the scope.
+    pub(crate) fn add_scalar(&self, scalar: ValueRef, is_schema: bool) {
+        self.with_scopes(|scopes| {
+            if let Some(last) = scopes.last_mut() {
+                // To avoid conflicts, only the last schema scalar expressions are allowed.
+                if is_schema {
+                    // Remove the last schema scalar.
+                    if last.schema_scalar_idx < last.scalars.len() {
+                        last.scalars.remove(last.schema_scalar_idx);
+                    }
+                    // Override the last schema scalar.
+                    last.scalars.push(scalar);
+                    last.schema_scalar_idx = last.scalars.len() - 1;
+                } else {
+                    last.scalars.push(scalar);
+                }
+            }
+        })
+    }
+
+    /// Append a variable into the current scope when it is not existed.
+    pub(crate) fn add_variable(&self, name: &str, value: ValueRef) {
+        self.with_scopes(|scopes| {
+            if let Some(last) = scopes.last_mut() {
+                if !last.variables.contains_key(name) {
+                    last.variables.insert(name.to_string(), value);
+                }
+            }
+        })
+    }
+
+    /// Store the argument named `name` in the current scope.
+    pub(crate) fn store_argument_in_current_scope(&self, name: &str) {
+        self.with_scopes(|scopes| {
+            if let Some(last) = scopes.last_mut() {
+                last.arguments.insert(name.to_string());
+            }
+        })
+    }
+
+    /// Store the variable named `name` with `value` in the current scope, which
+    /// appends the variable when it is not existed.
+    pub(crate) fn store_variable_in_current_scope(&self, name: &str, value: ValueRef) {
+        self.with_scopes(|scopes| {
+            if let Some(last) = scopes.last_mut() {
+                last.variables.insert(name.to_string(), value);
+            }
+        })
+    }
+
+    /// Store the variable named `name` with `value` from the scope, return false when not found
+    pub(crate) fn store_variable(&self, name: &str, value: ValueRef) -> bool {
+        self.with_scopes(|scopes| {
+            for scope in scopes.iter_mut().rev() {
+                if let Some(var) = scope.variables.get_mut(name) {
+                    *var = value;
+                    return true;
+                }
+            }
+            false
+        })
+    }
+
+    /// Resolve variable in scope, return false when not found
+    pub(crate) fn resolve_variable(&self, name: &str) -> bool {
+        self.with_scopes(|scopes| {
+            scopes
+                .iter()
+                .any(|scope| scope.variables.contains_key(name))
+        })
+    }
+
+    /// Append a variable or update the existed local variable.
+    pub(crate) fn add_or_update_local_variable(&self, name: &str, value: ValueRef) {
+        let local_vars = self.local_vars.borrow();
+        self.with_scopes(|scopes| {
+            let mut existed = false;
+            for (index, scope) in scopes.iter_mut().enumerate().rev() {
+                // If the local varibale is found, store the new value for the variable.
+                // We cannot update rule/lambda/schema arguments because they are read-only.
+                if index > GLOBAL_LEVEL
+                    && !local_vars.contains(name)
+                    && !scope.arguments.contains(name)
+                {
+                    if let Some(var) = scope.variables.get_mut(name) {
+                        *var = value.clone();
+                        existed = true;
+                    }
+                }
+            }
+            // If not found, add a new varibale into the current scope.
+            if !existed {
+                if let Some(last) = scopes.last_mut() {
+                    last.variables.insert(name.to_string(), value);
+                }
+            }
+        })
+    }
+
+    /// Append a variable or update the existed variable in the global scope.
+    pub(crate) fn add_or_update_global_variable(&self, name: &str, value: ValueRef) {
+        self.with_scopes(|scopes| {
+            if let Some(last) = scopes.last_mut() {
+                last.variables.insert(name.to_string(), value);
+            }
+        })
+    }
+
+    /// Get the variable value named `name` from the scope.
+    pub(crate) fn get_variable(&self, name: &str) -> EvalResult {
+        let current_pkgpath = self.current_pkgpath();
+        self.get_variable_in_pkgpath(name, &current_pkgpath)
+    }
+
+    /// Get the variable value named `name` from the schema value, the schema
+    /// attribute is calculated lazily through the schema cal map.
+    pub(crate) fn get_variable_in_schema(&self, name: &str) -> EvalResult {
+        let schema_value = self.get_variable(SCHEMA_SELF_NAME)?;
+        let cal_map = self.get_variable(SCHEMA_CAL_MAP)?;
+        if cal_map.dict_get_value(name).is_some() || schema_value.dict_get_value(name).is_some() {
+            let config = self.get_variable(SCHEMA_CONFIG_NAME)?;
+            let config_meta = self.get_variable(SCHEMA_CONFIG_META_NAME)?;
+            let backtrack_level_map = self.get_variable(BACKTRACK_LEVEL_MAP)?;
+            let backtrack_cache = self.get_variable(BACKTRACK_CACHE)?;
+            let args = self.get_variable(SCHEMA_ARGS)?;
+            let kwargs = self.get_variable(SCHEMA_KWARGS)?;
+            let target_attr = self
+                .target_vars
+                .borrow()
+                .last()
+                .cloned()
+                .unwrap_or_default();
+            Ok(utils::schema_get_value(
+                &schema_value,
+                name,
+                &config,
+                &config_meta,
+                &cal_map,
+                &target_attr,
+                &backtrack_level_map,
+                &backtrack_cache,
+                &args,
+                &kwargs,
+            ))
+        } else {
+            Ok(self
+                .find_variable(name, &self.current_pkgpath())?
+                .unwrap_or_else(ValueRef::undefined))
+        }
+    }
+
+    /// Get the variable value named `name` from the scope named `pkgpath`.
+    pub(crate) fn get_variable_in_pkgpath(&self, name: &str, pkgpath: &str) -> EvalResult {
+        let pkgpath =
+            if !pkgpath.starts_with(PKG_PATH_PREFIX) && pkgpath != kclvm_runtime::MAIN_PKG_PATH {
+                format!("{}{}", PKG_PATH_PREFIX, pkgpath)
+            } else {
+                pkgpath.to_string()
+            };
+        // System module
+        let value = if builtin::STANDARD_SYSTEM_MODULE_NAMES_WITH_AT.contains(&pkgpath.as_str()) {
+            let pkgpath = &pkgpath[1..];
+            if pkgpath == builtin::system_module::UNITS
+                && builtin::system_module::UNITS_FIELD_NAMES.contains(&name)
+            {
+                let value_float: f64 = kclvm_runtime::f64_unit_value(name);
+                let value_int: u64 = kclvm_runtime::u64_unit_value(name);
+                if value_int != 1 {
+                    ValueRef::int(value_int as i64)
+                } else {
+                    ValueRef::float(value_float)
+                }
+            } else {
+                let function_name = format!(
+                    "{}{}_{}",
+                    builtin::KCL_SYSTEM_MODULE_MANGLE_PREFIX,
+                    pkgpath,
+                    name
+                );
+                utils::system_function_value(&function_name)
+            }
+        }
+        // Plugin pkgpath
+        else if pkgpath.starts_with(plugin::PLUGIN_PREFIX_WITH_AT) {
+            let name = format!("{}.{}", &pkgpath[1..], name);
+            utils::plugin_function_value(&name)
+        }
+        // User pkgpath
+        else {
+            match self.find_variable(name, &pkgpath)? {
+                Some(value) => value,
+                None if !self.schema_stack.borrow().is_empty() => ValueRef::undefined(),
+                None => return Err(EvalError::name_not_defined(name)),
+            }
+        };
+        Ok(value)
+    }
+
+    /// Find the variable named `name` from the innermost scope of the package `pkgpath`.
+    fn find_variable(&self, name: &str, pkgpath: &str) -> Result<Option<ValueRef>, EvalError> {
+        let pkg_scopes = self.pkg_scopes.borrow();
+        let scopes = pkg_scopes
+            .get(pkgpath)
+            .ok_or_else(|| EvalError::pkg_not_found(pkgpath))?;
+        Ok(scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.variables.get(name).cloned()))
+    }
+
+    /// Get the current package path.
+    #[inline]
+    pub(crate) fn current_pkgpath(&self) -> String {
+        self.pkgpath_stack
+            .borrow()
+            .last()
+            .expect(INTERNAL_ERROR_MSG)
+            .to_string()
+    }
+
+    /// Get the current module filename.
+    #[inline]
+    pub(crate) fn current_filename(&self) -> String {
+        self.filename_stack
+            .borrow()
+            .last()
+            .expect(INTERNAL_ERROR_MSG)
+            .to_string()
+    }
+
+    #[inline]
+    pub(crate) fn push_pkgpath(&self, pkgpath: &str) {
+        self.pkgpath_stack.borrow_mut().push(pkgpath.to_string());
+    }
+
+    #[inline]
+    pub(crate) fn pop_pkgpath(&self) {
+        self.pkgpath_stack.borrow_mut().pop();
+    }
+
+    #[inline]
+    pub(crate) fn push_filename(&self, filename: &str) {
+        self.filename_stack.borrow_mut().push(filename.to_string());
+    }
+
+    #[inline]
+    pub(crate) fn pop_filename(&self) {
+        self.filename_stack.borrow_mut().pop();
+    }
+
+    /// Whether the evaluator is in a schema or a schema expression.
+    #[inline]
+    pub(crate) fn is_in_schema(&self) -> bool {
+        !self.schema_stack.borrow().is_empty() || self.schema_expr_depth.get() > 0
+    }
+
+    /// Whether the evaluator is in a lambda function body.
+    #[inline]
+    pub(crate) fn is_in_lambda(&self) -> bool {
+        *self.lambda_stack.borrow().last().expect(INTERNAL_ERROR_MSG)
+    }
+}
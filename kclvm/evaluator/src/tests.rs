@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use kclvm_ast::{ast::Program, MAIN_PKG};
+use kclvm_parser::parse_file;
+use kclvm_sema::resolver::resolve_program;
+
+use crate::{run, EvalOptions};
+
+fn evaluate(code: &str) -> String {
+    let mut module = parse_file("main.k", Some(code.to_string())).unwrap();
+    module.pkg = MAIN_PKG.to_string();
+    let mut pkgs = HashMap::new();
+    pkgs.insert(MAIN_PKG.to_string(), vec![module]);
+    let mut program = Program {
+        root: MAIN_PKG.to_string(),
+        main: MAIN_PKG.to_string(),
+        pkgs,
+    };
+    let scope = resolve_program(&mut program);
+    run(&program, scope.import_names, &EvalOptions::default())
+}
+
+#[test]
+fn test_eval_expr() {
+    let cases = [
+        ("a = 1 + 2 * 3", r#"{"a": 7}"#),
+        ("a = [1, 2] + [3]", r#"{"a": [1, 2, 3]}"#),
+        ("a = {k = 1} | {v = 2}", r#"{"a": {"k": 1, "v": 2}}"#),
+        ("a = 1 < 2 < 3", r#"{"a": true}"#),
+        ("a = None or 1", r#"{"a": 1}"#),
+        ("a = 1 if False else 2", r#"{"a": 2}"#),
+        ("a = [i * 2 for i in [1, 2] if i > 1]", r#"{"a": [4]}"#),
+        ("a = {k: v for k, v in {x = 1}}", r#"{"a": {"x": 1}}"#),
+        ("a = all x in [1, 2] { x > 0 }", r#"{"a": true}"#),
+        ("a = filter x in [1, 2, 3] { x > 1 }", r#"{"a": [2, 3]}"#),
+        ("a = [*[1, 2], if True: 3]", r#"{"a": [1, 2, 3]}"#),
+        ("a = \"${1 + 1}\"", r#"{"a": "2"}"#),
+        ("a = [1, 2, 3][1:]", r#"{"a": [2, 3]}"#),
+        ("_a = 1\nb = _a", r#"{"b": 1}"#),
+    ];
+    for (code, expected) in cases {
+        assert_eq!(evaluate(code), expected, "{}", code);
+    }
+}
+
+#[test]
+fn test_eval_stmt() {
+    let cases = [
+        ("a = 1\na += 2", r#"{"a": 3}"#),
+        (
+            "a = 1\nif a > 0:\n    b = 1\nelse:\n    c = 2",
+            r#"{"a": 1, "b": 1}"#,
+        ),
+        ("if False:\n    a = 1\nif a:\n    b = a", ""),
+        (
+            "a = lambda {\n    if False:\n        v = 1\n    v\n}()\nb = 1",
+            r#"{"b": 1}"#,
+        ),
+        ("assert True, \"msg\"\na = 1", r#"{"a": 1}"#),
+        ("a = b = [1]", r#"{"a": [1], "b": [1]}"#),
+    ];
+    for (code, expected) in cases {
+        assert_eq!(evaluate(code), expected, "{}", code);
+    }
+}
+
+#[test]
+fn test_eval_lambda() {
+    let code = r#"
+x = 1
+f = lambda a: int, b = 2 {
+    c = a + b + x
+    c
+}
+g = lambda {
+    lambda y { y + x }
+}
+a = f(1)
+b = f(1, b = 3)
+c = g()(2)
+"#;
+    assert_eq!(evaluate(code), r#"{"x": 1, "a": 4, "b": 5, "c": 3}"#);
+}
+
+#[test]
+fn test_eval_schema() {
+    let code = r#"
+schema Base:
+    name: str
+    labels: {str:str} = {app = name}
+
+schema Person(Base):
+    age: int = 18
+    description: str = "${name} is ${age}"
+
+    check:
+        age > 0
+
+person = Person {
+    name = "Alice"
+    age = 10
+}
+"#;
+    assert_eq!(
+        evaluate(code),
+        r#"{"person": {"name": "Alice", "labels": {"app": "Alice"}, "__settings__": {"output_type": "INLINE", "__schema_type__": "__main__.Person"}, "age": 10, "description": "Alice is 10"}}"#
+    );
+}
+
+#[test]
+fn test_eval_schema_mixin_and_unification() {
+    let code = r#"
+schema Info:
+    mixin [InfoMixin]
+    name: str
+    age?: int
+
+schema InfoMixin:
+    fullName: str = name + " Doe"
+
+_info: Info {name = "John"}
+_info: Info {age = 18}
+fullName = _info.fullName
+age = _info.age
+"#;
+    assert_eq!(evaluate(code), r#"{"fullName": "John Doe", "age": 18}"#);
+}
+
+#[test]
+fn test_eval_schema_backtrack() {
+    let code = r#"
+schema Data:
+    a: int = b + 1
+    b: int = 1
+    if a > 1:
+        b = 2
+
+data = Data {}
+"#;
+    assert_eq!(
+        evaluate(code),
+        r#"{"data": {"a": 3, "b": 2, "__settings__": {"output_type": "INLINE", "__schema_type__": "__main__.Data"}}}"#
+    );
+}
+
+#[test]
+fn test_eval_rule() {
+    let code = r#"
+age = 1
+
+rule Main:
+    age > 0
+
+Main()
+"#;
+    assert_eq!(evaluate(code), r#"{"age": 1}"#);
+}
+
+#[test]
+fn test_eval_runtime_error() {
+    let result = evaluate("a = b");
+    assert!(result.contains("__kcl_PanicInfo__"), "{}", result);
+    assert!(result.contains("name 'b' is not defined"), "{}", result);
+}
+
+#[test]
+fn test_eval_proxy_runtime_error() {
+    // The runtime errors in the schema and lambda proxies are unwound to the evaluator.
+    let code = r#"
+schema Person:
+    age: int
+    check:
+        age > 0, "age must be positive"
+
+p = Person {age = -1}
+"#;
+    let result = evaluate(code);
+    assert!(result.contains("__kcl_PanicInfo__"), "{}", result);
+    assert!(result.contains("age must be positive"), "{}", result);
+    let result = evaluate("f = lambda x {\n    assert x > 1, \"too small\"\n    x\n}\na = f(0)");
+    assert!(result.contains("too small"), "{}", result);
+}
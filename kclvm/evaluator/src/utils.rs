@@ -0,0 +1,481 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+//! Safe wrappers of the runtime value functions which are called by the LLVM
+//! code generator, the evaluator calls them to keep the same runtime semantics.
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use kclvm_ast::ast;
+use kclvm_runtime::*;
+
+use crate::Evaluator;
+
+/// Convert a Rust string to a C string used by the runtime functions.
+#[inline]
+pub(crate) fn cstring(s: &str) -> CString {
+    CString::new(s).unwrap_or_else(|_| panic!("invalid string {:?} with a nul byte", s))
+}
+
+#[inline]
+fn c(s: &CString) -> *const c_char {
+    s.as_ptr() as *const c_char
+}
+
+#[inline]
+fn p(value: &ValueRef) -> *const ValueRef {
+    value as *const ValueRef
+}
+
+/// Runtime functions only mutate the values through the inner shared reference,
+/// so a cloned value is passed when the function requires a mutable pointer.
+#[inline]
+fn mp(value: &mut ValueRef) -> *mut ValueRef {
+    value as *mut ValueRef
+}
+
+#[inline]
+fn v(value: *const ValueRef) -> ValueRef {
+    ptr_as_ref(value).clone()
+}
+
+#[inline]
+fn ctx() -> *mut Context {
+    unsafe { kclvm_context_current() }
+}
+
+/// Update runtime context pkgpath
+pub(crate) fn update_ctx_pkgpath(pkgpath: &str) {
+    Context::current_context_mut().set_kcl_pkgpath(pkgpath);
+}
+
+/// Update runtime context filename
+pub(crate) fn update_ctx_filename<T>(node: &ast::Node<T>) {
+    set_ctx_filename(&node.filename);
+}
+
+/// Set runtime context filename when it is not empty
+pub(crate) fn set_ctx_filename(filename: &str) {
+    Context::current_context_mut().set_kcl_filename(filename);
+}
+
+/// Update runtime context line and column
+pub(crate) fn update_ctx_line_col<T>(e: &Evaluator, node: &ast::Node<T>) {
+    e.current_line.set(node.line);
+    Context::current_context_mut().set_kcl_line_col(node.line as i32, 0);
+}
+
+/// Update runtime context line using the line of the latest walked node
+pub(crate) fn update_ctx_current_line(e: &Evaluator) {
+    Context::current_context_mut().set_kcl_line_col(e.current_line.get() as i32, 0);
+}
+
+/// Get a builtin function value named `function_name`.
+pub(crate) fn builtin_function_value(function_name: &str) -> ValueRef {
+    let fn_ptr = _kclvm_get_fn_ptr_by_name(function_name);
+    ValueRef::func(fn_ptr, 0, ValueRef::none(), function_name, "", false)
+}
+
+/// Get a system module function value named `function_name`.
+pub(crate) fn system_function_value(function_name: &str) -> ValueRef {
+    let fn_ptr = _kclvm_get_fn_ptr_by_name(function_name);
+    ValueRef::func(fn_ptr, 0, ValueRef::none(), function_name, "", false)
+}
+
+/// Get a plugin function value named `name`.
+pub(crate) fn plugin_function_value(name: &str) -> ValueRef {
+    ValueRef::func(0, 0, ValueRef::none(), name, "", true)
+}
+
+/// Get a lambda function value of the proxy function `fn_ptr`.
+pub(crate) fn lambda_function_value(fn_ptr: u64, closure: &ValueRef, name: &str) -> ValueRef {
+    ValueRef::func(fn_ptr, 0, closure.clone(), name, "", false)
+}
+
+/// Get a schema or rule function value and register it in the runtime context.
+pub(crate) fn schema_function_value(
+    fn_ptr: u64,
+    check_fn_ptr: u64,
+    runtime_type: &str,
+) -> ValueRef {
+    let runtime_type = cstring(runtime_type);
+    unsafe {
+        v(kclvm_value_schema_function(
+            fn_ptr as *const u64,
+            check_fn_ptr as *const u64,
+            c(&runtime_type),
+        ))
+    }
+}
+
+/// Call the function `fn_ptr` directly, which is a native function or a proxy function.
+pub(crate) fn call_function_ptr(fn_ptr: u64, args: &ValueRef, kwargs: &ValueRef) -> ValueRef {
+    unsafe { v(call_schema_type_func(fn_ptr, ctx(), p(args), p(kwargs))) }
+}
+
+pub(crate) fn context_set_import_names(import_names: &ValueRef) {
+    unsafe { kclvm_context_set_import_names(ctx(), p(import_names)) }
+}
+
+pub(crate) fn value_function_invoke(
+    func: &ValueRef,
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    pkgpath: &str,
+    is_in_schema: bool,
+) -> ValueRef {
+    let pkgpath = cstring(pkgpath);
+    let is_in_schema = ValueRef::bool(is_in_schema);
+    let mut args = args.clone();
+    unsafe {
+        v(kclvm_value_function_invoke(
+            p(func),
+            ctx(),
+            mp(&mut args),
+            p(kwargs),
+            c(&pkgpath),
+            p(&is_in_schema),
+        ))
+    }
+}
+
+pub(crate) fn schema_value_new(
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    schema_value_or_func: &ValueRef,
+    config: &ValueRef,
+    config_meta: &ValueRef,
+    pkgpath: &str,
+) -> ValueRef {
+    let pkgpath = cstring(pkgpath);
+    let mut args = args.clone();
+    unsafe {
+        v(kclvm_schema_value_new(
+            ctx(),
+            mp(&mut args),
+            p(kwargs),
+            p(schema_value_or_func),
+            p(config),
+            p(config_meta),
+            c(&pkgpath),
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn schema_get_value(
+    schema: &ValueRef,
+    key: &str,
+    config: &ValueRef,
+    config_meta: &ValueRef,
+    cal_map: &ValueRef,
+    target_attr: &str,
+    backtrack_level_map: &ValueRef,
+    backtrack_cache: &ValueRef,
+    args: &ValueRef,
+    kwargs: &ValueRef,
+) -> ValueRef {
+    let key = cstring(key);
+    let target_attr = cstring(target_attr);
+    let mut backtrack_level_map = backtrack_level_map.clone();
+    let mut backtrack_cache = backtrack_cache.clone();
+    let mut args = args.clone();
+    let mut kwargs = kwargs.clone();
+    unsafe {
+        v(kclvm_schema_get_value(
+            p(schema),
+            c(&key),
+            p(config),
+            p(config_meta),
+            p(cal_map),
+            c(&target_attr),
+            mp(&mut backtrack_level_map),
+            mp(&mut backtrack_cache),
+            mp(&mut args),
+            mp(&mut kwargs),
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn schema_with_config(
+    schema: &ValueRef,
+    config: &ValueRef,
+    config_meta: &ValueRef,
+    name: &str,
+    pkgpath: &str,
+    is_sub_schema: &ValueRef,
+    record_instance: &ValueRef,
+    instance_pkgpath: &ValueRef,
+    optional_mapping: &ValueRef,
+) -> ValueRef {
+    let name = cstring(name);
+    let pkgpath = cstring(pkgpath);
+    unsafe {
+        v(kclvm_value_schema_with_config(
+            p(schema),
+            p(config),
+            p(config_meta),
+            c(&name),
+            c(&pkgpath),
+            p(is_sub_schema),
+            p(record_instance),
+            p(instance_pkgpath),
+            p(optional_mapping),
+        ))
+    }
+}
+
+pub(crate) fn schema_backtrack_cache(
+    schema: &ValueRef,
+    cache: &ValueRef,
+    cal_map: &ValueRef,
+    name: &str,
+    runtime_type: &ValueRef,
+) {
+    let name = cstring(name);
+    let mut cache = cache.clone();
+    unsafe {
+        kclvm_schema_backtrack_cache(
+            p(schema),
+            mp(&mut cache),
+            p(cal_map),
+            c(&name),
+            p(runtime_type),
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn schema_value_check(
+    schema: &ValueRef,
+    config: &ValueRef,
+    config_meta: &ValueRef,
+    name: &str,
+    index_sign_value: &ValueRef,
+    key_name: &str,
+    key_type: &str,
+    value_type: &str,
+    any_other: bool,
+) {
+    let name = cstring(name);
+    let key_name = cstring(key_name);
+    let key_type = cstring(key_type);
+    let value_type = cstring(value_type);
+    let mut schema = schema.clone();
+    unsafe {
+        kclvm_schema_value_check(
+            mp(&mut schema),
+            p(config),
+            p(config_meta),
+            c(&name),
+            p(index_sign_value),
+            c(&key_name),
+            c(&key_type),
+            c(&value_type),
+            any_other as i8,
+        )
+    }
+}
+
+pub(crate) fn schema_do_check_with_index_sign_attr(
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    check_fn_ptr: u64,
+    attr_name: &str,
+) {
+    let attr_name = cstring(attr_name);
+    unsafe {
+        kclvm_schema_do_check_with_index_sign_attr(
+            ctx(),
+            p(args),
+            p(kwargs),
+            check_fn_ptr as *const u64,
+            c(&attr_name),
+        )
+    }
+}
+
+pub(crate) fn schema_optional_check(schema: &ValueRef) {
+    unsafe { kclvm_schema_optional_check(p(schema)) }
+}
+
+pub(crate) fn schema_default_settings(schema: &ValueRef, config: &ValueRef, runtime_type: &str) {
+    let runtime_type = cstring(runtime_type);
+    let mut schema = schema.clone();
+    unsafe { kclvm_schema_default_settings(mp(&mut schema), p(config), c(&runtime_type)) }
+}
+
+pub(crate) fn schema_assert(value: &ValueRef, msg: &ValueRef, config_meta: &ValueRef) {
+    unsafe { kclvm_schema_assert(p(value), p(msg), p(config_meta)) }
+}
+
+pub(crate) fn convert_collection_value(
+    value: &ValueRef,
+    tpe: &str,
+    is_in_schema: bool,
+) -> ValueRef {
+    let tpe = cstring(tpe);
+    let is_in_schema = ValueRef::bool(is_in_schema);
+    unsafe {
+        v(kclvm_convert_collection_value(
+            p(value),
+            c(&tpe),
+            p(&is_in_schema),
+        ))
+    }
+}
+
+pub(crate) fn config_attr_map(value: &ValueRef, name: &str, type_str: &str) {
+    let name = cstring(name);
+    let type_str = cstring(type_str);
+    let mut value = value.clone();
+    unsafe { kclvm_config_attr_map(mp(&mut value), c(&name), c(&type_str)) }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decorator(
+    name: &str,
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    config_meta: &ValueRef,
+    attr_name: &str,
+    config: &ValueRef,
+    is_schema_target: bool,
+) {
+    let name = cstring(name);
+    let attr_name = cstring(attr_name);
+    let is_schema_target = ValueRef::bool(is_schema_target);
+    unsafe {
+        kclvm_value_Decorator(
+            c(&name),
+            p(args),
+            p(kwargs),
+            p(config_meta),
+            c(&attr_name),
+            p(config),
+            p(&is_schema_target),
+        );
+    }
+}
+
+pub(crate) fn value_union(schema: &ValueRef, value: &ValueRef) {
+    let mut schema = schema.clone();
+    unsafe {
+        kclvm_value_union(mp(&mut schema), p(value));
+    }
+}
+
+pub(crate) fn load_attr(obj: &ValueRef, key: &str) -> ValueRef {
+    let key = cstring(key);
+    unsafe { v(kclvm_value_load_attr(p(obj), c(&key))) }
+}
+
+pub(crate) fn load_attr_option(obj: &ValueRef, key: &str) -> ValueRef {
+    let key = cstring(key);
+    unsafe { v(kclvm_value_load_attr_option(p(obj), c(&key))) }
+}
+
+pub(crate) fn remove_item(value: &ValueRef, item: &ValueRef) {
+    let mut value = value.clone();
+    unsafe { kclvm_value_remove_item(mp(&mut value), p(item)) }
+}
+
+/// Merge the key value pair into the dict with the attribute type checking.
+pub(crate) fn dict_merge(dict: &ValueRef, key: &str, value: &ValueRef, op: i32, index: i32) {
+    let key = cstring(key);
+    let mut dict = dict.clone();
+    unsafe { kclvm_dict_merge(mp(&mut dict), c(&key), p(value), op, index) }
+}
+
+pub(crate) fn dict_insert_value(
+    dict: &ValueRef,
+    key: &ValueRef,
+    value: &ValueRef,
+    op: i32,
+    index: i32,
+) {
+    let mut dict = dict.clone();
+    unsafe { kclvm_dict_insert_value(mp(&mut dict), p(key), p(value), op, index) }
+}
+
+pub(crate) fn dict_set_value(dict: &ValueRef, key: &str, value: &ValueRef) {
+    let key = cstring(key);
+    let mut dict = dict.clone();
+    unsafe { kclvm_dict_set_value(mp(&mut dict), c(&key), p(value)) }
+}
+
+pub(crate) fn default_collection_insert_int_pointer(dict: &ValueRef, key: &str, ptr: u64) {
+    let key = cstring(key);
+    let mut dict = dict.clone();
+    unsafe {
+        kclvm_default_collection_insert_int_pointer(mp(&mut dict), c(&key), ptr as *const u64)
+    }
+}
+
+pub(crate) fn default_collection_insert_value(dict: &ValueRef, key: &str, value: &ValueRef) {
+    let key = cstring(key);
+    let mut dict = dict.clone();
+    unsafe { kclvm_default_collection_insert_value(mp(&mut dict), c(&key), p(value)) }
+}
+
+/// Insert or override the key value pair into the dict.
+pub(crate) fn dict_insert_override_item(dict: &ValueRef, key: &str, value: &ValueRef) {
+    let mut dict = dict.clone();
+    dict.dict_insert(key, value, ConfigEntryOperationKind::Override, -1);
+}
+
+pub(crate) fn assert(value: &ValueRef, msg: &ValueRef) {
+    unsafe { kclvm_assert(p(value), p(msg)) }
+}
+
+pub(crate) fn dict_insert_unpack(dict: &ValueRef, value: &ValueRef) {
+    let mut dict = dict.clone();
+    unsafe { kclvm_dict_insert_unpack(mp(&mut dict), p(value)) }
+}
+
+pub(crate) fn list_append_unpack(list: &ValueRef, value: &ValueRef) {
+    let mut list = list.clone();
+    unsafe { kclvm_list_append_unpack(mp(&mut list), p(value)) }
+}
+
+/// The runtime unary operator functions, e.g., `kclvm_value_unary_minus`.
+pub(crate) type UnaryFunc = unsafe extern "C-unwind" fn(*const ValueRef) -> *mut ValueRef;
+/// The runtime binary operator functions, e.g., `kclvm_value_op_add`.
+pub(crate) type BinaryFunc =
+    unsafe extern "C-unwind" fn(*const ValueRef, *const ValueRef) -> *mut ValueRef;
+/// The runtime augmented assignment operator functions, e.g., `kclvm_value_op_aug_add`.
+pub(crate) type AugFunc =
+    unsafe extern "C-unwind" fn(*mut ValueRef, *const ValueRef) -> *const ValueRef;
+/// The runtime slice functions, e.g., `kclvm_value_slice`.
+pub(crate) type SliceFunc = unsafe extern "C-unwind" fn(
+    *const ValueRef,
+    *const ValueRef,
+    *const ValueRef,
+    *const ValueRef,
+) -> *const ValueRef;
+
+pub(crate) fn unary_op(f: UnaryFunc, value: &ValueRef) -> ValueRef {
+    unsafe { v(f(p(value))) }
+}
+
+pub(crate) fn binary_op(f: BinaryFunc, left: &ValueRef, right: &ValueRef) -> ValueRef {
+    unsafe { v(f(p(left), p(right))) }
+}
+
+/// Call the augmented assignment operator, the left value is updated in place
+/// when it is a reference value such as a list or a dict.
+pub(crate) fn aug_op(f: AugFunc, left: &ValueRef, right: &ValueRef) -> ValueRef {
+    let mut left = left.clone();
+    unsafe { v(f(mp(&mut left), p(right))) }
+}
+
+pub(crate) fn slice_op(
+    f: SliceFunc,
+    value: &ValueRef,
+    lower: &ValueRef,
+    upper: &ValueRef,
+    step: &ValueRef,
+) -> ValueRef {
+    unsafe { v(f(p(value), p(lower), p(upper), p(step))) }
+}
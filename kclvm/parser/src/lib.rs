@@ -14,6 +14,7 @@ use compiler_base_macros::bug;
 use compiler_base_session::Session;
 use compiler_base_span::span::new_byte_pos;
 use kclvm_ast::ast;
+use kclvm_config::cache::PkgCache;
 use kclvm_config::modfile::{
     get_pkg_root_from_paths, get_vendor_home, KCL_FILE_EXTENSION, KCL_FILE_SUFFIX, KCL_MOD_FILE,
     KCL_MOD_PATH_ENV,
@@ -26,12 +27,17 @@ use kclvm_utils::pkgpath::rm_external_pkg_name;
 
 use lexer::parse_token_streams;
 use parser::Parser;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use kclvm_span::create_session_globals_then;
 
+/// The kind of the parsed package ASTs in the package cache.
+const AST_CACHE_KIND: &str = "ast";
+
 #[derive(Default, Debug)]
 /// [`PkgInfo`] is some basic information about a kcl package.
 pub(crate) struct PkgInfo {
@@ -160,6 +166,60 @@ pub fn parse_file_with_session(
     Ok(m)
 }
 
+/// Parse a KCL file to the AST module with the module cache, the cached module is reused
+/// if the source code of the file is unchanged. Different from [`parse_file`], the module
+/// is returned even if there are syntax errors.
+pub fn parse_file_with_cache(
+    filename: &str,
+    code: Option<String>,
+    module_cache: &KCLModuleCache,
+) -> Result<ast::Module, String> {
+    let sess = Arc::new(ParseSession::default());
+    create_session_globals_then(move || {
+        parse_file_with_session_and_cache(sess, filename, code, module_cache)
+    })
+}
+
+/// Parse a KCL file to the AST module with the parse session and the module cache.
+fn parse_file_with_session_and_cache(
+    sess: Arc<ParseSession>,
+    filename: &str,
+    code: Option<String>,
+    module_cache: &KCLModuleCache,
+) -> Result<ast::Module, String> {
+    let code = match code {
+        Some(code) => code,
+        None => std::fs::read_to_string(filename)
+            .map_err(|err| format!("Failed to load KCL file '{filename}'. Because '{err}'"))?,
+    };
+    let mut hasher = DefaultHasher::new();
+    code.hash(&mut hasher);
+    let code_hash = hasher.finish();
+    if let Some(cached) = module_cache.read().unwrap().get(filename) {
+        if cached.code_hash == code_hash {
+            // Keep the source file in the source map for the diagnostics of the resolver.
+            sess.0
+                .sm
+                .new_source_file(PathBuf::from(filename).into(), code);
+            return Ok(cached.module.clone());
+        }
+    }
+    let diag_count = sess.1.borrow().diagnostics.len();
+    let module = parse_file_with_session(sess.clone(), filename, Some(code))?;
+    // The modules with syntax errors are not cached, so that the errors are
+    // reported again by the next session.
+    if sess.1.borrow().diagnostics.len() == diag_count {
+        module_cache.write().unwrap().insert(
+            filename.to_string(),
+            CachedModule {
+                code_hash,
+                module: module.clone(),
+            },
+        );
+    }
+    Ok(module)
+}
+
 /// Parse a KCL file to the AST module with the parse session and the global session
 #[inline]
 pub fn parse_file_with_global_session(
@@ -208,6 +268,17 @@ pub fn parse_expr(src: &str) -> Option<ast::NodeRef<ast::Expr>> {
     }
 }
 
+/// The parsed modules cached by their file names, which can be shared by multiple
+/// [`load_program`] calls, e.g., the requests of the language server.
+pub type KCLModuleCache = Arc<RwLock<HashMap<String, CachedModule>>>;
+
+/// A parsed module and the hash of the source code it is parsed from.
+#[derive(Debug, Clone)]
+pub struct CachedModule {
+    pub code_hash: u64,
+    pub module: ast::Module,
+}
+
 #[derive(Debug, Clone)]
 pub struct LoadProgramOptions {
     pub work_dir: String,
@@ -223,6 +294,9 @@ pub struct LoadProgramOptions {
     pub load_packages: bool,
     /// Whether to load plugins
     pub load_plugins: bool,
+    /// The module cache, a file is parsed again only when its source code is
+    /// different from the cached one.
+    pub module_cache: Option<KCLModuleCache>,
 }
 
 impl Default for LoadProgramOptions {
@@ -237,6 +311,7 @@ impl Default for LoadProgramOptions {
             mode: ParseMode::ParseComments,
             load_packages: true,
             load_plugins: false,
+            module_cache: None,
         }
     }
 }
@@ -246,7 +321,6 @@ pub fn load_program(
     paths: &[&str],
     opts: Option<LoadProgramOptions>,
 ) -> Result<ast::Program, String> {
-    // todo: support cache
     if let Some(opts) = opts {
         Loader::new(sess, paths, Some(opts)).load_main()
     } else {
@@ -259,6 +333,7 @@ struct Loader {
     paths: Vec<String>,
     opts: LoadProgramOptions,
     missing_pkgs: Vec<String>,
+    pkg_cache: Option<PkgCache>,
 }
 
 impl Loader {
@@ -268,6 +343,7 @@ impl Loader {
             paths: paths.iter().map(|s| s.to_string()).collect(),
             opts: opts.unwrap_or_default(),
             missing_pkgs: Default::default(),
+            pkg_cache: None,
         }
     }
 
@@ -279,6 +355,7 @@ impl Loader {
     fn _load_main(&mut self) -> Result<ast::Program, String> {
         let root = get_pkg_root_from_paths(&self.paths)?;
         let main_pkg_name = kclvm_ast::MAIN_PKG.to_string();
+        self.pkg_cache = PkgCache::new(&root);
 
         // Get files from options with root.
         let k_files = self.get_main_files(&root)?;
@@ -288,15 +365,11 @@ impl Loader {
         let mut pkg_files = Vec::new();
         for (i, filename) in k_files.iter().enumerate() {
             if i < self.opts.k_code_list.len() {
-                let mut m = parse_file_with_session(
-                    self.sess.clone(),
-                    filename,
-                    Some(self.opts.k_code_list[i].clone()),
-                )?;
+                let mut m = self.parse_file(filename, Some(self.opts.k_code_list[i].clone()))?;
                 self.fix_rel_import_path(&root, &mut m);
                 pkg_files.push(m)
             } else {
-                let mut m = parse_file_with_session(self.sess.clone(), filename, None)?;
+                let mut m = self.parse_file(filename, None)?;
                 self.fix_rel_import_path(&root, &mut m);
                 pkg_files.push(m);
             }
@@ -317,6 +390,69 @@ impl Loader {
         })
     }
 
+    /// Parses the file, the module in the module cache is reused if the source code
+    /// of the file is unchanged.
+    fn parse_file(&self, filename: &str, code: Option<String>) -> Result<ast::Module, String> {
+        match &self.opts.module_cache {
+            Some(module_cache) => {
+                parse_file_with_session_and_cache(self.sess.clone(), filename, code, module_cache)
+            }
+            None => parse_file_with_session(self.sess.clone(), filename, code),
+        }
+    }
+
+    /// Parses the files of the imported package, the modules are loaded from the package
+    /// cache if it is enabled and the source code of the package is unchanged.
+    fn parse_pkg_files(
+        &self,
+        pkgpath: &str,
+        k_files: &[String],
+    ) -> Result<Vec<ast::Module>, String> {
+        let pkg_cache = match &self.pkg_cache {
+            Some(pkg_cache) if pkg_cache.is_cached_pkg(pkgpath) => pkg_cache,
+            _ => {
+                return k_files
+                    .iter()
+                    .map(|filename| self.parse_file(filename, None))
+                    .collect();
+            }
+        };
+        let mut sources = Vec::with_capacity(k_files.len());
+        for filename in k_files {
+            let code = std::fs::read_to_string(filename)
+                .map_err(|err| format!("Failed to load KCL file '{filename}'. Because '{err}'"))?;
+            sources.push((filename.to_string(), code));
+        }
+        // The modules parsed in different modes, e.g., with or without comments, are not shared.
+        let key = pkg_cache.key(
+            AST_CACHE_KIND,
+            pkgpath,
+            &sources,
+            &[format!("{:?}", self.opts.mode)],
+        );
+        if let Some(modules) = pkg_cache.load::<Vec<ast::Module>>(&key) {
+            // Keep the source files in the source map for the diagnostics of the resolver.
+            for (filename, code) in sources {
+                self.sess
+                    .0
+                    .sm
+                    .new_source_file(PathBuf::from(filename).into(), code);
+            }
+            return Ok(modules);
+        }
+        let diag_count = self.sess.1.borrow().diagnostics.len();
+        let modules = sources
+            .into_iter()
+            .map(|(filename, code)| self.parse_file(&filename, Some(code)))
+            .collect::<Result<Vec<ast::Module>, String>>()?;
+        // The packages with syntax errors are not cached, and a failure to save
+        // the cache only makes the package parsed again by the next loading.
+        if self.sess.1.borrow().diagnostics.len() == diag_count {
+            let _ = pkg_cache.save(&key, &modules);
+        }
+        Ok(modules)
+    }
+
     /// [`find_packages`] will find the kcl package.
     /// If the package is found, the basic information of the package [`PkgInfo`] will be returned.
     ///
@@ -548,10 +684,7 @@ impl Loader {
         }
 
         let mut pkg_files = Vec::new();
-        let k_files = pkg_info.k_files.clone();
-        for filename in k_files {
-            let mut m = parse_file_with_session(self.sess.clone(), filename.as_str(), None)?;
-
+        for mut m in self.parse_pkg_files(&pkg_info.pkg_path, &pkg_info.k_files)? {
             m.pkg = pkg_info.pkg_path.clone();
             m.name = "".to_string();
             self.fix_rel_import_path(&pkg_info.pkg_root, &mut m);
@@ -434,14 +434,28 @@ impl<'a> Parser<'a> {
     }
 
     /// Syntax:
-    /// type_alias_stmt: "type" NAME ASSIGN type
+    /// type_alias_stmt: "type" NAME [type_params] ASSIGN type
     fn parse_type_alias_stmt(&mut self) -> NodeRef<Stmt> {
         self.bump_keyword(kw::Type);
 
         let type_name_pos = self.token;
-        let expr = self.parse_expr();
-        let type_name = self.expr_as_identifier(expr, type_name_pos);
-        let type_name_end = self.prev_token;
+        let has_type_params = self.token.ident().is_some()
+            && matches!(
+                self.cursor.peek(),
+                Some(Token {
+                    kind: TokenKind::OpenDelim(DelimToken::Bracket),
+                    ..
+                })
+            );
+        let (type_name, type_name_end, type_params) = if has_type_params {
+            let type_name = self.parse_identifier().node;
+            let type_name_end = self.prev_token;
+            (type_name, type_name_end, self.parse_type_params())
+        } else {
+            let expr = self.parse_expr();
+            let type_name = self.expr_as_identifier(expr, type_name_pos);
+            (type_name, self.prev_token, vec![])
+        };
 
         self.bump_token(TokenKind::Assign);
 
@@ -454,6 +468,7 @@ impl<'a> Parser<'a> {
         node_ref!(
             Stmt::TypeAlias(TypeAliasStmt {
                 type_name: node_ref!(type_name, self.token_span_pos(type_name_pos, type_name_end)),
+                type_params,
                 type_value: node_ref!(typ.node.to_string(), self.token_span_pos(typ_pos, typ_end)),
                 ty: typ,
             }),
@@ -461,6 +476,45 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Syntax:
+    /// type_params: LEFT_BRACKETS NAME (COMMA NAME)* RIGHT_BRACKETS
+    fn parse_type_params(&mut self) -> Vec<NodeRef<String>> {
+        self.bump_token(TokenKind::OpenDelim(DelimToken::Bracket));
+        let mut type_params = vec![];
+        loop {
+            match self.token.kind {
+                TokenKind::CloseDelim(DelimToken::Bracket) => {
+                    self.bump();
+                    break;
+                }
+                TokenKind::Newline | TokenKind::Eof => {
+                    self.sess.struct_token_error(
+                        &[TokenKind::CloseDelim(DelimToken::Bracket).into()],
+                        self.token,
+                    );
+                    break;
+                }
+                TokenKind::Comma => self.bump(),
+                _ => {
+                    let token = self.token;
+                    match token.ident() {
+                        Some(id) => {
+                            self.bump();
+                            type_params
+                                .push(node_ref!(id.as_str(), self.token_span_pos(token, token)));
+                        }
+                        None => {
+                            self.sess
+                                .struct_token_error(&[TokenKind::ident_value()], token);
+                            self.bump();
+                        }
+                    }
+                }
+            }
+        }
+        type_params
+    }
+
     /// Syntax:
     /// if_stmt: IF test COLON execution_block (ELIF test COLON execution_block)* (ELSE COLON execution_block)?
     /// execution_block: if_simple_stmt | NEWLINE _INDENT schema_init_stmt+ _DEDENT
@@ -581,9 +635,12 @@ impl<'a> Parser<'a> {
 
     /// Syntax:
     /// schema_stmt: [decorators] (SCHEMA|MIXIN|PROTOCOL) NAME
-    ///   [LEFT_BRACKETS [schema_arguments] RIGHT_BRACKETS]
+    ///   [schema_parameters]
     ///   [LEFT_PARENTHESES identifier (COMMA identifier)* RIGHT_PARENTHESES]
     ///   [for_host] COLON NEWLINE [schema_body]
+    ///
+    /// The type parameters are declared with the `type` keyword before the schema
+    /// arguments, e.g., `T` in `schema Page[type T, size: int = 10]`.
     fn parse_schema_stmt(&mut self, decorators: Option<Vec<NodeRef<CallExpr>>>) -> NodeRef<Stmt> {
         let token = self.token;
 
@@ -626,15 +683,12 @@ impl<'a> Parser<'a> {
             is_protocol = true;
         }
 
-        // schema Name[args...]
-        let args = if let TokenKind::OpenDelim(DelimToken::Bracket) = self.token.kind {
-            self.parse_parameters(
-                &[TokenKind::OpenDelim(DelimToken::Bracket)],
-                &[TokenKind::CloseDelim(DelimToken::Bracket)],
-                true,
-            )
+        // schema Name[type T, args...]
+        let (type_params, args) = if let TokenKind::OpenDelim(DelimToken::Bracket) = self.token.kind
+        {
+            self.parse_schema_parameters()
         } else {
-            None
+            (vec![], None)
         };
 
         // schema Name [args...](Base)
@@ -678,6 +732,7 @@ impl<'a> Parser<'a> {
                     for_host_name,
                     is_mixin,
                     is_protocol,
+                    type_params,
                     args,
                     mixins: body.mixins,
                     body: body.body,
@@ -697,6 +752,7 @@ impl<'a> Parser<'a> {
                     for_host_name,
                     is_mixin,
                     is_protocol,
+                    type_params,
                     args,
                     mixins: vec![],
                     body: vec![],
@@ -744,6 +800,39 @@ impl<'a> Parser<'a> {
         decorators
     }
 
+    /// Syntax:
+    /// schema_parameters: LEFT_BRACKETS [schema_type_params [COMMA]] [schema_arguments] RIGHT_BRACKETS
+    /// schema_type_params: TYPE NAME (COMMA TYPE NAME)*
+    fn parse_schema_parameters(&mut self) -> (Vec<NodeRef<String>>, Option<NodeRef<Arguments>>) {
+        let has_type_params =
+            matches!(self.cursor.peek(), Some(token) if token.is_keyword(kw::Type));
+        if !has_type_params {
+            let args = self.parse_parameters(
+                &[TokenKind::OpenDelim(DelimToken::Bracket)],
+                &[TokenKind::CloseDelim(DelimToken::Bracket)],
+                true,
+            );
+            return (vec![], args);
+        }
+        self.bump_token(TokenKind::OpenDelim(DelimToken::Bracket));
+        let mut type_params = vec![];
+        while self.token.is_keyword(kw::Type) {
+            self.bump_keyword(kw::Type);
+            let name = self.parse_identifier();
+            let pos = name.pos();
+            type_params.push(node_ref!(name.node.names.join("."), pos));
+            if let TokenKind::Comma = self.token.kind {
+                self.bump();
+            }
+        }
+        let args = self.parse_parameters(&[], &[TokenKind::CloseDelim(DelimToken::Bracket)], true);
+        // An empty argument list after the type parameters is treated as no arguments.
+        match args {
+            Some(args) if args.node.args.is_empty() => (type_params, None),
+            args => (type_params, args),
+        }
+    }
+
     /// Syntax:
     /// schema_arguments: schema_argument (COMMA schema_argument)*
     /// schema_argument: NAME [COLON type] [ASSIGN test]
@@ -1011,6 +1100,7 @@ impl<'a> Parser<'a> {
             for_host_name: None,
             is_mixin: false,
             is_protocol: false,
+            type_params: Vec::new(),
             args: None,
             decorators: Vec::new(),
         }
@@ -1018,6 +1018,22 @@ fn test_union_type() {
     );
 }
 
+#[test]
+fn test_function_type() {
+    check_parsing_type(
+        r####"() -> int"####,
+        expect![[r#"
+        Node { node: Function(FunctionType { params_ty: [], ret_ty: Node { node: Basic(Int), filename: "", line: 1, column: 6, end_line: 1, end_column: 9 } }), filename: "", line: 1, column: 0, end_line: 1, end_column: 9 }
+        "#]],
+    );
+    check_parsing_type(
+        r####"(int, str) -> bool"####,
+        expect![[r#"
+        Node { node: Function(FunctionType { params_ty: [Node { node: Basic(Int), filename: "", line: 1, column: 1, end_line: 1, end_column: 4 }, Node { node: Basic(Str), filename: "", line: 1, column: 6, end_line: 1, end_column: 9 }], ret_ty: Node { node: Basic(Bool), filename: "", line: 1, column: 14, end_line: 1, end_column: 18 } }), filename: "", line: 1, column: 0, end_line: 1, end_column: 18 }
+        "#]],
+    );
+}
+
 #[test]
 fn test_named_type() {
     check_parsing_type(
@@ -1034,6 +1050,22 @@ fn test_named_type() {
     )
 }
 
+#[test]
+fn test_generic_type() {
+    check_parsing_type(
+        r####"Page[Person]"####,
+        expect![[r#"
+        Node { node: Generic(GenericType { name: Identifier { names: ["Page"], pkgpath: "", ctx: Load }, args: [Node { node: Named(Identifier { names: ["Person"], pkgpath: "", ctx: Load }), filename: "", line: 1, column: 5, end_line: 1, end_column: 11 }] }), filename: "", line: 1, column: 0, end_line: 1, end_column: 12 }
+        "#]],
+    );
+    check_parsing_type(
+        r####"Pair[int, [str]]"####,
+        expect![[r#"
+        Node { node: Generic(GenericType { name: Identifier { names: ["Pair"], pkgpath: "", ctx: Load }, args: [Node { node: Basic(Int), filename: "", line: 1, column: 5, end_line: 1, end_column: 8 }, Node { node: List(ListType { inner_type: Some(Node { node: Basic(Str), filename: "", line: 1, column: 11, end_line: 1, end_column: 14 }) }), filename: "", line: 1, column: 10, end_line: 1, end_column: 15 }] }), filename: "", line: 1, column: 0, end_line: 1, end_column: 16 }
+        "#]],
+    );
+}
+
 #[test]
 fn test_literal_type() {
     check_parsing_type(
@@ -1090,6 +1122,14 @@ fn test_type_str() {
         r####"[ [{str: float}] | int]"####,
         expect![[r#"[[{str:float}]|int]"#]],
     );
+    check_type_str(
+        r####"(int,[str]) ->  (int) -> str | int"####,
+        expect![[r#"(int, [str]) -> (int) -> str|int"#]],
+    );
+    check_type_str(
+        r####"Pair[ str,Page[int] ] | None"####,
+        expect![[r#"Pair[str, Page[int]]|None"#]],
+    );
 }
 
 #[test]
@@ -1131,6 +1171,36 @@ schema TestBool:
     );
 }
 
+#[test]
+fn test_parse_schema_type_params() {
+    let module = parse_file(
+        "hello.k",
+        Some(
+            r####"
+schema Person[Name]:
+    name: str = Name
+
+schema Page[type T, Size]:
+    items: [T] = []
+"####
+                .to_string(),
+        ),
+    )
+    .unwrap();
+    let schema_stmts = module.filter_schema_stmt_from_module();
+    // An uppercase schema argument without the type annotation is still a value argument.
+    let person = &schema_stmts[0].node;
+    assert!(person.type_params.is_empty());
+    let args = &person.args.as_ref().unwrap().node;
+    assert_eq!(args.args[0].node.get_name(), "Name");
+    // Only the arguments declared with the `type` keyword are type parameters.
+    let page = &schema_stmts[1].node;
+    assert_eq!(page.type_params[0].node, "T");
+    let args = &page.args.as_ref().unwrap().node;
+    assert_eq!(args.args.len(), 1);
+    assert_eq!(args.args[0].node.get_name(), "Size");
+}
+
 #[test]
 fn test_parse_joined_string() {
     check_parsing_expr(
@@ -12,13 +12,14 @@ impl<'a> Parser<'a> {
     /// Syntax:
     ///
     /// type: type_element (OR type_element)*
-    /// type_element: schema_type | basic_type | compound_type | literal_type
-    /// schema_type: identifier
+    /// type_element: schema_type | basic_type | compound_type | literal_type | function_type
+    /// schema_type: identifier [LEFT_BRACKETS type (COMMA type)* RIGHT_BRACKETS]
     /// basic_type: STRING_TYPE | INT_TYPE | FLOAT_TYPE | BOOL_TYPE | ANY_TYPE
     /// compound_type: list_type | dict_type
     /// list_type: LEFT_BRACKETS (type)? RIGHT_BRACKETS
     /// dict_type: LEFT_BRACE (type)? COLON (type)? RIGHT_BRACE
     /// literal_type: string | number | TRUE | FALSE | NONE
+    /// function_type: LEFT_PARENTHESES [type (COMMA type)*] RIGHT_PARENTHESES RIGHT_ARROW type
     pub(crate) fn parse_type_annotation(&mut self) -> NodeRef<Type> {
         let token = self.token;
         let mut type_node_list = vec![self.parse_type_element()];
@@ -107,6 +108,21 @@ impl<'a> Parser<'a> {
         if let TokenKind::Ident(_) = self.token.kind {
             let ident = self.parse_identifier_expr();
             let ident = expr_as!(ident, Expr::Identifier).unwrap();
+            // generic type instantiation, e.g., Page[Person]
+            if let TokenKind::OpenDelim(DelimToken::Bracket) = self.token.kind {
+                self.bump_token(TokenKind::OpenDelim(DelimToken::Bracket));
+                let mut args = vec![self.parse_type_annotation()];
+                while let TokenKind::Comma = self.token.kind {
+                    self.bump_token(TokenKind::Comma);
+                    args.push(self.parse_type_annotation());
+                }
+                self.bump_token(TokenKind::CloseDelim(DelimToken::Bracket));
+                let t = Type::Generic(ast::GenericType { name: ident, args });
+                return Box::new(Node::node(
+                    t,
+                    self.sess.struct_token_loc(token, self.prev_token),
+                ));
+            }
             let t = Type::Named(ident);
             return Box::new(Node::node(
                 t,
@@ -219,6 +235,44 @@ impl<'a> Parser<'a> {
                 self.sess.struct_token_loc(token, self.prev_token),
             ));
         }
+        // (type, type) -> type
+        else if let TokenKind::OpenDelim(DelimToken::Paren) = self.token.kind {
+            self.bump_token(TokenKind::OpenDelim(DelimToken::Paren));
+
+            let mut params_ty = vec![];
+            while !matches!(
+                self.token.kind,
+                TokenKind::CloseDelim(DelimToken::Paren) | TokenKind::Eof
+            ) {
+                params_ty.push(self.parse_type_annotation());
+                if let TokenKind::Comma = self.token.kind {
+                    self.bump();
+                } else {
+                    break;
+                }
+            }
+
+            self.bump_token(TokenKind::CloseDelim(DelimToken::Paren));
+
+            // Recover from the missing return type without consuming the following tokens.
+            let ret_ty = if let TokenKind::RArrow = self.token.kind {
+                self.bump();
+                self.parse_type_annotation()
+            } else {
+                self.sess
+                    .struct_token_error(&[TokenKind::RArrow.into()], self.token);
+                Box::new(Node::node(
+                    Type::Any,
+                    self.sess.struct_token_loc(self.prev_token, self.prev_token),
+                ))
+            };
+            let t = Type::Function(ast::FunctionType { params_ty, ret_ty });
+
+            return Box::new(Node::node(
+                t,
+                self.sess.struct_token_loc(token, self.prev_token),
+            ));
+        }
 
         // Expect type tokens
         self.sess.struct_token_error(
@@ -234,6 +288,7 @@ impl<'a> Parser<'a> {
                 TokenKind::literal_value(),
                 TokenKind::OpenDelim(DelimToken::Bracket).into(),
                 TokenKind::OpenDelim(DelimToken::Brace).into(),
+                TokenKind::OpenDelim(DelimToken::Paren).into(),
             ],
             self.token,
         );
@@ -424,3 +424,153 @@ fn test_import_vendor_by_external_arguments() {
             });
         });
 }
+
+#[test]
+fn test_load_program_with_module_cache() {
+    let path = PathBuf::from(".")
+        .join("testdata")
+        .join("assert-01.k")
+        .canonicalize()
+        .unwrap()
+        .display()
+        .to_string();
+    let module_cache = KCLModuleCache::default();
+    let opts = LoadProgramOptions {
+        module_cache: Some(module_cache.clone()),
+        ..Default::default()
+    };
+    let load = |opts: &LoadProgramOptions| {
+        load_program(
+            Arc::new(ParseSession::default()),
+            &[&path],
+            Some(opts.clone()),
+        )
+        .unwrap()
+    };
+    let code_hash = |module_cache: &KCLModuleCache| {
+        module_cache
+            .read()
+            .unwrap()
+            .get(&path)
+            .map(|cached| cached.code_hash)
+    };
+
+    let program = load(&opts);
+    assert_eq!(program.pkgs["__main__"][0].body.len(), 3);
+    let cached_hash = code_hash(&module_cache);
+    assert!(cached_hash.is_some());
+
+    // The unchanged file is loaded from the cache.
+    let program = load(&opts);
+    assert_eq!(program.pkgs["__main__"][0].body.len(), 3);
+    assert_eq!(program.pkgs["__main__"][0].filename, path);
+    assert_eq!(code_hash(&module_cache), cached_hash);
+
+    // The changed file is parsed again.
+    let changed_opts = LoadProgramOptions {
+        k_code_list: vec!["assert 1".to_string()],
+        ..opts.clone()
+    };
+    let program = load(&changed_opts);
+    assert_eq!(program.pkgs["__main__"][0].body.len(), 1);
+    assert_ne!(code_hash(&module_cache), cached_hash);
+
+    // The file with syntax errors is not cached.
+    module_cache.write().unwrap().clear();
+    let invalid_opts = LoadProgramOptions {
+        k_code_list: vec!["assert ".to_string()],
+        ..opts
+    };
+    load(&invalid_opts);
+    assert_eq!(code_hash(&module_cache), None);
+}
+
+#[test]
+fn test_parse_file_with_cache() {
+    let module_cache = KCLModuleCache::default();
+    let module = parse_file_with_cache("test.k", Some("a = 1".to_string()), &module_cache).unwrap();
+    assert_eq!(module.body.len(), 1);
+    assert!(module_cache.read().unwrap().contains_key("test.k"));
+
+    // The module is returned even if there are syntax errors, but it is not cached.
+    let module = parse_file_with_cache("error.k", Some("a = ".to_string()), &module_cache).unwrap();
+    assert_eq!(module.filename, "error.k");
+    assert!(!module_cache.read().unwrap().contains_key("error.k"));
+}
+
+#[test]
+fn test_load_program_with_pkg_cache() {
+    let root = env::temp_dir().join(format!(
+        "kclvm_test_parser_pkg_cache_{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(root.join("pkg")).unwrap();
+    std::fs::write(
+        root.join(KCL_MOD_FILE),
+        "[build]\nenable_pkg_cache = true\n",
+    )
+    .unwrap();
+    std::fs::write(root.join("main.k"), "import pkg\n\na = pkg.b\n").unwrap();
+    std::fs::write(root.join("pkg").join("pkg.k"), "b = 1\nc = 2\n").unwrap();
+    let main_file = root.join("main.k").display().to_string();
+    let load = || load_program(Arc::new(ParseSession::default()), &[&main_file], None).unwrap();
+    let cache_entries = || -> Vec<PathBuf> {
+        // The package cache is in the only version directory of the cache directory.
+        let version_dir = std::fs::read_dir(root.join(".kclvm").join("cache"))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let cache_dir = version_dir.join("pkg");
+        std::fs::read_dir(cache_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_none())
+            .collect()
+    };
+
+    let program = load();
+    assert_eq!(program.pkgs["pkg"][0].body.len(), 2);
+    let entries = cache_entries();
+    assert_eq!(entries.len(), 1);
+
+    // The unchanged package is loaded from the cache without saving it again.
+    let modified = || std::fs::metadata(&entries[0]).unwrap().modified().unwrap();
+    let saved_time = modified();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    let program = load();
+    assert_eq!(program.pkgs["pkg"][0].body.len(), 2);
+    assert_eq!(program.pkgs["pkg"][0].pkg, "pkg");
+    assert_eq!(modified(), saved_time);
+
+    // The package loaded in another parse mode is parsed and saved again.
+    let opts = LoadProgramOptions {
+        mode: ParseMode::Null,
+        ..Default::default()
+    };
+    load_program(Arc::new(ParseSession::default()), &[&main_file], Some(opts)).unwrap();
+    assert_ne!(modified(), saved_time);
+    load();
+
+    // The corrupted entry is parsed and saved again.
+    let content = std::fs::read_to_string(&entries[0]).unwrap();
+    let corrupted = content.replace("\"c\"", "\"e\"");
+    assert_ne!(corrupted, content);
+    std::fs::write(&entries[0], corrupted).unwrap();
+    let program = load();
+    assert_eq!(program.pkgs["pkg"][0].body.len(), 2);
+    assert_eq!(std::fs::read_to_string(&entries[0]).unwrap(), content);
+
+    // The changed package is parsed again and replaces the former entry.
+    std::fs::write(root.join("pkg").join("pkg.k"), "b = 1\nc = 2\nd = 3\n").unwrap();
+    let program = load();
+    assert_eq!(program.pkgs["pkg"][0].body.len(), 3);
+    assert_eq!(cache_entries(), entries);
+
+    // The package with syntax errors is not cached, and the former entry is removed.
+    std::fs::write(root.join("pkg").join("pkg.k"), "b = \n").unwrap();
+    load();
+    assert!(cache_entries().is_empty());
+    std::fs::remove_dir_all(root).unwrap();
+}
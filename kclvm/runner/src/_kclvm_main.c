@@ -0,0 +1,111 @@
+// Copyright 2023 The KCL Authors. All rights reserved.
+
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#define KCLVM_RESULT_SIZE (2048 * 2048)
+#define KCLVM_PANIC_INFO_KEY "__kcl_PanicInfo__"
+
+extern void* kclvm_main(void* ctx);
+
+extern int32_t _kcl_run(
+    uint64_t kclvm_main_ptr, // main.k => kclvm_main
+    int32_t option_len,
+    const char** option_keys,
+    const char** option_values,
+    int32_t strict_range_check,
+    int32_t disable_none,
+    int32_t disable_schema_check,
+    int32_t list_option_mode,
+    int32_t debug_mode,
+    int32_t result_buffer_len,
+    char* result_buffer,
+    int32_t warn_buffer_len,
+    char* warn_buffer
+);
+
+static void usage(const char* name) {
+    fprintf(stderr, "usage: %s [-r] [-n] [-d] [-D key=value]...\n", name);
+}
+
+// Run the linked KCL program with the top-level arguments `-D key=value`,
+// print the result to stdout and the error message to stderr.
+int _kclvm_main(int argc, char** argv) {
+    int32_t option_len = 0;
+    const char** option_keys = calloc(argc + 1, sizeof(char*));
+    const char** option_values = calloc(argc + 1, sizeof(char*));
+    int32_t strict_range_check = 0;
+    int32_t disable_none = 0;
+    int32_t debug_mode = 0;
+
+    for (int i = 1; i < argc; i++) {
+        const char* arg = argv[i];
+        if (strcmp(arg, "-r") == 0 || strcmp(arg, "--strict_range_check") == 0) {
+            strict_range_check = 1;
+        } else if (strcmp(arg, "-n") == 0 || strcmp(arg, "--disable_none") == 0) {
+            disable_none = 1;
+        } else if (strcmp(arg, "-d") == 0 || strcmp(arg, "--debug") == 0) {
+            debug_mode = 1;
+        } else if (strncmp(arg, "-D", 2) == 0 || strcmp(arg, "--argument") == 0) {
+            // Both `-D key=value` and `-Dkey=value` are supported.
+            const char* kv = NULL;
+            if (strcmp(arg, "-D") == 0 || strcmp(arg, "--argument") == 0) {
+                kv = i + 1 < argc ? argv[++i] : NULL;
+            } else {
+                kv = arg + 2;
+            }
+            char* sep = kv ? strchr(kv, '=') : NULL;
+            if (sep == NULL) {
+                fprintf(stderr, "invalid value for '-D': the argument must be 'key=value'\n");
+                usage(argv[0]);
+                return 2;
+            }
+            char* key = strdup(kv);
+            key[sep - kv] = '\0';
+            option_keys[option_len] = key;
+            option_values[option_len] = sep + 1;
+            option_len++;
+        } else {
+            fprintf(stderr, "unexpected argument '%s'\n", arg);
+            usage(argv[0]);
+            return 2;
+        }
+    }
+
+    char* result = calloc(KCLVM_RESULT_SIZE, 1);
+    char* warn = calloc(KCLVM_RESULT_SIZE, 1);
+    int32_t n = _kcl_run(
+        (uint64_t)(uintptr_t)kclvm_main,
+        option_len,
+        option_keys,
+        option_values,
+        strict_range_check,
+        disable_none,
+        0,
+        0,
+        debug_mode,
+        KCLVM_RESULT_SIZE - 1,
+        result,
+        KCLVM_RESULT_SIZE - 1,
+        warn
+    );
+
+    int code = 0;
+    if (n < 0) {
+        fprintf(stderr, "%s\n", warn);
+        code = 1;
+    } else if (strstr(result, KCLVM_PANIC_INFO_KEY) != NULL) {
+        // The panic info is written to the result buffer when the program panics.
+        fprintf(stderr, "%s\n", result);
+        code = 1;
+    } else if (n > 0) {
+        printf("%s\n", result);
+    }
+    return code;
+}
+
+int main(int argc, char** argv) {
+    return _kclvm_main(argc, argv);
+}
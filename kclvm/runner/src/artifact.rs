@@ -0,0 +1,182 @@
+//! Ahead-of-time compiled KCL programs, which are shared libraries or executables
+//! linked by [`crate::linker::KclvmLinker`], and the manifest files next to them that
+//! record the top-level arguments they accept.
+
+use anyhow::Result;
+use kclvm_ast::{ast, walker::MutSelfWalker};
+use kclvm_ast_pretty::{print_ast_node, ASTNode};
+use serde::{Deserialize, Serialize};
+
+/// The suffix of the manifest file next to the artifact, e.g., `app.so.json`.
+pub const ARTIFACT_MANIFEST_SUFFIX: &str = ".json";
+
+/// The C source of the executable entry `_kclvm_main`, which parses the `-D key=value`
+/// arguments from the command line and runs `kclvm_main` with `_kcl_run`.
+pub(crate) const KCLVM_MAIN_C_SOURCE: &str = include_str!("_kclvm_main.c");
+
+/// The file name of the executable entry source written before linking.
+pub(crate) const KCLVM_MAIN_C_FILE: &str = "_kclvm_main.c";
+
+/// This enum lists all the artifact kinds which can be built from the KCL program.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ArtifactKind {
+    /// A shared library exporting `_kcl_run` and `kclvm_main`, which is loaded and
+    /// run by [`crate::runner::KclvmRunner`].
+    #[default]
+    Lib,
+    /// An executable whose entry `_kclvm_main` runs the program with the
+    /// `-D key=value` command line arguments.
+    Exe,
+}
+
+/// The top-level argument defined by the builtin function `option()` in the program.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct OptionSpec {
+    pub name: String,
+    pub typ: String,
+    pub required: bool,
+    pub default_value: Option<String>,
+    pub help: String,
+}
+
+/// ArtifactManifest records how the artifact is built and the top-level arguments it accepts.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ArtifactManifest {
+    pub kind: ArtifactKind,
+    /// The KCL version which builds the artifact, the artifact can only be run
+    /// with the same version of the runtime library.
+    pub version: String,
+    pub options: Vec<OptionSpec>,
+}
+
+impl ArtifactManifest {
+    /// New a manifest of the artifact built from the program.
+    pub fn new(program: &ast::Program, kind: ArtifactKind) -> Self {
+        Self {
+            kind,
+            version: kclvm_version::VERSION.to_string(),
+            options: list_options(program),
+        }
+    }
+
+    /// Returns the manifest file path of the artifact.
+    pub fn path(artifact: &str) -> String {
+        format!("{}{}", artifact, ARTIFACT_MANIFEST_SUFFIX)
+    }
+
+    /// Load the manifest next to the artifact.
+    pub fn load(artifact: &str) -> Result<Self> {
+        let path = Self::path(artifact);
+        let data = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("failed to read the artifact manifest {}: {}", path, e))?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Check that the "-D" arguments are the top-level arguments the artifact accepts.
+    pub fn check_args(&self, args: &[ast::CmdArgSpec]) -> Result<()> {
+        let unknown_args = args
+            .iter()
+            .filter(|arg| !self.options.iter().any(|option| option.name == arg.name))
+            .map(|arg| format!("'{}'", arg.name))
+            .collect::<Vec<String>>();
+        if !unknown_args.is_empty() {
+            anyhow::bail!(
+                "unknown top-level arguments {} of the artifact, the accepted arguments are [{}]",
+                unknown_args.join(", "),
+                self.options
+                    .iter()
+                    .map(|option| format!("'{}'", option.name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+        }
+        Ok(())
+    }
+
+    /// Save the manifest next to the artifact.
+    pub fn save(&self, artifact: &str) -> Result<()> {
+        std::fs::write(Self::path(artifact), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// List the top-level arguments defined by the `option()` calls with a string literal
+/// name in the program. The duplicated options are merged in the same way as the
+/// runtime `list_option_mode`.
+pub fn list_options(program: &ast::Program) -> Vec<OptionSpec> {
+    let mut pkgpaths = program.pkgs.keys().collect::<Vec<&String>>();
+    pkgpaths.sort();
+    let mut walker = OptionWalker::default();
+    for pkgpath in pkgpaths {
+        for module in &program.pkgs[pkgpath] {
+            walker.walk_module(module);
+        }
+    }
+    walker.options
+}
+
+#[derive(Default)]
+struct OptionWalker {
+    options: Vec<OptionSpec>,
+}
+
+impl OptionWalker {
+    fn define_option(&mut self, option: OptionSpec) {
+        match self.options.iter_mut().find(|o| o.name == option.name) {
+            Some(o) => {
+                if o.typ.is_empty() {
+                    o.typ = option.typ;
+                }
+                o.required = o.required || option.required;
+                if o.default_value.is_none() {
+                    o.default_value = option.default_value;
+                }
+                if o.help.is_empty() {
+                    o.help = option.help;
+                }
+            }
+            None => self.options.push(option),
+        }
+    }
+}
+
+impl MutSelfWalker for OptionWalker {
+    fn walk_call_expr(&mut self, call_expr: &ast::CallExpr) {
+        if let ast::Expr::Identifier(func) = &call_expr.func.node {
+            if func.get_name() == "option" {
+                if let Some(ast::Expr::StringLit(name)) = call_expr.args.first().map(|a| &a.node) {
+                    let mut option = OptionSpec {
+                        name: name.value.clone(),
+                        ..Default::default()
+                    };
+                    for keyword in &call_expr.keywords {
+                        let value = match &keyword.node.value {
+                            Some(value) => value,
+                            None => continue,
+                        };
+                        match (keyword.node.arg.node.get_name().as_str(), &value.node) {
+                            ("type", ast::Expr::StringLit(s)) => option.typ = s.value.clone(),
+                            ("help", ast::Expr::StringLit(s)) => option.help = s.value.clone(),
+                            ("required", ast::Expr::NameConstantLit(c)) => {
+                                option.required = matches!(c.value, ast::NameConstant::True)
+                            }
+                            ("default", _) => {
+                                option.default_value = Some(print_ast_node(ASTNode::Expr(value)))
+                            }
+                            _ => {}
+                        }
+                    }
+                    self.define_option(option);
+                }
+            }
+        }
+        self.walk_expr(&call_expr.func.node);
+        for arg in &call_expr.args {
+            self.walk_expr(&arg.node);
+        }
+        for keyword in &call_expr.keywords {
+            self.walk_keyword(&keyword.node);
+        }
+    }
+}
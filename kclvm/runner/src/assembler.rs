@@ -4,7 +4,7 @@ use indexmap::IndexMap;
 use kclvm_ast::ast::{self, Program};
 use kclvm_compiler::codegen::{
     llvm::{emit_code, OBJECT_FILE_SUFFIX},
-    EmitOptions,
+    EmitFileType, EmitOptions,
 };
 use kclvm_config::cache::{load_pkg_cache, save_pkg_cache, CacheOption};
 use kclvm_sema::resolver::scope::ProgramScope;
@@ -12,7 +12,9 @@ use std::{
     collections::HashMap,
     env,
     path::{Path, PathBuf},
+    sync::mpsc::channel,
 };
+use threadpool::ThreadPool;
 
 /// IR code file suffix.
 const DEFAULT_IR_FILE: &str = "_a.out";
@@ -157,6 +159,7 @@ impl LibAssembler for LlvmLibAssembler {
                 from_path: None,
                 emit_path: Some(code_file),
                 no_link: true,
+                file_type: EmitFileType::Object,
             },
         )
         .map_err(|e| {
@@ -196,11 +199,12 @@ pub(crate) struct KclvmAssembler {
     single_file_assembler: KclvmLibAssembler,
     target: String,
     external_pkgs: HashMap<String, String>,
+    thread_count: usize,
 }
 
 impl KclvmAssembler {
-    /// Constructs an KclvmAssembler instance with a default value 4
-    /// for the number of threads in multi-file compilation.
+    /// Constructs an KclvmAssembler instance with the available parallelism of the
+    /// machine as the number of threads in multi-file compilation.
     #[inline]
     pub(crate) fn new(
         program: ast::Program,
@@ -216,6 +220,9 @@ impl KclvmAssembler {
             single_file_assembler,
             target: env!("KCLVM_DEFAULT_TARGET").to_string(),
             external_pkgs,
+            thread_count: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
         }
     }
 
@@ -301,7 +308,7 @@ impl KclvmAssembler {
                 ),
             );
         }
-        let mut lib_paths = vec![];
+        let mut tasks = vec![];
         for (pkgpath, (compile_prog, import_names, cache_dir)) in compile_progs {
             // Clone a single file assembler for one thread.
             let assembler = self.single_file_assembler.clone();
@@ -319,80 +326,155 @@ impl KclvmAssembler {
                 .to_str()
                 .ok_or(anyhow::anyhow!("Internal error: get cache file failed"))?
                 .to_string();
-            let code_file_path = assembler.add_code_file_suffix(&code_file);
-            let lock_file_path = format!("{}.lock", code_file_path);
-            let target = self.target.clone();
-            {
-                // Locking file for parallel code generation.
-                let mut file_lock = fslock::LockFile::open(&lock_file_path)?;
-                file_lock.lock()?;
-
-                let root = &compile_prog.root;
-                // The main package does not perform cache reading and writing,
-                // and other packages perform read and write caching. Because
-                // KCL supports multi-file compilation, it is impossible to
-                // specify a standard entry for these multi-files and cannot
-                // be shared, so the cache of the main package is not read and
-                // written.
-                let file_path = if is_main_pkg {
-                    // generate dynamic link library for single file kcl program
-                    assembler.assemble(&compile_prog, import_names, &code_file, &code_file_path)?
-                } else {
-                    // Read the lib path cache
-                    let file_relative_path: Option<String> = load_pkg_cache(
-                        root,
-                        &target,
-                        &pkgpath,
-                        CacheOption::default(),
-                        &self.external_pkgs,
-                    );
-                    let file_abs_path = match file_relative_path {
-                        Some(file_relative_path) => {
-                            let path = if file_relative_path.starts_with('.') {
-                                file_relative_path.replacen('.', root, 1)
-                            } else {
-                                file_relative_path
-                            };
-                            if Path::new(&path).exists() {
-                                Some(path)
-                            } else {
-                                None
-                            }
-                        }
-                        None => None,
-                    };
-                    match file_abs_path {
-                        Some(path) => path,
-                        None => {
-                            // Generate the object file for single file kcl program.
-                            let file_path = assembler.assemble(
-                                &compile_prog,
-                                import_names,
-                                &code_file,
-                                &code_file_path,
-                            )?;
-                            let lib_relative_path = file_path.replacen(root, ".", 1);
-                            save_pkg_cache(
-                                root,
-                                &target,
-                                &pkgpath,
-                                lib_relative_path,
-                                CacheOption::default(),
-                            );
-                            file_path
-                        }
-                    }
-                };
-                file_lock.unlock()?;
-                lib_paths.push(file_path);
-            };
+            tasks.push(GenLibTask {
+                pkgpath,
+                compile_prog,
+                import_names,
+                code_file,
+                is_main_pkg,
+                assembler,
+                target: self.target.clone(),
+                external_pkgs: self.external_pkgs.clone(),
+            });
+        }
+        // The lib paths are returned in the order of the packages in the program,
+        // regardless of the order in which the threads finish.
+        let mut lib_paths: IndexMap<String, Option<String>> = tasks
+            .iter()
+            .map(|task| (task.pkgpath.clone(), None))
+            .collect();
+        let pool = ThreadPool::new(self.thread_count);
+        let (tx, rx) = channel();
+        for task in tasks {
+            let tx = tx.clone();
+            pool.execute(move || {
+                // The receiver is alive until all the tasks are finished.
+                let _ = tx.send((task.pkgpath.clone(), task.gen_lib()));
+            });
+        }
+        // Drop the sender of the current thread, so that the receiver stops when all the
+        // tasks are finished, including the tasks whose threads are panicked.
+        drop(tx);
+        let mut errors = vec![];
+        for (pkgpath, result) in rx {
+            match result {
+                Ok(lib_path) => {
+                    lib_paths.insert(pkgpath, Some(lib_path));
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+        if let Some(err) = errors.into_iter().next() {
+            return Err(err);
         }
+        let lib_paths = lib_paths
+            .into_iter()
+            .map(|(pkgpath, lib_path)| {
+                lib_path.ok_or(anyhow::anyhow!(
+                    "Internal error: the lib of the package '{}' is not generated",
+                    pkgpath
+                ))
+            })
+            .collect::<Result<Vec<String>>>()?;
         self.single_file_assembler
             .clean_lock_file(&self.entry_file)?;
         Ok(lib_paths)
     }
 }
 
+/// GenLibTask generates the object file for a single package of the kcl program,
+/// and it is executed in a thread of the pool in [KclvmAssembler::gen_libs].
+///
+/// The generated files are locked with a "*.lock" file during generation, so the
+/// concurrent code generations in different threads or processes that share the
+/// cache dir will not affect each other.
+struct GenLibTask {
+    pkgpath: String,
+    compile_prog: ast::Program,
+    import_names: IndexMap<String, IndexMap<String, String>>,
+    code_file: String,
+    is_main_pkg: bool,
+    assembler: KclvmLibAssembler,
+    target: String,
+    external_pkgs: HashMap<String, String>,
+}
+
+impl GenLibTask {
+    /// Generate the object file for the package and return the object file path.
+    fn gen_lib(&self) -> Result<String> {
+        let assembler = &self.assembler;
+        let code_file = &self.code_file;
+        let code_file_path = assembler.add_code_file_suffix(code_file);
+        let lock_file_path = format!("{}.lock", code_file_path);
+        let import_names = self.import_names.clone();
+        let compile_prog = &self.compile_prog;
+        let pkgpath = &self.pkgpath;
+        let target = &self.target;
+        // Locking file for parallel code generation.
+        let mut file_lock = fslock::LockFile::open(&lock_file_path)?;
+        file_lock.lock()?;
+
+        let root = &compile_prog.root;
+        // The main package does not perform cache reading and writing,
+        // and other packages perform read and write caching. Because
+        // KCL supports multi-file compilation, it is impossible to
+        // specify a standard entry for these multi-files and cannot
+        // be shared, so the cache of the main package is not read and
+        // written.
+        let file_path = if self.is_main_pkg {
+            // generate dynamic link library for single file kcl program
+            assembler.assemble(compile_prog, import_names, code_file, &code_file_path)?
+        } else {
+            // Read the lib path cache
+            let file_relative_path: Option<String> = load_pkg_cache(
+                root,
+                target,
+                pkgpath,
+                CacheOption::default(),
+                &self.external_pkgs,
+            );
+            let file_abs_path = match file_relative_path {
+                Some(file_relative_path) => {
+                    let path = if file_relative_path.starts_with('.') {
+                        file_relative_path.replacen('.', root, 1)
+                    } else {
+                        file_relative_path
+                    };
+                    if Path::new(&path).exists() {
+                        Some(path)
+                    } else {
+                        None
+                    }
+                }
+                None => None,
+            };
+            match file_abs_path {
+                Some(path) => path,
+                None => {
+                    // Generate the object file for single file kcl program.
+                    let file_path = assembler.assemble(
+                        compile_prog,
+                        import_names,
+                        code_file,
+                        &code_file_path,
+                    )?;
+                    let lib_relative_path = file_path.replacen(root, ".", 1);
+                    save_pkg_cache(
+                        root,
+                        target,
+                        pkgpath,
+                        lib_relative_path,
+                        CacheOption::default(),
+                    );
+                    file_path
+                }
+            }
+        };
+        file_lock.unlock()?;
+        Ok(file_path)
+    }
+}
+
 #[inline]
 pub(crate) fn clean_path(path: &str) -> Result<()> {
     if Path::new(path).exists() {
@@ -0,0 +1,119 @@
+//! Emit the ASTs, LLVM IR, bitcode and object files of each package in the KCL
+//! program to a directory, so that the compiler issues can be reported with the
+//! reproducible artifacts.
+
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use kclvm_ast::ast::Program;
+use kclvm_compiler::codegen::{llvm::emit_code, EmitFileType, EmitOptions};
+use serde::{de::IntoDeserializer, Deserialize, Serialize};
+
+/// The suffix of the AST JSON file.
+pub const AST_FILE_SUFFIX: &str = ".ast.json";
+
+/// This enum lists all the outputs which can be emitted for each package.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmitKind {
+    /// The JSON AST with positions before resolving.
+    Ast,
+    /// The LLVM IR text file.
+    LlvmIr,
+    /// The LLVM bitcode file.
+    LlvmBc,
+    /// The native object file.
+    Obj,
+}
+
+impl FromStr for EmitKind {
+    type Err = serde::de::value::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+
+/// Emit the outputs of the resolved program for the emit kinds except [`EmitKind::Ast`],
+/// which is emitted before resolving by [`emit_ast`].
+pub(crate) fn emit_resolved_program(
+    program: &Program,
+    import_names: &IndexMap<String, IndexMap<String, String>>,
+    kinds: &[EmitKind],
+    dir: &str,
+) -> Result<()> {
+    for kind in kinds {
+        match kind {
+            EmitKind::Ast => {}
+            EmitKind::LlvmIr => emit_llvm(program, import_names, dir, EmitFileType::LLVMIR)?,
+            EmitKind::LlvmBc => emit_llvm(program, import_names, dir, EmitFileType::LLVMBitcode)?,
+            EmitKind::Obj => emit_llvm(program, import_names, dir, EmitFileType::Object)?,
+        }
+    }
+    Ok(())
+}
+
+/// Emit the JSON AST of each package in the program to `<dir>/<pkgpath><suffix>`,
+/// the file is a program only containing the package, see [`Program::to_json`].
+pub(crate) fn emit_ast(program: &Program, dir: &str, suffix: &str) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for (pkgpath, program) in split_program(program) {
+        let path = Path::new(dir).join(format!("{}{}", pkgpath, suffix));
+        std::fs::write(path, program.to_json()?)?;
+    }
+    Ok(())
+}
+
+/// Emit the LLVM IR, bitcode or object file of each package in the program to
+/// `<dir>/<pkgpath><suffix>`, the same as the packages compiled by the assembler.
+pub(crate) fn emit_llvm(
+    program: &Program,
+    import_names: &IndexMap<String, IndexMap<String, String>>,
+    dir: &str,
+    file_type: EmitFileType,
+) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for (pkgpath, program) in split_program(program) {
+        let path = Path::new(dir).join(&pkgpath).display().to_string();
+        emit_code(
+            &program,
+            import_names.clone(),
+            &EmitOptions {
+                from_path: None,
+                emit_path: Some(&path),
+                no_link: true,
+                file_type,
+            },
+        )
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "Internal error: compile KCL package {} to LLVM error {}",
+                pkgpath,
+                e
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Split the program into the programs only containing one package.
+fn split_program(program: &Program) -> Vec<(String, Program)> {
+    let mut pkgpaths = program.pkgs.keys().collect::<Vec<&String>>();
+    pkgpaths.sort();
+    pkgpaths
+        .into_iter()
+        .map(|pkgpath| {
+            let mut pkgs = HashMap::new();
+            pkgs.insert(pkgpath.clone(), program.pkgs[pkgpath].clone());
+            (
+                pkgpath.clone(),
+                Program {
+                    root: program.root.clone(),
+                    main: program.main.clone(),
+                    pkgs,
+                },
+            )
+        })
+        .collect()
+}
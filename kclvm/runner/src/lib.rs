@@ -1,23 +1,29 @@
 use std::{collections::HashMap, path::Path, sync::Arc, time::SystemTime};
 
 use anyhow::Result;
+use artifact::{ArtifactKind, ArtifactManifest};
 use assembler::KclvmLibAssembler;
+use emit::EmitKind;
 use kclvm_ast::{
     ast::{Module, Program},
     MAIN_PKG,
 };
+use kclvm_config::modfile::load_mod_lint_levels;
 use kclvm_driver::canonicalize_input_files;
 use kclvm_error::{Diagnostic, Handler};
 use kclvm_parser::{load_program, ParseSession};
 use kclvm_query::apply_overrides;
 use kclvm_runtime::{PanicInfo, ValueRef};
-use kclvm_sema::resolver::resolve_program;
+use kclvm_sema::lint::parse_lint_levels;
+use kclvm_sema::resolver::{resolve_program_with_opts, scope::ProgramScope, Options};
 use linker::Command;
-pub use runner::ExecProgramArgs;
+pub use runner::{ExecProgramArgs, KclvmBackend};
 use runner::{ExecProgramResult, KclvmRunner, KclvmRunnerOptions};
 use tempfile::tempdir;
 
+pub mod artifact;
 pub mod assembler;
+pub mod emit;
 pub mod linker;
 pub mod runner;
 
@@ -73,6 +79,114 @@ pub fn exec_program(
     sess: Arc<ParseSession>,
     args: &ExecProgramArgs,
 ) -> Result<ExecProgramResult, String> {
+    // Run the prebuilt artifact directly without loading the input files.
+    if let Some(artifact) = &args.artifact {
+        if !args.emit.is_empty() {
+            return Err(format!(
+                "the outputs {:?} can not be emitted from the prebuilt artifact {}, run the input files instead",
+                args.emit, artifact
+            ));
+        }
+        return exec_artifact(artifact, args);
+    }
+
+    let program = load_program_with_overrides(sess.clone(), args)?;
+
+    let start_time = SystemTime::now();
+    let exec_result = execute(sess, program, args);
+    exec_result_to_program_result(exec_result, start_time, args)
+}
+
+/// Build the kcl program into a shared library or an executable at the output path
+/// ahead of time, and record the top-level arguments the artifact accepts in the
+/// manifest file next to it (see [`ArtifactManifest`]).
+///
+/// The runtime library is copied next to the artifact, and the artifact can be run by
+/// [`exec_artifact`] (or directly for the executable) with different "-D" arguments
+/// without parsing and compiling the program again.
+///
+/// It returns the absolute path of the artifact.
+///
+/// **Note that it is not thread safe.**
+pub fn build_program(
+    sess: Arc<ParseSession>,
+    args: &ExecProgramArgs,
+    output: &str,
+    kind: ArtifactKind,
+) -> Result<String, String> {
+    if args.backend != KclvmBackend::Llvm {
+        return Err(format!(
+            "the {:?} backend can not build artifacts, use the LLVM backend instead",
+            args.backend
+        ));
+    }
+    let mut program = load_program_with_overrides(sess.clone(), args)?;
+    let scope = resolve_program_for_exec(sess, &mut program, args)?;
+    let manifest = ArtifactManifest::new(&program, kind);
+
+    // Create a temp entry file and the temp dir will be delete automatically
+    let temp_dir = tempdir().map_err(|e| e.to_string())?;
+    let temp_dir_path = temp_dir.path().to_str().ok_or(format!(
+        "Internal error: {}: No such file or directory",
+        temp_dir.path().display()
+    ))?;
+    let temp_entry_file = temp_file(temp_dir_path).map_err(|e| e.to_string())?;
+
+    // Generate libs
+    let lib_paths = gen_libs(program, scope, &temp_entry_file, args)?;
+
+    // Link libs into the artifact, the runtime library is copied into the
+    // parent directory of the absolute output path.
+    let output = std::env::current_dir()
+        .map_err(|e| e.to_string())?
+        .join(output)
+        .display()
+        .to_string();
+    let artifact_path = match kind {
+        ArtifactKind::Lib => linker::KclvmLinker::link_all_libs(lib_paths, output),
+        ArtifactKind::Exe => linker::KclvmLinker::link_all_libs_to_executable(lib_paths, output),
+    }
+    .map_err(|e| e.to_string())?;
+    manifest.save(&artifact_path).map_err(|e| e.to_string())?;
+    Ok(artifact_path)
+}
+
+/// Execute the artifact built by [`build_program`] with the "-D" arguments and the
+/// runtime options in "args", the input files in "args" are ignored. An error is returned
+/// if the "-D" arguments are not recorded in the manifest of the artifact.
+///
+/// The shared library is loaded and run by KclvmRunner in the current process, and the
+/// executable is run in a child process.
+///
+/// **Note that it is not thread safe.**
+pub fn exec_artifact(artifact: &str, args: &ExecProgramArgs) -> Result<ExecProgramResult, String> {
+    let manifest = ArtifactManifest::load(artifact).map_err(|e| e.to_string())?;
+    if manifest.version != kclvm_version::VERSION {
+        return Err(format!(
+            "the artifact {} is built by KCL {}, which is different from the current version {}, please build it again",
+            artifact,
+            manifest.version,
+            kclvm_version::VERSION
+        ));
+    }
+    manifest.check_args(&args.args).map_err(|e| e.to_string())?;
+    let start_time = SystemTime::now();
+    let exec_result = match manifest.kind {
+        ArtifactKind::Lib => KclvmRunner::new(Some(KclvmRunnerOptions {
+            plugin_agent_ptr: args.plugin_agent,
+        }))
+        .run(artifact, args),
+        ArtifactKind::Exe => runner::run_executable(artifact, args),
+    }
+    .map_err(wrap_runtime_err);
+    exec_result_to_program_result(exec_result, start_time, args)
+}
+
+/// Load the program of the input files in "args" and apply the overrides on it.
+fn load_program_with_overrides(
+    sess: Arc<ParseSession>,
+    args: &ExecProgramArgs,
+) -> Result<Program, String> {
     // parse args from json string
     let opts = args.get_load_program_options();
     let k_files = &args.k_filename_list;
@@ -81,7 +195,7 @@ pub fn exec_program(
 
     let kcl_paths_str = kcl_paths.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
 
-    let mut program = load_program(sess.clone(), kcl_paths_str.as_slice(), Some(opts))?;
+    let mut program = load_program(sess, kcl_paths_str.as_slice(), Some(opts))?;
 
     if let Err(err) = apply_overrides(
         &mut program,
@@ -91,9 +205,16 @@ pub fn exec_program(
     ) {
         return Err(err.to_string());
     }
+    Ok(program)
+}
 
-    let start_time = SystemTime::now();
-    let exec_result = execute(sess, program, args);
+/// Convert the JSON or YAML string executing result to the program result with the
+/// path selector and the output options in "args".
+fn exec_result_to_program_result(
+    exec_result: Result<String, String>,
+    start_time: SystemTime,
+    args: &ExecProgramArgs,
+) -> Result<ExecProgramResult, String> {
     let escape_time = match SystemTime::now().duration_since(start_time) {
         Ok(dur) => dur.as_secs_f32(),
         Err(err) => return Err(err.to_string()),
@@ -160,6 +281,12 @@ pub fn exec_program(
 ///
 /// At last, KclvmRunner will be constructed and call method "run" to execute the kcl program.
 ///
+/// When "args.backend" is `KclvmBackend::Evaluator`, the resolved program is executed by the
+/// tree-walking evaluator instead, and no libs are generated or linked.
+///
+/// When "args.emit" is not empty, the ASTs, LLVM IR, bitcode or object files of each package
+/// are written to "args.emit_dir" before the program is executed.
+///
 /// **Note that it is not thread safe.**
 ///
 /// # Examples
@@ -189,9 +316,20 @@ pub fn execute(
     mut program: Program,
     args: &ExecProgramArgs,
 ) -> Result<String, String> {
+    let emit_dir = args.get_emit_dir();
+    if args.emit.contains(&EmitKind::Ast) {
+        emit::emit_ast(&program, &emit_dir, emit::AST_FILE_SUFFIX).map_err(|e| e.to_string())?;
+    }
+
     // Resolve ast
-    let scope = resolve_program(&mut program);
-    scope.emit_diagnostics_to_string(sess.0.clone())?;
+    let scope = resolve_program_for_exec(sess, &mut program, args)?;
+    emit::emit_resolved_program(&program, &scope.import_names, &args.emit, &emit_dir)
+        .map_err(|e| e.to_string())?;
+
+    // Evaluate the resolved ast directly without generating and linking libs.
+    if args.backend == KclvmBackend::Evaluator {
+        return runner::eval_program(&program, scope.import_names, args).map_err(wrap_runtime_err);
+    }
 
     // Create a temp entry file and the temp dir will be delete automatically
     let temp_dir = tempdir().map_err(|e| e.to_string())?;
@@ -202,15 +340,7 @@ pub fn execute(
     let temp_entry_file = temp_file(temp_dir_path).map_err(|e| e.to_string())?;
 
     // Generate libs
-    let lib_paths = assembler::KclvmAssembler::new(
-        program,
-        scope,
-        temp_entry_file.clone(),
-        KclvmLibAssembler::LLVM,
-        args.get_package_maps_from_external_pkg(),
-    )
-    .gen_libs()
-    .map_err(|e| e.to_string())?;
+    let lib_paths = gen_libs(program, scope, &temp_entry_file, args)?;
 
     // Link libs
     let lib_suffix = Command::get_lib_suffix();
@@ -231,16 +361,58 @@ pub fn execute(
     remove_file(&lib_path).map_err(|e| e.to_string())?;
     #[cfg(not(target_os = "windows"))]
     clean_tmp_files(&temp_entry_file, &lib_suffix).map_err(|e| e.to_string())?;
-    // Wrap runtime error into diagnostic style string.
-    result.map_err(|err| {
-        match Handler::default()
-            .add_diagnostic(<PanicInfo as Into<Diagnostic>>::into(PanicInfo::from(err)))
-            .emit_to_string()
-        {
-            Ok(msg) => msg,
-            Err(err) => err.to_string(),
-        }
-    })
+    result.map_err(wrap_runtime_err)
+}
+
+/// Resolve the program with the options in "args" and the lint levels in the `[lint]` section
+/// of `kcl.mod`, and emit the diagnostics.
+fn resolve_program_for_exec(
+    sess: Arc<ParseSession>,
+    program: &mut Program,
+    args: &ExecProgramArgs,
+) -> Result<ProgramScope, String> {
+    let lint_levels = load_mod_lint_levels(&program.root).map_err(|e| e.to_string())?;
+    let scope = resolve_program_with_opts(
+        program,
+        Options {
+            raise_err: true,
+            config_auto_fix: false,
+            lint_check: true,
+            strict_type_check: args.strict_type_check,
+            lint_levels: parse_lint_levels(&lint_levels)?,
+        },
+    );
+    scope.emit_diagnostics_to_string(sess.0.clone())?;
+    Ok(scope)
+}
+
+/// Generate the libs of the resolved program with the entry file, and returns the lib paths.
+fn gen_libs(
+    program: Program,
+    scope: ProgramScope,
+    entry_file: &str,
+    args: &ExecProgramArgs,
+) -> Result<Vec<String>, String> {
+    assembler::KclvmAssembler::new(
+        program,
+        scope,
+        entry_file.to_string(),
+        KclvmLibAssembler::LLVM,
+        args.get_package_maps_from_external_pkg(),
+    )
+    .gen_libs()
+    .map_err(|e| e.to_string())
+}
+
+/// Wrap runtime error into diagnostic style string.
+fn wrap_runtime_err(err: String) -> String {
+    match Handler::default()
+        .add_diagnostic(<PanicInfo as Into<Diagnostic>>::into(PanicInfo::from(err)))
+        .emit_to_string()
+    {
+        Ok(msg) => msg,
+        Err(err) => err.to_string(),
+    }
 }
 
 /// `execute_module` can directly execute the ast `Module`.
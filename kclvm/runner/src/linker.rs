@@ -1,3 +1,4 @@
+use crate::artifact::{KCLVM_MAIN_C_FILE, KCLVM_MAIN_C_SOURCE};
 use anyhow::Result;
 use kclvm_utils::path::PathPrefix;
 use std::env::consts::DLL_SUFFIX;
@@ -9,6 +10,12 @@ const KCLVM_LIB_SHORT_NAME: &str = "kclvm_cli_cdylib";
 
 const EXEC_ROOT_NOT_FOUND_MSG: &str = "Internal error: the executable root is not found";
 
+/// The rpath of the directory of the linked output, where the runtime library is copied to.
+#[cfg(target_os = "macos")]
+const OUTPUT_DIR_RPATH: &str = "@loader_path";
+#[cfg(not(target_os = "macos"))]
+const OUTPUT_DIR_RPATH: &str = "$ORIGIN";
+
 /// KclvmLinker is mainly responsible for linking the libs generated by KclvmAssembler.
 pub struct KclvmLinker;
 impl KclvmLinker {
@@ -18,6 +25,12 @@ impl KclvmLinker {
         // not allow external mounting of the implementation.
         Command::new()?.link_libs_with_cc(&lib_paths, &lib_path)
     }
+
+    /// Link the libs generated by method "gen_bc_or_ll_file" and the `_kclvm_main`
+    /// entry into an executable.
+    pub fn link_all_libs_to_executable(lib_paths: Vec<String>, exe_path: String) -> Result<String> {
+        Command::new()?.link_executable_with_cc(&lib_paths, &exe_path)
+    }
 }
 
 #[derive(Debug)]
@@ -42,7 +55,34 @@ impl Command {
         } else {
             lib_path.to_string()
         };
+        self.link_with_cc(libs, &lib_path, false)
+    }
+
+    /// Link dynamic libraries and the `_kclvm_main` entry into one executable using cc-rs lib.
+    pub(crate) fn link_executable_with_cc(
+        &mut self,
+        libs: &[String],
+        exe_path: &str,
+    ) -> Result<String> {
+        let exe_suffix = std::env::consts::EXE_SUFFIX;
+        let exe_path = if exe_path.is_empty() {
+            format!("{}{}", "a.out", exe_suffix)
+        } else if !exe_path.ends_with(exe_suffix) {
+            format!("{}{}", exe_path, exe_suffix)
+        } else {
+            exe_path.to_string()
+        };
+        // Write the entry source into a temp dir and compile it along with the libs.
+        let temp_dir = tempfile::tempdir()?;
+        let main_file = temp_dir.path().join(KCLVM_MAIN_C_FILE);
+        std::fs::write(&main_file, KCLVM_MAIN_C_SOURCE)?;
+        let mut libs = libs.to_vec();
+        libs.push(main_file.to_string_lossy().to_string());
+        self.link_with_cc(&libs, &exe_path, true)
+    }
 
+    /// Link the libs into a shared library or an executable at the output path using cc-rs lib.
+    fn link_with_cc(&mut self, libs: &[String], output: &str, executable: bool) -> Result<String> {
         #[cfg(not(target_os = "windows"))]
         let target = format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS);
 
@@ -55,18 +95,22 @@ impl Command {
             .cargo_metadata(false)
             .no_default_flags(false)
             .pic(true)
-            .shared_flag(true)
+            .shared_flag(!executable)
             .opt_level(0)
             .target(&target)
             .host(&target)
             .flag("-o")
-            .flag(&lib_path);
+            .flag(output);
 
         build.files(libs);
 
         // Run command with cc.
         let mut cmd = build.try_get_compiler()?.to_command();
-        self.add_args(libs, lib_path.to_string(), &mut cmd)?;
+        if executable {
+            self.add_exe_args(libs, output.to_string(), &mut cmd)?;
+        } else {
+            self.add_args(libs, output.to_string(), &mut cmd)?;
+        }
         let result = cmd.output()?;
         if !result.status.success() {
             anyhow::bail!(
@@ -76,7 +120,7 @@ impl Command {
             );
         }
         // Use absolute path.
-        let path = PathBuf::from(&lib_path).canonicalize()?;
+        let path = PathBuf::from(output).canonicalize()?;
         Ok(path.adjust_canonicalization())
     }
 
@@ -96,6 +140,22 @@ impl Command {
         Ok(())
     }
 
+    /// Add args for cc to link an executable.
+    pub(crate) fn add_exe_args(
+        &self,
+        libs: &[String],
+        exe_path: String,
+        cmd: &mut std::process::Command,
+    ) -> Result<()> {
+        #[cfg(not(target_os = "windows"))]
+        self.unix_args(libs, exe_path, cmd)?;
+
+        #[cfg(target_os = "windows")]
+        self.msvc_win_exe_args(libs, exe_path, cmd)?;
+
+        Ok(())
+    }
+
     /// Add args for cc on unix os.
     pub(crate) fn unix_args(
         &self,
@@ -103,17 +163,21 @@ impl Command {
         lib_path: String,
         cmd: &mut std::process::Command,
     ) -> Result<()> {
-        let path = self.runtime_lib_path(&lib_path)?;
+        let (path, copied) = self.runtime_lib_path(&lib_path)?;
+        // The copied runtime library is found next to the output, so that the output
+        // can be moved along with the runtime library.
+        let rpath = if copied { OUTPUT_DIR_RPATH } else { &path };
         cmd.args(libs)
-            .arg(&format!("-Wl,-rpath,{}", &path))
+            .arg(&format!("-Wl,-rpath,{}", rpath))
             .arg(&format!("-L{}", &path))
             .arg(&format!("-I{}/include", self.executable_root))
             .arg("-lkclvm_cli_cdylib");
         Ok(())
     }
 
-    /// Get the runtime library path.
-    pub(crate) fn runtime_lib_path(&self, lib_path: &str) -> Result<String> {
+    /// Get the runtime library path and whether the runtime library is copied to the
+    /// directory of the output.
+    pub(crate) fn runtime_lib_path(&self, lib_path: &str) -> Result<(String, bool)> {
         let path = self.get_lib_link_path()?;
         let lib_name = Self::get_lib_name();
         let lib_file_path = std::path::Path::new(&path).join(&lib_name);
@@ -122,15 +186,18 @@ impl Command {
             if let Some(target_path) = std::path::Path::new(&lib_path).parent() {
                 let target_lib_file_path = std::path::Path::new(target_path).join(&lib_name);
 
-                // Locking file for parallel file copy.
-                let mut file_lock =
-                    fslock::LockFile::open(&format!("{}.lock", target_lib_file_path.display()))?;
-                file_lock.lock()?;
-
-                std::fs::copy(lib_file_path, target_lib_file_path)?;
-                target_path.to_string_lossy().to_string()
+                // Copy to a temp file and rename it for parallel file copy, the runtime
+                // library loaded by the running programs is not overwritten either.
+                let temp_file = if target_path.as_os_str().is_empty() {
+                    tempfile::NamedTempFile::new_in(".")?
+                } else {
+                    tempfile::NamedTempFile::new_in(target_path)?
+                };
+                std::fs::copy(lib_file_path, temp_file.path())?;
+                temp_file.persist(target_lib_file_path)?;
+                (target_path.to_string_lossy().to_string(), true)
             } else {
-                path
+                (path, false)
             },
         )
     }
@@ -159,6 +226,25 @@ impl Command {
         Ok(())
     }
 
+    // Add args for cc on windows os to link an executable.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn msvc_win_exe_args(
+        &self,
+        libs: &[String],
+        exe_path: String,
+        cmd: &mut std::process::Command,
+    ) -> Result<()> {
+        cmd.args(libs)
+            .arg("kclvm_cli_cdylib.lib")
+            .arg("/link")
+            .arg("/NOLOGO")
+            .arg(format!(r#"/LIBPATH:"{}""#, self.get_lib_link_path()?))
+            .arg("/DEFAULTLIB:msvcrt.lib")
+            .arg("/DEFAULTLIB:libcmt.lib")
+            .arg(format!("/OUT:{}", exe_path));
+        Ok(())
+    }
+
     /// Get the executable root.
     fn get_executable_root() -> Result<String> {
         if let Ok(path) = std::env::var(KCLVM_CLI_BIN_PATH_ENV_VAR) {
@@ -1,13 +1,18 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 
+use indexmap::IndexMap;
 use kclvm_ast::ast;
 use kclvm_config::{
     modfile::get_vendor_home,
     settings::{SettingsFile, SettingsPathBuf},
 };
+use kclvm_evaluator::EvalOptions;
 use kclvm_query::r#override::parse_override_spec;
 use kclvm_runtime::ValueRef;
-use serde::{Deserialize, Serialize};
+use serde::{de::IntoDeserializer, Deserialize, Serialize};
+
+use crate::emit::EmitKind;
 
 const RESULT_SIZE: usize = 2048 * 2048;
 
@@ -50,9 +55,45 @@ pub struct ExecProgramArgs {
     pub sort_keys: bool,
     // include schema type path in JSON/YAML result
     pub include_schema_type_path: bool,
+    // --strict_type_check
+    #[serde(default)]
+    pub strict_type_check: bool,
     // plugin_agent is the address of plugin.
     #[serde(skip)]
     pub plugin_agent: u64,
+    // --backend llvm/evaluator
+    #[serde(default)]
+    pub backend: KclvmBackend,
+    // --artifact: run the prebuilt artifact instead of compiling the input files.
+    #[serde(default)]
+    pub artifact: Option<String>,
+    // --emit ast/llvm-ir/llvm-bc/obj
+    #[serde(default)]
+    pub emit: Vec<EmitKind>,
+    // --emit_dir: the directory of the emitted files, default is the current directory.
+    #[serde(default)]
+    pub emit_dir: Option<String>,
+}
+
+/// This enum lists all the backends which can be used to execute the KCL program.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum KclvmBackend {
+    /// Generate dynamic link libraries with LLVM, link and run them.
+    /// The empty name is the unset backend of the API arguments.
+    #[default]
+    #[serde(alias = "")]
+    Llvm,
+    /// Walk the resolved AST directly without the LLVM codegen and the C linker.
+    Evaluator,
+}
+
+impl FromStr for KclvmBackend {
+    type Err = serde::de::value::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
 }
 
 impl ExecProgramArgs {
@@ -100,6 +141,11 @@ impl ExecProgramArgs {
         serde_json::ser::to_string(self).unwrap()
     }
 
+    /// Get the directory of the emitted files.
+    pub fn get_emit_dir(&self) -> String {
+        self.emit_dir.clone().unwrap_or_else(|| ".".to_string())
+    }
+
     /// Get the input file list.
     pub fn get_files(&self) -> Vec<&str> {
         self.k_filename_list.iter().map(|s| s.as_str()).collect()
@@ -130,6 +176,7 @@ impl TryFrom<SettingsFile> for ExecProgramArgs {
                 args.k_filename_list = cli_configs.file.unwrap_or_default();
             }
             args.strict_range_check = cli_configs.strict_range_check.unwrap_or_default();
+            args.strict_type_check = cli_configs.strict_type_check.unwrap_or_default();
             args.disable_none = cli_configs.disable_none.unwrap_or_default();
             args.verbose = cli_configs.verbose.unwrap_or_default() as i32;
             args.debug = cli_configs.debug.unwrap_or_default() as i32;
@@ -335,6 +382,63 @@ impl KclvmRunner {
     }
 }
 
+/// Run the executable artifact in a child process with the "-D" arguments and
+/// the runtime options, the result is read from the stdout of the executable.
+pub(crate) fn run_executable(exe_path: &str, args: &ExecProgramArgs) -> Result<String, String> {
+    let mut cmd = std::process::Command::new(exe_path);
+    if args.strict_range_check {
+        cmd.arg("-r");
+    }
+    if args.disable_none {
+        cmd.arg("-n");
+    }
+    if args.debug > 0 {
+        cmd.arg("-d");
+    }
+    for arg in &args.args {
+        cmd.arg("-D").arg(format!("{}={}", arg.name, arg.value));
+    }
+    let output = cmd.output().map_err(|e| e.to_string())?;
+    if output.status.success() {
+        let stdout = String::from_utf8(output.stdout).map_err(|e| e.to_string())?;
+        // Remove the newline printed after the result.
+        let result = stdout.strip_suffix('\n').unwrap_or(&stdout);
+        if result.is_empty() {
+            Ok(result.to_string())
+        } else {
+            wrap_msg_in_result(result)
+        }
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(stderr.trim_end().to_string())
+    }
+}
+
+/// Execute the resolved program with the tree-walking evaluator backend.
+pub(crate) fn eval_program(
+    program: &ast::Program,
+    import_names: IndexMap<String, IndexMap<String, String>>,
+    args: &ExecProgramArgs,
+) -> Result<String, String> {
+    let opts = EvalOptions {
+        args: args
+            .args
+            .iter()
+            .map(|arg| (arg.name.clone(), arg.value.clone()))
+            .collect(),
+        strict_range_check: args.strict_range_check,
+        disable_none: args.disable_none,
+        debug_mode: args.debug > 0,
+        plugin_agent: args.plugin_agent,
+    };
+    let result = kclvm_evaluator::run(program, import_names, &opts);
+    if result.is_empty() {
+        Ok(result)
+    } else {
+        wrap_msg_in_result(&result)
+    }
+}
+
 fn wrap_msg_in_result(msg: &str) -> Result<String, String> {
     // YAML is compatible with JSON. We can use YAML library for result parsing.
     let kcl_val = match ValueRef::from_yaml_stream(msg) {
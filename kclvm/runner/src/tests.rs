@@ -1,14 +1,16 @@
+use crate::artifact::{list_options, ArtifactKind, ArtifactManifest, OptionSpec};
 use crate::assembler::clean_path;
 use crate::assembler::KclvmAssembler;
 use crate::assembler::KclvmLibAssembler;
 use crate::assembler::LibAssembler;
+use crate::emit::{EmitKind, AST_FILE_SUFFIX};
 use crate::exec_program;
 use crate::temp_file;
-use crate::{execute, runner::ExecProgramArgs};
+use crate::{execute, runner::ExecProgramArgs, KclvmBackend};
 use anyhow::Context;
 use anyhow::Result;
-use kclvm_ast::ast::{Module, Program};
-use kclvm_compiler::codegen::llvm::OBJECT_FILE_SUFFIX;
+use kclvm_ast::ast::{CmdArgSpec, Module, Program};
+use kclvm_compiler::codegen::llvm::{LL_FILE_SUFFIX, OBJECT_FILE_SUFFIX};
 use kclvm_config::settings::load_file;
 use kclvm_parser::load_program;
 use kclvm_parser::ParseSession;
@@ -432,6 +434,112 @@ fn test_clean_path_for_genlibs() {
     assert_eq!(path2.exists(), false);
 }
 
+#[test]
+fn test_list_options() {
+    let main_file = Path::new(&test_case_path())
+        .join("options")
+        .join(KCL_FILE_NAME)
+        .display()
+        .to_string();
+    let program = parse_program(&main_file);
+    assert_eq!(
+        list_options(&program),
+        vec![
+            OptionSpec {
+                name: "name".to_string(),
+                typ: "str".to_string(),
+                required: true,
+                default_value: None,
+                help: "The app name".to_string(),
+            },
+            OptionSpec {
+                name: "replicas".to_string(),
+                typ: "int".to_string(),
+                required: false,
+                default_value: Some("1".to_string()),
+                help: "".to_string(),
+            },
+            OptionSpec {
+                name: "env".to_string(),
+                typ: "".to_string(),
+                required: false,
+                default_value: Some("\"dev\"".to_string()),
+                help: "".to_string(),
+            },
+        ]
+    );
+
+    let manifest = ArtifactManifest::new(&program, ArtifactKind::Exe);
+    assert_eq!(manifest.version, kclvm_version::VERSION);
+    let temp_dir = tempdir().unwrap();
+    let artifact = temp_dir.path().join("app").display().to_string();
+    manifest.save(&artifact).unwrap();
+    assert_eq!(ArtifactManifest::load(&artifact).unwrap(), manifest);
+
+    let arg = |name: &str| CmdArgSpec {
+        name: name.to_string(),
+        value: "\"v\"".to_string(),
+    };
+    assert!(manifest.check_args(&[arg("name"), arg("env")]).is_ok());
+    assert_eq!(
+        manifest
+            .check_args(&[arg("name"), arg("image")])
+            .unwrap_err()
+            .to_string(),
+        "unknown top-level arguments 'image' of the artifact, the accepted arguments are ['name', 'replicas', 'env']"
+    );
+}
+
+#[test]
+fn test_exec_with_emit() {
+    let temp_dir = tempdir().unwrap();
+    let emit_dir = temp_dir.path().display().to_string();
+    let main_file = Path::new(&test_case_path())
+        .join("init_check_order_0")
+        .join(KCL_FILE_NAME)
+        .display()
+        .to_string();
+    let mut args = ExecProgramArgs::default();
+    args.k_filename_list.push(main_file.clone());
+    args.backend = KclvmBackend::Evaluator;
+    args.emit = vec![EmitKind::Ast, EmitKind::LlvmIr];
+    args.emit_dir = Some(emit_dir);
+    exec_program(Arc::new(ParseSession::default()), &args).unwrap();
+
+    let ast_file = temp_dir
+        .path()
+        .join(format!("{}{}", MAIN_PKG_NAME, AST_FILE_SUFFIX));
+    let program = Program::from_json(&fs::read_to_string(ast_file).unwrap()).unwrap();
+    assert!(Path::new(&program.pkgs[MAIN_PKG_NAME][0].filename)
+        .ends_with(Path::new("init_check_order_0").join(KCL_FILE_NAME)));
+    assert!(temp_dir
+        .path()
+        .join(format!("{}{}", MAIN_PKG_NAME, LL_FILE_SUFFIX))
+        .exists());
+    assert_eq!("llvm-bc".parse::<EmitKind>().unwrap(), EmitKind::LlvmBc);
+    assert!("llvm".parse::<EmitKind>().is_err());
+    assert!("resolved-ast".parse::<EmitKind>().is_err());
+
+    // The outputs can not be emitted from the prebuilt artifact.
+    args.artifact = Some("app.so".to_string());
+    let err = exec_program(Arc::new(ParseSession::default()), &args).unwrap_err();
+    assert!(err.contains("can not be emitted from the prebuilt artifact app.so"));
+}
+
+#[test]
+fn test_exec_with_lint_levels() {
+    let main_file = Path::new(&test_case_path())
+        .join("lint_levels")
+        .join(KCL_FILE_NAME)
+        .display()
+        .to_string();
+    let mut args = ExecProgramArgs::default();
+    args.k_filename_list.push(main_file);
+    args.backend = KclvmBackend::Evaluator;
+    let err = exec_program(Arc::new(ParseSession::default()), &args).unwrap_err();
+    assert!(err.contains("Module 'math' imported but unused"), "{}", err);
+}
+
 #[test]
 fn test_to_json_program_arg() {
     for case in exec_prog_args_test_case() {
@@ -458,6 +566,25 @@ fn test_from_str_program_arg() {
     }
 }
 
+#[test]
+fn test_backend_program_arg() {
+    assert_eq!("llvm".parse::<KclvmBackend>().unwrap(), KclvmBackend::Llvm);
+    assert_eq!(
+        "evaluator".parse::<KclvmBackend>().unwrap(),
+        KclvmBackend::Evaluator
+    );
+    assert!("python".parse::<KclvmBackend>().is_err());
+    // The unset backend of the API arguments is the LLVM backend.
+    let json_str = ExecProgramArgs::default().to_json();
+    let args =
+        ExecProgramArgs::from_str(&json_str.replace(r#""backend":"llvm""#, r#""backend":"""#));
+    assert_eq!(args.backend, KclvmBackend::Llvm);
+    let args = ExecProgramArgs::from_str(
+        &json_str.replace(r#""backend":"llvm""#, r#""backend":"evaluator""#),
+    );
+    assert_eq!(args.backend, KclvmBackend::Evaluator);
+}
+
 #[test]
 fn test_from_setting_file_program_arg() {
     for (case_yaml, case_json) in settings_file_test_case() {
@@ -492,6 +619,17 @@ fn test_exec_file() {
     std::panic::set_hook(prev_hook);
 }
 
+fn test_exec_with_evaluator_backend() {
+    for file in get_files(exec_data_path(), false, true, ".k") {
+        assert_eq!(
+            exec_with_backend(&file, KclvmBackend::Llvm),
+            exec_with_backend(&file, KclvmBackend::Evaluator),
+            "{}",
+            file
+        );
+    }
+}
+
 fn test_custom_manifests_output() {
     exec_with_result_at(&custom_manifests_data_path());
 }
@@ -538,11 +676,19 @@ fn test_exec() {
 
     test_exec_with_err_result();
     println!("test_exec_with_err_result - PASS");
+
+    test_exec_with_evaluator_backend();
+    println!("test_exec_with_evaluator_backend - PASS");
 }
 
 fn exec(file: &str) -> Result<String, String> {
+    exec_with_backend(file, KclvmBackend::Llvm)
+}
+
+fn exec_with_backend(file: &str, backend: KclvmBackend) -> Result<String, String> {
     let mut args = ExecProgramArgs::default();
     args.k_filename_list.push(file.to_string());
+    args.backend = backend;
     let opts = args.get_load_program_options();
     let sess = Arc::new(ParseSession::default());
     // Load AST program
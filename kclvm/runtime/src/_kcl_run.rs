@@ -40,7 +40,7 @@ pub type kclvm_float_t = f64;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn _kcl_run(
+pub unsafe extern "C-unwind" fn _kcl_run(
     kclvm_main_ptr: u64, // main.k => kclvm_main
     option_len: kclvm_size_t,
     option_keys: *const *const kclvm_char_t,
@@ -136,7 +136,7 @@ unsafe fn _kcl_run_in_closure(
     let ctx = kclvm_context_current();
 
     let kclvm_main = (&kclvm_main_ptr as *const u64) as *const ()
-        as *const extern "C" fn(ctx: *mut kclvm_context_t) -> *mut kclvm_value_ref_t;
+        as *const extern "C-unwind" fn(ctx: *mut kclvm_context_t) -> *mut kclvm_value_ref_t;
 
     kclvm_context_set_strict_range_check(ctx, strict_range_check as kclvm_bool_t);
     kclvm_context_set_disable_none(ctx, disable_none as kclvm_bool_t);
@@ -385,6 +385,8 @@ pub struct Context {
     pub symbol_names: Vec<String>,
     pub symbol_values: Vec<Value>,
     pub func_handlers: Vec<FuncHandler>,
+    /// The handler address of the proxy functions, see [`crate::FUNC_PROXY_TAG`].
+    pub func_proxy_handler: u64,
 
     pub option_helps: Vec<OptionHelp>,
     pub buffer: ContextBuffer,
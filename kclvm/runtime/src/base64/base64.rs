@@ -9,7 +9,7 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_base64_encode(
+pub extern "C-unwind" fn kclvm_base64_encode(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -32,7 +32,7 @@ pub extern "C" fn kclvm_base64_encode(
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_base64_decode(
+pub extern "C-unwind" fn kclvm_base64_decode(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -38,45 +38,39 @@ type kclvm_float_t = f64;
 // new/delete
 // ----------------------------------------------------------------------------
 
-// singleton
+// singleton of the current thread
 
-#[allow(non_camel_case_types, non_upper_case_globals)]
-static mut _kclvm_context_current: u64 = 0;
+thread_local! {
+    static KCLVM_CONTEXT_CURRENT: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_current() -> *mut kclvm_context_t {
-    unsafe {
-        if _kclvm_context_current == 0 {
-            _kclvm_context_current = kclvm_context_new() as u64;
-        }
-        _kclvm_context_current as *mut kclvm_context_t
+pub unsafe extern "C-unwind" fn kclvm_context_current() -> *mut kclvm_context_t {
+    if KCLVM_CONTEXT_CURRENT.with(|current| current.get()) == 0 {
+        kclvm_context_new();
     }
+    KCLVM_CONTEXT_CURRENT.with(|current| current.get()) as *mut kclvm_context_t
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_new() -> *mut kclvm_context_t {
+pub unsafe extern "C-unwind" fn kclvm_context_new() -> *mut kclvm_context_t {
     let p = Box::into_raw(Box::new(Context::new()));
-    unsafe {
-        _kclvm_context_current = p as u64;
-    }
+    KCLVM_CONTEXT_CURRENT.with(|current| current.set(p as u64));
     p
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_delete(p: *mut kclvm_context_t) {
+pub unsafe extern "C-unwind" fn kclvm_context_delete(p: *mut kclvm_context_t) {
     let ctx = mut_ptr_as_ref(p);
     for o in &ctx.objects {
         let ptr = (*o) as *mut kclvm_value_ref_t;
         kclvm_value_delete(ptr);
     }
-    unsafe {
-        //todo: remove global _kclvm_context_current
-        //set _kclvm_context_current to null to invoid internal unsoundness
-        _kclvm_context_current = 0;
-    }
+    // Set the current context to null to avoid internal unsoundness.
+    KCLVM_CONTEXT_CURRENT.with(|current| current.set(0));
     free_mut_ptr(p);
 }
 
@@ -86,14 +80,14 @@ pub unsafe extern "C" fn kclvm_context_delete(p: *mut kclvm_context_t) {
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_main_begin_hook(p: *mut kclvm_context_t) {
+pub unsafe extern "C-unwind" fn kclvm_context_main_begin_hook(p: *mut kclvm_context_t) {
     let p = mut_ptr_as_ref(p);
     p.main_begin_hook();
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_main_end_hook(
+pub unsafe extern "C-unwind" fn kclvm_context_main_end_hook(
     p: *mut kclvm_context_t,
     return_value: *mut kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -107,7 +101,7 @@ pub unsafe extern "C" fn kclvm_context_main_end_hook(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_kcl_location(
+pub unsafe extern "C-unwind" fn kclvm_context_set_kcl_location(
     p: *mut kclvm_context_t,
     filename: *const i8,
     line: i32,
@@ -123,7 +117,7 @@ pub unsafe extern "C" fn kclvm_context_set_kcl_location(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_kcl_pkgpath(
+pub unsafe extern "C-unwind" fn kclvm_context_set_kcl_pkgpath(
     p: *mut kclvm_context_t,
     pkgpath: *const i8,
 ) {
@@ -135,7 +129,7 @@ pub unsafe extern "C" fn kclvm_context_set_kcl_pkgpath(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_kcl_filename(filename: *const i8) {
+pub unsafe extern "C-unwind" fn kclvm_context_set_kcl_filename(filename: *const i8) {
     let p = Context::current_context_mut();
     if !filename.is_null() {
         p.set_kcl_filename(c2str(filename));
@@ -144,7 +138,7 @@ pub unsafe extern "C" fn kclvm_context_set_kcl_filename(filename: *const i8) {
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_kcl_line_col(line: i32, col: i32) {
+pub unsafe extern "C-unwind" fn kclvm_context_set_kcl_line_col(line: i32, col: i32) {
     let p = Context::current_context_mut();
     p.set_kcl_line_col(line, col);
 }
@@ -155,7 +149,10 @@ pub unsafe extern "C" fn kclvm_context_set_kcl_line_col(line: i32, col: i32) {
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_put_type(p: *mut kclvm_context_t, typ: *const kclvm_type_t) {
+pub unsafe extern "C-unwind" fn kclvm_context_put_type(
+    p: *mut kclvm_context_t,
+    typ: *const kclvm_type_t,
+) {
     let p = mut_ptr_as_ref(p);
     let typ = ptr_as_ref(typ);
 
@@ -164,7 +161,7 @@ pub unsafe extern "C" fn kclvm_context_put_type(p: *mut kclvm_context_t, typ: *c
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_clear_all_types(p: *mut kclvm_context_t) {
+pub unsafe extern "C-unwind" fn kclvm_context_clear_all_types(p: *mut kclvm_context_t) {
     let p = mut_ptr_as_ref(p);
     p.all_types.clear();
 }
@@ -175,7 +172,7 @@ pub unsafe extern "C" fn kclvm_context_clear_all_types(p: *mut kclvm_context_t)
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_symbol_init(
+pub unsafe extern "C-unwind" fn kclvm_context_symbol_init(
     p: *mut kclvm_context_t,
     n: kclvm_size_t,
     symbol_names: *const *const kclvm_char_t,
@@ -197,7 +194,9 @@ pub unsafe extern "C" fn kclvm_context_symbol_init(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_symbol_num(p: *const kclvm_context_t) -> kclvm_size_t {
+pub unsafe extern "C-unwind" fn kclvm_context_symbol_num(
+    p: *const kclvm_context_t,
+) -> kclvm_size_t {
     let p = ptr_as_ref(p);
 
     p.symbol_names.len() as kclvm_size_t
@@ -205,7 +204,7 @@ pub unsafe extern "C" fn kclvm_context_symbol_num(p: *const kclvm_context_t) ->
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_symbol_name(
+pub unsafe extern "C-unwind" fn kclvm_context_symbol_name(
     p: *const kclvm_context_t,
     i: kclvm_size_t,
 ) -> *const kclvm_char_t {
@@ -218,7 +217,7 @@ pub unsafe extern "C" fn kclvm_context_symbol_name(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_symbol_value(
+pub unsafe extern "C-unwind" fn kclvm_context_symbol_value(
     p: *const kclvm_context_t,
     i: kclvm_size_t,
 ) -> *const kclvm_value_t {
@@ -235,7 +234,7 @@ pub unsafe extern "C" fn kclvm_context_symbol_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_args_get(
+pub unsafe extern "C-unwind" fn kclvm_context_args_get(
     _p: *const kclvm_context_t,
     _key: *const kclvm_char_t,
 ) -> *const kclvm_char_t {
@@ -249,7 +248,7 @@ pub unsafe extern "C" fn kclvm_context_args_get(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_args_set(
+pub unsafe extern "C-unwind" fn kclvm_context_args_set(
     _p: *mut kclvm_context_t,
     _key: *const kclvm_char_t,
     _value: *const kclvm_char_t,
@@ -261,7 +260,7 @@ pub unsafe extern "C" fn kclvm_context_args_set(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_args_clear(p: *mut kclvm_context_t) {
+pub unsafe extern "C-unwind" fn kclvm_context_args_clear(p: *mut kclvm_context_t) {
     let p = mut_ptr_as_ref(p);
     p.app_args.clear();
 }
@@ -272,14 +271,17 @@ pub unsafe extern "C" fn kclvm_context_args_clear(p: *mut kclvm_context_t) {
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_debug_mode(p: *mut kclvm_context_t, v: kclvm_bool_t) {
+pub unsafe extern "C-unwind" fn kclvm_context_set_debug_mode(
+    p: *mut kclvm_context_t,
+    v: kclvm_bool_t,
+) {
     let p = mut_ptr_as_ref(p);
     p.cfg.debug_mode = v != 0;
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_strict_range_check(
+pub unsafe extern "C-unwind" fn kclvm_context_set_strict_range_check(
     p: *mut kclvm_context_t,
     v: kclvm_bool_t,
 ) {
@@ -289,14 +291,17 @@ pub unsafe extern "C" fn kclvm_context_set_strict_range_check(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_disable_none(p: *mut kclvm_context_t, v: kclvm_bool_t) {
+pub unsafe extern "C-unwind" fn kclvm_context_set_disable_none(
+    p: *mut kclvm_context_t,
+    v: kclvm_bool_t,
+) {
     let p = mut_ptr_as_ref(p);
     p.cfg.disable_none = v != 0;
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_disable_schema_check(
+pub unsafe extern "C-unwind" fn kclvm_context_set_disable_schema_check(
     p: *mut kclvm_context_t,
     v: kclvm_bool_t,
 ) {
@@ -306,7 +311,7 @@ pub unsafe extern "C" fn kclvm_context_set_disable_schema_check(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_list_option_mode(
+pub unsafe extern "C-unwind" fn kclvm_context_set_list_option_mode(
     p: *mut kclvm_context_t,
     v: kclvm_bool_t,
 ) {
@@ -320,7 +325,7 @@ pub unsafe extern "C" fn kclvm_context_set_list_option_mode(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_invoke(
+pub unsafe extern "C-unwind" fn kclvm_context_invoke(
     p: *mut kclvm_context_t,
     method: *const c_char,
     args: *const c_char,
@@ -357,7 +362,7 @@ unsafe fn _kclvm_context_invoke(
     }
 
     let ptr = (&fn_addr as *const u64) as *const ()
-        as *const extern "C" fn(
+        as *const extern "C-unwind" fn(
             ctx: *mut kclvm_context_t,
             args: *const kclvm_value_ref_t,
             kwargs: *const kclvm_value_ref_t,
@@ -368,7 +373,7 @@ unsafe fn _kclvm_context_invoke(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_pkgpath_is_imported(
+pub unsafe extern "C-unwind" fn kclvm_context_pkgpath_is_imported(
     pkgpath: *const kclvm_char_t,
 ) -> kclvm_bool_t {
     let pkgpath = c2str(pkgpath);
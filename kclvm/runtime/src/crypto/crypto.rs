@@ -18,7 +18,7 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_crypto_md5(
+pub unsafe extern "C-unwind" fn kclvm_crypto_md5(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -36,7 +36,7 @@ pub unsafe extern "C" fn kclvm_crypto_md5(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_crypto_sha1(
+pub unsafe extern "C-unwind" fn kclvm_crypto_sha1(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -54,7 +54,7 @@ pub unsafe extern "C" fn kclvm_crypto_sha1(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_crypto_sha224(
+pub unsafe extern "C-unwind" fn kclvm_crypto_sha224(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -82,7 +82,7 @@ pub unsafe extern "C" fn kclvm_crypto_sha224(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_crypto_sha256(
+pub unsafe extern "C-unwind" fn kclvm_crypto_sha256(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -110,7 +110,7 @@ pub unsafe extern "C" fn kclvm_crypto_sha256(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_crypto_sha384(
+pub unsafe extern "C-unwind" fn kclvm_crypto_sha384(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -138,7 +138,7 @@ pub unsafe extern "C" fn kclvm_crypto_sha384(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_crypto_sha512(
+pub unsafe extern "C-unwind" fn kclvm_crypto_sha512(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -16,7 +16,7 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_datetime_today(
+pub unsafe extern "C-unwind" fn kclvm_datetime_today(
     _ctx: *mut kclvm_context_t,
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -29,7 +29,7 @@ pub unsafe extern "C" fn kclvm_datetime_today(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_datetime_now(
+pub unsafe extern "C-unwind" fn kclvm_datetime_now(
     _ctx: *mut kclvm_context_t,
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -42,7 +42,7 @@ pub unsafe extern "C" fn kclvm_datetime_now(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_datetime_ticks(
+pub unsafe extern "C-unwind" fn kclvm_datetime_ticks(
     _ctx: *mut kclvm_context_t,
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -55,7 +55,7 @@ pub unsafe extern "C" fn kclvm_datetime_ticks(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_datetime_date(
+pub unsafe extern "C-unwind" fn kclvm_datetime_date(
     _ctx: *mut kclvm_context_t,
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -12,7 +12,7 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_json_encode(
+pub unsafe extern "C-unwind" fn kclvm_json_encode(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -20,37 +20,44 @@ pub unsafe extern "C" fn kclvm_json_encode(
     let args = ptr_as_ref(args);
     let kwargs = ptr_as_ref(kwargs);
 
-    let mut opt = JsonEncodeOptions::default();
+    if let Some(arg0) = get_call_arg(args, kwargs, 0, "data") {
+        let s = ValueRef::str(
+            arg0.to_json_string_with_option(&kwargs_to_opts(kwargs))
+                .as_ref(),
+        );
+        return s.into_raw();
+    }
+    panic!("encode() missing 1 required positional argument: 'data'")
+}
+
+fn kwargs_to_opts(kwargs: &ValueRef) -> JsonEncodeOptions {
+    let mut opts = JsonEncodeOptions::default();
     if let Some(sort_keys) = kwargs.kwarg_bool("sort_keys", None) {
-        opt.sort_keys = sort_keys;
+        opts.sort_keys = sort_keys;
     }
     if let Some(indent) = kwargs.kwarg_int("indent", None) {
-        opt.indent = indent;
+        opts.indent = indent;
     }
     if let Some(ignore_private) = kwargs.kwarg_bool("ignore_private", None) {
-        opt.ignore_private = ignore_private;
+        opts.ignore_private = ignore_private;
     }
     if let Some(ignore_none) = kwargs.kwarg_bool("ignore_none", None) {
-        opt.ignore_none = ignore_none;
-    }
-
-    if let Some(arg0) = args.arg_i(0) {
-        let s = ValueRef::str(arg0.to_json_string_with_option(&opt).as_ref());
-        return s.into_raw();
+        opts.ignore_none = ignore_none;
     }
-    panic!("encode() missing 1 required positional argument: 'value'")
+    opts
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_json_decode(
+pub unsafe extern "C-unwind" fn kclvm_json_decode(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(arg0) = args.arg_i(0) {
+    if let Some(arg0) = get_call_arg(args, kwargs, 0, "value") {
         match ValueRef::from_json(arg0.as_str().as_ref()) {
             Ok(x) => return x.into_raw(),
             Err(err) => panic!("{}", err),
@@ -61,19 +68,20 @@ pub unsafe extern "C" fn kclvm_json_decode(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_json_dump_to_file(
+pub unsafe extern "C-unwind" fn kclvm_json_dump_to_file(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(data) = args.arg_i(0) {
-        if let Some(filename) = args.arg_i(0) {
-            let yaml = data.to_json_string();
-            let filename = filename.as_str();
-
-            std::fs::write(filename, yaml).expect("Unable to write file");
+    if let Some(data) = get_call_arg(args, kwargs, 0, "data") {
+        if let Some(filename) = get_call_arg_str(args, kwargs, 1, "filename", None) {
+            let json = data.to_json_string_with_option(&kwargs_to_opts(kwargs));
+            std::fs::write(&filename, json)
+                .unwrap_or_else(|e| panic!("Unable to write file '{}': {}", filename, e));
+            return kclvm_value_None();
         }
     }
     panic!("dump_to_file() missing 2 required positional arguments: 'data' and 'filename'")
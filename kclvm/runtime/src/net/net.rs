@@ -16,7 +16,7 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_split_host_port(
+pub unsafe extern "C-unwind" fn kclvm_net_split_host_port(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -38,7 +38,7 @@ pub unsafe extern "C" fn kclvm_net_split_host_port(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_join_host_port(
+pub unsafe extern "C-unwind" fn kclvm_net_join_host_port(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -62,7 +62,7 @@ pub unsafe extern "C" fn kclvm_net_join_host_port(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_fqdn(
+pub unsafe extern "C-unwind" fn kclvm_net_fqdn(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -79,7 +79,7 @@ pub unsafe extern "C" fn kclvm_net_fqdn(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_parse_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_parse_IP(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -91,7 +91,7 @@ pub unsafe extern "C" fn kclvm_net_parse_IP(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_to_IP4(
+pub unsafe extern "C-unwind" fn kclvm_net_to_IP4(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -103,7 +103,7 @@ pub unsafe extern "C" fn kclvm_net_to_IP4(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_to_IP16(
+pub unsafe extern "C-unwind" fn kclvm_net_to_IP16(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -115,7 +115,7 @@ pub unsafe extern "C" fn kclvm_net_to_IP16(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_IP_string(
+pub unsafe extern "C-unwind" fn kclvm_net_IP_string(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -142,7 +142,7 @@ pub unsafe extern "C" fn kclvm_net_IP_string(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_IPv4(
+pub unsafe extern "C-unwind" fn kclvm_net_is_IPv4(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -167,7 +167,7 @@ pub unsafe extern "C" fn kclvm_net_is_IPv4(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_is_IP(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -192,7 +192,7 @@ pub unsafe extern "C" fn kclvm_net_is_IP(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_loopback_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_is_loopback_IP(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -219,7 +219,7 @@ pub unsafe extern "C" fn kclvm_net_is_loopback_IP(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_multicast_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_is_multicast_IP(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -246,7 +246,7 @@ pub unsafe extern "C" fn kclvm_net_is_multicast_IP(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_interface_local_multicast_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_is_interface_local_multicast_IP(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -273,7 +273,7 @@ pub unsafe extern "C" fn kclvm_net_is_interface_local_multicast_IP(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_link_local_multicast_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_is_link_local_multicast_IP(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -301,7 +301,7 @@ pub unsafe extern "C" fn kclvm_net_is_link_local_multicast_IP(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_link_local_unicast_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_is_link_local_unicast_IP(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -334,7 +334,7 @@ pub const fn Ipv6Addr_is_unicast_link_local(_self: &Ipv6Addr) -> bool {
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_global_unicast_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_is_global_unicast_IP(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -399,7 +399,7 @@ const fn Ipv4Addr_is_benchmarking(_self: &std::net::Ipv4Addr) -> bool {
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_net_is_unspecified_IP(
+pub unsafe extern "C-unwind" fn kclvm_net_is_unspecified_IP(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
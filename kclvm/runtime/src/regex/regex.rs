@@ -20,15 +20,16 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_regex_match(
+pub unsafe extern "C-unwind" fn kclvm_regex_match(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(string) = args.arg_i_str(0, None) {
-        if let Some(pattern) = args.arg_i_str(1, None) {
+    if let Some(string) = get_call_arg_str(args, kwargs, 0, "string", None) {
+        if let Some(pattern) = get_call_arg_str(args, kwargs, 1, "pattern", None) {
             let re = fancy_regex::Regex::new(pattern.as_ref()).unwrap();
             match re.is_match(string.as_ref()) {
                 Ok(ok) => {
@@ -50,17 +51,18 @@ pub unsafe extern "C" fn kclvm_regex_match(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_regex_replace(
+pub unsafe extern "C-unwind" fn kclvm_regex_replace(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(string) = args.arg_i_str(0, None) {
-        if let Some(pattern) = args.arg_i_str(1, None) {
-            if let Some(replace) = args.arg_i_str(2, None) {
-                if let Some(count) = args.arg_i_int(3, Some(0)) {
+    if let Some(string) = get_call_arg_str(args, kwargs, 0, "string", None) {
+        if let Some(pattern) = get_call_arg_str(args, kwargs, 1, "pattern", None) {
+            if let Some(replace) = get_call_arg_str(args, kwargs, 2, "replace", None) {
+                if let Some(count) = get_call_arg_int(args, kwargs, 3, "count", Some(0)) {
                     let re = fancy_regex::Regex::new(pattern.as_ref()).unwrap();
                     let s = re.replacen(string.as_ref(), count as usize, replace.as_ref() as &str);
                     return ValueRef::str(&s).into_raw();
@@ -75,14 +77,15 @@ pub unsafe extern "C" fn kclvm_regex_replace(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_regex_compile(
+pub unsafe extern "C-unwind" fn kclvm_regex_compile(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(pattern) = args.arg_i_str(0, None) {
+    if let Some(pattern) = get_call_arg_str(args, kwargs, 0, "pattern", None) {
         match fancy_regex::Regex::new(pattern.as_ref()) {
             Ok(_) => return kclvm_value_Bool(1),
             _ => return kclvm_value_Bool(0),
@@ -95,15 +98,16 @@ pub unsafe extern "C" fn kclvm_regex_compile(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_regex_findall(
+pub unsafe extern "C-unwind" fn kclvm_regex_findall(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(string) = args.arg_i_str(0, None) {
-        if let Some(pattern) = args.arg_i_str(1, None) {
+    if let Some(string) = get_call_arg_str(args, kwargs, 0, "string", None) {
+        if let Some(pattern) = get_call_arg_str(args, kwargs, 1, "pattern", None) {
             let mut list = ValueRef::list(None);
 
             for x in fancy_regex::Regex::new(pattern.as_ref())
@@ -134,15 +138,16 @@ pub unsafe extern "C" fn kclvm_regex_findall(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_regex_search(
+pub unsafe extern "C-unwind" fn kclvm_regex_search(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(string) = args.arg_i_str(0, None) {
-        if let Some(pattern) = args.arg_i_str(1, None) {
+    if let Some(string) = get_call_arg_str(args, kwargs, 0, "string", None) {
+        if let Some(pattern) = get_call_arg_str(args, kwargs, 1, "pattern", None) {
             let re = fancy_regex::Regex::new(pattern.as_ref()).unwrap();
 
             if let Ok(Some(..)) = re.find(string.as_ref()) {
@@ -158,16 +163,17 @@ pub unsafe extern "C" fn kclvm_regex_search(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_regex_split(
+pub unsafe extern "C-unwind" fn kclvm_regex_split(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(string) = args.arg_i_str(0, None) {
-        if let Some(pattern) = args.arg_i_str(1, None) {
-            if let Some(maxsplit) = args.arg_i_int(2, Some(0)) {
+    if let Some(string) = get_call_arg_str(args, kwargs, 0, "string", None) {
+        if let Some(pattern) = get_call_arg_str(args, kwargs, 1, "pattern", None) {
+            if let Some(maxsplit) = get_call_arg_int(args, kwargs, 2, "maxsplit", Some(0)) {
                 let mut list = ValueRef::list(None);
 
                 let re = fancy_regex::Regex::new(pattern.as_ref()).unwrap();
@@ -180,10 +186,11 @@ pub unsafe extern "C" fn kclvm_regex_split(
                         .map_or(None, |c| c);
                     if let Some(Some(cap)) = capture.map(|c| c.get(0)) {
                         fields.push(string[current_pos..cap.start()].to_string());
+                        current_pos = cap.end();
                         if maxsplit > 0 && fields.len() >= (maxsplit as usize) {
+                            fields.push(string[current_pos..].to_string());
                             break;
                         }
-                        current_pos = cap.end();
                     } else {
                         fields.push(string[current_pos..].to_string());
                         break;
@@ -7,7 +7,7 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_assert(
+pub unsafe extern "C-unwind" fn kclvm_assert(
     value: *const kclvm_value_ref_t,
     msg: *const kclvm_value_ref_t,
 ) {
@@ -8,7 +8,7 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_option_init(
+pub unsafe extern "C-unwind" fn kclvm_builtin_option_init(
     ctx: *mut kclvm_context_t,
     key: *const i8,
     value: *const i8,
@@ -19,7 +19,7 @@ pub unsafe extern "C" fn kclvm_builtin_option_init(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_option_reset(
+pub unsafe extern "C-unwind" fn kclvm_builtin_option_reset(
     ctx: *mut kclvm_context_t,
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -34,7 +34,7 @@ pub unsafe extern "C" fn kclvm_builtin_option_reset(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_option(
+pub unsafe extern "C-unwind" fn kclvm_builtin_option(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -250,7 +250,7 @@ pub unsafe extern "C" fn kclvm_builtin_option(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_print(
+pub unsafe extern "C-unwind" fn kclvm_builtin_print(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -276,7 +276,7 @@ pub unsafe extern "C" fn kclvm_builtin_print(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_len(
+pub unsafe extern "C-unwind" fn kclvm_builtin_len(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -291,7 +291,7 @@ pub unsafe extern "C" fn kclvm_builtin_len(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_any_true(
+pub unsafe extern "C-unwind" fn kclvm_builtin_any_true(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -308,7 +308,7 @@ pub unsafe extern "C" fn kclvm_builtin_any_true(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_isunique(
+pub unsafe extern "C-unwind" fn kclvm_builtin_isunique(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -325,7 +325,7 @@ pub unsafe extern "C" fn kclvm_builtin_isunique(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_sorted(
+pub unsafe extern "C-unwind" fn kclvm_builtin_sorted(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -343,7 +343,7 @@ pub unsafe extern "C" fn kclvm_builtin_sorted(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_int(
+pub unsafe extern "C-unwind" fn kclvm_builtin_int(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -361,7 +361,7 @@ pub unsafe extern "C" fn kclvm_builtin_int(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_float(
+pub unsafe extern "C-unwind" fn kclvm_builtin_float(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -378,7 +378,7 @@ pub unsafe extern "C" fn kclvm_builtin_float(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_bool(
+pub unsafe extern "C-unwind" fn kclvm_builtin_bool(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -393,7 +393,7 @@ pub unsafe extern "C" fn kclvm_builtin_bool(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -408,7 +408,7 @@ pub unsafe extern "C" fn kclvm_builtin_str(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_max(
+pub unsafe extern "C-unwind" fn kclvm_builtin_max(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -427,7 +427,7 @@ pub unsafe extern "C" fn kclvm_builtin_max(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_min(
+pub unsafe extern "C-unwind" fn kclvm_builtin_min(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -446,7 +446,7 @@ pub unsafe extern "C" fn kclvm_builtin_min(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_multiplyof(
+pub unsafe extern "C-unwind" fn kclvm_builtin_multiplyof(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -465,7 +465,7 @@ pub unsafe extern "C" fn kclvm_builtin_multiplyof(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_abs(
+pub unsafe extern "C-unwind" fn kclvm_builtin_abs(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -481,7 +481,7 @@ pub unsafe extern "C" fn kclvm_builtin_abs(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_all_true(
+pub unsafe extern "C-unwind" fn kclvm_builtin_all_true(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -497,7 +497,7 @@ pub unsafe extern "C" fn kclvm_builtin_all_true(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_hex(
+pub unsafe extern "C-unwind" fn kclvm_builtin_hex(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -513,7 +513,7 @@ pub unsafe extern "C" fn kclvm_builtin_hex(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_sum(
+pub unsafe extern "C-unwind" fn kclvm_builtin_sum(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -532,7 +532,7 @@ pub unsafe extern "C" fn kclvm_builtin_sum(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_pow(
+pub unsafe extern "C-unwind" fn kclvm_builtin_pow(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -551,7 +551,7 @@ pub unsafe extern "C" fn kclvm_builtin_pow(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_round(
+pub unsafe extern "C-unwind" fn kclvm_builtin_round(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -570,7 +570,7 @@ pub unsafe extern "C" fn kclvm_builtin_round(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_zip(
+pub unsafe extern "C-unwind" fn kclvm_builtin_zip(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -583,7 +583,7 @@ pub unsafe extern "C" fn kclvm_builtin_zip(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_list(
+pub unsafe extern "C-unwind" fn kclvm_builtin_list(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -603,7 +603,7 @@ pub unsafe extern "C" fn kclvm_builtin_list(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_dict(
+pub unsafe extern "C-unwind" fn kclvm_builtin_dict(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -621,7 +621,7 @@ pub unsafe extern "C" fn kclvm_builtin_dict(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_typeof(
+pub unsafe extern "C-unwind" fn kclvm_builtin_typeof(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -641,7 +641,7 @@ pub unsafe extern "C" fn kclvm_builtin_typeof(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_bin(
+pub unsafe extern "C-unwind" fn kclvm_builtin_bin(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -657,7 +657,7 @@ pub unsafe extern "C" fn kclvm_builtin_bin(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_oct(
+pub unsafe extern "C-unwind" fn kclvm_builtin_oct(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -673,7 +673,7 @@ pub unsafe extern "C" fn kclvm_builtin_oct(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_ord(
+pub unsafe extern "C-unwind" fn kclvm_builtin_ord(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -692,7 +692,7 @@ pub unsafe extern "C" fn kclvm_builtin_ord(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_range(
+pub unsafe extern "C-unwind" fn kclvm_builtin_range(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -10,7 +10,7 @@ static mut _plugin_handler_fn_ptr: u64 = 0;
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_plugin_init(
+pub extern "C-unwind" fn kclvm_plugin_init(
     fn_ptr: extern "C" fn(
         method: *const i8,
         args_json: *const c_char,
@@ -29,7 +29,7 @@ pub extern "C" fn kclvm_plugin_init(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_plugin_invoke(
+pub unsafe extern "C-unwind" fn kclvm_plugin_invoke(
     method: *const i8,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -62,7 +62,7 @@ pub unsafe extern "C" fn kclvm_plugin_invoke(
 #[cfg(not(target_arch = "wasm32"))]
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_plugin_invoke_json(
+pub extern "C-unwind" fn kclvm_plugin_invoke_json(
     method: *const i8,
     args: *const c_char,
     kwargs: *const c_char,
@@ -86,7 +86,7 @@ pub extern "C" fn kclvm_plugin_invoke_json(
 #[cfg(target_arch = "wasm32")]
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_plugin_invoke_json(
+pub extern "C-unwind" fn kclvm_plugin_invoke_json(
     method: *const i8,
     args: *const c_char,
     kwargs: *const c_char,
@@ -9,7 +9,7 @@ type kclvm_value_ref_t = ValueRef;
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_testing_arguments(
+pub extern "C-unwind" fn kclvm_testing_arguments(
     _ctx: *mut kclvm_context_t,
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -19,7 +19,7 @@ pub extern "C" fn kclvm_testing_arguments(
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_testing_setting_file(
+pub extern "C-unwind" fn kclvm_testing_setting_file(
     _ctx: *mut kclvm_context_t,
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -36,7 +36,7 @@ type kclvm_float_t = f64;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_delete(p: *mut kclvm_type_t) {
+pub unsafe extern "C-unwind" fn kclvm_type_delete(p: *mut kclvm_type_t) {
     free_mut_ptr(p);
 }
 
@@ -46,7 +46,7 @@ pub unsafe extern "C" fn kclvm_type_delete(p: *mut kclvm_type_t) {
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_kind(p: *const kclvm_type_t) -> kclvm_kind_t {
+pub unsafe extern "C-unwind" fn kclvm_type_kind(p: *const kclvm_type_t) -> kclvm_kind_t {
     let p = ptr_as_ref(p);
 
     p.kind()
@@ -58,7 +58,7 @@ pub unsafe extern "C" fn kclvm_type_kind(p: *const kclvm_type_t) -> kclvm_kind_t
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_str(p: *const kclvm_type_t) -> kclvm_kind_t {
+pub unsafe extern "C-unwind" fn kclvm_type_str(p: *const kclvm_type_t) -> kclvm_kind_t {
     let p = ptr_as_ref(p);
 
     p.kind()
@@ -70,7 +70,7 @@ pub unsafe extern "C" fn kclvm_type_str(p: *const kclvm_type_t) -> kclvm_kind_t
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_BoolLit_value(p: *const kclvm_type_t) -> kclvm_bool_t {
+pub unsafe extern "C-unwind" fn kclvm_type_BoolLit_value(p: *const kclvm_type_t) -> kclvm_bool_t {
     match ptr_as_ref(p) {
         Type::bool_lit_type(ref v) => *v as kclvm_bool_t,
         _ => 0,
@@ -79,7 +79,7 @@ pub unsafe extern "C" fn kclvm_type_BoolLit_value(p: *const kclvm_type_t) -> kcl
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_IntLit_value(p: *const kclvm_type_t) -> i64 {
+pub unsafe extern "C-unwind" fn kclvm_type_IntLit_value(p: *const kclvm_type_t) -> i64 {
     let p = ptr_as_ref(p);
     match p {
         Type::int_lit_type(ref v) => *v,
@@ -89,7 +89,7 @@ pub unsafe extern "C" fn kclvm_type_IntLit_value(p: *const kclvm_type_t) -> i64
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_FloatLit_value(p: *const kclvm_type_t) -> f64 {
+pub unsafe extern "C-unwind" fn kclvm_type_FloatLit_value(p: *const kclvm_type_t) -> f64 {
     let p = ptr_as_ref(p);
     match p {
         Type::float_lit_type(ref v) => *v,
@@ -99,7 +99,9 @@ pub unsafe extern "C" fn kclvm_type_FloatLit_value(p: *const kclvm_type_t) -> f6
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_StrLit_value(p: *const kclvm_type_t) -> *const kclvm_char_t {
+pub unsafe extern "C-unwind" fn kclvm_type_StrLit_value(
+    p: *const kclvm_type_t,
+) -> *const kclvm_char_t {
     let p = ptr_as_ref(p);
     match p {
         Type::str_lit_type(ref v) => v.as_ptr() as *const kclvm_char_t,
@@ -113,7 +115,7 @@ pub unsafe extern "C" fn kclvm_type_StrLit_value(p: *const kclvm_type_t) -> *con
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_key_type(p: *const kclvm_type_t) -> *const kclvm_type_t {
+pub unsafe extern "C-unwind" fn kclvm_type_key_type(p: *const kclvm_type_t) -> *const kclvm_type_t {
     let p = ptr_as_ref(p);
     match p {
         Type::dict_type(ref v) => {
@@ -125,7 +127,9 @@ pub unsafe extern "C" fn kclvm_type_key_type(p: *const kclvm_type_t) -> *const k
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_elem_type(p: *const kclvm_type_t) -> *const kclvm_type_t {
+pub unsafe extern "C-unwind" fn kclvm_type_elem_type(
+    p: *const kclvm_type_t,
+) -> *const kclvm_type_t {
     let p = ptr_as_ref(p);
     match p {
         Type::list_type(ref v) => {
@@ -144,7 +148,9 @@ pub unsafe extern "C" fn kclvm_type_elem_type(p: *const kclvm_type_t) -> *const
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_schema_name(p: *const kclvm_type_t) -> *const kclvm_char_t {
+pub unsafe extern "C-unwind" fn kclvm_type_schema_name(
+    p: *const kclvm_type_t,
+) -> *const kclvm_char_t {
     let p = ptr_as_ref(p);
     match p {
         Type::schema_type(ref v) => v.name.as_ptr() as *const kclvm_char_t,
@@ -154,7 +160,7 @@ pub unsafe extern "C" fn kclvm_type_schema_name(p: *const kclvm_type_t) -> *cons
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_schema_parent_name(
+pub unsafe extern "C-unwind" fn kclvm_type_schema_parent_name(
     p: *const kclvm_type_t,
 ) -> *const kclvm_char_t {
     let p = ptr_as_ref(p);
@@ -166,7 +172,7 @@ pub unsafe extern "C" fn kclvm_type_schema_parent_name(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_schema_relaxed(p: *const kclvm_type_t) -> kclvm_bool_t {
+pub unsafe extern "C-unwind" fn kclvm_type_schema_relaxed(p: *const kclvm_type_t) -> kclvm_bool_t {
     let p = ptr_as_ref(p);
     match p {
         Type::schema_type(..) => false as kclvm_bool_t,
@@ -176,7 +182,9 @@ pub unsafe extern "C" fn kclvm_type_schema_relaxed(p: *const kclvm_type_t) -> kc
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_schema_field_num(p: *const kclvm_type_t) -> kclvm_size_t {
+pub unsafe extern "C-unwind" fn kclvm_type_schema_field_num(
+    p: *const kclvm_type_t,
+) -> kclvm_size_t {
     let p = ptr_as_ref(p);
     match p {
         Type::schema_type(ref v) => v.field_names.len() as kclvm_size_t,
@@ -186,7 +194,7 @@ pub unsafe extern "C" fn kclvm_type_schema_field_num(p: *const kclvm_type_t) ->
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_schema_field_name(
+pub unsafe extern "C-unwind" fn kclvm_type_schema_field_name(
     p: *const kclvm_type_t,
     i: kclvm_size_t,
 ) -> *const kclvm_char_t {
@@ -199,7 +207,7 @@ pub unsafe extern "C" fn kclvm_type_schema_field_name(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_schema_field_type(
+pub unsafe extern "C-unwind" fn kclvm_type_schema_field_type(
     p: *const kclvm_type_t,
     i: kclvm_size_t,
 ) -> *const kclvm_type_t {
@@ -216,7 +224,7 @@ pub unsafe extern "C" fn kclvm_type_schema_field_type(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_arg_num(p: *const kclvm_type_t) -> kclvm_size_t {
+pub unsafe extern "C-unwind" fn kclvm_type_arg_num(p: *const kclvm_type_t) -> kclvm_size_t {
     let p = ptr_as_ref(p);
     match p {
         Type::func_type(ref v) => v.args_types.len() as kclvm_size_t,
@@ -226,7 +234,7 @@ pub unsafe extern "C" fn kclvm_type_arg_num(p: *const kclvm_type_t) -> kclvm_siz
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_arg_type(
+pub unsafe extern "C-unwind" fn kclvm_type_arg_type(
     p: *const kclvm_type_t,
     i: kclvm_size_t,
 ) -> *const kclvm_type_t {
@@ -239,7 +247,9 @@ pub unsafe extern "C" fn kclvm_type_arg_type(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_type_return_type(p: *const kclvm_type_t) -> *const kclvm_type_t {
+pub unsafe extern "C-unwind" fn kclvm_type_return_type(
+    p: *const kclvm_type_t,
+) -> *const kclvm_type_t {
     let p = ptr_as_ref(p);
     match p {
         Type::func_type(ref v) => v.return_type.as_ref() as *const kclvm_type_t,
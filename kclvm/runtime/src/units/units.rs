@@ -47,7 +47,7 @@ pub const INVALID_UNITS: [&str; 4] = ["ni", "ui", "mi", "ki"];
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_n(
+pub unsafe extern "C-unwind" fn kclvm_units_to_n(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -65,7 +65,7 @@ pub unsafe extern "C" fn kclvm_units_to_n(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_u(
+pub unsafe extern "C-unwind" fn kclvm_units_to_u(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -83,7 +83,7 @@ pub unsafe extern "C" fn kclvm_units_to_u(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_m(
+pub unsafe extern "C-unwind" fn kclvm_units_to_m(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -101,7 +101,7 @@ pub unsafe extern "C" fn kclvm_units_to_m(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_K(
+pub unsafe extern "C-unwind" fn kclvm_units_to_K(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -121,7 +121,7 @@ pub unsafe extern "C" fn kclvm_units_to_K(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_M(
+pub unsafe extern "C-unwind" fn kclvm_units_to_M(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -141,7 +141,7 @@ pub unsafe extern "C" fn kclvm_units_to_M(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_G(
+pub unsafe extern "C-unwind" fn kclvm_units_to_G(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -161,7 +161,7 @@ pub unsafe extern "C" fn kclvm_units_to_G(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_T(
+pub unsafe extern "C-unwind" fn kclvm_units_to_T(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -181,7 +181,7 @@ pub unsafe extern "C" fn kclvm_units_to_T(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_P(
+pub unsafe extern "C-unwind" fn kclvm_units_to_P(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -201,7 +201,7 @@ pub unsafe extern "C" fn kclvm_units_to_P(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_Ki(
+pub unsafe extern "C-unwind" fn kclvm_units_to_Ki(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -221,7 +221,7 @@ pub unsafe extern "C" fn kclvm_units_to_Ki(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_Mi(
+pub unsafe extern "C-unwind" fn kclvm_units_to_Mi(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -241,7 +241,7 @@ pub unsafe extern "C" fn kclvm_units_to_Mi(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_Gi(
+pub unsafe extern "C-unwind" fn kclvm_units_to_Gi(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -261,7 +261,7 @@ pub unsafe extern "C" fn kclvm_units_to_Gi(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_Ti(
+pub unsafe extern "C-unwind" fn kclvm_units_to_Ti(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -281,7 +281,7 @@ pub unsafe extern "C" fn kclvm_units_to_Ti(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_units_to_Pi(
+pub unsafe extern "C-unwind" fn kclvm_units_to_Pi(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
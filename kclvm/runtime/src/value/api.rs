@@ -1,8 +1,6 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
 #![allow(clippy::missing_safety_doc)]
 
-use std::mem::transmute_copy;
-
 use crate::*;
 
 #[allow(non_camel_case_types)]
@@ -43,7 +41,7 @@ pub type kclvm_float_t = f64;
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_context_set_import_names(
+pub unsafe extern "C-unwind" fn kclvm_context_set_import_names(
     p: *mut kclvm_context_t,
     import_names: *const kclvm_value_ref_t,
 ) {
@@ -89,13 +87,13 @@ static mut kclvm_value_Float_0_obj: usize = 0;
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_Undefined() -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_Undefined() -> *mut kclvm_value_ref_t {
     new_mut_ptr(ValueRef::undefined())
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_None() -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_None() -> *mut kclvm_value_ref_t {
     new_mut_ptr(ValueRef::none())
 }
 
@@ -103,19 +101,19 @@ pub extern "C" fn kclvm_value_None() -> *mut kclvm_value_ref_t {
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_True() -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_True() -> *mut kclvm_value_ref_t {
     kclvm_value_Bool(1)
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_False() -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_False() -> *mut kclvm_value_ref_t {
     kclvm_value_Bool(0)
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_Bool(v: kclvm_bool_t) -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_Bool(v: kclvm_bool_t) -> *mut kclvm_value_ref_t {
     unsafe {
         if v != 0 {
             if kclvm_value_Bool_true_obj == 0 {
@@ -133,7 +131,7 @@ pub extern "C" fn kclvm_value_Bool(v: kclvm_bool_t) -> *mut kclvm_value_ref_t {
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_Int(v: kclvm_int_t) -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_Int(v: kclvm_int_t) -> *mut kclvm_value_ref_t {
     if v == 0 {
         unsafe {
             if kclvm_value_Int_0_obj == 0 {
@@ -147,7 +145,7 @@ pub extern "C" fn kclvm_value_Int(v: kclvm_int_t) -> *mut kclvm_value_ref_t {
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_Float(v: kclvm_float_t) -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_Float(v: kclvm_float_t) -> *mut kclvm_value_ref_t {
     if v == 0.0 {
         unsafe {
             if kclvm_value_Float_0_obj == 0 {
@@ -161,7 +159,7 @@ pub extern "C" fn kclvm_value_Float(v: kclvm_float_t) -> *mut kclvm_value_ref_t
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_Unit(
+pub extern "C-unwind" fn kclvm_value_Unit(
     v: kclvm_float_t,
     raw: kclvm_int_t,
     unit: *const kclvm_char_t,
@@ -172,7 +170,7 @@ pub extern "C" fn kclvm_value_Unit(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Str(v: *const kclvm_char_t) -> *mut kclvm_value_ref_t {
+pub unsafe extern "C-unwind" fn kclvm_value_Str(v: *const kclvm_char_t) -> *mut kclvm_value_ref_t {
     unsafe {
         if v.is_null() || *v == '\0' as i8 {
             return new_mut_ptr(ValueRef::str(""));
@@ -185,13 +183,13 @@ pub unsafe extern "C" fn kclvm_value_Str(v: *const kclvm_char_t) -> *mut kclvm_v
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_List() -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_List() -> *mut kclvm_value_ref_t {
     new_mut_ptr(ValueRef::list(None))
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_List6(
+pub extern "C-unwind" fn kclvm_value_List6(
     v1: *const kclvm_value_ref_t,
     v2: *const kclvm_value_ref_t,
     v3: *const kclvm_value_ref_t,
@@ -208,7 +206,7 @@ pub extern "C" fn kclvm_value_List6(
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_List10(
+pub extern "C-unwind" fn kclvm_value_List10(
     v1: *const kclvm_value_ref_t,
     v2: *const kclvm_value_ref_t,
     v3: *const kclvm_value_ref_t,
@@ -229,7 +227,7 @@ pub extern "C" fn kclvm_value_List10(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_ListN(
+pub unsafe extern "C-unwind" fn kclvm_value_ListN(
     n: kclvm_int_t,
     elem_values: *const *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -247,19 +245,19 @@ pub unsafe extern "C" fn kclvm_value_ListN(
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_Dict() -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_Dict() -> *mut kclvm_value_ref_t {
     new_mut_ptr(ValueRef::dict(None))
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_Schema() -> *mut kclvm_value_ref_t {
+pub extern "C-unwind" fn kclvm_value_Schema() -> *mut kclvm_value_ref_t {
     new_mut_ptr(ValueRef::schema())
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_schema_with_config(
+pub unsafe extern "C-unwind" fn kclvm_value_schema_with_config(
     schema_dict: *const kclvm_value_ref_t,
     config: *const kclvm_value_ref_t,
     config_meta: *const kclvm_value_ref_t,
@@ -317,7 +315,7 @@ pub unsafe extern "C" fn kclvm_value_schema_with_config(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Function(
+pub unsafe extern "C-unwind" fn kclvm_value_Function(
     fn_ptr: *const u64,
     closure: *const kclvm_value_ref_t,
     name: *const kclvm_char_t,
@@ -337,7 +335,7 @@ pub unsafe extern "C" fn kclvm_value_Function(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Function_using_ptr(
+pub unsafe extern "C-unwind" fn kclvm_value_Function_using_ptr(
     fn_ptr: *const u64,
     name: *const kclvm_char_t,
 ) -> *mut kclvm_value_ref_t {
@@ -354,7 +352,7 @@ pub unsafe extern "C" fn kclvm_value_Function_using_ptr(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_schema_function(
+pub unsafe extern "C-unwind" fn kclvm_value_schema_function(
     fn_ptr: *const u64,
     check_fn_ptr: *const u64,
     tpe: *const kclvm_char_t,
@@ -406,7 +404,9 @@ pub unsafe extern "C" fn kclvm_value_schema_function(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_from_json(s: *const kclvm_char_t) -> *mut kclvm_value_ref_t {
+pub unsafe extern "C-unwind" fn kclvm_value_from_json(
+    s: *const kclvm_char_t,
+) -> *mut kclvm_value_ref_t {
     if s.is_null() {
         return kclvm_value_Undefined();
     }
@@ -418,7 +418,9 @@ pub unsafe extern "C" fn kclvm_value_from_json(s: *const kclvm_char_t) -> *mut k
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_to_json(p: *const kclvm_value_ref_t) -> *mut kclvm_buffer_t {
+pub unsafe extern "C-unwind" fn kclvm_value_to_json(
+    p: *const kclvm_value_ref_t,
+) -> *mut kclvm_buffer_t {
     let p = ptr_as_ref(p);
     let x = p.to_json();
     let buf = Buffer::new_with_buf(&x);
@@ -427,7 +429,7 @@ pub unsafe extern "C" fn kclvm_value_to_json(p: *const kclvm_value_ref_t) -> *mu
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_to_json_value(
+pub unsafe extern "C-unwind" fn kclvm_value_to_json_value(
     p: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     if p.is_null() {
@@ -442,7 +444,7 @@ pub unsafe extern "C" fn kclvm_value_to_json_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_to_json_value_with_null(
+pub unsafe extern "C-unwind" fn kclvm_value_to_json_value_with_null(
     p: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     if p.is_null() {
@@ -457,7 +459,7 @@ pub unsafe extern "C" fn kclvm_value_to_json_value_with_null(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_plan_to_json(
+pub unsafe extern "C-unwind" fn kclvm_value_plan_to_json(
     p: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let p = ptr_as_ref(p);
@@ -468,7 +470,7 @@ pub unsafe extern "C" fn kclvm_value_plan_to_json(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_plan_to_yaml(
+pub unsafe extern "C-unwind" fn kclvm_value_plan_to_yaml(
     p: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let p = ptr_as_ref(p);
@@ -479,7 +481,7 @@ pub unsafe extern "C" fn kclvm_value_plan_to_yaml(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_to_yaml_value(
+pub unsafe extern "C-unwind" fn kclvm_value_to_yaml_value(
     p: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     if p.is_null() {
@@ -494,7 +496,7 @@ pub unsafe extern "C" fn kclvm_value_to_yaml_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_to_str_value(
+pub unsafe extern "C-unwind" fn kclvm_value_to_str_value(
     p: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     if p.is_null() {
@@ -513,7 +515,9 @@ pub unsafe extern "C" fn kclvm_value_to_str_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Bool_ptr(p: *const kclvm_value_ref_t) -> *const kclvm_bool_t {
+pub unsafe extern "C-unwind" fn kclvm_value_Bool_ptr(
+    p: *const kclvm_value_ref_t,
+) -> *const kclvm_bool_t {
     let p = ptr_as_ref(p);
     match &*p.rc.borrow() {
         Value::bool_value(ref v) => v as *const bool as *const kclvm_bool_t, // sizeof(bool) == sizeof(i8)
@@ -523,7 +527,9 @@ pub unsafe extern "C" fn kclvm_value_Bool_ptr(p: *const kclvm_value_ref_t) -> *c
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Int_ptr(p: *const kclvm_value_ref_t) -> *const kclvm_int_t {
+pub unsafe extern "C-unwind" fn kclvm_value_Int_ptr(
+    p: *const kclvm_value_ref_t,
+) -> *const kclvm_int_t {
     let p = ptr_as_ref(p);
     match &*p.rc.borrow() {
         Value::int_value(ref v) => v as *const kclvm_int_t,
@@ -533,7 +539,7 @@ pub unsafe extern "C" fn kclvm_value_Int_ptr(p: *const kclvm_value_ref_t) -> *co
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Float_ptr(
+pub unsafe extern "C-unwind" fn kclvm_value_Float_ptr(
     p: *const kclvm_value_ref_t,
 ) -> *const kclvm_float_t {
     let p = ptr_as_ref(p);
@@ -545,7 +551,9 @@ pub unsafe extern "C" fn kclvm_value_Float_ptr(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Str_ptr(p: *const kclvm_value_ref_t) -> *const kclvm_char_t {
+pub unsafe extern "C-unwind" fn kclvm_value_Str_ptr(
+    p: *const kclvm_value_ref_t,
+) -> *const kclvm_char_t {
     let p = ptr_as_ref(p);
     match &*p.rc.borrow() {
         Value::str_value(ref v) => v.as_ptr() as *const i8,
@@ -555,21 +563,23 @@ pub unsafe extern "C" fn kclvm_value_Str_ptr(p: *const kclvm_value_ref_t) -> *co
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Str_len(p: *const kclvm_value_ref_t) -> kclvm_size_t {
+pub unsafe extern "C-unwind" fn kclvm_value_Str_len(p: *const kclvm_value_ref_t) -> kclvm_size_t {
     let p = ptr_as_ref(p);
     p.str_len() as kclvm_size_t
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Str_resize(p: *mut kclvm_value_ref_t, n: kclvm_size_t) {
+pub unsafe extern "C-unwind" fn kclvm_value_Str_resize(p: *mut kclvm_value_ref_t, n: kclvm_size_t) {
     let p = mut_ptr_as_ref(p);
     p.str_resize(n as usize)
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_function_ptr(p: *const kclvm_value_ref_t) -> *const u64 {
+pub unsafe extern "C-unwind" fn kclvm_value_function_ptr(
+    p: *const kclvm_value_ref_t,
+) -> *const u64 {
     let p = ptr_as_ref(p);
     match &*p.rc.borrow() {
         Value::func_value(ref v) => v.fn_ptr as *const u64,
@@ -579,7 +589,9 @@ pub unsafe extern "C" fn kclvm_value_function_ptr(p: *const kclvm_value_ref_t) -
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_check_function_ptr(p: *const kclvm_value_ref_t) -> *const u64 {
+pub unsafe extern "C-unwind" fn kclvm_value_check_function_ptr(
+    p: *const kclvm_value_ref_t,
+) -> *const u64 {
     let p = ptr_as_ref(p);
     match &*p.rc.borrow() {
         Value::func_value(ref v) => v.check_fn_ptr as *const u64,
@@ -589,7 +601,7 @@ pub unsafe extern "C" fn kclvm_value_check_function_ptr(p: *const kclvm_value_re
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_function_is_external(
+pub unsafe extern "C-unwind" fn kclvm_value_function_is_external(
     p: *const kclvm_value_ref_t,
 ) -> kclvm_bool_t {
     let p = ptr_as_ref(p);
@@ -601,7 +613,7 @@ pub unsafe extern "C" fn kclvm_value_function_is_external(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_function_external_invoke(
+pub unsafe extern "C-unwind" fn kclvm_value_function_external_invoke(
     p: *const kclvm_value_ref_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -618,7 +630,7 @@ pub unsafe extern "C" fn kclvm_value_function_external_invoke(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_function_invoke(
+pub unsafe extern "C-unwind" fn kclvm_value_function_invoke(
     p: *const kclvm_value_ref_t,
     ctx: *mut kclvm_context_t,
     args: *mut kclvm_value_ref_t,
@@ -642,7 +654,6 @@ pub unsafe extern "C" fn kclvm_value_function_invoke(
         }
         let now_meta_info = ctx_ref.panic_info.clone();
         unsafe {
-            let call_fn: SchemaTypeFunc = transmute_copy(&fn_ptr);
             // Call schema constructor twice
             let value = if is_schema {
                 let pkgpath = c2str(pkgpath);
@@ -655,7 +666,7 @@ pub unsafe extern "C" fn kclvm_value_function_invoke(
                 let instance_pkgpath_index = closure.len() - 1;
                 args_ref.list_append_unpack(closure);
                 let args = args_ref.clone().into_raw();
-                call_fn(ctx, args, kwargs);
+                call_schema_type_func(fn_ptr, ctx, args, kwargs);
                 let cal_map = closure.list_get(cal_map_index).unwrap();
                 // is sub schema
                 closure_new.list_set(0, &ValueRef::bool(true));
@@ -673,7 +684,7 @@ pub unsafe extern "C" fn kclvm_value_function_invoke(
                 );
                 closure_new.list_set(config_meta_index as usize, &config_meta);
                 args_new.list_append_unpack(&closure_new);
-                call_fn(ctx, args_new.into_raw(), kwargs)
+                call_schema_type_func(fn_ptr, ctx, args_new.into_raw(), kwargs)
             // Normal kcl function, call directly
             } else if func.is_external {
                 let name = format!("{}\0", func.name);
@@ -681,7 +692,7 @@ pub unsafe extern "C" fn kclvm_value_function_invoke(
             } else {
                 args_ref.list_append_unpack_first(closure);
                 let args = args_ref.clone().into_raw();
-                call_fn(ctx, args, kwargs)
+                call_schema_type_func(fn_ptr, ctx, args, kwargs)
             };
             let is_in_schema = ptr_as_ref(is_in_schema);
             if is_schema && !is_in_schema.is_truthy() {
@@ -700,7 +711,7 @@ pub unsafe extern "C" fn kclvm_value_function_invoke(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_function_get_closure(
+pub unsafe extern "C-unwind" fn kclvm_value_function_get_closure(
     p: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let p = ptr_as_ref(p);
@@ -719,7 +730,7 @@ pub unsafe extern "C" fn kclvm_value_function_get_closure(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_kind(p: *const kclvm_value_ref_t) -> kclvm_kind_t {
+pub unsafe extern "C-unwind" fn kclvm_value_kind(p: *const kclvm_value_ref_t) -> kclvm_kind_t {
     let p = ptr_as_ref(p);
     p.kind()
 }
@@ -728,7 +739,7 @@ pub unsafe extern "C" fn kclvm_value_kind(p: *const kclvm_value_ref_t) -> kclvm_
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_deep_copy(
+pub unsafe extern "C-unwind" fn kclvm_value_deep_copy(
     p: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let p = ptr_as_ref(p);
@@ -739,7 +750,7 @@ pub unsafe extern "C" fn kclvm_value_deep_copy(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_delete(p: *mut kclvm_value_ref_t) {
+pub unsafe extern "C-unwind" fn kclvm_value_delete(p: *mut kclvm_value_ref_t) {
     if p.is_null() {
         return;
     }
@@ -774,7 +785,9 @@ pub unsafe extern "C" fn kclvm_value_delete(p: *mut kclvm_value_ref_t) {
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_iter(p: *const kclvm_value_ref_t) -> *mut kclvm_iterator_t {
+pub unsafe extern "C-unwind" fn kclvm_value_iter(
+    p: *const kclvm_value_ref_t,
+) -> *mut kclvm_iterator_t {
     let p = ptr_as_ref(p);
     let iter = ValueIterator::from_value(p);
     Box::into_raw(Box::new(iter))
@@ -782,20 +795,20 @@ pub unsafe extern "C" fn kclvm_value_iter(p: *const kclvm_value_ref_t) -> *mut k
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_iterator_delete(p: *mut kclvm_iterator_t) {
+pub unsafe extern "C-unwind" fn kclvm_iterator_delete(p: *mut kclvm_iterator_t) {
     free_mut_ptr(p);
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_iterator_is_end(p: *mut kclvm_iterator_t) -> kclvm_bool_t {
+pub unsafe extern "C-unwind" fn kclvm_iterator_is_end(p: *mut kclvm_iterator_t) -> kclvm_bool_t {
     let p = ptr_as_ref(p);
     p.is_end() as kclvm_bool_t
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_iterator_cur_key(
+pub unsafe extern "C-unwind" fn kclvm_iterator_cur_key(
     p: *mut kclvm_iterator_t,
 ) -> *const kclvm_value_ref_t {
     let p = ptr_as_ref(p);
@@ -807,7 +820,7 @@ pub unsafe extern "C" fn kclvm_iterator_cur_key(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_iterator_cur_value(
+pub unsafe extern "C-unwind" fn kclvm_iterator_cur_value(
     p: *mut kclvm_iterator_t,
 ) -> *const kclvm_value_ref_t {
     let p = mut_ptr_as_ref(p);
@@ -819,7 +832,7 @@ pub unsafe extern "C" fn kclvm_iterator_cur_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_iterator_next_value(
+pub unsafe extern "C-unwind" fn kclvm_iterator_next_value(
     p: *mut kclvm_iterator_t,
     host: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -838,21 +851,24 @@ pub unsafe extern "C" fn kclvm_iterator_next_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_len(p: *const kclvm_value_ref_t) -> kclvm_size_t {
+pub unsafe extern "C-unwind" fn kclvm_list_len(p: *const kclvm_value_ref_t) -> kclvm_size_t {
     let p = ptr_as_ref(p);
     p.len() as kclvm_size_t
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_resize(p: *mut kclvm_value_ref_t, newsize: kclvm_size_t) {
+pub unsafe extern "C-unwind" fn kclvm_list_resize(
+    p: *mut kclvm_value_ref_t,
+    newsize: kclvm_size_t,
+) {
     let p = mut_ptr_as_ref(p);
     p.list_resize(newsize as usize);
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_clear(p: *mut kclvm_value_ref_t) {
+pub unsafe extern "C-unwind" fn kclvm_list_clear(p: *mut kclvm_value_ref_t) {
     let p = mut_ptr_as_ref(p);
     p.list_clear();
 }
@@ -860,7 +876,7 @@ pub unsafe extern "C" fn kclvm_list_clear(p: *mut kclvm_value_ref_t) {
 /// Return number of occurrences of the list value.
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_count(
+pub unsafe extern "C-unwind" fn kclvm_list_count(
     p: *const kclvm_value_ref_t,
     item: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -874,7 +890,7 @@ pub unsafe extern "C" fn kclvm_list_count(
 /// Return first index of the list value. Panic if the value is not present.
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_find(
+pub unsafe extern "C-unwind" fn kclvm_list_find(
     p: *const kclvm_value_ref_t,
     item: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -888,7 +904,7 @@ pub unsafe extern "C" fn kclvm_list_find(
 /// Insert object before index of the list value.
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_insert(
+pub unsafe extern "C-unwind" fn kclvm_list_insert(
     p: *mut kclvm_value_ref_t,
     index: *const kclvm_value_ref_t,
     value: *const kclvm_value_ref_t,
@@ -901,7 +917,7 @@ pub unsafe extern "C" fn kclvm_list_insert(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_get(
+pub unsafe extern "C-unwind" fn kclvm_list_get(
     p: *const kclvm_value_ref_t,
     i: kclvm_size_t,
 ) -> *const kclvm_value_ref_t {
@@ -914,7 +930,7 @@ pub unsafe extern "C" fn kclvm_list_get(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_get_option(
+pub unsafe extern "C-unwind" fn kclvm_list_get_option(
     p: *const kclvm_value_ref_t,
     i: kclvm_size_t,
 ) -> *const kclvm_value_ref_t {
@@ -927,7 +943,7 @@ pub unsafe extern "C" fn kclvm_list_get_option(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_set(
+pub unsafe extern "C-unwind" fn kclvm_list_set(
     p: *mut kclvm_value_ref_t,
     i: kclvm_size_t,
     v: *const kclvm_value_ref_t,
@@ -939,7 +955,9 @@ pub unsafe extern "C" fn kclvm_list_set(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_pop(p: *mut kclvm_value_ref_t) -> *const kclvm_value_ref_t {
+pub unsafe extern "C-unwind" fn kclvm_list_pop(
+    p: *mut kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
     let p = mut_ptr_as_ref(p);
     match p.list_pop() {
         Some(x) => x.into_raw(),
@@ -949,7 +967,7 @@ pub unsafe extern "C" fn kclvm_list_pop(p: *mut kclvm_value_ref_t) -> *const kcl
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_pop_first(
+pub unsafe extern "C-unwind" fn kclvm_list_pop_first(
     p: *mut kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let p = mut_ptr_as_ref(p);
@@ -961,7 +979,10 @@ pub unsafe extern "C" fn kclvm_list_pop_first(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_append(p: *mut kclvm_value_ref_t, v: *const kclvm_value_ref_t) {
+pub unsafe extern "C-unwind" fn kclvm_list_append(
+    p: *mut kclvm_value_ref_t,
+    v: *const kclvm_value_ref_t,
+) {
     let p = mut_ptr_as_ref(p);
     let v = ptr_as_ref(v);
     p.list_append(v);
@@ -969,35 +990,41 @@ pub unsafe extern "C" fn kclvm_list_append(p: *mut kclvm_value_ref_t, v: *const
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_append_bool(p: *mut kclvm_value_ref_t, v: kclvm_bool_t) {
+pub unsafe extern "C-unwind" fn kclvm_list_append_bool(p: *mut kclvm_value_ref_t, v: kclvm_bool_t) {
     let p = mut_ptr_as_ref(p);
     p.list_append(&ValueRef::bool(v != 0));
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_append_int(p: *mut kclvm_value_ref_t, v: kclvm_int_t) {
+pub unsafe extern "C-unwind" fn kclvm_list_append_int(p: *mut kclvm_value_ref_t, v: kclvm_int_t) {
     let p = mut_ptr_as_ref(p);
     p.list_append(&ValueRef::int(v));
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_append_float(p: *mut kclvm_value_ref_t, v: kclvm_float_t) {
+pub unsafe extern "C-unwind" fn kclvm_list_append_float(
+    p: *mut kclvm_value_ref_t,
+    v: kclvm_float_t,
+) {
     let p = mut_ptr_as_ref(p);
     p.list_append(&ValueRef::float(v));
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_append_str(p: *mut kclvm_value_ref_t, v: *const kclvm_char_t) {
+pub unsafe extern "C-unwind" fn kclvm_list_append_str(
+    p: *mut kclvm_value_ref_t,
+    v: *const kclvm_char_t,
+) {
     let p = mut_ptr_as_ref(p);
     p.list_append(&ValueRef::str(c2str(v)));
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_append_unpack(
+pub unsafe extern "C-unwind" fn kclvm_list_append_unpack(
     p: *mut kclvm_value_ref_t,
     v: *const kclvm_value_ref_t,
 ) {
@@ -1011,7 +1038,7 @@ pub unsafe extern "C" fn kclvm_list_append_unpack(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_list_remove_at(p: *mut kclvm_value_ref_t, i: kclvm_size_t) {
+pub unsafe extern "C-unwind" fn kclvm_list_remove_at(p: *mut kclvm_value_ref_t, i: kclvm_size_t) {
     let p = mut_ptr_as_ref(p);
     p.list_remove_at(i as usize);
 }
@@ -1022,7 +1049,7 @@ pub unsafe extern "C" fn kclvm_list_remove_at(p: *mut kclvm_value_ref_t, i: kclv
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_len(p: *const kclvm_value_ref_t) -> kclvm_size_t {
+pub unsafe extern "C-unwind" fn kclvm_dict_len(p: *const kclvm_value_ref_t) -> kclvm_size_t {
     let p = ptr_as_ref(p);
     match &*p.rc.borrow() {
         Value::dict_value(ref dict) => dict.values.len() as kclvm_size_t,
@@ -1032,14 +1059,14 @@ pub unsafe extern "C" fn kclvm_dict_len(p: *const kclvm_value_ref_t) -> kclvm_si
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_clear(p: *mut kclvm_value_ref_t) {
+pub unsafe extern "C-unwind" fn kclvm_dict_clear(p: *mut kclvm_value_ref_t) {
     let p = mut_ptr_as_ref(p);
     p.dict_clear();
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_get(
+pub unsafe extern "C-unwind" fn kclvm_dict_get(
     p: *const kclvm_value_ref_t,
     key: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1054,7 +1081,7 @@ pub unsafe extern "C" fn kclvm_dict_get(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_has_value(
+pub unsafe extern "C-unwind" fn kclvm_dict_has_value(
     p: *const kclvm_value_ref_t,
     key: *const kclvm_char_t,
 ) -> kclvm_bool_t {
@@ -1068,7 +1095,7 @@ pub unsafe extern "C" fn kclvm_dict_has_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_get_value(
+pub unsafe extern "C-unwind" fn kclvm_dict_get_value(
     p: *const kclvm_value_ref_t,
     key: *const kclvm_char_t,
 ) -> *const kclvm_value_ref_t {
@@ -1082,7 +1109,7 @@ pub unsafe extern "C" fn kclvm_dict_get_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_get_entry(
+pub unsafe extern "C-unwind" fn kclvm_dict_get_entry(
     p: *const kclvm_value_ref_t,
     key: *const kclvm_char_t,
 ) -> *const kclvm_value_ref_t {
@@ -1096,7 +1123,7 @@ pub unsafe extern "C" fn kclvm_dict_get_entry(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_get_value_by_path(
+pub unsafe extern "C-unwind" fn kclvm_dict_get_value_by_path(
     p: *const kclvm_value_ref_t,
     path: *const kclvm_char_t,
 ) -> *const kclvm_value_ref_t {
@@ -1110,7 +1137,7 @@ pub unsafe extern "C" fn kclvm_dict_get_value_by_path(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_set_value(
+pub unsafe extern "C-unwind" fn kclvm_dict_set_value(
     p: *mut kclvm_value_ref_t,
     key: *const kclvm_char_t,
     val: *const kclvm_value_ref_t,
@@ -1137,7 +1164,9 @@ pub unsafe extern "C" fn kclvm_dict_set_value(
 #[no_mangle]
 #[runtime_fn]
 /// Return all dict keys.
-pub unsafe extern "C" fn kclvm_dict_keys(p: *const kclvm_value_ref_t) -> *const kclvm_value_ref_t {
+pub unsafe extern "C-unwind" fn kclvm_dict_keys(
+    p: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
     let p = ptr_as_ref(p);
     let r = p.dict_keys();
     r.into_raw()
@@ -1146,7 +1175,7 @@ pub unsafe extern "C" fn kclvm_dict_keys(p: *const kclvm_value_ref_t) -> *const
 #[no_mangle]
 #[runtime_fn]
 /// Return all dict values.
-pub unsafe extern "C" fn kclvm_dict_values(
+pub unsafe extern "C-unwind" fn kclvm_dict_values(
     p: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let p = ptr_as_ref(p);
@@ -1156,7 +1185,7 @@ pub unsafe extern "C" fn kclvm_dict_values(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_insert(
+pub unsafe extern "C-unwind" fn kclvm_dict_insert(
     p: *mut kclvm_value_ref_t,
     key: *const kclvm_char_t,
     v: *const kclvm_value_ref_t,
@@ -1175,7 +1204,7 @@ pub unsafe extern "C" fn kclvm_dict_insert(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_merge(
+pub unsafe extern "C-unwind" fn kclvm_dict_merge(
     p: *mut kclvm_value_ref_t,
     key: *const kclvm_char_t,
     v: *const kclvm_value_ref_t,
@@ -1207,7 +1236,7 @@ pub unsafe extern "C" fn kclvm_dict_merge(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_insert_value(
+pub unsafe extern "C-unwind" fn kclvm_dict_insert_value(
     p: *mut kclvm_value_ref_t,
     key: *const kclvm_value_ref_t,
     v: *const kclvm_value_ref_t,
@@ -1228,7 +1257,7 @@ pub unsafe extern "C" fn kclvm_dict_insert_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_update_key_value(
+pub unsafe extern "C-unwind" fn kclvm_dict_update_key_value(
     p: *mut kclvm_value_ref_t,
     key: *const kclvm_value_ref_t,
     v: *const kclvm_value_ref_t,
@@ -1242,7 +1271,7 @@ pub unsafe extern "C" fn kclvm_dict_update_key_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_safe_insert(
+pub unsafe extern "C-unwind" fn kclvm_dict_safe_insert(
     p: *mut kclvm_value_ref_t,
     key: *const kclvm_char_t,
     v: *const kclvm_value_ref_t,
@@ -1257,7 +1286,7 @@ pub unsafe extern "C" fn kclvm_dict_safe_insert(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_insert_unpack(
+pub unsafe extern "C-unwind" fn kclvm_dict_insert_unpack(
     p: *mut kclvm_value_ref_t,
     v: *const kclvm_value_ref_t,
 ) {
@@ -1268,7 +1297,7 @@ pub unsafe extern "C" fn kclvm_dict_insert_unpack(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_default_collection_insert_int_pointer(
+pub unsafe extern "C-unwind" fn kclvm_default_collection_insert_int_pointer(
     p: *mut kclvm_value_ref_t,
     key: *const kclvm_char_t,
     ptr: *const u64,
@@ -1292,7 +1321,7 @@ pub unsafe extern "C" fn kclvm_default_collection_insert_int_pointer(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_default_collection_insert_value(
+pub unsafe extern "C-unwind" fn kclvm_default_collection_insert_value(
     p: *mut kclvm_value_ref_t,
     key: *const kclvm_char_t,
     value: *const kclvm_value_ref_t,
@@ -1315,14 +1344,20 @@ pub unsafe extern "C" fn kclvm_default_collection_insert_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_remove(p: *mut kclvm_value_ref_t, key: *const kclvm_char_t) {
+pub unsafe extern "C-unwind" fn kclvm_dict_remove(
+    p: *mut kclvm_value_ref_t,
+    key: *const kclvm_char_t,
+) {
     let p = mut_ptr_as_ref(p);
     p.dict_remove(c2str(key));
 }
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_dict_update(p: *mut kclvm_value_ref_t, v: *const kclvm_value_ref_t) {
+pub unsafe extern "C-unwind" fn kclvm_dict_update(
+    p: *mut kclvm_value_ref_t,
+    v: *const kclvm_value_ref_t,
+) {
     let p = mut_ptr_as_ref(p);
     let v = ptr_as_ref(v);
     p.dict_update(v);
@@ -1336,7 +1371,7 @@ pub unsafe extern "C" fn kclvm_dict_update(p: *mut kclvm_value_ref_t, v: *const
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_is_truthy(p: *const kclvm_value_ref_t) -> kclvm_bool_t {
+pub unsafe extern "C-unwind" fn kclvm_value_is_truthy(p: *const kclvm_value_ref_t) -> kclvm_bool_t {
     let p = ptr_as_ref(p);
     p.is_truthy() as kclvm_bool_t
 }
@@ -1345,7 +1380,7 @@ pub unsafe extern "C" fn kclvm_value_is_truthy(p: *const kclvm_value_ref_t) -> k
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_len(p: *const kclvm_value_ref_t) -> kclvm_size_t {
+pub unsafe extern "C-unwind" fn kclvm_value_len(p: *const kclvm_value_ref_t) -> kclvm_size_t {
     let p = ptr_as_ref(p);
     p.len() as kclvm_size_t
 }
@@ -1354,7 +1389,7 @@ pub unsafe extern "C" fn kclvm_value_len(p: *const kclvm_value_ref_t) -> kclvm_s
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_cmp_equal_to(
+pub unsafe extern "C-unwind" fn kclvm_value_cmp_equal_to(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1368,7 +1403,7 @@ pub unsafe extern "C" fn kclvm_value_cmp_equal_to(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_cmp_not_equal_to(
+pub unsafe extern "C-unwind" fn kclvm_value_cmp_not_equal_to(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1382,7 +1417,7 @@ pub unsafe extern "C" fn kclvm_value_cmp_not_equal_to(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_cmp_less_than(
+pub unsafe extern "C-unwind" fn kclvm_value_cmp_less_than(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1396,7 +1431,7 @@ pub unsafe extern "C" fn kclvm_value_cmp_less_than(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_cmp_less_than_or_equal(
+pub unsafe extern "C-unwind" fn kclvm_value_cmp_less_than_or_equal(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1407,7 +1442,7 @@ pub unsafe extern "C" fn kclvm_value_cmp_less_than_or_equal(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_cmp_greater_than(
+pub unsafe extern "C-unwind" fn kclvm_value_cmp_greater_than(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1421,7 +1456,7 @@ pub unsafe extern "C" fn kclvm_value_cmp_greater_than(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_cmp_greater_than_or_equal(
+pub unsafe extern "C-unwind" fn kclvm_value_cmp_greater_than_or_equal(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1434,7 +1469,7 @@ pub unsafe extern "C" fn kclvm_value_cmp_greater_than_or_equal(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_is(
+pub unsafe extern "C-unwind" fn kclvm_value_is(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1448,7 +1483,7 @@ pub unsafe extern "C" fn kclvm_value_is(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_is_not(
+pub unsafe extern "C-unwind" fn kclvm_value_is_not(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1462,7 +1497,7 @@ pub unsafe extern "C" fn kclvm_value_is_not(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_in(
+pub unsafe extern "C-unwind" fn kclvm_value_in(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1473,7 +1508,7 @@ pub unsafe extern "C" fn kclvm_value_in(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_not_in(
+pub unsafe extern "C-unwind" fn kclvm_value_not_in(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1484,7 +1519,7 @@ pub unsafe extern "C" fn kclvm_value_not_in(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_as(
+pub unsafe extern "C-unwind" fn kclvm_value_as(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1499,7 +1534,7 @@ pub unsafe extern "C" fn kclvm_value_as(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_unary_plus(
+pub unsafe extern "C-unwind" fn kclvm_value_unary_plus(
     a: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let a = ptr_as_ref(a);
@@ -1508,7 +1543,7 @@ pub unsafe extern "C" fn kclvm_value_unary_plus(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_unary_minus(
+pub unsafe extern "C-unwind" fn kclvm_value_unary_minus(
     a: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let a = ptr_as_ref(a);
@@ -1517,7 +1552,7 @@ pub unsafe extern "C" fn kclvm_value_unary_minus(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_unary_not(
+pub unsafe extern "C-unwind" fn kclvm_value_unary_not(
     a: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let a = ptr_as_ref(a);
@@ -1526,7 +1561,7 @@ pub unsafe extern "C" fn kclvm_value_unary_not(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_unary_l_not(
+pub unsafe extern "C-unwind" fn kclvm_value_unary_l_not(
     a: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let a = ptr_as_ref(a);
@@ -1537,7 +1572,7 @@ pub unsafe extern "C" fn kclvm_value_unary_l_not(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_add(
+pub unsafe extern "C-unwind" fn kclvm_value_op_add(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1548,7 +1583,7 @@ pub unsafe extern "C" fn kclvm_value_op_add(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_sub(
+pub unsafe extern "C-unwind" fn kclvm_value_op_sub(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1559,7 +1594,7 @@ pub unsafe extern "C" fn kclvm_value_op_sub(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_mul(
+pub unsafe extern "C-unwind" fn kclvm_value_op_mul(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1570,7 +1605,7 @@ pub unsafe extern "C" fn kclvm_value_op_mul(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_div(
+pub unsafe extern "C-unwind" fn kclvm_value_op_div(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1581,7 +1616,7 @@ pub unsafe extern "C" fn kclvm_value_op_div(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_mod(
+pub unsafe extern "C-unwind" fn kclvm_value_op_mod(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1592,7 +1627,7 @@ pub unsafe extern "C" fn kclvm_value_op_mod(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_pow(
+pub unsafe extern "C-unwind" fn kclvm_value_op_pow(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1603,7 +1638,7 @@ pub unsafe extern "C" fn kclvm_value_op_pow(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_floor_div(
+pub unsafe extern "C-unwind" fn kclvm_value_op_floor_div(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1614,7 +1649,7 @@ pub unsafe extern "C" fn kclvm_value_op_floor_div(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_bit_lshift(
+pub unsafe extern "C-unwind" fn kclvm_value_op_bit_lshift(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1625,7 +1660,7 @@ pub unsafe extern "C" fn kclvm_value_op_bit_lshift(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_bit_rshift(
+pub unsafe extern "C-unwind" fn kclvm_value_op_bit_rshift(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1636,7 +1671,7 @@ pub unsafe extern "C" fn kclvm_value_op_bit_rshift(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_bit_and(
+pub unsafe extern "C-unwind" fn kclvm_value_op_bit_and(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1647,7 +1682,7 @@ pub unsafe extern "C" fn kclvm_value_op_bit_and(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_bit_xor(
+pub unsafe extern "C-unwind" fn kclvm_value_op_bit_xor(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1658,7 +1693,7 @@ pub unsafe extern "C" fn kclvm_value_op_bit_xor(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_bit_or(
+pub unsafe extern "C-unwind" fn kclvm_value_op_bit_or(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1671,7 +1706,7 @@ pub unsafe extern "C" fn kclvm_value_op_bit_or(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_add(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_add(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1682,7 +1717,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_add(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_sub(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_sub(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1693,7 +1728,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_sub(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_mul(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_mul(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1704,7 +1739,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_mul(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_div(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_div(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1715,7 +1750,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_div(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_mod(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_mod(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1726,7 +1761,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_mod(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_pow(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_pow(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1737,7 +1772,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_pow(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_floor_div(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_floor_div(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1748,7 +1783,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_floor_div(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_bit_lshift(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_bit_lshift(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1759,7 +1794,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_bit_lshift(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_bit_rshift(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_bit_rshift(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1770,7 +1805,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_bit_rshift(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_bit_and(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_bit_and(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1781,7 +1816,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_bit_and(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_bit_xor(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_bit_xor(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1792,7 +1827,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_bit_xor(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_op_aug_bit_or(
+pub unsafe extern "C-unwind" fn kclvm_value_op_aug_bit_or(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1803,7 +1838,7 @@ pub unsafe extern "C" fn kclvm_value_op_aug_bit_or(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_union(
+pub unsafe extern "C-unwind" fn kclvm_value_union(
     schema: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
@@ -1840,7 +1875,7 @@ pub unsafe extern "C" fn kclvm_value_union(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_logic_and(
+pub unsafe extern "C-unwind" fn kclvm_value_logic_and(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1851,7 +1886,7 @@ pub unsafe extern "C" fn kclvm_value_logic_and(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_logic_or(
+pub unsafe extern "C-unwind" fn kclvm_value_logic_or(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1862,7 +1897,7 @@ pub unsafe extern "C" fn kclvm_value_logic_or(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_subscr(
+pub unsafe extern "C-unwind" fn kclvm_value_subscr(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1873,7 +1908,7 @@ pub unsafe extern "C" fn kclvm_value_subscr(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_subscr_option(
+pub unsafe extern "C-unwind" fn kclvm_value_subscr_option(
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
@@ -1884,7 +1919,7 @@ pub unsafe extern "C" fn kclvm_value_subscr_option(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_load_attr(
+pub unsafe extern "C-unwind" fn kclvm_value_load_attr(
     obj: *const kclvm_value_ref_t,
     key: *const kclvm_char_t,
 ) -> *const kclvm_value_ref_t {
@@ -1976,7 +2011,7 @@ pub unsafe extern "C" fn kclvm_value_load_attr(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_load_attr_option(
+pub unsafe extern "C-unwind" fn kclvm_value_load_attr_option(
     p: *const kclvm_value_ref_t,
     key: *const kclvm_char_t,
 ) -> *const kclvm_value_ref_t {
@@ -1990,7 +2025,7 @@ pub unsafe extern "C" fn kclvm_value_load_attr_option(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_remove_item(
+pub unsafe extern "C-unwind" fn kclvm_value_remove_item(
     a: *mut kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
 ) {
@@ -2007,7 +2042,7 @@ pub unsafe extern "C" fn kclvm_value_remove_item(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_slice(
+pub unsafe extern "C-unwind" fn kclvm_value_slice(
     x: *const kclvm_value_ref_t,
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
@@ -2022,7 +2057,7 @@ pub unsafe extern "C" fn kclvm_value_slice(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_slice_option(
+pub unsafe extern "C-unwind" fn kclvm_value_slice_option(
     x: *const kclvm_value_ref_t,
     a: *const kclvm_value_ref_t,
     b: *const kclvm_value_ref_t,
@@ -2042,7 +2077,7 @@ pub unsafe extern "C" fn kclvm_value_slice_option(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_backtrack_cache(
+pub unsafe extern "C-unwind" fn kclvm_schema_backtrack_cache(
     schema: *const kclvm_value_ref_t,
     cache: *mut kclvm_value_ref_t,
     cal_map: *const kclvm_value_ref_t,
@@ -2081,7 +2116,7 @@ pub unsafe extern "C" fn kclvm_schema_backtrack_cache(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_instances(
+pub unsafe extern "C-unwind" fn kclvm_schema_instances(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -2144,7 +2179,7 @@ pub unsafe extern "C" fn kclvm_schema_instances(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_value_check(
+pub unsafe extern "C-unwind" fn kclvm_schema_value_check(
     schema_value: *mut kclvm_value_ref_t,
     schema_config: *const kclvm_value_ref_t,
     _config_meta: *const kclvm_value_ref_t,
@@ -2210,7 +2245,7 @@ pub unsafe extern "C" fn kclvm_schema_value_check(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_do_check_with_index_sign_attr(
+pub unsafe extern "C-unwind" fn kclvm_schema_do_check_with_index_sign_attr(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -2221,7 +2256,6 @@ pub unsafe extern "C" fn kclvm_schema_do_check_with_index_sign_attr(
     let args_value = ptr_as_ref(args);
     let attr_name = c2str(attr_name);
     unsafe {
-        let check_fn: SchemaTypeFunc = transmute_copy(&check_fn_ptr);
         // args_0: config_meta, args_1: config, args_2: schema, args_3: cal_map
         // Schema check function closure
         let config_meta = args_value.arg_i(0).unwrap();
@@ -2244,7 +2278,7 @@ pub unsafe extern "C" fn kclvm_schema_do_check_with_index_sign_attr(
                 args.list_append(&backtrack_level_map);
                 args.list_append(&backtrack_cache);
                 let args = args.clone().into_raw();
-                check_fn(ctx, args, kwargs);
+                call_schema_type_func(check_fn_ptr, ctx, args, kwargs);
             }
         }
         schema.dict_remove(attr_name);
@@ -2253,7 +2287,7 @@ pub unsafe extern "C" fn kclvm_schema_do_check_with_index_sign_attr(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_optional_check(p: *const kclvm_value_ref_t) {
+pub unsafe extern "C-unwind" fn kclvm_schema_optional_check(p: *const kclvm_value_ref_t) {
     let p = ptr_as_ref(p);
 
     let ctx = Context::current_context();
@@ -2264,7 +2298,7 @@ pub unsafe extern "C" fn kclvm_schema_optional_check(p: *const kclvm_value_ref_t
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_default_settings(
+pub unsafe extern "C-unwind" fn kclvm_schema_default_settings(
     schema_value: *mut kclvm_value_ref_t,
     config_value: *const kclvm_value_ref_t,
     runtime_type: *const kclvm_char_t,
@@ -2277,7 +2311,7 @@ pub unsafe extern "C" fn kclvm_schema_default_settings(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_assert(
+pub unsafe extern "C-unwind" fn kclvm_schema_assert(
     value: *const kclvm_value_ref_t,
     msg: *const kclvm_value_ref_t,
     config_meta: *const kclvm_value_ref_t,
@@ -2307,7 +2341,7 @@ pub unsafe extern "C" fn kclvm_schema_assert(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_value_new(
+pub unsafe extern "C-unwind" fn kclvm_schema_value_new(
     ctx: *mut kclvm_context_t,
     args: *mut kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -2330,7 +2364,6 @@ pub unsafe extern "C" fn kclvm_schema_value_new(
         }
         let value = unsafe {
             let org_args = ptr_as_ref(args).deep_copy();
-            let schema_fn: SchemaTypeFunc = transmute_copy(&schema_fn_ptr);
             let cal_map = kclvm_value_Dict();
             let instance_pkgpath = kclvm_value_Str(pkgpath);
             // Schema function closures
@@ -2359,7 +2392,7 @@ pub unsafe extern "C" fn kclvm_schema_value_new(
             for value in values {
                 kclvm_list_append(args, value);
             }
-            schema_fn(ctx, args, kwargs);
+            call_schema_type_func(schema_fn_ptr, ctx, args, kwargs);
             // schema args
             let args = org_args.into_raw();
             let values = [
@@ -2387,7 +2420,7 @@ pub unsafe extern "C" fn kclvm_schema_value_new(
             for value in values {
                 kclvm_list_append(args, value);
             }
-            schema_fn(ctx, args, kwargs)
+            call_schema_type_func(schema_fn_ptr, ctx, args, kwargs)
         };
         ctx_ref.panic_info = now_meta_info;
         if ctx_ref.cfg.debug_mode {
@@ -2406,7 +2439,7 @@ pub unsafe extern "C" fn kclvm_schema_value_new(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_convert_collection_value(
+pub unsafe extern "C-unwind" fn kclvm_convert_collection_value(
     value: *const kclvm_value_ref_t,
     tpe: *const kclvm_char_t,
     is_in_schema: *const kclvm_value_ref_t,
@@ -2423,7 +2456,7 @@ pub unsafe extern "C" fn kclvm_convert_collection_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_schema_get_value(
+pub unsafe extern "C-unwind" fn kclvm_schema_get_value(
     p: *const kclvm_value_ref_t,
     key: *const kclvm_char_t,
     config: *const kclvm_value_ref_t,
@@ -2479,7 +2512,6 @@ pub unsafe extern "C" fn kclvm_schema_get_value(
         let ctx = Context::current_context_mut();
         let panic_info = ctx.panic_info.clone();
         unsafe {
-            let attr_fn: SchemaTypeFunc = transmute_copy(&fn_ptr);
             // args_0: config_meta, args_1: config, args_2: schema, args_3: cal_map
             let config_meta = ptr_as_ref(config_meta);
             let config = ptr_as_ref(config);
@@ -2498,7 +2530,7 @@ pub unsafe extern "C" fn kclvm_schema_get_value(
             let args = args.into_raw();
             let kwargs = kwargs.clone().into_raw();
             let ctx = kclvm_context_current();
-            attr_fn(ctx, args, kwargs);
+            call_schema_type_func(fn_ptr as u64, ctx, args, kwargs);
         };
         // Restore the panic info of current schema attribute.
         ctx.panic_info = panic_info;
@@ -2517,7 +2549,7 @@ pub unsafe extern "C" fn kclvm_schema_get_value(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_config_attr_map(
+pub unsafe extern "C-unwind" fn kclvm_config_attr_map(
     value: *mut kclvm_value_ref_t,
     name: *const kclvm_char_t,
     type_str: *const kclvm_char_t,
@@ -2534,7 +2566,7 @@ pub unsafe extern "C" fn kclvm_config_attr_map(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_value_Decorator(
+pub unsafe extern "C-unwind" fn kclvm_value_Decorator(
     name: *const kclvm_char_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -2566,7 +2598,7 @@ pub unsafe extern "C" fn kclvm_value_Decorator(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_lower(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_lower(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2581,7 +2613,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_lower(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_upper(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_upper(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2596,7 +2628,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_upper(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_capitalize(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_capitalize(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2611,7 +2643,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_capitalize(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_count(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_count(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2632,7 +2664,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_count(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_endswith(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_endswith(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2654,7 +2686,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_endswith(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_find(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_find(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2675,7 +2707,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_find(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_format(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_format(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -2691,7 +2723,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_format(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_index(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_index(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2712,7 +2744,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_index(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_isalnum(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_isalnum(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2727,7 +2759,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_isalnum(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_isalpha(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_isalpha(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2742,7 +2774,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_isalpha(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_isdigit(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_isdigit(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2757,7 +2789,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_isdigit(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_islower(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_islower(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2772,7 +2804,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_islower(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_isspace(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_isspace(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2787,7 +2819,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_isspace(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_istitle(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_istitle(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2802,7 +2834,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_istitle(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_isupper(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_isupper(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2817,7 +2849,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_isupper(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_join(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_join(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2833,7 +2865,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_join(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_lstrip(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_lstrip(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2849,7 +2881,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_lstrip(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_rstrip(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_rstrip(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2865,7 +2897,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_rstrip(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_replace(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_replace(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2885,7 +2917,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_replace(
 /// Otherwise, return a copy of the original string.
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_removeprefix(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_removeprefix(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2903,7 +2935,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_removeprefix(
 /// Otherwise, return a copy of the original string.
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_removesuffix(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_removesuffix(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2919,7 +2951,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_removesuffix(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_rfind(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_rfind(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2940,7 +2972,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_rfind(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_rindex(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_rindex(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -2962,7 +2994,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_rindex(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_rsplit(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_rsplit(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -2988,7 +3020,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_rsplit(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_split(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_split(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -3015,7 +3047,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_split(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_splitlines(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_splitlines(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     kwargs: *const kclvm_value_ref_t,
@@ -3037,7 +3069,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_splitlines(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_startswith(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_startswith(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -3059,7 +3091,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_startswith(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_strip(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_strip(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -3075,7 +3107,7 @@ pub unsafe extern "C" fn kclvm_builtin_str_strip(
 
 #[no_mangle]
 #[runtime_fn]
-pub unsafe extern "C" fn kclvm_builtin_str_title(
+pub unsafe extern "C-unwind" fn kclvm_builtin_str_title(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
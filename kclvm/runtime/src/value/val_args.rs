@@ -16,6 +16,42 @@ pub fn adjust_parameter(value: Option<&ValueRef>) -> Option<&ValueRef> {
     })
 }
 
+/// Get the call argument by the positional index `i`, or else by the keyword name `key`.
+#[inline]
+pub fn get_call_arg(args: &ValueRef, kwargs: &ValueRef, i: usize, key: &str) -> Option<ValueRef> {
+    args.arg_i(i).or_else(|| kwargs.kwarg(key))
+}
+
+/// Get the str call argument by the positional index `i`, or else by the keyword name `key`.
+pub fn get_call_arg_str(
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    i: usize,
+    key: &str,
+    default: Option<String>,
+) -> Option<String> {
+    if args.arg_i(i).is_some() {
+        args.arg_i_str(i, default)
+    } else {
+        kwargs.kwarg_str(key, default)
+    }
+}
+
+/// Get the int call argument by the positional index `i`, or else by the keyword name `key`.
+pub fn get_call_arg_int(
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    i: usize,
+    key: &str,
+    default: Option<i64>,
+) -> Option<i64> {
+    if args.arg_i(i).is_some() {
+        args.arg_i_int(i, default)
+    } else {
+        kwargs.kwarg_int(key, default)
+    }
+}
+
 impl ValueRef {
     pub fn arg_0(&self) -> Option<Self> {
         self.arg_i(0)
@@ -233,4 +269,24 @@ mod test_value_args {
             "2"
         );
     }
+
+    #[test]
+    fn test_get_call_arg() {
+        let args = ValueRef::list(Some(&[&ValueRef::str("s")]));
+        let mut kwargs = ValueRef::dict(None);
+        kwargs.dict_update_key_value("count", ValueRef::int(1));
+        assert_eq!(
+            get_call_arg_str(&args, &kwargs, 0, "string", None).unwrap(),
+            "s"
+        );
+        assert_eq!(
+            get_call_arg_int(&args, &kwargs, 1, "count", Some(0)),
+            Some(1)
+        );
+        assert_eq!(
+            get_call_arg_int(&args, &kwargs, 2, "limit", Some(0)),
+            Some(0)
+        );
+        assert!(get_call_arg(&args, &kwargs, 3, "pattern").is_none());
+    }
 }
@@ -36,12 +36,56 @@ pub const NUMBER_MULTIPLIER_TYPE: &str = "units.NumberMultiplier";
 pub const NUMBER_MULTIPLIER_REGEX: &str =
     r"^([1-9][0-9]{0,63})(E|P|T|G|M|K|k|m|u|n|Ei|Pi|Ti|Gi|Mi|Ki)$";
 
-pub type SchemaTypeFunc = unsafe extern "C" fn(
+pub type SchemaTypeFunc = unsafe extern "C-unwind" fn(
     *mut kclvm_context_t,
     *const kclvm_value_ref_t,
     *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t;
 
+/// The handler of the proxy functions, the first argument is the proxy index. The
+/// handler reports errors by panics like the runtime, so it uses the "C-unwind" ABI
+/// to unwind the panics to the caller instead of aborting the process.
+pub type FuncProxyHandler = unsafe extern "C-unwind" fn(
+    u64,
+    *mut kclvm_context_t,
+    *const kclvm_value_ref_t,
+    *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t;
+
+/// The tag of the function pointers which are proxy indices instead of native
+/// function addresses, e.g., the functions defined in a tree-walking evaluator.
+pub const FUNC_PROXY_TAG: u64 = 1 << 63;
+
+/// Call the schema, schema attribute, check block or lambda function `fn_ptr`, the
+/// proxy function is dispatched to the proxy handler of the context.
+///
+/// # Safety
+///
+/// `fn_ptr` must be a native [`SchemaTypeFunc`] address or a tagged proxy index, and
+/// the pointer arguments must be valid.
+pub unsafe fn call_schema_type_func(
+    fn_ptr: u64,
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    if fn_ptr & FUNC_PROXY_TAG != 0 {
+        let handler_ptr = mut_ptr_as_ref(ctx).func_proxy_handler;
+        if handler_ptr == 0 {
+            mut_ptr_as_ref(ctx).set_err_type(&ErrType::EvaluationError_TYPE);
+            panic!(
+                "the function proxy {} can not be called without the function proxy handler",
+                fn_ptr & !FUNC_PROXY_TAG
+            );
+        }
+        let handler: FuncProxyHandler = transmute_copy(&handler_ptr);
+        handler(fn_ptr & !FUNC_PROXY_TAG, ctx, args, kwargs)
+    } else {
+        let call_fn: SchemaTypeFunc = transmute_copy(&fn_ptr);
+        call_fn(ctx, args, kwargs)
+    }
+}
+
 // common
 impl ValueRef {
     pub fn type_str(&self) -> String {
@@ -93,7 +137,6 @@ pub fn resolve_schema(schema: &ValueRef, keys: &[String]) -> ValueRef {
         );
         let config_meta_new = config_meta.clone();
         let value = unsafe {
-            let schema_fn: SchemaTypeFunc = transmute_copy(&schema_fn_ptr);
             let ctx = kclvm_context_current();
             let cal_map = kclvm_value_Dict();
             let list = kclvm_value_List();
@@ -119,7 +162,7 @@ pub fn resolve_schema(schema: &ValueRef, keys: &[String]) -> ValueRef {
             // instance pkgpath
             kclvm_list_append(list, ValueRef::str(&now_meta_info.kcl_pkgpath).into_raw());
             let dict = kclvm_value_Dict();
-            schema_fn(ctx, list, dict);
+            call_schema_type_func(schema_fn_ptr, ctx, list, dict);
             let list = kclvm_value_List();
             // Schema function closures
             // is sub schema
@@ -142,7 +185,7 @@ pub fn resolve_schema(schema: &ValueRef, keys: &[String]) -> ValueRef {
             kclvm_list_append(list, ValueRef::bool(true).into_raw());
             // instance pkgpath
             kclvm_list_append(list, ValueRef::str(&now_meta_info.kcl_pkgpath).into_raw());
-            let value = schema_fn(ctx, list, dict);
+            let value = call_schema_type_func(schema_fn_ptr, ctx, list, dict);
             ptr_as_ref(value)
         };
         ctx.panic_info = now_meta_info;
@@ -284,7 +327,6 @@ pub fn convert_collection_value(value: &ValueRef, tpe: &str) -> ValueRef {
             let schema_type = schema_type.as_function();
             let schema_fn_ptr = schema_type.fn_ptr;
             let value = unsafe {
-                let schema_fn: SchemaTypeFunc = transmute_copy(&schema_fn_ptr);
                 let ctx = kclvm_context_current();
                 let cal_order = kclvm_value_Dict();
                 let list = kclvm_value_List();
@@ -310,7 +352,7 @@ pub fn convert_collection_value(value: &ValueRef, tpe: &str) -> ValueRef {
                 // instance pkgpath
                 kclvm_list_append(list, ValueRef::str(&now_meta_info.kcl_pkgpath).into_raw());
                 let dict = kclvm_value_Dict();
-                schema_fn(ctx, list, dict);
+                call_schema_type_func(schema_fn_ptr, ctx, list, dict);
                 let list = kclvm_value_List();
                 // Schema function closures
                 // is_sub_schema
@@ -333,7 +375,7 @@ pub fn convert_collection_value(value: &ValueRef, tpe: &str) -> ValueRef {
                 kclvm_list_append(list, ValueRef::bool(true).into_raw());
                 // instance pkgpath
                 kclvm_list_append(list, ValueRef::str(&now_meta_info.kcl_pkgpath).into_raw());
-                let value = schema_fn(ctx, list, dict);
+                let value = call_schema_type_func(schema_fn_ptr, ctx, list, dict);
                 ptr_as_ref(value)
             };
             ctx.panic_info = now_meta_info;
@@ -355,6 +397,10 @@ pub fn check_type(value: &ValueRef, tpe: &str) -> bool {
     if is_type_union(tpe) {
         return check_type_union(value, tpe);
     }
+    // The parameter and return types of the function are checked at compile time.
+    if is_func_type(tpe) {
+        return value.is_func();
+    }
 
     if check_type_literal(value, tpe) {
         return true;
@@ -523,6 +569,12 @@ pub fn is_list_type(tpe: &str) -> bool {
         && matches!(tpe.chars().nth(count - 1), Some(']'))
 }
 
+/// is_func_type returns the type string whether is a function type e.g., `(int) -> str`
+#[inline]
+pub fn is_func_type(tpe: &str) -> bool {
+    tpe.starts_with('(') && tpe.contains("->")
+}
+
 #[inline]
 pub fn is_builtin_type(tpe: &str) -> bool {
     BUILTIN_TYPES.contains(&tpe)
@@ -535,6 +587,7 @@ pub fn is_schema_type(expected_type: &str) -> bool {
     }
     !is_list_type(expected_type)
         && !is_dict_type(expected_type)
+        && !is_func_type(expected_type)
         && !is_builtin_type(expected_type)
         && !is_literal_type(expected_type)
 }
@@ -547,9 +600,9 @@ pub fn is_type_union(tpe: &str) -> bool {
         let c = tpe.chars().nth(i).unwrap();
         if c == '|' && stack.is_empty() {
             return true;
-        } else if c == '[' || c == '{' {
+        } else if c == '[' || c == '{' || c == '(' {
             stack.push(c);
-        } else if c == ']' || c == '}' {
+        } else if c == ']' || c == '}' || c == ')' {
             stack.pop();
         } else if c == '\"' {
             let t: String = tpe.chars().skip(i).collect();
@@ -591,12 +644,12 @@ pub fn split_type_union(tpe: &str) -> Vec<&str> {
             types.push(&tpe[s_index..c_idx]);
             s_index = c_idx + 1;
         }
-        // List/Dict type
-        else if c == '[' || c == '{' {
+        // List/Dict/Function type
+        else if c == '[' || c == '{' || c == '(' {
             stack.push(c);
         }
-        // List/Dict type
-        else if c == ']' || c == '}' {
+        // List/Dict/Function type
+        else if c == ']' || c == '}' || c == ')' {
             stack.pop();
         }
         // String literal type
@@ -678,9 +731,15 @@ mod test_value_type {
             (ValueRef::str("123"), "str", true),
             (ValueRef::list_int(&[1, 2, 3]), "[int]", true),
             (ValueRef::dict_str(&[("key", "value")]), "{str:}", true),
+            (
+                ValueRef::func(0, 0, ValueRef::none(), "", "", false),
+                "(int) -> str",
+                true,
+            ),
             // false cases
             (ValueRef::int(0), "str", false),
             (ValueRef::str("0"), "int", false),
+            (ValueRef::int(0), "(int) -> str", false),
         ];
         for (value, tpe, expected) in cases {
             assert_eq!(check_type(&value, tpe), expected);
@@ -914,6 +973,8 @@ mod test_value_type {
             ("\"aa\\\"ab|\"|\"aa\\\"abccc\"", true),
             ("[\"|\"]|\"\"", true),
             ("{str:\"|\"}|\"|\"", true),
+            ("(int|str) -> int|str", true),
+            ("(int|str) -> int", false),
             ("\"aa\\\"ab|\"", false),
             ("\"|aa\\\"ab|\"", false),
         ];
@@ -930,6 +991,7 @@ mod test_value_type {
             ("str|int|bool", vec!["str", "int", "bool"]),
             ("str|[str]", vec!["str", "[str]"]),
             ("str|{str:int}", vec!["str", "{str:int}"]),
+            ("str|(int|str) -> int", vec!["str", "(int|str) -> int"]),
             ("A|B|C", vec!["A", "B", "C"]),
             ("'123'|'456'|'789'", vec!["'123'", "'456'", "'789'"]),
             ("'|'|'||'|'|||'", vec!["'|'", "'||'", "'|||'"]),
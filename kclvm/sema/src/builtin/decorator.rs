@@ -42,6 +42,15 @@ register_decorator! {
         ],
         r#"This decorator is used to get the deprecation message according to the wrapped key-value pair.
 
+        Parameters
+        ----------
+        version: str, optional
+            The version since which the schema or attribute is deprecated.
+        reason: str, optional
+            The reason of the deprecation.
+        strict: bool, optional
+            Whether to raise an error instead of a warning when the deprecated schema or attribute is used, the default is True.
+
         Examples
         --------
         @deprecated(version="v1.16", reason="The age attribute was deprecated", strict=True)
@@ -58,6 +67,11 @@ register_decorator! {
         &[],
         r#"Info decorator is used to mark some compile-time information for external API queries
 
+        Parameters
+        ----------
+        **kwargs
+            The keyword arguments of the information, e.g., `message="User message"`.
+
         Examples
         --------
         @info(message="User message")
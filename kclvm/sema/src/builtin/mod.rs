@@ -106,7 +106,7 @@ register_builtin! {
         ],
         "Check if the modular result of a and b is 0.",
         true,
-        Some(0),
+        None,
     )
     isunique => Type::function(
         None,
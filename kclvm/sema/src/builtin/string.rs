@@ -2,7 +2,7 @@ use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use std::rc::Rc;
 
-use crate::ty::Type;
+use crate::ty::{Parameter, Type};
 
 macro_rules! register_string_member {
     ($($name:ident => $ty:expr)*) => (
@@ -18,33 +18,130 @@ macro_rules! register_string_member {
 register_string_member! {
     capitalize => Type::function(
         Some(Rc::new(Type::STR)),
-        Rc::new(Type::ANY),
+        Rc::new(Type::STR),
         &[],
-        r#"Return a copy of the string with its first character capitalized and the rest lowercased."#,
+        r#"Return a copy of the string with its first character capitalized and the rest lowercased.
+
+        Examples
+        --------
+        "kcl lang".capitalize()  # "Kcl lang"
+        "#,
         false,
         None,
     )
     count => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::INT),
-        &[],
-        r#"Return the number of non-overlapping occurrences of substring sub in the range [start, end]. Optional arguments start and end are interpreted as in slice notation."#,
+        &[
+            Parameter {
+                name: "sub".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "start".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "end".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return the number of non-overlapping occurrences of substring sub in the range [start, end]. Optional arguments start and end are interpreted as in slice notation.
+
+        Parameters
+        ----------
+        sub: str
+            The substring to look for.
+        start: int, optional
+            The start index of the slice to look in, the default is 0.
+        end: int, optional
+            The end index of the slice to look in, the default is the length of the string.
+
+        Examples
+        --------
+        "banana".count("a")  # 3
+        "banana".count("a", 2)  # 2
+        "#,
         false,
         None,
     )
     endswith => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
-        &[],
-        r#"Return True if the string ends with the specified suffix, otherwise return False. suffix can also be a tuple of suffixes to look for. With optional start, test beginning at that position. With optional end, stop comparing at that position."#,
+        &[
+            Parameter {
+                name: "suffix".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "start".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "end".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return True if the string ends with the specified suffix, otherwise return False. With optional start, test beginning at that position. With optional end, stop comparing at that position.
+
+        Parameters
+        ----------
+        suffix: str
+            The suffix to look for.
+        start: int, optional
+            The start index of the slice to look in, the default is 0.
+        end: int, optional
+            The end index of the slice to look in, the default is the length of the string.
+
+        Examples
+        --------
+        "main.k".endswith(".k")  # True
+        "#,
         false,
         None,
     )
     find => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::INT),
-        &[],
-        r#"Return the lowest index in the string where substring sub is found within the slice s[start:end]. Optional arguments start and end are interpreted as in slice notation. Return -1 if sub is not found."#,
+        &[
+            Parameter {
+                name: "sub".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "start".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "end".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return the lowest index in the string where substring sub is found within the slice s[start:end]. Optional arguments start and end are interpreted as in slice notation. Return -1 if sub is not found.
+
+        Parameters
+        ----------
+        sub: str
+            The substring to look for.
+        start: int, optional
+            The start index of the slice to look in, the default is 0.
+        end: int, optional
+            The end index of the slice to look in, the default is the length of the string.
+
+        Examples
+        --------
+        "banana".find("n")  # 2
+        "banana".find("x")  # -1
+        "#,
         false,
         None,
     )
@@ -52,15 +149,51 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
         &[],
-        r#"Perform a string formatting operation. The string on which this method is called can contain literal text or replacement fields delimited by braces {}. Each replacement field contains either the numeric index of a positional argument, or the name of a keyword argument. Returns a copy of the string where each replacement field is replaced with the string value of the corresponding argument."#,
+        r#"Perform a string formatting operation. The string on which this method is called can contain literal text or replacement fields delimited by braces {}. Each replacement field contains either the numeric index of a positional argument, or the name of a keyword argument. Returns a copy of the string where each replacement field is replaced with the string value of the corresponding argument.
+
+        Examples
+        --------
+        "{} is {}".format("KCL", "fun")  # "KCL is fun"
+        "{name} is {age}".format(name="Alice", age=18)  # "Alice is 18"
+        "#,
         true,
         None,
     )
     index => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::INT),
-        &[],
-        r#"Like str.find(), but raise an error when the substring is not found."#,
+        &[
+            Parameter {
+                name: "sub".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "start".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "end".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Like str.find(), but raise an error when the substring is not found.
+
+        Parameters
+        ----------
+        sub: str
+            The substring to look for.
+        start: int, optional
+            The start index of the slice to look in, the default is 0.
+        end: int, optional
+            The end index of the slice to look in, the default is the length of the string.
+
+        Examples
+        --------
+        "banana".index("n")  # 2
+        "#,
         false,
         None,
     )
@@ -68,7 +201,12 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
         &[],
-        r#"Return True if all characters in the string are alphabetic and there is at least one character, False otherwise. Alphabetic characters are those characters defined in the Unicode character database as “Letter”, i.e., those with general category property being one of “Lm”, “Lt”, “Lu”, “Ll”, or “Lo”. Note that this is different from the “Alphabetic” property defined in the Unicode Standard."#,
+        r#"Return True if all characters in the string are alphabetic and there is at least one character, False otherwise. Alphabetic characters are those characters defined in the Unicode character database as “Letter”, i.e., those with general category property being one of “Lm”, “Lt”, “Lu”, “Ll”, or “Lo”. Note that this is different from the “Alphabetic” property defined in the Unicode Standard.
+
+        Examples
+        --------
+        "kcl".isalpha()  # True
+        "#,
         false,
         None,
     )
@@ -76,7 +214,12 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
         &[],
-        r#"Return True if all characters in the string are alphanumeric and there is at least one character, False otherwise. A character c is alphanumeric if one of the following returns True: c.isalpha(), c.isdecimal(), c.isdigit(), or c.isnumeric()."#,
+        r#"Return True if all characters in the string are alphanumeric and there is at least one character, False otherwise. A character c is alphanumeric if one of the following returns True: c.isalpha(), c.isdecimal(), c.isdigit(), or c.isnumeric().
+
+        Examples
+        --------
+        "kcl3".isalnum()  # True
+        "#,
         false,
         None,
     )
@@ -84,7 +227,12 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
         &[],
-        r#"Return True if all characters in the string are digits and there is at least one character, False otherwise. Digits include decimal characters and digits that need special handling, such as the compatibility superscript digits. This covers digits which cannot be used to form numbers in base 10, like the Kharosthi numbers. Formally, a digit is a character that has the property value Numeric_Type=Digit or Numeric_Type=Decimal."#,
+        r#"Return True if all characters in the string are digits and there is at least one character, False otherwise. Digits include decimal characters and digits that need special handling, such as the compatibility superscript digits. This covers digits which cannot be used to form numbers in base 10, like the Kharosthi numbers. Formally, a digit is a character that has the property value Numeric_Type=Digit or Numeric_Type=Decimal.
+
+        Examples
+        --------
+        "123".isdigit()  # True
+        "#,
         false,
         None,
     )
@@ -92,7 +240,12 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
         &[],
-        r#"Return True if all cased characters in the string are lowercase and there is at least one cased character, False otherwise."#,
+        r#"Return True if all cased characters in the string are lowercase and there is at least one cased character, False otherwise.
+
+        Examples
+        --------
+        "kcl".islower()  # True
+        "#,
         false,
         None,
     )
@@ -100,7 +253,12 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
         &[],
-        r#"Return True if there are only whitespace characters in the string and there is at least one character, False otherwise."#,
+        r#"Return True if there are only whitespace characters in the string and there is at least one character, False otherwise.
+
+        Examples
+        --------
+        " \t".isspace()  # True
+        "#,
         false,
         None,
     )
@@ -108,7 +266,12 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
         &[],
-        r#"Return True if the string is a titlecased string and there is at least one character, for example uppercase characters may only follow uncased characters and lowercase characters only cased ones. Return False otherwise."#,
+        r#"Return True if the string is a titlecased string and there is at least one character, for example uppercase characters may only follow uncased characters and lowercase characters only cased ones. Return False otherwise.
+
+        Examples
+        --------
+        "Kcl Lang".istitle()  # True
+        "#,
         false,
         None,
     )
@@ -116,127 +279,425 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
         &[],
-        r#"Return True if all cased characters in the string are uppercase and there is at least one cased character, False otherwise."#,
+        r#"Return True if all cased characters in the string are uppercase and there is at least one cased character, False otherwise.
+
+        Examples
+        --------
+        "KCL".isupper()  # True
+        "#,
         false,
         None,
     )
     join => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
-        &[],
-        r#"Return a string which is the concatenation of the strings in iterable. An error will be raised if there are any non-string values in iterable. The separator between elements is the string providing this method."#,
-        true,
+        &[
+            Parameter {
+                name: "iter".to_string(),
+                ty: Type::list_ref(Rc::new(Type::STR)),
+                has_default: false,
+            },
+        ],
+        r#"Return a string which is the concatenation of the strings in iterable. An error will be raised if there are any non-string values in iterable. The separator between elements is the string providing this method.
+
+        Parameters
+        ----------
+        iter: [str]
+            The strings to concatenate.
+
+        Examples
+        --------
+        ", ".join(["a", "b", "c"])  # "a, b, c"
+        "#,
+        false,
         None,
     )
     lower => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
         &[],
-        r#"Return a copy of the string with all the cased characters converted to lowercase."#,
-        true,
+        r#"Return a copy of the string with all the cased characters converted to lowercase.
+
+        Examples
+        --------
+        "KCL".lower()  # "kcl"
+        "#,
+        false,
         None,
     )
     upper => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
         &[],
-        r#""#,
-        true,
+        r#"Return a copy of the string with all the cased characters converted to uppercase.
+
+        Examples
+        --------
+        "kcl".upper()  # "KCL"
+        "#,
+        false,
         None,
     )
     lstrip => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
-        &[],
-        r#"Return a copy of the string with leading characters removed. The chars argument is a string specifying the set of characters to be removed. If omitted or None, the chars argument defaults to removing whitespace. The chars argument is not a prefix; rather, all combinations of its values are stripped:"#,
-        true,
+        &[
+            Parameter {
+                name: "chars".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return a copy of the string with leading characters removed. The chars argument is not a prefix; rather, all combinations of its values are stripped.
+
+        Parameters
+        ----------
+        chars: str, optional
+            The set of characters to remove, the whitespace characters are removed by default.
+
+        Examples
+        --------
+        "  kcl  ".lstrip()  # "kcl  "
+        "xxkclxx".lstrip("x")  # "kclxx"
+        "#,
+        false,
         None,
     )
     rstrip => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
-        &[],
-        r#"Return a copy of the string with trailing characters removed. The chars argument is a string specifying the set of characters to be removed. If omitted or None, the chars argument defaults to removing whitespace. The chars argument is not a suffix; rather, all combinations of its values are stripped:"#,
-        true,
+        &[
+            Parameter {
+                name: "chars".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return a copy of the string with trailing characters removed. The chars argument is not a suffix; rather, all combinations of its values are stripped.
+
+        Parameters
+        ----------
+        chars: str, optional
+            The set of characters to remove, the whitespace characters are removed by default.
+
+        Examples
+        --------
+        "  kcl  ".rstrip()  # "  kcl"
+        "#,
+        false,
         None,
     )
     replace => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
-        &[],
-        r#"Return a copy of the string with all occurrences of substring old replaced by new. If the optional argument count is given, only the first count occurrences are replaced.Return a copy of the string with all occurrences of substring old replaced by new. If the optional argument count is given, only the first count occurrences are replaced."#,
-        true,
+        &[
+            Parameter {
+                name: "old".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "new".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "count".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return a copy of the string with all occurrences of substring old replaced by new. If the optional argument count is given, only the first count occurrences are replaced.
+
+        Parameters
+        ----------
+        old: str
+            The substring to replace.
+        new: str
+            The replacement string.
+        count: int, optional
+            The maximum number of the replacements, all the occurrences are replaced by default.
+
+        Examples
+        --------
+        "a-b-c".replace("-", "+")  # "a+b+c"
+        "a-b-c".replace("-", "+", 1)  # "a+b-c"
+        "#,
+        false,
         None,
     )
     removeprefix => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
-        &[],
-        r#"If the string starts with the prefix string, return string[len(prefix):]. Otherwise, return a copy of the original string."#,
-        true,
+        &[
+            Parameter {
+                name: "prefix".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"If the string starts with the prefix string, return string[len(prefix):]. Otherwise, return a copy of the original string.
+
+        Parameters
+        ----------
+        prefix: str
+            The prefix to remove.
+
+        Examples
+        --------
+        "v1.0".removeprefix("v")  # "1.0"
+        "#,
+        false,
         None,
     )
     removesuffix => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
-        &[],
-        r#"If the string ends with the suffix string and that suffix is not empty, return string[:-len(suffix)]. Otherwise, return a copy of the original string."#,
-        true,
+        &[
+            Parameter {
+                name: "suffix".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"If the string ends with the suffix string and that suffix is not empty, return string[:-len(suffix)]. Otherwise, return a copy of the original string.
+
+        Parameters
+        ----------
+        suffix: str
+            The suffix to remove.
+
+        Examples
+        --------
+        "main.k".removesuffix(".k")  # "main"
+        "#,
+        false,
         None,
     )
     rfind => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::INT),
-        &[],
-        r#"Return the highest index in the string where substring sub is found, such that sub is contained within s[start:end]. Optional arguments start and end are interpreted as in slice notation. Return -1 on failure."#,
-        true,
+        &[
+            Parameter {
+                name: "sub".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "start".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "end".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return the highest index in the string where substring sub is found, such that sub is contained within s[start:end]. Optional arguments start and end are interpreted as in slice notation. Return -1 on failure.
+
+        Parameters
+        ----------
+        sub: str
+            The substring to look for.
+        start: int, optional
+            The start index of the slice to look in, the default is 0.
+        end: int, optional
+            The end index of the slice to look in, the default is the length of the string.
+
+        Examples
+        --------
+        "banana".rfind("n")  # 4
+        "#,
+        false,
         None,
     )
     rindex => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::INT),
-        &[],
-        r#"Like rfind() but raises ValueError when the substring sub is not found."#,
-        true,
+        &[
+            Parameter {
+                name: "sub".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "start".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "end".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Like str.rfind(), but raise an error when the substring is not found.
+
+        Parameters
+        ----------
+        sub: str
+            The substring to look for.
+        start: int, optional
+            The start index of the slice to look in, the default is 0.
+        end: int, optional
+            The end index of the slice to look in, the default is the length of the string.
+
+        Examples
+        --------
+        "banana".rindex("n")  # 4
+        "#,
+        false,
         None,
     )
     rsplit => Type::function(
         Some(Rc::new(Type::STR)),
         Type::list_ref(Rc::new(Type::STR)),
-        &[],
-        r#"Return a list of the words in the string, using sep as the delimiter string. If maxsplit is given, at most maxsplit splits are done, the rightmost ones. If sep is not specified or None, any whitespace string is a separator. Except for splitting from the right, rsplit() behaves like split() which is described in detail below."#,
-        true,
+        &[
+            Parameter {
+                name: "sep".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+            Parameter {
+                name: "maxsplit".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return a list of the words in the string, using sep as the delimiter string. If maxsplit is given, at most maxsplit splits are done, the rightmost ones. Except for splitting from the right, rsplit() behaves like split().
+
+        Parameters
+        ----------
+        sep: str, optional
+            The delimiter string, any whitespace string is a separator by default.
+        maxsplit: int, optional
+            The maximum number of the splits, -1 means no limit.
+
+        Examples
+        --------
+        "a,b,c".rsplit(",", 1)  # ["a,b", "c"]
+        "#,
+        false,
         None,
     )
     split => Type::function(
         Some(Rc::new(Type::STR)),
         Type::list_ref(Rc::new(Type::STR)),
-        &[],
-        r#"Return a list of the words in the string, using sep as the delimiter string. If maxsplit is given, at most maxsplit splits are done (thus, the list will have at most maxsplit+1 elements). If maxsplit is not specified or -1, then there is no limit on the number of splits (all possible splits are made)."#,
-        true,
+        &[
+            Parameter {
+                name: "sep".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+            Parameter {
+                name: "maxsplit".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return a list of the words in the string, using sep as the delimiter string. If maxsplit is given, at most maxsplit splits are done (thus, the list will have at most maxsplit+1 elements).
+
+        Parameters
+        ----------
+        sep: str, optional
+            The delimiter string, any whitespace string is a separator by default.
+        maxsplit: int, optional
+            The maximum number of the splits, -1 means no limit.
+
+        Examples
+        --------
+        "a,b,c".split(",")  # ["a", "b", "c"]
+        "a b  c".split()  # ["a", "b", "c"]
+        "a,b,c".split(sep=",", maxsplit=1)  # ["a", "b,c"]
+        "#,
+        false,
         None,
     )
     splitlines => Type::function(
         Some(Rc::new(Type::STR)),
         Type::list_ref(Rc::new(Type::STR)),
-        &[],
-        r#"Return a list of the lines in the string, breaking at line boundaries. Line breaks are not included in the resulting list unless keepends is given and true."#,
-        true,
+        &[
+            Parameter {
+                name: "keepends".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+        ],
+        r#"Return a list of the lines in the string, breaking at line boundaries. Line breaks are not included in the resulting list unless keepends is given and true.
+
+        Parameters
+        ----------
+        keepends: bool, optional
+            Whether to keep the line breaks in the lines, the default is False.
+
+        Examples
+        --------
+        "a\nb\r\nc".splitlines()  # ["a", "b", "c"]
+        "a\nb".splitlines(True)  # ["a\n", "b"]
+        "#,
+        false,
         None,
     )
     startswith => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::BOOL),
-        &[],
-        r#"Return True if string starts with the prefix, otherwise return False. prefix can also be a tuple of prefixes to look for. With optional start, test string beginning at that position. With optional end, stop comparing string at that position."#,
+        &[
+            Parameter {
+                name: "prefix".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "start".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "end".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return True if string starts with the prefix, otherwise return False. With optional start, test string beginning at that position. With optional end, stop comparing string at that position.
+
+        Parameters
+        ----------
+        prefix: str
+            The prefix to look for.
+        start: int, optional
+            The start index of the slice to look in, the default is 0.
+        end: int, optional
+            The end index of the slice to look in, the default is the length of the string.
+
+        Examples
+        --------
+        "main.k".startswith("main")  # True
+        "#,
         false,
         None,
     )
     strip => Type::function(
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
-        &[],
-        r#"Return a copy of the string with the leading and trailing characters removed. The chars argument is a string specifying the set of characters to be removed. If omitted or None, the chars argument defaults to removing whitespace. The chars argument is not a prefix or suffix; rather, all combinations of its values are stripped:"#,
+        &[
+            Parameter {
+                name: "chars".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return a copy of the string with the leading and trailing characters removed. The chars argument is not a prefix or suffix; rather, all combinations of its values are stripped.
+
+        Parameters
+        ----------
+        chars: str, optional
+            The set of characters to remove, the whitespace characters are removed by default.
+
+        Examples
+        --------
+        "  kcl  ".strip()  # "kcl"
+        "#,
         false,
         None,
     )
@@ -244,7 +705,12 @@ register_string_member! {
         Some(Rc::new(Type::STR)),
         Rc::new(Type::STR),
         &[],
-        r#"Return a titlecased version of the string where words start with an uppercase character and the remaining characters are lowercase."#,
+        r#"Return a titlecased version of the string where words start with an uppercase character and the remaining characters are lowercase.
+
+        Examples
+        --------
+        "kcl lang".title()  # "Kcl Lang"
+        "#,
         false,
         None,
     )
@@ -1,8 +1,95 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
 
+use std::rc::Rc;
+
+use crate::ty::{Parameter, Type};
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+
+macro_rules! register_system_module_functions {
+    ($types:ident, $($name:literal => $ty:expr)*) => (
+        // System module member function map.
+        pub const $types: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+            let mut function_mapping = IndexMap::default();
+            $( function_mapping.insert($name.to_string(), $ty); )*
+            function_mapping
+        });
+    )
+}
+
 pub const BASE64: &str = "base64";
 pub const BASE64_FUNCTION_NAMES: [&str; 2] = ["encode", "decode"];
 
+register_system_module_functions! {
+    BASE64_FUNCTION_TYPES,
+    "encode" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Encode the string `value` using the codec registered for encoding.
+
+        Parameters
+        ----------
+        value: str
+            The string to encode.
+        encoding: str, optional
+            The encoding of the string, the default is "utf-8".
+
+        Examples
+        --------
+        import base64
+
+        base64.encode("hello")  # "aGVsbG8="
+        "#,
+        false,
+        None,
+    )
+    "decode" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Decode the string `value` using the codec registered for encoding.
+
+        Parameters
+        ----------
+        value: str
+            The base64 string to decode.
+        encoding: str, optional
+            The encoding of the decoded string, the default is "utf-8".
+
+        Examples
+        --------
+        import base64
+
+        base64.decode("aGVsbG8=")  # "hello"
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const NET: &str = "net";
 pub const NET_FUNCTION_NAMES: [&str; 16] = [
     "split_host_port",
@@ -23,9 +110,477 @@ pub const NET_FUNCTION_NAMES: [&str; 16] = [
     "is_unspecified_IP",
 ];
 
+register_system_module_functions! {
+    NET_FUNCTION_TYPES,
+    "split_host_port" => Type::function(
+        None,
+        Type::list_ref(Rc::new(Type::STR)),
+        &[
+            Parameter {
+                name: "ip_end_point".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Split the `host` and `port` from the `ip_end_point`.
+
+        Parameters
+        ----------
+        ip_end_point: str
+            The network address in the form of "host:port".
+
+        Examples
+        --------
+        import net
+
+        net.split_host_port("127.0.0.1:8080")  # ["127.0.0.1", "8080"]
+        "#,
+        false,
+        None,
+    )
+    "join_host_port" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "host".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "port".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::STR), Rc::new(Type::INT)]),
+                has_default: false,
+            },
+        ],
+        r#"Merge the `host` and `port`.
+
+        Parameters
+        ----------
+        host: str
+            The host name or IP address.
+        port: str|int
+            The port number.
+
+        Examples
+        --------
+        import net
+
+        net.join_host_port("127.0.0.1", 8080)  # "127.0.0.1:8080"
+        "#,
+        false,
+        None,
+    )
+    "fqdn" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "name".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return Fully Qualified Domain Name (FQDN).
+
+        Parameters
+        ----------
+        name: str, optional
+            The host name, the local host name by default.
+
+        Examples
+        --------
+        import net
+
+        name = net.fqdn("localhost")
+        "#,
+        false,
+        None,
+    )
+    "parse_IP" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Parse ip to a real IP address.
+
+        Parameters
+        ----------
+        ip: str
+            The IPv4 or IPv6 address string.
+
+        Examples
+        --------
+        import net
+
+        net.parse_IP("192.168.0.1")  # "192.168.0.1"
+        "#,
+        false,
+        None,
+    )
+    "to_IP4" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Get the IP4 form of ip.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.to_IP4("192.168.0.1")  # "192.168.0.1"
+        "#,
+        false,
+        None,
+    )
+    "to_IP16" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Get the IP16 form of ip.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.to_IP16("192.168.0.1")  # "192.168.0.1"
+        "#,
+        false,
+        None,
+    )
+    "IP_string" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Get the IP string.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.IP_string("192.168.0.1")  # "192.168.0.1"
+        "#,
+        false,
+        None,
+    )
+    "is_IPv4" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a IPv4 one.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.is_IPv4("192.168.0.1")  # True
+        "#,
+        false,
+        None,
+    )
+    "is_IP" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a valid ip address.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.is_IP("192.168.0.1")  # True
+        "#,
+        false,
+        None,
+    )
+    "is_loopback_IP" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a loopback one.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.is_loopback_IP("127.0.0.1")  # True
+        "#,
+        false,
+        None,
+    )
+    "is_multicast_IP" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a multicast one.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.is_multicast_IP("224.0.0.1")  # True
+        "#,
+        false,
+        None,
+    )
+    "is_interface_local_multicast_IP" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a interface, local and multicast one.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        result = net.is_interface_local_multicast_IP("224.0.0.0")
+        "#,
+        false,
+        None,
+    )
+    "is_link_local_multicast_IP" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a link local and multicast one.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        result = net.is_link_local_multicast_IP("224.0.0.0")
+        "#,
+        false,
+        None,
+    )
+    "is_link_local_unicast_IP" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a link local and unicast one.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.is_link_local_unicast_IP("fe80::1")  # True
+        "#,
+        false,
+        None,
+    )
+    "is_global_unicast_IP" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a global and unicast one.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.is_global_unicast_IP("8.8.8.8")  # True
+        "#,
+        false,
+        None,
+    )
+    "is_unspecified_IP" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Whether ip is a unspecified one.
+
+        Parameters
+        ----------
+        ip: str
+            The IP address string.
+
+        Examples
+        --------
+        import net
+
+        net.is_unspecified_IP("0.0.0.0")  # True
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const MANIFESTS: &str = "manifests";
 pub const MANIFESTS_FUNCTION_NAMES: [&str; 1] = ["yaml_stream"];
 
+register_system_module_functions! {
+    MANIFESTS_FUNCTION_TYPES,
+    "yaml_stream" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "values".to_string(),
+                ty: Type::list_ref(Rc::new(Type::ANY)),
+                has_default: false,
+            },
+            Parameter {
+                name: "opts".to_string(),
+                ty: Type::dict_ref(Rc::new(Type::STR), Rc::new(Type::ANY)),
+                has_default: true,
+            },
+        ],
+        r#"This function is used to serialize the KCL object list into YAML output with the --- separator.
+
+        Parameters
+        ----------
+        values: [any]
+            A list of KCL objects.
+        opts: {str:any}, optional
+            The YAML serialization options:
+            + sort_keys: Whether to sort the serialized results in the dictionary order of attribute names (the default is False).
+            + ignore_private: Whether to ignore the attribute output whose name starts with the character _ (the default value is True).
+            + ignore_none: Whether to ignore the attribute with the value of None (the default value is False).
+            + sep: Set the separator between multiple YAML documents (the default value is "---").
+
+        Examples
+        --------
+        import manifests
+
+        manifests.yaml_stream([{name = "Alice"}, {name = "Bob"}], opts={sort_keys = True})
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const MATH: &str = "math";
 pub const MATH_FUNCTION_NAMES: [&str; 16] = [
     "ceil",
@@ -46,31 +601,1639 @@ pub const MATH_FUNCTION_NAMES: [&str; 16] = [
     "sqrt",
 ];
 
+register_system_module_functions! {
+    MATH_FUNCTION_TYPES,
+    "ceil" => Type::function(
+        None,
+        Rc::new(Type::INT),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return the ceiling of `x` as an Integral. This is the smallest integer >= x.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to round up.
+
+        Examples
+        --------
+        import math
+
+        math.ceil(2.5)  # 3
+        "#,
+        false,
+        None,
+    )
+    "factorial" => Type::function(
+        None,
+        Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return `x`!. Raise a error if `x` is negative or non-integral.
+
+        Parameters
+        ----------
+        x: int|float
+            The non-negative integral number.
+
+        Examples
+        --------
+        import math
+
+        math.factorial(5)  # 120
+        "#,
+        false,
+        None,
+    )
+    "floor" => Type::function(
+        None,
+        Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return the floor of `x` as an Integral. This is the largest integer <= x.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to round down.
+
+        Examples
+        --------
+        import math
+
+        math.floor(2.5)  # 2.0
+        "#,
+        false,
+        None,
+    )
+    "gcd" => Type::function(
+        None,
+        Rc::new(Type::INT),
+        &[
+            Parameter {
+                name: "a".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: false,
+            },
+        ],
+        r#"Return the greatest common divisor of `a` and `b`.
+
+        Parameters
+        ----------
+        a: int
+            The first integer.
+        b: int
+            The second integer.
+
+        Examples
+        --------
+        import math
+
+        math.gcd(12, 18)  # 6
+        "#,
+        false,
+        None,
+    )
+    "isfinite" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return `True` if `x` is neither an infinity nor a NaN, and `False` otherwise.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to check.
+
+        Examples
+        --------
+        import math
+
+        math.isfinite(1.0)  # True
+        "#,
+        false,
+        None,
+    )
+    "isinf" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return `True` if `x` is a positive or negative infinity, and `False` otherwise.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to check.
+
+        Examples
+        --------
+        import math
+
+        math.isinf(1.0)  # False
+        "#,
+        false,
+        None,
+    )
+    "isnan" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return `True` if `x` is a NaN (not a number), and `False` otherwise.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to check.
+
+        Examples
+        --------
+        import math
+
+        math.isnan(1.0)  # False
+        "#,
+        false,
+        None,
+    )
+    "modf" => Type::function(
+        None,
+        Type::list_ref(Rc::new(Type::FLOAT)),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return the fractional and integer parts of `x`. Both results carry the sign of `x` and are floats.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to split.
+
+        Examples
+        --------
+        import math
+
+        math.modf(2.5)  # [0.5, 2.0]
+        "#,
+        false,
+        None,
+    )
+    "exp" => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return `e` raised to the power of `x`.
+
+        Parameters
+        ----------
+        x: int|float
+            The exponent.
+
+        Examples
+        --------
+        import math
+
+        math.exp(0)  # 1.0
+        "#,
+        false,
+        None,
+    )
+    "expm1" => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return `exp(x) - 1`. This function avoids the loss of precision involved in the direct evaluation of `exp(x) - 1` for small `x`.
+
+        Parameters
+        ----------
+        x: int|float
+            The exponent.
+
+        Examples
+        --------
+        import math
+
+        math.expm1(0)  # 0.0
+        "#,
+        false,
+        None,
+    )
+    "log" => Type::function(
+        None,
+        Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+            Parameter {
+                name: "e".to_string(),
+                ty: Rc::new(Type::FLOAT),
+                has_default: true,
+            },
+        ],
+        r#"Return the logarithm of `x` to the base `e`.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to compute the logarithm of.
+        e: float, optional
+            The base of the logarithm, the natural logarithm is computed by default.
+
+        Examples
+        --------
+        import math
+
+        math.log(100.0, 10.0)  # 2.0
+        "#,
+        false,
+        None,
+    )
+    "log1p" => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return the natural logarithm of `1+x` (base `e`). The result is computed in a way which is accurate for `x` near zero.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to compute the logarithm of `1+x`.
+
+        Examples
+        --------
+        import math
+
+        n = math.log1p(0.001)
+        "#,
+        false,
+        None,
+    )
+    "log2" => Type::function(
+        None,
+        Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return the base 2 logarithm of x.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to compute the logarithm of.
+
+        Examples
+        --------
+        import math
+
+        math.log2(8)  # 3
+        "#,
+        false,
+        None,
+    )
+    "log10" => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return the base 10 logarithm of `x`.
+
+        Parameters
+        ----------
+        x: int|float
+            The number to compute the logarithm of.
+
+        Examples
+        --------
+        import math
+
+        math.log10(100)  # 2.0
+        "#,
+        false,
+        None,
+    )
+    "pow" => Type::function(
+        None,
+        Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+            Parameter {
+                name: "y".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return `x**y` (`x` to the power of `y`).
+
+        Parameters
+        ----------
+        x: int|float
+            The base.
+        y: int|float
+            The exponent.
+
+        Examples
+        --------
+        import math
+
+        math.pow(2, 10)  # 1024
+        "#,
+        false,
+        None,
+    )
+    "sqrt" => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[
+            Parameter {
+                name: "x".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Return the square root of `x`.
+
+        Parameters
+        ----------
+        x: int|float
+            The non-negative number.
+
+        Examples
+        --------
+        import math
+
+        math.sqrt(16)  # 4.0
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const DATETIME: &str = "datetime";
 pub const DATETIME_FUNCTION_NAMES: [&str; 4] = ["today", "now", "ticks", "date"];
 
+register_system_module_functions! {
+    DATETIME_FUNCTION_TYPES,
+    "today" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[],
+        r#"Return the `datetime` today.
+
+        Examples
+        --------
+        import datetime
+
+        today = datetime.today()
+        "#,
+        false,
+        None,
+    )
+    "now" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[],
+        r#"Return the local time. e.g. `Sat Jun 06 16:26:11 1998`.
+
+        Examples
+        --------
+        import datetime
+
+        now = datetime.now()
+        "#,
+        false,
+        None,
+    )
+    "ticks" => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[],
+        r#"Return the current time in seconds since the Epoch. Fractions of a second may be present if the system clock provides them.
+
+        Examples
+        --------
+        import datetime
+
+        ticks = datetime.ticks()
+        "#,
+        false,
+        None,
+    )
+    "date" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[],
+        r#"Return the `datetime` string now.
+
+        Examples
+        --------
+        import datetime
+
+        date = datetime.date()
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const REGEX: &str = "regex";
 pub const REGEX_FUNCTION_NAMES: [&str; 6] =
     ["replace", "match", "compile", "findall", "search", "split"];
 
+register_system_module_functions! {
+    REGEX_FUNCTION_TYPES,
+    "replace" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "replace".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "count".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return the string obtained by replacing the leftmost non-overlapping occurrences of the pattern in string by the replacement.
+
+        Parameters
+        ----------
+        string: str
+            The string to replace in.
+        pattern: str
+            The regular expression to match.
+        replace: str
+            The replacement string.
+        count: int, optional
+            The maximum number of the replacements, 0 means replacing all the occurrences.
+
+        Examples
+        --------
+        import regex
+
+        regex.replace("a1b2c3", r"\d", "-")  # "a-b-c-"
+        regex.replace("a1b2c3", r"\d", "-", count=1)  # "a-b2c3"
+        "#,
+        false,
+        None,
+    )
+    "match" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Try to apply the pattern at the start of the string, returning a bool value `True` if any match was found, or `False` if no match was found.
+
+        Parameters
+        ----------
+        string: str
+            The string to match.
+        pattern: str
+            The regular expression to match.
+
+        Examples
+        --------
+        import regex
+
+        regex.match("kcl-lang", r"kcl")  # True
+        "#,
+        false,
+        None,
+    )
+    "compile" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Compile a regular expression pattern, returning a bool value denoting whether the pattern is valid.
+
+        Parameters
+        ----------
+        pattern: str
+            The regular expression to compile.
+
+        Examples
+        --------
+        import regex
+
+        regex.compile(r"[a-z]+")  # True
+        "#,
+        false,
+        None,
+    )
+    "findall" => Type::function(
+        None,
+        Type::list_ref(Rc::new(Type::STR)),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Return a list of all non-overlapping matches in the string.
+
+        Parameters
+        ----------
+        string: str
+            The string to search in.
+        pattern: str
+            The regular expression to match.
+
+        Examples
+        --------
+        import regex
+
+        regex.findall("a1b22c333", r"\d+")  # ["1", "22", "333"]
+        "#,
+        false,
+        None,
+    )
+    "search" => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Scan through string looking for a match to the pattern, returning a bool value `True` if any match was found, or `False` if no match was found.
+
+        Parameters
+        ----------
+        string: str
+            The string to search in.
+        pattern: str
+            The regular expression to match.
+
+        Examples
+        --------
+        import regex
+
+        regex.search("kcl-lang", r"lang")  # True
+        "#,
+        false,
+        None,
+    )
+    "split" => Type::function(
+        None,
+        Type::list_ref(Rc::new(Type::STR)),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "maxsplit".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+        ],
+        r#"Return a list composed of words from the string, splitting up to a maximum of `maxsplit` times using `pattern` as the separator.
+
+        Parameters
+        ----------
+        string: str
+            The string to split.
+        pattern: str
+            The regular expression of the separator.
+        maxsplit: int, optional
+            The maximum number of the splits, 0 means no limit.
+
+        Examples
+        --------
+        import regex
+
+        regex.split("a,b,c", ",")  # ["a", "b", "c"]
+        regex.split("a,b,c", ",", maxsplit=1)  # ["a", "b,c"]
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const YAML: &str = "yaml";
 pub const YAML_FUNCTION_NAMES: [&str; 3] = ["encode", "decode", "dump_to_file"];
 
+register_system_module_functions! {
+    YAML_FUNCTION_TYPES,
+    "encode" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "data".to_string(),
+                ty: Rc::new(Type::ANY),
+                has_default: false,
+            },
+            Parameter {
+                name: "sort_keys".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_private".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_none".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+        ],
+        r#"Serialize a KCL object `data` to a YAML formatted str.
+
+        Parameters
+        ----------
+        data: any
+            The KCL object to serialize.
+        sort_keys: bool, optional
+            Whether to sort the keys of the dicts and schemas, the default is False.
+        ignore_private: bool, optional
+            Whether to ignore the attributes whose names start with the character _, the default is False.
+        ignore_none: bool, optional
+            Whether to ignore the attributes with the value None, the default is False.
+
+        Examples
+        --------
+        import yaml
+
+        yaml.encode({name = "Alice", age = 18})  # "name: Alice\nage: 18\n"
+        yaml.encode({b = 1, a = None}, sort_keys=True, ignore_none=True)  # "b: 1\n"
+        "#,
+        false,
+        Some(1),
+    )
+    "decode" => Type::function(
+        None,
+        Rc::new(Type::ANY),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Deserialize `value` (a string instance containing a YAML document) to a KCL object.
+
+        Parameters
+        ----------
+        value: str
+            The YAML string to deserialize.
+
+        Examples
+        --------
+        import yaml
+
+        yaml.decode("name: Alice\nage: 18")  # {name = "Alice", age = 18}
+        "#,
+        false,
+        None,
+    )
+    "dump_to_file" => Type::function(
+        None,
+        Rc::new(Type::NONE),
+        &[
+            Parameter {
+                name: "data".to_string(),
+                ty: Rc::new(Type::ANY),
+                has_default: false,
+            },
+            Parameter {
+                name: "filename".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "sort_keys".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_private".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_none".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+        ],
+        r#"Serialize a KCL object `data` to a YAML formatted str and write it into the file `filename`.
+
+        Parameters
+        ----------
+        data: any
+            The KCL object to serialize.
+        filename: str
+            The path of the file to write.
+        sort_keys: bool, optional
+            Whether to sort the keys of the dicts and schemas, the default is False.
+        ignore_private: bool, optional
+            Whether to ignore the attributes whose names start with the character _, the default is False.
+        ignore_none: bool, optional
+            Whether to ignore the attributes with the value None, the default is False.
+
+        Examples
+        --------
+        import yaml
+
+        yaml.dump_to_file({name = "Alice"}, "person.yaml")
+        "#,
+        false,
+        Some(2),
+    )
+}
+
 pub const JSON: &str = "json";
 pub const JSON_FUNCTION_NAMES: [&str; 3] = ["encode", "decode", "dump_to_file"];
 
+register_system_module_functions! {
+    JSON_FUNCTION_TYPES,
+    "encode" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "data".to_string(),
+                ty: Rc::new(Type::ANY),
+                has_default: false,
+            },
+            Parameter {
+                name: "sort_keys".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+            Parameter {
+                name: "indent".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_private".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_none".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+        ],
+        r#"Serialize a KCL object `data` to a JSON formatted str.
+
+        Parameters
+        ----------
+        data: any
+            The KCL object to serialize.
+        sort_keys: bool, optional
+            Whether to sort the keys of the dicts and schemas, the default is False.
+        indent: int, optional
+            The number of spaces to indent the JSON output, the output is not indented by default.
+        ignore_private: bool, optional
+            Whether to ignore the attributes whose names start with the character _, the default is False.
+        ignore_none: bool, optional
+            Whether to ignore the attributes with the value None, the default is False.
+
+        Examples
+        --------
+        import json
+
+        json.encode({name = "Alice", age = 18})  # "{\"name\": \"Alice\", \"age\": 18}"
+        json.encode({b = 1, a = 2}, sort_keys=True, indent=2)  # "{\n  \"a\": 2,\n  \"b\": 1\n}"
+        "#,
+        false,
+        Some(1),
+    )
+    "decode" => Type::function(
+        None,
+        Rc::new(Type::ANY),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Deserialize `value` (a string instance containing a JSON document) to a KCL object.
+
+        Parameters
+        ----------
+        value: str
+            The JSON string to deserialize.
+
+        Examples
+        --------
+        import json
+
+        json.decode("{\"name\": \"Alice\", \"age\": 18}")  # {name = "Alice", age = 18}
+        "#,
+        false,
+        None,
+    )
+    "dump_to_file" => Type::function(
+        None,
+        Rc::new(Type::NONE),
+        &[
+            Parameter {
+                name: "data".to_string(),
+                ty: Rc::new(Type::ANY),
+                has_default: false,
+            },
+            Parameter {
+                name: "filename".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "sort_keys".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+            Parameter {
+                name: "indent".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_private".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_none".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+        ],
+        r#"Serialize a KCL object `data` to a JSON formatted str and write it into the file `filename`.
+
+        Parameters
+        ----------
+        data: any
+            The KCL object to serialize.
+        filename: str
+            The path of the file to write.
+        sort_keys: bool, optional
+            Whether to sort the keys of the dicts and schemas, the default is False.
+        indent: int, optional
+            The number of spaces to indent the JSON output, the output is not indented by default.
+        ignore_private: bool, optional
+            Whether to ignore the attributes whose names start with the character _, the default is False.
+        ignore_none: bool, optional
+            Whether to ignore the attributes with the value None, the default is False.
+
+        Examples
+        --------
+        import json
+
+        json.dump_to_file({name = "Alice"}, "person.json", indent=4)
+        "#,
+        false,
+        Some(2),
+    )
+}
+
 pub const CRYPTO: &str = "crypto";
 pub const CRYPTO_FUNCTION_NAMES: [&str; 6] =
     ["md5", "sha1", "sha224", "sha256", "sha384", "sha512"];
 
+register_system_module_functions! {
+    CRYPTO_FUNCTION_TYPES,
+    "md5" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `MD5` codec.
+
+        Parameters
+        ----------
+        value: str
+            The string to encrypt.
+        encoding: str, optional
+            The encoding of the string, the default is "utf-8".
+
+        Examples
+        --------
+        import crypto
+
+        crypto.md5("hello")  # "5d41402abc4b2a76b9719d911017c592"
+        "#,
+        false,
+        None,
+    )
+    "sha1" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA1` codec.
+
+        Parameters
+        ----------
+        value: str
+            The string to encrypt.
+        encoding: str, optional
+            The encoding of the string, the default is "utf-8".
+
+        Examples
+        --------
+        import crypto
+
+        crypto.sha1("hello")  # "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d"
+        "#,
+        false,
+        None,
+    )
+    "sha224" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA224` codec.
+
+        Parameters
+        ----------
+        value: str
+            The string to encrypt.
+        encoding: str, optional
+            The encoding of the string, the default is "utf-8".
+
+        Examples
+        --------
+        import crypto
+
+        crypto.sha224("hello")  # "ea09ae9cc6768c50fcee903ed054556e5bfc8347907f12598aa24193"
+        "#,
+        false,
+        None,
+    )
+    "sha256" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA256` codec.
+
+        Parameters
+        ----------
+        value: str
+            The string to encrypt.
+        encoding: str, optional
+            The encoding of the string, the default is "utf-8".
+
+        Examples
+        --------
+        import crypto
+
+        crypto.sha256("hello")  # "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        "#,
+        false,
+        None,
+    )
+    "sha384" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA384` codec.
+
+        Parameters
+        ----------
+        value: str
+            The string to encrypt.
+        encoding: str, optional
+            The encoding of the string, the default is "utf-8".
+
+        Examples
+        --------
+        import crypto
+
+        digest = crypto.sha384("hello")
+        "#,
+        false,
+        None,
+    )
+    "sha512" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA512` codec.
+
+        Parameters
+        ----------
+        value: str
+            The string to encrypt.
+        encoding: str, optional
+            The encoding of the string, the default is "utf-8".
+
+        Examples
+        --------
+        import crypto
+
+        digest = crypto.sha512("hello")
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const TESTING: &str = "testing";
 pub const TESTING_FUNCTION_NAMES: [&str; 2] = ["arguments", "setting_file"];
 
+register_system_module_functions! {
+    TESTING_FUNCTION_TYPES,
+    "arguments" => Type::function(
+        None,
+        Rc::new(Type::NONE),
+        &[
+            Parameter {
+                name: "name".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::ANY),
+                has_default: false,
+            },
+        ],
+        r#"Set arguments for option function in test.
+
+        Parameters
+        ----------
+        name: str
+            The name of the option.
+        value: any
+            The value of the option.
+
+        Examples
+        --------
+        import testing
+
+        testing.arguments("env", "prod")
+        "#,
+        false,
+        None,
+    )
+    "setting_file" => Type::function(
+        None,
+        Rc::new(Type::NONE),
+        &[
+            Parameter {
+                name: "filename".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Set setting file for option function in test.
+
+        Parameters
+        ----------
+        filename: str
+            The path of the setting file.
+
+        Examples
+        --------
+        import testing
+
+        testing.setting_file("settings.yaml")
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const UNITS: &str = "units";
 pub const UNITS_FUNCTION_NAMES: [&str; 13] = [
     "to_n", "to_u", "to_m", "to_K", "to_M", "to_G", "to_T", "to_P", "to_Ki", "to_Mi", "to_Gi",
     "to_Ti", "to_Pi",
 ];
+
+register_system_module_functions! {
+    UNITS_FUNCTION_TYPES,
+    "to_n" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `n` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_n(1.5)  # "1500000000n"
+        "#,
+        false,
+        None,
+    )
+    "to_u" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `u` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_u(2)  # "2000000u"
+        "#,
+        false,
+        None,
+    )
+    "to_m" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `m` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_m(0.5)  # "500m"
+        "#,
+        false,
+        None,
+    )
+    "to_K" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `K` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_K(1000)  # "1K"
+        "#,
+        false,
+        None,
+    )
+    "to_M" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `M` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_M(2000000)  # "2M"
+        "#,
+        false,
+        None,
+    )
+    "to_G" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `G` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_G(3000000000)  # "3G"
+        "#,
+        false,
+        None,
+    )
+    "to_T" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `T` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_T(1000000000000)  # "1T"
+        "#,
+        false,
+        None,
+    )
+    "to_P" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `P` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_P(1000000000000000)  # "1P"
+        "#,
+        false,
+        None,
+    )
+    "to_Ki" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Ki` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_Ki(1024)  # "1Ki"
+        "#,
+        false,
+        None,
+    )
+    "to_Mi" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Mi` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_Mi(2097152)  # "2Mi"
+        "#,
+        false,
+        None,
+    )
+    "to_Gi" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Gi` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_Gi(1073741824)  # "1Gi"
+        "#,
+        false,
+        None,
+    )
+    "to_Ti" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Ti` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_Ti(1099511627776)  # "1Ti"
+        "#,
+        false,
+        None,
+    )
+    "to_Pi" => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::FLOAT)]),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Pi` suffix
+
+        Parameters
+        ----------
+        num: int|float
+            The number to convert.
+
+        Examples
+        --------
+        import units
+
+        units.to_Pi(1125899906842624)  # "1Pi"
+        "#,
+        false,
+        None,
+    )
+}
 pub const UNITS_NUMBER_MULTIPLIER: &str = "NumberMultiplier";
 pub const UNITS_FIELD_NAMES: [&str; 15] = [
     "n",
@@ -93,6 +2256,36 @@ pub const UNITS_FIELD_NAMES: [&str; 15] = [
 pub const COLLECTION: &str = "collection";
 pub const COLLECTION_FUNCTION_NAMES: [&str; 1] = ["union_all"];
 
+register_system_module_functions! {
+    COLLECTION_FUNCTION_TYPES,
+    "union_all" => Type::function(
+        None,
+        Rc::new(Type::ANY),
+        &[
+            Parameter {
+                name: "data".to_string(),
+                ty: Type::list_ref(Rc::new(Type::ANY)),
+                has_default: false,
+            },
+        ],
+        r#"Union all object to one object.
+
+        Parameters
+        ----------
+        data: [any]
+            The list of the objects to union.
+
+        Examples
+        --------
+        import collection
+
+        collection.union_all([{a = 1}, {b = 2}])  # {a = 1, b = 2}
+        "#,
+        false,
+        None,
+    )
+}
+
 pub const STANDARD_SYSTEM_MODULES: [&str; 12] = [
     COLLECTION, NET, MANIFESTS, MATH, DATETIME, REGEX, YAML, JSON, CRYPTO, BASE64, TESTING, UNITS,
 ];
@@ -134,3 +2327,23 @@ pub fn get_system_module_members(name: &str) -> Vec<&str> {
         _ => bug!("invalid system module name '{}'", name),
     }
 }
+
+/// Get the function type of the system module member function.
+pub fn get_system_member_function_ty(name: &str, func: &str) -> Option<Rc<Type>> {
+    let function_types = match name {
+        BASE64 => BASE64_FUNCTION_TYPES,
+        NET => NET_FUNCTION_TYPES,
+        MANIFESTS => MANIFESTS_FUNCTION_TYPES,
+        MATH => MATH_FUNCTION_TYPES,
+        DATETIME => DATETIME_FUNCTION_TYPES,
+        REGEX => REGEX_FUNCTION_TYPES,
+        YAML => YAML_FUNCTION_TYPES,
+        JSON => JSON_FUNCTION_TYPES,
+        CRYPTO => CRYPTO_FUNCTION_TYPES,
+        TESTING => TESTING_FUNCTION_TYPES,
+        UNITS => UNITS_FUNCTION_TYPES,
+        COLLECTION => COLLECTION_FUNCTION_TYPES,
+        _ => return None,
+    };
+    function_types.get(func).map(|ty| Rc::new(ty.clone()))
+}
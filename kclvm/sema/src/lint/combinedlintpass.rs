@@ -1,8 +1,18 @@
 use crate::lint::lint::{LintArray, LintContext};
 use crate::lint::lintpass::LintPass;
+use crate::lint::lints_def::ConstantCondition;
+use crate::lint::lints_def::DuplicateConfigKey;
 use crate::lint::lints_def::ImportPosition;
+use crate::lint::lints_def::MutableDefault;
+use crate::lint::lints_def::NameShadowing;
 use crate::lint::lints_def::ReImport;
+use crate::lint::lints_def::SelfComparison;
 use crate::lint::lints_def::UnusedImport;
+use crate::lint::lints_def::UnusedLambdaParameter;
+use crate::lint::lints_def::UnusedPrivateVariable;
+use crate::lint::lints_def::UnusedSchema;
+use crate::lint::lints_def::UnusedSchemaLocalVariable;
+use crate::lint::lints_def::UnusedTypeAlias;
 use crate::lint_methods;
 use crate::resolver::scope::Scope;
 use kclvm_ast::ast;
@@ -112,6 +122,16 @@ macro_rules! default_lint_passes {
                 ImportPosition: ImportPosition,
                 UnusedImport: UnusedImport,
                 ReImport: ReImport,
+                UnusedPrivateVariable: UnusedPrivateVariable,
+                UnusedLambdaParameter: UnusedLambdaParameter,
+                UnusedSchemaLocalVariable: UnusedSchemaLocalVariable,
+                UnusedTypeAlias: UnusedTypeAlias,
+                UnusedSchema: UnusedSchema,
+                NameShadowing: NameShadowing::default(),
+                SelfComparison: SelfComparison,
+                ConstantCondition: ConstantCondition,
+                DuplicateConfigKey: DuplicateConfigKey,
+                MutableDefault: MutableDefault,
             ]
         );
     };
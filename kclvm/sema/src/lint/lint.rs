@@ -1,4 +1,4 @@
-use kclvm_error::{Level, Position};
+use kclvm_error::{Level, Position, WarningKind};
 
 /// Record the information at `LintContext` when traversing the AST for analysis across AST nodes, e.g., record
 /// used importstmt(used_import_names) when traversing `ast::Identifier` and `ast::SchemaAttr`, and detect unused
@@ -6,6 +6,8 @@ use kclvm_error::{Level, Position};
 pub struct LintContext {
     /// What source file are we in.
     pub filename: String,
+    /// What package path are we in.
+    pub pkgpath: String,
     /// Are we resolving the ast node start position.
     pub start_pos: Position,
     /// Are we resolving the ast node end position.
@@ -28,12 +30,60 @@ pub struct Lint {
     // Error/Warning code
     pub code: &'static str,
 
+    /// The warning kind of the diagnostics reported by the lint.
+    pub kind: WarningKind,
+
     // Suggest methods to fix this problem
     pub note: Option<&'static str>,
 }
 
 pub type LintArray = Vec<&'static Lint>;
 
+impl Lint {
+    /// Returns the lowercase name used in the lint configurations and the suppression
+    /// comments, e.g., `unused_import`.
+    pub fn name_lower(&self) -> String {
+        self.name.to_ascii_lowercase()
+    }
+}
+
+/// The level of a lint set by the `[lint]` section of `kcl.mod` or the lint config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    /// The lint is disabled.
+    Allow,
+    /// The lint is reported as a warning.
+    Warn,
+    /// The lint is reported as an error.
+    Deny,
+}
+
+impl LintLevel {
+    /// Returns the default level of the lint.
+    pub fn from_lint(lint: &Lint) -> Self {
+        match lint.level {
+            Level::Error => LintLevel::Deny,
+            Level::Warning | Level::Note => LintLevel::Warn,
+        }
+    }
+}
+
+impl std::str::FromStr for LintLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            _ => Err(format!(
+                "invalid lint level '{}', expected 'allow', 'warn' or 'deny'",
+                s
+            )),
+        }
+    }
+}
+
 /// Declares a static `LintArray` and return it as an expression.
 #[macro_export]
 macro_rules! lint_array {
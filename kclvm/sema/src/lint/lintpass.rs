@@ -23,12 +23,12 @@ macro_rules! lint_methods {
             // fn check_expr_stmt(expr_stmt: &ast::ExprStmt);
             // fn check_unification_stmt(unification_stmt: &ast::UnificationStmt);
             // fn check_type_alias_stmt(type_alias_stmt: &ast::TypeAliasStmt);
-            // fn check_assign_stmt(assign_stmt: &ast::AssignStmt);
+            fn check_assign_stmt(_assign_stmt: &ast::AssignStmt);
             // fn check_aug_assign_stmt(aug_assign_stmt: &ast::AugAssignStmt);
-            // fn check_assert_stmt(assert_stmt: &ast::AssertStmt);
-            // fn check_if_stmt(if_stmt: &ast::IfStmt);
+            fn check_assert_stmt(_assert_stmt: &ast::AssertStmt);
+            fn check_if_stmt(_if_stmt: &ast::IfStmt);
             // fn check_import_stmt(import_stmt: &ast::ImportStmt);
-            // fn check_schema_stmt(schema_stmt: &ast::SchemaStmt);
+            fn check_schema_stmt(_schema_stmt: &ast::SchemaStmt);
             // fn check_rule_stmt(rule_stmt: &ast::RuleStmt);
 
             /*
@@ -37,7 +37,7 @@ macro_rules! lint_methods {
 
             // fn check_expr(expr: &ast::Node<&ast::Expr>);
             // fn check_quant_expr(quant_expr: &ast::QuantExpr);
-            // fn check_schema_attr(schema_attr: &ast::SchemaAttr);
+            fn check_schema_attr(_schema_attr: &ast::SchemaAttr);
             // fn check_if_expr(if_expr: &ast::IfExpr);
             // fn check_unary_expr(unary_expr: &ast::UnaryExpr);
             // fn check_binary_expr(binary_expr: &ast::BinaryExpr);
@@ -54,12 +54,12 @@ macro_rules! lint_methods {
             // );
             // fn check_comp_clause(comp_clause: &ast::CompClause);
             // fn check_schema_expr(schema_expr: &ast::SchemaExpr);
-            // fn check_config_expr(config_expr: &ast::ConfigExpr);
-            // fn check_check_expr(check_expr: &ast::CheckExpr);
-            // fn check_lambda_expr(lambda_expr: &ast::LambdaExpr);
+            fn check_config_expr(_config_expr: &ast::ConfigExpr);
+            fn check_check_expr(_check_expr: &ast::CheckExpr);
+            fn check_lambda_expr(_lambda_expr: &ast::LambdaExpr);
             // fn check_keyword(keyword: &ast::Keyword);
             // fn check_arguments(arguments: &ast::Arguments);
-            // fn check_compare(compare: &ast::Compare);
+            fn check_compare(_compare: &ast::Compare);
             // fn check_identifier(id: &ast::Identifier);
             // fn check_number_lit(number_lit: &ast::NumberLit);
             // fn check_string_lit(string_lit: &ast::StringLit);
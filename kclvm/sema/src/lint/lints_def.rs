@@ -1,9 +1,13 @@
+use crate::builtin::BUILTIN_FUNCTION_NAMES;
+use crate::info::is_private_field;
 use crate::lint::lint::{Lint, LintArray, LintContext};
 use crate::lint::lintpass::LintPass;
-use crate::resolver::scope::Scope;
-use crate::{declare_lint_pass, resolver::scope::ScopeObjectKind};
+use crate::resolver::scope::{Scope, ScopeKind, ScopeObject};
+use crate::ty::TypeKind;
+use crate::{declare_lint_pass, impl_lint_pass, resolver::scope::ScopeObjectKind};
 use indexmap::IndexSet;
-use kclvm_ast::ast;
+use kclvm_ast::pos::GetPos;
+use kclvm_ast::{ast, MAIN_PKG};
 use kclvm_error::{Handler, Level, Message, Position, Style, WarningKind};
 
 /// The 'import_position' lint detects import statements that are not declared at the top of file.
@@ -20,10 +24,11 @@ use kclvm_error::{Handler, Level, Message, Position, Style, WarningKind};
 ///
 /// According to the KCL code style conventions, import statement are always declared at the top of the file.
 pub static IMPORT_POSITION: &Lint = &Lint {
-    name: stringify!("IMPORT_POSITION"),
+    name: stringify!(IMPORT_POSITION),
     level: Level::Warning,
     desc: "Check for importstmt that are not defined at the top of file",
     code: "W0413",
+    kind: WarningKind::ImportPositionWarning,
     note: Some("Consider moving tihs statement to the top of the file"),
 };
 
@@ -83,10 +88,11 @@ impl LintPass for ImportPosition {
 ///
 /// Useless imports can affect the speed of compilation. It is necessary to remove useless imports from the kcl code.
 pub static UNUSED_IMPORT: &Lint = &Lint {
-    name: stringify!("UNUSED_IMPORT"),
+    name: stringify!(UNUSED_IMPORT),
     level: Level::Warning,
     desc: "Check for unused importstmt",
     code: "W0411",
+    kind: WarningKind::UnusedImportWarning,
     note: Some("Consider removing this statement"),
 };
 
@@ -131,10 +137,11 @@ impl LintPass for UnusedImport {
 ///
 /// The import statement should be declared only once
 pub static REIMPORT: &Lint = &Lint {
-    name: stringify!("REIMPORT"),
+    name: stringify!(REIMPORT),
     level: Level::Warning,
     desc: "Check for deplicate importstmt",
     code: "W0404",
+    kind: WarningKind::ReimportWarning,
     note: Some("Consider removing this statement"),
 };
 
@@ -169,3 +176,687 @@ impl LintPass for ReImport {
         }
     }
 }
+
+/// The 'unused_private_variable' lint detects private top-level variables that are assigned but never used.
+///
+/// ### Example
+///
+/// ```kcl
+/// _port = 80
+///
+/// name = "app"
+/// ```
+/// ### Explanation
+///
+/// The private variables whose names start with `_` are not output, an unused private variable is dead code.
+pub static UNUSED_PRIVATE_VARIABLE: &Lint = &Lint {
+    name: stringify!(UNUSED_PRIVATE_VARIABLE),
+    level: Level::Warning,
+    desc: "Check for unused private variables",
+    code: "W1002",
+    kind: WarningKind::UnusedPrivateVariableWarning,
+    note: Some("Consider removing this variable"),
+};
+
+declare_lint_pass!(UnusedPrivateVariable => [UNUSED_PRIVATE_VARIABLE]);
+
+impl LintPass for UnusedPrivateVariable {
+    fn check_scope(&mut self, handler: &mut Handler, _ctx: &mut LintContext, scope: &Scope) {
+        if !matches!(scope.kind, ScopeKind::Package(_)) {
+            return;
+        }
+        for scope_obj in scope.elems.values() {
+            let scope_obj = scope_obj.borrow();
+            if scope_obj.kind == ScopeObjectKind::Variable
+                && is_private_field(&scope_obj.name)
+                && !scope_obj.used
+            {
+                add_unused_warning(
+                    handler,
+                    UNUSED_PRIVATE_VARIABLE,
+                    &scope_obj,
+                    format!("Private variable '{}' assigned but unused", scope_obj.name),
+                );
+            }
+        }
+    }
+}
+
+/// The 'unused_lambda_parameter' lint detects lambda parameters that are never used in the lambda body.
+///
+/// ### Example
+///
+/// ```kcl
+/// f = lambda x, y {
+///     x * 2
+/// }
+/// ```
+/// ### Explanation
+///
+/// An unused parameter is usually a mistake, the parameter whose name starts with `_` is ignored.
+pub static UNUSED_LAMBDA_PARAMETER: &Lint = &Lint {
+    name: stringify!(UNUSED_LAMBDA_PARAMETER),
+    level: Level::Warning,
+    desc: "Check for unused lambda parameters",
+    code: "W1003",
+    kind: WarningKind::UnusedLambdaParameterWarning,
+    note: Some("Consider removing this parameter or prefixing it with '_'"),
+};
+
+declare_lint_pass!(UnusedLambdaParameter => [UNUSED_LAMBDA_PARAMETER]);
+
+impl LintPass for UnusedLambdaParameter {
+    fn check_scope(&mut self, handler: &mut Handler, _ctx: &mut LintContext, scope: &Scope) {
+        if !matches!(scope.kind, ScopeKind::Lambda) {
+            return;
+        }
+        for scope_obj in scope.elems.values() {
+            let scope_obj = scope_obj.borrow();
+            if scope_obj.kind == ScopeObjectKind::Parameter
+                && !is_private_field(&scope_obj.name)
+                && !scope_obj.used
+            {
+                add_unused_warning(
+                    handler,
+                    UNUSED_LAMBDA_PARAMETER,
+                    &scope_obj,
+                    format!("Lambda parameter '{}' defined but unused", scope_obj.name),
+                );
+            }
+        }
+    }
+}
+
+/// The 'unused_schema_local_variable' lint detects private schema variables that are assigned but never
+/// used in the schema and its sub schemas.
+///
+/// ### Example
+///
+/// ```kcl
+/// schema Service:
+///     _port: int = 80
+///     name: str
+/// ```
+/// ### Explanation
+///
+/// The private schema variables whose names start with `_` are not output, an unused one is dead code.
+pub static UNUSED_SCHEMA_LOCAL_VARIABLE: &Lint = &Lint {
+    name: stringify!(UNUSED_SCHEMA_LOCAL_VARIABLE),
+    level: Level::Warning,
+    desc: "Check for unused schema local variables",
+    code: "W1004",
+    kind: WarningKind::UnusedSchemaLocalVariableWarning,
+    note: Some("Consider removing this variable"),
+};
+
+declare_lint_pass!(UnusedSchemaLocalVariable => [UNUSED_SCHEMA_LOCAL_VARIABLE]);
+
+impl LintPass for UnusedSchemaLocalVariable {
+    fn check_scope(&mut self, handler: &mut Handler, _ctx: &mut LintContext, scope: &Scope) {
+        if !matches!(scope.kind, ScopeKind::Schema(_)) {
+            return;
+        }
+        for scope_obj in scope.elems.values() {
+            let scope_obj = scope_obj.borrow();
+            if matches!(
+                scope_obj.kind,
+                ScopeObjectKind::Variable | ScopeObjectKind::Attribute
+            ) && is_private_field(&scope_obj.name)
+                && !scope_obj.used
+            {
+                add_unused_warning(
+                    handler,
+                    UNUSED_SCHEMA_LOCAL_VARIABLE,
+                    &scope_obj,
+                    format!(
+                        "Schema local variable '{}' assigned but unused",
+                        scope_obj.name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// The 'unused_type_alias' lint detects type aliases that are defined but never used.
+///
+/// ### Example
+///
+/// ```kcl
+/// type Port = int
+///
+/// port: int = 80
+/// ```
+/// ### Explanation
+///
+/// An unused type alias is dead code. Only the main package is checked, because the type aliases
+/// in the imported packages may be used by other programs.
+pub static UNUSED_TYPE_ALIAS: &Lint = &Lint {
+    name: stringify!(UNUSED_TYPE_ALIAS),
+    level: Level::Warning,
+    desc: "Check for unused type aliases",
+    code: "W1005",
+    kind: WarningKind::UnusedTypeAliasWarning,
+    note: Some("Consider removing this type alias"),
+};
+
+declare_lint_pass!(UnusedTypeAlias => [UNUSED_TYPE_ALIAS]);
+
+impl LintPass for UnusedTypeAlias {
+    fn check_scope(&mut self, handler: &mut Handler, ctx: &mut LintContext, scope: &Scope) {
+        if ctx.pkgpath != MAIN_PKG || !matches!(scope.kind, ScopeKind::Package(_)) {
+            return;
+        }
+        for scope_obj in scope.elems.values() {
+            let scope_obj = scope_obj.borrow();
+            if scope_obj.kind == ScopeObjectKind::TypeAlias && !scope_obj.used {
+                add_unused_warning(
+                    handler,
+                    UNUSED_TYPE_ALIAS,
+                    &scope_obj,
+                    format!("Type alias '{}' defined but unused", scope_obj.name),
+                );
+            }
+        }
+    }
+}
+
+/// The 'unused_schema' lint detects schemas that are never instantiated nor referenced in the whole program.
+///
+/// ### Example
+///
+/// ```kcl
+/// schema Person:
+///     name: str
+///
+/// person = {name = "Alice"}
+/// ```
+/// ### Explanation
+///
+/// A schema is used when it is instantiated or referenced as a type annotation, a base schema, a mixin
+/// or a protocol. Only the main package is checked, because the schemas in the imported packages may be
+/// used by other programs.
+pub static UNUSED_SCHEMA: &Lint = &Lint {
+    name: stringify!(UNUSED_SCHEMA),
+    level: Level::Warning,
+    desc: "Check for unused schemas",
+    code: "W1006",
+    kind: WarningKind::UnusedSchemaWarning,
+    note: Some("Consider removing this schema"),
+};
+
+declare_lint_pass!(UnusedSchema => [UNUSED_SCHEMA]);
+
+impl LintPass for UnusedSchema {
+    fn check_scope(&mut self, handler: &mut Handler, ctx: &mut LintContext, scope: &Scope) {
+        if ctx.pkgpath != MAIN_PKG || !matches!(scope.kind, ScopeKind::Package(_)) {
+            return;
+        }
+        for scope_obj in scope.elems.values() {
+            let scope_obj = scope_obj.borrow();
+            let is_schema =
+                matches!(&scope_obj.ty.kind, TypeKind::Schema(schema_ty) if !schema_ty.is_rule);
+            if scope_obj.kind == ScopeObjectKind::Definition && is_schema && !scope_obj.used {
+                add_unused_warning(
+                    handler,
+                    UNUSED_SCHEMA,
+                    &scope_obj,
+                    format!("Schema '{}' defined but unused", scope_obj.name),
+                );
+            }
+        }
+    }
+}
+
+fn add_unused_warning(
+    handler: &mut Handler,
+    lint: &Lint,
+    scope_obj: &ScopeObject,
+    message: String,
+) {
+    handler.add_warning(
+        lint.kind.clone(),
+        &[Message {
+            pos: scope_obj.start.clone(),
+            style: Style::LineAndColumn,
+            message,
+            note: lint.note.map(|note| note.to_string()),
+        }],
+    );
+}
+
+/// The 'name_shadowing' lint detects variables and lambda parameters that shadow a builtin function
+/// or an imported module.
+///
+/// ### Example
+///
+/// ```kcl
+/// import math
+///
+/// len = 1
+/// f = lambda math {
+///     math + 1
+/// }
+/// ```
+/// ### Explanation
+///
+/// The builtin function or the imported module can not be accessed after it is shadowed, and the
+/// code reading the name is easily misunderstood.
+pub static NAME_SHADOWING: &Lint = &Lint {
+    name: stringify!(NAME_SHADOWING),
+    level: Level::Warning,
+    desc: "Check for variables that shadow a builtin function or an imported module",
+    code: "W1007",
+    kind: WarningKind::NameShadowingWarning,
+    note: Some("Consider renaming this variable"),
+};
+
+/// The lintpass records the import names of the module being checked.
+#[derive(Default, Clone)]
+pub struct NameShadowing {
+    import_names: IndexSet<String>,
+}
+
+impl_lint_pass!(NameShadowing => [NAME_SHADOWING]);
+
+impl NameShadowing {
+    fn check_name(&self, handler: &mut Handler, kind: &str, name: &str, pos: Position) {
+        let shadowed = if BUILTIN_FUNCTION_NAMES.contains(&name) {
+            "builtin function"
+        } else if self.import_names.contains(name) {
+            "imported module"
+        } else {
+            return;
+        };
+        handler.add_warning(
+            WarningKind::NameShadowingWarning,
+            &[Message {
+                pos,
+                style: Style::LineAndColumn,
+                message: format!("{} '{}' shadows the {} '{}'", kind, name, shadowed, name),
+                note: NAME_SHADOWING.note.map(|note| note.to_string()),
+            }],
+        );
+    }
+}
+
+impl LintPass for NameShadowing {
+    fn check_module(
+        &mut self,
+        _handler: &mut Handler,
+        _ctx: &mut LintContext,
+        module: &ast::Module,
+    ) {
+        self.import_names.clear();
+        for stmt in &module.body {
+            if let ast::Stmt::Import(import_stmt) = &stmt.node {
+                self.import_names.insert(import_stmt.name.clone());
+            }
+        }
+    }
+
+    fn check_assign_stmt(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        assign_stmt: &ast::AssignStmt,
+    ) {
+        for target in &assign_stmt.targets {
+            if target.node.names.len() == 1 && target.node.pkgpath.is_empty() {
+                self.check_name(handler, "Variable", &target.node.names[0], target.get_pos());
+            }
+        }
+    }
+
+    fn check_lambda_expr(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        lambda_expr: &ast::LambdaExpr,
+    ) {
+        if let Some(args) = &lambda_expr.args {
+            for arg in &args.node.args {
+                self.check_name(
+                    handler,
+                    "Lambda parameter",
+                    &arg.node.get_name(),
+                    arg.get_pos(),
+                );
+            }
+        }
+    }
+}
+
+/// The 'self_comparison' lint detects comparisons whose operands are identical.
+///
+/// ### Example
+///
+/// ```kcl
+/// a = 1
+/// b = a == a
+/// ```
+/// ### Explanation
+///
+/// The result of comparing an expression with itself is always the same, it is usually a typo
+/// of another operand.
+pub static SELF_COMPARISON: &Lint = &Lint {
+    name: stringify!(SELF_COMPARISON),
+    level: Level::Warning,
+    desc: "Check for comparisons whose operands are identical",
+    code: "W1008",
+    kind: WarningKind::SelfComparisonWarning,
+    note: Some("Consider checking the operands of this comparison"),
+};
+
+declare_lint_pass!(SelfComparison => [SELF_COMPARISON]);
+
+impl LintPass for SelfComparison {
+    fn check_compare(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        compare: &ast::Compare,
+    ) {
+        let mut left = &compare.left;
+        for (op, right) in compare.ops.iter().zip(&compare.comparators) {
+            let result = match op {
+                ast::CmpOp::Eq | ast::CmpOp::LtE | ast::CmpOp::GtE | ast::CmpOp::Is => Some(true),
+                ast::CmpOp::NotEq | ast::CmpOp::Lt | ast::CmpOp::Gt | ast::CmpOp::IsNot => {
+                    Some(false)
+                }
+                _ => None,
+            };
+            if let Some(result) = result {
+                if !is_constant_expr(&left.node) && is_same_expr(&left.node, &right.node) {
+                    handler.add_warning(
+                        WarningKind::SelfComparisonWarning,
+                        &[Message {
+                            pos: left.get_pos(),
+                            style: Style::LineAndColumn,
+                            message: format!(
+                                "Comparison of an expression with itself is always {}",
+                                bool_str(result)
+                            ),
+                            note: SELF_COMPARISON.note.map(|note| note.to_string()),
+                        }],
+                    );
+                }
+            }
+            left = right;
+        }
+    }
+}
+
+/// The 'constant_condition' lint detects `if`, `assert` and `check` conditions that are compile-time
+/// constant.
+///
+/// ### Example
+///
+/// ```kcl
+/// if 1 > 0:
+///     a = 1
+/// ```
+/// ### Explanation
+///
+/// The branch of a constant condition is either always or never taken, which is usually a leftover
+/// of debugging. `assert False` is not checked because it is used to raise an error on purpose.
+pub static CONSTANT_CONDITION: &Lint = &Lint {
+    name: stringify!(CONSTANT_CONDITION),
+    level: Level::Warning,
+    desc: "Check for conditions that are compile-time constant",
+    code: "W1009",
+    kind: WarningKind::ConstantConditionWarning,
+    note: Some("Consider removing this condition"),
+};
+
+declare_lint_pass!(ConstantCondition => [CONSTANT_CONDITION]);
+
+impl ConstantCondition {
+    fn check_condition(&self, handler: &mut Handler, kind: &str, cond: &ast::NodeRef<ast::Expr>) {
+        if is_constant_expr(&cond.node) {
+            handler.add_warning(
+                WarningKind::ConstantConditionWarning,
+                &[Message {
+                    pos: cond.get_pos(),
+                    style: Style::LineAndColumn,
+                    message: format!(
+                        "The condition of the {} statement is a compile-time constant",
+                        kind
+                    ),
+                    note: CONSTANT_CONDITION.note.map(|note| note.to_string()),
+                }],
+            );
+        }
+    }
+}
+
+impl LintPass for ConstantCondition {
+    fn check_if_stmt(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        if_stmt: &ast::IfStmt,
+    ) {
+        self.check_condition(handler, "if", &if_stmt.cond);
+    }
+
+    fn check_assert_stmt(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        assert_stmt: &ast::AssertStmt,
+    ) {
+        let is_false = matches!(
+            &assert_stmt.test.node,
+            ast::Expr::NameConstantLit(lit) if matches!(lit.value, ast::NameConstant::False)
+        );
+        if assert_stmt.if_cond.is_none() && !is_false {
+            self.check_condition(handler, "assert", &assert_stmt.test);
+        }
+    }
+
+    fn check_check_expr(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        check_expr: &ast::CheckExpr,
+    ) {
+        if check_expr.if_cond.is_none() {
+            self.check_condition(handler, "check", &check_expr.test);
+        }
+    }
+}
+
+/// The 'duplicate_config_key' lint detects keys in a config literal that are overridden by a later
+/// entry with the same key.
+///
+/// ### Example
+///
+/// ```kcl
+/// config = {
+///     name = "app"
+///     name = "server"
+/// }
+/// ```
+/// ### Explanation
+///
+/// The former value is silently overridden by the latter one with the `=` operator, it is usually
+/// a copy-paste mistake.
+pub static DUPLICATE_CONFIG_KEY: &Lint = &Lint {
+    name: stringify!(DUPLICATE_CONFIG_KEY),
+    level: Level::Warning,
+    desc: "Check for duplicate keys in config literals",
+    code: "W1010",
+    kind: WarningKind::DuplicateConfigKeyWarning,
+    note: Some("Consider removing the duplicate entries"),
+};
+
+declare_lint_pass!(DuplicateConfigKey => [DUPLICATE_CONFIG_KEY]);
+
+impl LintPass for DuplicateConfigKey {
+    fn check_config_expr(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        config_expr: &ast::ConfigExpr,
+    ) {
+        let mut keys = IndexSet::<String>::new();
+        for item in &config_expr.items {
+            let key = match &item.node.key {
+                Some(key) => key,
+                None => continue,
+            };
+            let key_name = match &key.node {
+                ast::Expr::Identifier(identifier) => identifier.names.join("."),
+                ast::Expr::StringLit(string_lit) => string_lit.value.clone(),
+                _ => continue,
+            };
+            if !keys.insert(key_name.clone())
+                && matches!(item.node.operation, ast::ConfigEntryOperation::Override)
+            {
+                handler.add_warning(
+                    WarningKind::DuplicateConfigKeyWarning,
+                    &[Message {
+                        pos: key.get_pos(),
+                        style: Style::LineAndColumn,
+                        message: format!(
+                            "Duplicate config key '{}' overrides the former value",
+                            key_name
+                        ),
+                        note: DUPLICATE_CONFIG_KEY.note.map(|note| note.to_string()),
+                    }],
+                );
+            }
+        }
+    }
+}
+
+/// The 'mutable_default' lint detects the non-empty list and dict default values of schema attributes.
+///
+/// ### Example
+///
+/// ```kcl
+/// schema App:
+///     labels: {str:str} = {app = "web"}
+///
+/// app = App {labels: {env = "prod"}}
+/// ```
+/// ### Explanation
+///
+/// The default value is unioned with the config value instead of being replaced, e.g., the `labels`
+/// of `app` is `{app = "web", env = "prod"}`.
+pub static MUTABLE_DEFAULT: &Lint = &Lint {
+    name: stringify!(MUTABLE_DEFAULT),
+    level: Level::Warning,
+    desc: "Check for non-empty list and dict default values of schema attributes",
+    code: "W1011",
+    kind: WarningKind::MutableDefaultWarning,
+    note: Some("Consider using an empty default value"),
+};
+
+declare_lint_pass!(MutableDefault => [MUTABLE_DEFAULT]);
+
+impl LintPass for MutableDefault {
+    fn check_schema_attr(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        schema_attr: &ast::SchemaAttr,
+    ) {
+        let value = match &schema_attr.value {
+            Some(value) => value,
+            None => return,
+        };
+        let is_mutable = match &value.node {
+            ast::Expr::List(list_expr) => !list_expr.elts.is_empty(),
+            ast::Expr::Config(config_expr) => !config_expr.items.is_empty(),
+            ast::Expr::ListComp(_) | ast::Expr::DictComp(_) => true,
+            _ => false,
+        };
+        if is_mutable {
+            handler.add_warning(
+                WarningKind::MutableDefaultWarning,
+                &[Message {
+                    pos: value.get_pos(),
+                    style: Style::LineAndColumn,
+                    message: format!(
+                        "Mutable default value of attribute '{}' is unioned with config values",
+                        schema_attr.name.node
+                    ),
+                    note: MUTABLE_DEFAULT.note.map(|note| note.to_string()),
+                }],
+            );
+        }
+    }
+}
+
+/// Whether the expression is made up of literals only, e.g., `1 > 0` and `not True`.
+fn is_constant_expr(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::NameConstantLit(_) | ast::Expr::NumberLit(_) | ast::Expr::StringLit(_) => true,
+        ast::Expr::Paren(paren_expr) => is_constant_expr(&paren_expr.expr.node),
+        ast::Expr::Unary(unary_expr) => is_constant_expr(&unary_expr.operand.node),
+        ast::Expr::Binary(binary_expr) => {
+            is_constant_expr(&binary_expr.left.node) && is_constant_expr(&binary_expr.right.node)
+        }
+        ast::Expr::Compare(compare) => {
+            is_constant_expr(&compare.left.node)
+                && compare
+                    .comparators
+                    .iter()
+                    .all(|comparator| is_constant_expr(&comparator.node))
+        }
+        _ => false,
+    }
+}
+
+/// Whether the two expressions are the same variable, attribute or element, e.g., `a.b` and `(a.b)`.
+fn is_same_expr(left: &ast::Expr, right: &ast::Expr) -> bool {
+    match (left, right) {
+        (ast::Expr::Paren(paren_expr), _) => is_same_expr(&paren_expr.expr.node, right),
+        (_, ast::Expr::Paren(paren_expr)) => is_same_expr(left, &paren_expr.expr.node),
+        (ast::Expr::Identifier(left), ast::Expr::Identifier(right)) => {
+            left.names == right.names && left.pkgpath == right.pkgpath
+        }
+        (ast::Expr::Selector(left), ast::Expr::Selector(right)) => {
+            left.has_question == right.has_question
+                && left.attr.node.names == right.attr.node.names
+                && is_same_expr(&left.value.node, &right.value.node)
+        }
+        (ast::Expr::Subscript(left), ast::Expr::Subscript(right)) => {
+            let is_slice = |subscript: &ast::Subscript| {
+                subscript.lower.is_some() || subscript.upper.is_some() || subscript.step.is_some()
+            };
+            match (&left.index, &right.index) {
+                (Some(left_index), Some(right_index)) => {
+                    !is_slice(left)
+                        && !is_slice(right)
+                        && left.has_question == right.has_question
+                        && is_same_expr(&left.value.node, &right.value.node)
+                        && is_same_expr(&left_index.node, &right_index.node)
+                }
+                _ => false,
+            }
+        }
+        (ast::Expr::StringLit(left), ast::Expr::StringLit(right)) => left.value == right.value,
+        (ast::Expr::NumberLit(left), ast::Expr::NumberLit(right)) => {
+            match (&left.value, &right.value) {
+                (ast::NumberLitValue::Int(left_value), ast::NumberLitValue::Int(right_value)) => {
+                    left_value == right_value
+                        && left.binary_suffix.is_none()
+                        && right.binary_suffix.is_none()
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn bool_str(value: bool) -> &'static str {
+    if value {
+        "True"
+    } else {
+        "False"
+    }
+}
@@ -71,10 +71,17 @@
 //! 6. If new `check_*` method was added in step 4, it needs to override the walk_* method in Linter.
 //! In addition to calling the self.pass.check_* function, the original walk method in MutSelfWalker
 //! should be copied here so that it can continue to traverse the child nodes.
+//!
+//! Lint levels:
+//! Each lint is reported with its default level unless it is set to `allow`, `warn` or `deny` by its lowercase
+//! name in the `lint_levels` of the resolve options, e.g., `unused_import = "allow"` in the `[lint]` section of
+//! `kcl.mod`. A lint can also be suppressed by the comments `# kcl-lint: disable=unused_import` and
+//! `# kcl-lint: disable-next-line=unused_import`.
 
 use crate::resolver::{scope::Scope, Resolver};
+use indexmap::{IndexMap, IndexSet};
 use kclvm_ast::pos::GetPos;
-use kclvm_error::{Handler, Position};
+use kclvm_error::{DiagnosticId, Handler, Level, Position};
 mod combinedlintpass;
 mod lint;
 mod lintpass;
@@ -82,7 +89,14 @@ mod lints_def;
 use kclvm_ast::ast;
 use kclvm_ast::walker::MutSelfWalker;
 
-pub use self::{combinedlintpass::CombinedLintPass, lint::LintContext, lintpass::LintPass};
+pub use self::{
+    combinedlintpass::CombinedLintPass,
+    lint::{LintContext, LintLevel},
+    lintpass::LintPass,
+};
+
+/// The prefix of the lint suppression comments, e.g., `# kcl-lint: disable=unused_import`.
+const LINT_COMMENT_PREFIX: &str = "kcl-lint:";
 
 /// The struct `Linter` is used to traverse the AST and call the `check_*` method defined in `CombinedLintPass`.
 pub struct Linter<T: LintPass> {
@@ -95,6 +109,7 @@ impl LintContext {
     pub fn dummy_ctx() -> Self {
         LintContext {
             filename: "".to_string(),
+            pkgpath: "".to_string(),
             start_pos: Position::dummy_pos(),
             end_pos: Position::dummy_pos(),
         }
@@ -119,6 +134,7 @@ impl Resolver<'_> {
     /// Iterate the module and run lint checks, generating diagnostics and save them in `lint.handler`
     pub fn lint_check_module(&mut self, module: &ast::Module) {
         self.linter.ctx.filename = module.filename.clone();
+        self.linter.ctx.pkgpath = module.pkg.clone();
         self.linter.walk_module(module);
     }
     /// Recursively iterate the scope and its child scope, run lint checks, generating diagnostics and save them in `lint.handler`
@@ -132,10 +148,143 @@ impl Resolver<'_> {
     /// Iterate the resolver.scope_map and run lint checks, generating diagnostics and save them in `lint.handler`
     pub fn lint_check_scope_map(&mut self) {
         let scope_map = self.scope_map.clone();
-        for (_, scope) in scope_map.iter() {
+        for (pkgpath, scope) in scope_map.iter() {
+            self.linter.ctx.pkgpath = pkgpath.to_string();
             self.lint_check_scope(&scope.borrow())
         }
     }
+
+    /// Apply the lint levels in the resolve options and the suppression comments to the diagnostics in `lint.handler`,
+    /// the allowed and suppressed ones are removed and the denied ones are turned into errors.
+    pub fn lint_apply_levels(&mut self) {
+        let mut suppressions = IndexMap::<String, LintSuppressions>::new();
+        for modules in self.program.pkgs.values() {
+            for module in modules {
+                let module_suppressions = LintSuppressions::from_module(module);
+                if module_suppressions.is_empty() {
+                    continue;
+                }
+                // The positions of the scope objects use the filenames of the AST nodes, which may
+                // be different from the module filename.
+                if let Some(comment) = module.comments.first() {
+                    suppressions.insert(comment.filename.clone(), module_suppressions.clone());
+                }
+                suppressions.insert(module.filename.clone(), module_suppressions);
+            }
+        }
+        let lints = CombinedLintPass::get_lints();
+        let diagnostics = std::mem::take(&mut self.linter.handler.diagnostics);
+        for mut diag in diagnostics {
+            let lint = match &diag.code {
+                Some(DiagnosticId::Warning(kind)) => lints.iter().find(|lint| lint.kind == *kind),
+                _ => None,
+            };
+            if let Some(lint) = lint {
+                let name = lint.name_lower();
+                let is_suppressed = diag.messages.first().map_or(false, |msg| {
+                    suppressions
+                        .get(&msg.pos.filename)
+                        .map_or(false, |s| s.is_suppressed(&name, msg.pos.line))
+                });
+                let level = match self.options.lint_levels.get(&name) {
+                    Some(level) => *level,
+                    None => LintLevel::from_lint(lint),
+                };
+                match level {
+                    _ if is_suppressed => continue,
+                    LintLevel::Allow => continue,
+                    LintLevel::Warn => diag.level = Level::Warning,
+                    LintLevel::Deny => diag.level = Level::Error,
+                }
+            }
+            self.linter.handler.diagnostics.insert(diag);
+        }
+    }
+}
+
+/// Parse the lint levels from the lint name and level pairs, e.g., `unused_import = "allow"`
+/// in the `[lint]` section of `kcl.mod`.
+pub fn parse_lint_levels<'a>(
+    levels: impl IntoIterator<Item = (&'a String, &'a String)>,
+) -> Result<IndexMap<String, LintLevel>, String> {
+    let lint_names = CombinedLintPass::get_lints()
+        .iter()
+        .map(|lint| lint.name_lower())
+        .collect::<IndexSet<String>>();
+    let mut lint_levels = IndexMap::new();
+    for (name, level) in levels {
+        if !lint_names.contains(name) {
+            return Err(format!("unknown lint '{}'", name));
+        }
+        lint_levels.insert(name.clone(), level.parse::<LintLevel>()?);
+    }
+    Ok(lint_levels)
+}
+
+/// The lints disabled by the suppression comments of a module.
+#[derive(Debug, Clone, Default)]
+struct LintSuppressions {
+    /// The lints disabled in the whole file.
+    file: IndexSet<String>,
+    /// The lints disabled on each line.
+    lines: IndexMap<u64, IndexSet<String>>,
+}
+
+impl LintSuppressions {
+    /// Collect the suppression comments of the module:
+    ///
+    /// - `# kcl-lint: disable=unused_import,reimport` disables the lints on the line of the comment,
+    /// or in the whole file when it is placed before the first statement.
+    /// - `# kcl-lint: disable-next-line=unused_import` disables the lints on the next line.
+    fn from_module(module: &ast::Module) -> Self {
+        let first_stmt_line = module.body.first().map_or(u64::MAX, |stmt| stmt.line);
+        let mut suppressions = Self::default();
+        for comment in &module.comments {
+            let directive = match comment
+                .node
+                .text
+                .trim_start_matches('#')
+                .trim()
+                .strip_prefix(LINT_COMMENT_PREFIX)
+            {
+                Some(directive) => directive.trim(),
+                None => continue,
+            };
+            let (names, line) = if let Some(names) = directive.strip_prefix("disable-next-line=") {
+                (names, Some(comment.line + 1))
+            } else if let Some(names) = directive.strip_prefix("disable=") {
+                let line = if comment.line < first_stmt_line {
+                    None
+                } else {
+                    Some(comment.line)
+                };
+                (names, line)
+            } else {
+                continue;
+            };
+            let names = names
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty());
+            match line {
+                Some(line) => suppressions.lines.entry(line).or_default().extend(names),
+                None => suppressions.file.extend(names),
+            }
+        }
+        suppressions
+    }
+
+    fn is_empty(&self) -> bool {
+        self.file.is_empty() && self.lines.is_empty()
+    }
+
+    fn is_suppressed(&self, name: &str, line: u64) -> bool {
+        self.file.contains(name)
+            || self
+                .lines
+                .get(&line)
+                .map_or(false, |names| names.contains(name))
+    }
 }
 
 macro_rules! walk_set_list {
@@ -197,6 +346,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         self.walk_schema_expr(&unification_stmt.value.node);
     }
     fn walk_assign_stmt(&mut self, assign_stmt: &ast::AssignStmt) {
+        self.pass
+            .check_assign_stmt(&mut self.handler, &mut self.ctx, assign_stmt);
         for target in &assign_stmt.targets {
             set_pos!(self, &target);
             self.walk_identifier(&target.node)
@@ -211,12 +362,16 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         self.walk_expr(&aug_assign_stmt.value.node);
     }
     fn walk_assert_stmt(&mut self, assert_stmt: &ast::AssertStmt) {
+        self.pass
+            .check_assert_stmt(&mut self.handler, &mut self.ctx, assert_stmt);
         set_pos!(self, &assert_stmt.test);
         self.walk_expr(&assert_stmt.test.node);
         walk_set_if!(self, walk_expr, assert_stmt.if_cond);
         walk_set_if!(self, walk_expr, assert_stmt.msg);
     }
     fn walk_if_stmt(&mut self, if_stmt: &ast::IfStmt) {
+        self.pass
+            .check_if_stmt(&mut self.handler, &mut self.ctx, if_stmt);
         set_pos!(self, &if_stmt.cond);
         self.walk_expr(&if_stmt.cond.node);
         walk_set_list!(self, walk_stmt, if_stmt.body);
@@ -227,10 +382,14 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         let _ = import_stmt;
     }
     fn walk_schema_attr(&mut self, schema_attr: &ast::SchemaAttr) {
+        self.pass
+            .check_schema_attr(&mut self.handler, &mut self.ctx, schema_attr);
         walk_set_list!(self, walk_call_expr, schema_attr.decorators);
         walk_set_if!(self, walk_expr, schema_attr.value);
     }
     fn walk_schema_stmt(&mut self, schema_stmt: &ast::SchemaStmt) {
+        self.pass
+            .check_schema_stmt(&mut self.handler, &mut self.ctx, schema_stmt);
         walk_set_if!(self, walk_identifier, schema_stmt.parent_name);
         walk_set_if!(self, walk_identifier, schema_stmt.for_host_name);
         walk_set_if!(self, walk_arguments, schema_stmt.args);
@@ -352,6 +511,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         self.walk_expr(&schema_expr.config.node);
     }
     fn walk_config_expr(&mut self, config_expr: &ast::ConfigExpr) {
+        self.pass
+            .check_config_expr(&mut self.handler, &mut self.ctx, config_expr);
         for config_entry in &config_expr.items {
             walk_set_if!(self, walk_expr, config_entry.node.key);
             set_pos!(self, &config_entry.node.value);
@@ -359,12 +520,16 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         }
     }
     fn walk_check_expr(&mut self, check_expr: &ast::CheckExpr) {
+        self.pass
+            .check_check_expr(&mut self.handler, &mut self.ctx, check_expr);
         set_pos!(self, &check_expr.test);
         self.walk_expr(&check_expr.test.node);
         walk_set_if!(self, walk_expr, check_expr.if_cond);
         walk_set_if!(self, walk_expr, check_expr.msg);
     }
     fn walk_lambda_expr(&mut self, lambda_expr: &ast::LambdaExpr) {
+        self.pass
+            .check_lambda_expr(&mut self.handler, &mut self.ctx, lambda_expr);
         walk_set_if!(self, walk_arguments, lambda_expr.args);
         walk_set_list!(self, walk_stmt, lambda_expr.body);
     }
@@ -386,6 +551,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         }
     }
     fn walk_compare(&mut self, compare: &ast::Compare) {
+        self.pass
+            .check_compare(&mut self.handler, &mut self.ctx, compare);
         set_pos!(self, &compare.left);
         self.walk_expr(&compare.left.node);
         walk_set_list!(self, walk_expr, compare.comparators);
@@ -23,13 +23,15 @@ impl<'ctx> Resolver<'ctx> {
         }
     }
 
-    /// Do schema/function/decorator argument type check.
+    /// Do schema/function/decorator argument type check, the parameters at or after
+    /// `kw_only_index` can only be passed by the keyword arguments.
     pub fn do_arguments_type_check(
         &mut self,
         func: &ast::Expr,
         args: &'ctx [ast::NodeRef<ast::Expr>],
         kwargs: &'ctx [ast::NodeRef<ast::Keyword>],
         params: &[Parameter],
+        kw_only_index: Option<usize>,
     ) {
         let func_name = self.get_func_name(func);
         let arg_types = self.exprs(args);
@@ -53,15 +55,16 @@ impl<'ctx> Resolver<'ctx> {
             }
         }
         if !params.is_empty() {
+            let positional_count = kw_only_index.map_or(params.len(), |i| i.min(params.len()));
             for (i, ty) in arg_types.iter().enumerate() {
                 let expected_ty = match params.get(i) {
-                    Some(param) => param.ty.clone(),
-                    None => {
+                    Some(param) if i < positional_count => param.ty.clone(),
+                    _ => {
                         self.handler.add_compile_error(
                             &format!(
                                 "{} takes {} positional argument but {} were given",
                                 func_name,
-                                params.len(),
+                                positional_count,
                                 args.len(),
                             ),
                             args[i].get_pos(),
@@ -1,6 +1,9 @@
 use std::rc::Rc;
 
-use crate::builtin::system_module::{get_system_module_members, UNITS, UNITS_NUMBER_MULTIPLIER};
+use crate::builtin::system_module::{
+    get_system_member_function_ty, get_system_module_members, UNITS, UNITS_FIELD_NAMES,
+    UNITS_NUMBER_MULTIPLIER,
+};
 use crate::builtin::STRING_MEMBER_FUNCTIONS;
 use crate::resolver::Resolver;
 use crate::ty::{ModuleKind, Type, TypeKind};
@@ -28,7 +31,7 @@ impl<'ctx> Resolver<'ctx> {
 
     pub fn load_attr(&mut self, obj: Rc<Type>, attr: &str, pos: Position) -> ResolvedResult {
         let (result, return_ty) = match &obj.kind {
-            TypeKind::Any => (true, self.any_ty()),
+            TypeKind::Any | TypeKind::TypeVar(_) => (true, self.any_ty()),
             TypeKind::None
             | TypeKind::Bool
             | TypeKind::BoolLit(_)
@@ -74,6 +77,7 @@ impl<'ctx> Resolver<'ctx> {
                     crate::ty::ModuleKind::User => match self.scope_map.get(&module_ty.pkgpath) {
                         Some(scope) => match scope.borrow().elems.get(attr) {
                             Some(v) => {
+                                v.borrow_mut().used = true;
                                 if v.borrow().ty.is_module() {
                                     self.handler
                                             .add_compile_error(&format!("can not import the attribute '{}' from the module '{}'", attr, module_ty.pkgpath), pos.clone());
@@ -87,12 +91,33 @@ impl<'ctx> Resolver<'ctx> {
                     ModuleKind::System => {
                         if module_ty.pkgpath == UNITS && attr == UNITS_NUMBER_MULTIPLIER {
                             (true, Rc::new(Type::number_multiplier_non_lit_ty()))
+                        } else if module_ty.pkgpath == UNITS && UNITS_FIELD_NAMES.contains(&attr) {
+                            // The fractional units `n`, `u` and `m` are floats, others are ints.
+                            if ["n", "u", "m"].contains(&attr) {
+                                (true, self.float_ty())
+                            } else {
+                                (true, self.int_ty())
+                            }
                         } else {
-                            let members = get_system_module_members(&module_ty.pkgpath);
-                            (members.contains(&attr), self.any_ty())
+                            match get_system_member_function_ty(&module_ty.pkgpath, attr) {
+                                Some(ty) => (true, ty),
+                                None => {
+                                    let members = get_system_module_members(&module_ty.pkgpath);
+                                    (members.contains(&attr), self.any_ty())
+                                }
+                            }
                         }
                     }
-                    ModuleKind::Plugin => (true, self.any_ty()),
+                    ModuleKind::Plugin => {
+                        self.add_implicit_type_error(
+                            &format!(
+                                "the plugin member '{}.{}' has an implicit 'any' type",
+                                module_ty.pkgpath, attr
+                            ),
+                            pos.clone(),
+                        );
+                        (true, self.any_ty())
+                    }
                 }
             }
         };
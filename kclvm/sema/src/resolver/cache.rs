@@ -0,0 +1,340 @@
+use std::{cell::RefCell, rc::Rc};
+
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::{ast, MAIN_PKG};
+use kclvm_config::cache::{MemoryPkgCache, PkgCache, PkgCacheKey};
+use kclvm_error::{Diagnostic, Level, Position};
+use serde::{Deserialize, Serialize};
+
+use crate::resolver::scope::{ProgramScope, Scope, ScopeObjectKind};
+use crate::resolver::variance::{schema_owner, CovariantAlias};
+use crate::resolver::Resolver;
+use crate::ty::TypeKind;
+
+/// The kind of the resolved package scopes in the package cache.
+const SCOPE_CACHE_KIND: &str = "scope";
+
+/// The package scopes loaded from and saved to the package cache of the program root
+/// and the memory cache.
+#[derive(Debug, Default)]
+pub struct PkgScopeCache {
+    /// The package cache, which is `None` if it is not enabled in `kcl.mod`.
+    cache: Option<PkgCache>,
+    /// The memory cache shared by the resolves in the process, e.g., the language server.
+    memory: Option<MemoryPkgCache>,
+    /// The cache keys by the package paths, the key is `None` if the package can not be cached.
+    keys: IndexMap<String, Option<PkgCacheKey>>,
+    /// The packages loaded from the cache.
+    pub(super) loaded_pkgs: IndexSet<String>,
+}
+
+impl PkgScopeCache {
+    /// Returns the package scope cache of the program root, which is disabled if the
+    /// package cache is not enabled in `kcl.mod` as the default one.
+    pub fn new(root: &str) -> Self {
+        Self {
+            cache: PkgCache::new(root),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the package scope cache of the program root, which also loads the package
+    /// scopes from and saves them to the memory cache whether the package cache is enabled
+    /// or not, so the packages imported by the main package are resolved again only when
+    /// their files or the packages they import change.
+    pub fn with_memory_cache(root: &str, memory: MemoryPkgCache) -> Self {
+        Self {
+            memory: Some(memory),
+            ..Self::new(root)
+        }
+    }
+
+    /// Whether neither the package cache nor the memory cache is enabled.
+    fn is_disabled(&self) -> bool {
+        self.cache.is_none() && self.memory.is_none()
+    }
+
+    /// Load the package scope from the memory cache first and then the package cache.
+    fn load(&self, key: &PkgCacheKey) -> Option<CachedPkgScope> {
+        if let Some(cached) = self.memory.as_ref().and_then(|memory| memory.load(key)) {
+            return Some(cached);
+        }
+        let cached = self.cache.as_ref()?.load::<CachedPkgScope>(key)?;
+        // Keep it in the memory cache, so the later resolves do not read the entry file again.
+        if let Some(memory) = &self.memory {
+            let _ = memory.save(key, &cached);
+        }
+        Some(cached)
+    }
+
+    /// Save the package scope to the memory cache and the package cache.
+    fn save(&self, key: &PkgCacheKey, cached: &CachedPkgScope) {
+        // A failure to save the cache only makes the package resolved again.
+        if let Some(memory) = &self.memory {
+            let _ = memory.save(key, cached);
+        }
+        if let Some(cache) = &self.cache {
+            let _ = cache.save(key, cached);
+        }
+    }
+}
+
+/// The resolved package, which includes the package scope and the resolve context
+/// that the importers and the compiler backends depend on.
+///
+/// The schema types of the package are not saved, which are the same as the types of the
+/// schema definitions in the package scope, and they are restored from the scope on load,
+/// so the deserialized types are not copied.
+#[derive(Serialize, Deserialize)]
+struct CachedPkgScope {
+    scope: Rc<RefCell<Scope>>,
+    /// Import names of the package files.
+    import_names: IndexMap<String, IndexMap<String, String>>,
+    type_alias_mapping: IndexMap<String, String>,
+    type_alias_params: IndexMap<String, Vec<String>>,
+    /// Global names of the package and their positions.
+    global_names: IndexMap<String, Position>,
+    /// Private attributes of the base schemas used in the package schemas.
+    used_inherited_attrs: Vec<((String, String), IndexSet<String>)>,
+    /// Covariant aliases of the package and the package schemas.
+    covariant_aliases: Vec<((String, String), CovariantAlias)>,
+    /// Dependencies of the package on the imported packages and dependencies of the package
+    /// schemas and rules on their base schemas and rules by the runtime types.
+    type_dependencies: Vec<(String, String)>,
+    /// The user packages imported by the package.
+    dependencies: Vec<String>,
+    /// Warnings of the package files, which are reported again when the package is loaded.
+    warnings: Vec<Diagnostic>,
+}
+
+impl<'ctx> Resolver<'ctx> {
+    /// Load the package scope from the package cache and resolve or load the packages
+    /// it imports, returns `false` if the package is not in the cache.
+    pub(crate) fn load_pkg_scope_from_cache(&mut self, pkgpath: &str) -> bool {
+        let cached = match self
+            .pkg_scope_cache_key(pkgpath)
+            .and_then(|key| self.pkg_scope_cache.load(&key))
+        {
+            Some(cached) => cached,
+            None => return false,
+        };
+        cached.scope.borrow_mut().parent = Some(Rc::downgrade(&self.builtin_scope));
+        restore_scope_parents(&cached.scope);
+        for obj in cached.scope.borrow().elems.values() {
+            let obj = obj.borrow();
+            if let (ScopeObjectKind::Definition, TypeKind::Schema(schema_ty)) =
+                (&obj.kind, &obj.ty.kind)
+            {
+                if !schema_ty.is_rule {
+                    self.ctx.schema_mapping.insert(
+                        schema_owner(schema_ty),
+                        Rc::new(RefCell::new(schema_ty.clone())),
+                    );
+                }
+            }
+        }
+        self.scope_map.insert(pkgpath.to_string(), cached.scope);
+        self.ctx.import_names.extend(cached.import_names);
+        self.ctx
+            .type_alias_mapping
+            .insert(pkgpath.to_string(), cached.type_alias_mapping);
+        self.ctx
+            .type_alias_params
+            .insert(pkgpath.to_string(), cached.type_alias_params);
+        self.ctx
+            .global_names
+            .insert(pkgpath.to_string(), cached.global_names);
+        self.ctx.used_inherited_attrs.insert(
+            pkgpath.to_string(),
+            cached.used_inherited_attrs.into_iter().collect(),
+        );
+        self.ctx.covariant_aliases.extend(cached.covariant_aliases);
+        for (from, to) in &cached.type_dependencies {
+            self.ctx.ty_ctx.add_dependencies(from, to);
+        }
+        self.pkg_scope_cache.loaded_pkgs.insert(pkgpath.to_string());
+        for warning in cached.warnings {
+            self.handler.add_diagnostic(warning);
+        }
+        // The imported packages are not needed to resolve the importers of the package,
+        // but their import names are needed by the compiler backends.
+        for dep in &cached.dependencies {
+            if !self.scope_map.contains_key(dep) && !self.load_pkg_scope_from_cache(dep) {
+                self.check(dep);
+            }
+        }
+        true
+    }
+
+    /// Save the resolved package scopes without any errors to the package cache.
+    pub(crate) fn save_pkg_scopes_to_cache(&mut self, scope: &ProgramScope) {
+        if self.pkg_scope_cache.is_disabled() {
+            return;
+        }
+        let pkgpaths = self.scope_map.keys().cloned().collect::<Vec<String>>();
+        for pkgpath in pkgpaths {
+            if self.pkg_scope_cache.loaded_pkgs.contains(&pkgpath) {
+                continue;
+            }
+            let key = match self.pkg_scope_cache_key(&pkgpath) {
+                Some(key) => key,
+                None => continue,
+            };
+            let modules = &self.program.pkgs[&pkgpath];
+            let files = modules
+                .iter()
+                .map(|m| m.filename.as_str())
+                .collect::<IndexSet<&str>>();
+            let diagnostics = scope
+                .handler
+                .diagnostics
+                .iter()
+                // The diagnostics of the importers may refer to the package in the notes.
+                .filter(|diag| match diag.messages.first() {
+                    Some(msg) => files.contains(msg.pos.filename.as_str()),
+                    None => false,
+                })
+                .cloned()
+                .collect::<Vec<Diagnostic>>();
+            // The packages with errors are not cached, so that the errors are reported again.
+            if diagnostics.iter().any(|diag| diag.level == Level::Error) {
+                continue;
+            }
+            // The runtime types of the package schemas and rules, which own their attributes.
+            let owners = self.scope_map[&pkgpath]
+                .borrow()
+                .elems
+                .values()
+                .filter_map(|obj| match (&obj.borrow().kind, &obj.borrow().ty.kind) {
+                    (ScopeObjectKind::Definition, TypeKind::Schema(schema_ty)) => {
+                        Some(schema_owner(schema_ty))
+                    }
+                    _ => None,
+                })
+                .collect::<IndexSet<String>>();
+            let dep_graph = &self.ctx.ty_ctx.dep_graph;
+            let cached = CachedPkgScope {
+                scope: self.scope_map[&pkgpath].clone(),
+                import_names: self
+                    .ctx
+                    .import_names
+                    .iter()
+                    .filter(|(filename, _)| files.contains(filename.as_str()))
+                    .map(|(filename, names)| (filename.clone(), names.clone()))
+                    .collect(),
+                type_alias_mapping: self
+                    .ctx
+                    .type_alias_mapping
+                    .get(&pkgpath)
+                    .cloned()
+                    .unwrap_or_default(),
+                type_alias_params: self
+                    .ctx
+                    .type_alias_params
+                    .get(&pkgpath)
+                    .cloned()
+                    .unwrap_or_default(),
+                global_names: self
+                    .ctx
+                    .global_names
+                    .get(&pkgpath)
+                    .cloned()
+                    .unwrap_or_default(),
+                used_inherited_attrs: self
+                    .ctx
+                    .used_inherited_attrs
+                    .get(&pkgpath)
+                    .map(|attrs| attrs.clone().into_iter().collect())
+                    .unwrap_or_default(),
+                covariant_aliases: self
+                    .ctx
+                    .covariant_aliases
+                    .iter()
+                    .filter(|((owner, _), _)| *owner == pkgpath || owners.contains(owner))
+                    .map(|(key, alias)| (key.clone(), alias.clone()))
+                    .collect(),
+                type_dependencies: dep_graph
+                    .raw_edges()
+                    .iter()
+                    .filter(|edge| {
+                        let from = &dep_graph[edge.source()];
+                        *from == pkgpath || owners.contains(from)
+                    })
+                    .map(|edge| {
+                        (
+                            dep_graph[edge.source()].clone(),
+                            dep_graph[edge.target()].clone(),
+                        )
+                    })
+                    .collect(),
+                dependencies: self.pkg_dependencies(modules),
+                warnings: diagnostics,
+            };
+            self.pkg_scope_cache.save(&key, &cached);
+        }
+    }
+
+    /// Returns the cache key of the package scope, which depends on the package sources,
+    /// the resolve options and the cache keys of the imported packages. `None` is returned
+    /// if the package can not be cached, e.g., it is the main package or in an import cycle.
+    fn pkg_scope_cache_key(&mut self, pkgpath: &str) -> Option<PkgCacheKey> {
+        if let Some(key) = self.pkg_scope_cache.keys.get(pkgpath) {
+            return key.clone();
+        }
+        if self.pkg_scope_cache.is_disabled() || pkgpath == MAIN_PKG {
+            return None;
+        }
+        let cache = self.pkg_scope_cache.cache.clone();
+        if let Some(cache) = &cache {
+            if !cache.is_cached_pkg(pkgpath) {
+                return None;
+            }
+        }
+        let modules = self.program.pkgs.get(pkgpath)?;
+        // Insert the empty key in advance to stop at the import cycles.
+        self.pkg_scope_cache.keys.insert(pkgpath.to_string(), None);
+        let mut sources = Vec::with_capacity(modules.len());
+        for module in modules {
+            let code = std::fs::read_to_string(&module.filename).ok()?;
+            sources.push((module.filename.clone(), code));
+        }
+        let mut deps = vec![format!("{:?}", self.options)];
+        for dep in self.pkg_dependencies(modules) {
+            deps.push(self.pkg_scope_cache_key(&dep)?.hash);
+        }
+        let key = match (&cache, &self.pkg_scope_cache.memory) {
+            (Some(cache), _) => cache.key(SCOPE_CACHE_KIND, pkgpath, &sources, &deps),
+            (None, Some(memory)) => memory.key(SCOPE_CACHE_KIND, pkgpath, &sources, &deps),
+            (None, None) => return None,
+        };
+        self.pkg_scope_cache
+            .keys
+            .insert(pkgpath.to_string(), Some(key.clone()));
+        Some(key)
+    }
+
+    /// Returns the user packages imported by the package modules.
+    fn pkg_dependencies(&self, modules: &[ast::Module]) -> Vec<String> {
+        let mut deps = IndexSet::new();
+        for module in modules {
+            for stmt in &module.body {
+                if let ast::Stmt::Import(import_stmt) = &stmt.node {
+                    if import_stmt.path != MAIN_PKG
+                        && self.program.pkgs.contains_key(&import_stmt.path)
+                    {
+                        deps.insert(import_stmt.path.clone());
+                    }
+                }
+            }
+        }
+        deps.into_iter().collect()
+    }
+}
+
+/// Restore the parent links of the child scopes of the deserialized scope.
+fn restore_scope_parents(scope: &Rc<RefCell<Scope>>) {
+    for child in &scope.borrow().children {
+        child.borrow_mut().parent = Some(Rc::downgrade(scope));
+        restore_scope_parents(child);
+    }
+}
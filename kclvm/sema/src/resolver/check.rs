@@ -0,0 +1,428 @@
+use crate::resolver::Resolver;
+use crate::ty::SchemaType;
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::ast;
+use kclvm_ast::pos::GetPos;
+use kclvm_ast::walker::MutSelfWalker;
+use kclvm_error::{ErrorKind, Message, Style};
+
+/// The compile-time constant value of an expression.
+#[derive(Debug, Clone)]
+enum ConstValue {
+    None,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    List(Vec<ConstValue>),
+}
+
+/// The constant values of the schema attributes by the attribute names.
+type ConstAttrs = IndexMap<String, ConstValue>;
+
+impl<'ctx> Resolver<'ctx> {
+    /// Evaluate the check blocks of the schema and its base schemas with the constant attribute
+    /// values in the schema config, and report the checks that always fail at compile time, e.g.,
+    /// `Port {value = -1}` with `check: value > 0`. The checks that refer to non-constant values
+    /// are skipped, and they are still evaluated at runtime.
+    pub(crate) fn check_schema_config_constant(
+        &mut self,
+        schema_ty: &SchemaType,
+        schema_expr: &'ctx ast::SchemaExpr,
+    ) {
+        let config_expr = match &schema_expr.config.node {
+            ast::Expr::Config(config_expr) => config_expr,
+            _ => return,
+        };
+        // The schema statements from the root base schema to the schema itself.
+        let mut schema_stmts = vec![];
+        let mut ty = Some(schema_ty);
+        while let Some(schema_ty) = ty {
+            match self.find_schema_stmt(&schema_ty.pkgpath, &schema_ty.name) {
+                Some(schema_stmt) if schema_stmt.mixins.is_empty() => {
+                    schema_stmts.insert(0, schema_stmt)
+                }
+                _ => return,
+            }
+            ty = schema_ty.base.as_deref();
+        }
+        if schema_stmts.iter().all(|stmt| stmt.checks.is_empty()) {
+            return;
+        }
+        let mut attrs = ConstAttrs::default();
+        let mut dynamic_attrs = IndexSet::<String>::new();
+        for schema_stmt in &schema_stmts {
+            collect_schema_default_attrs(schema_stmt, &mut attrs, &mut dynamic_attrs);
+        }
+        for item in &config_expr.items {
+            let key = match &item.node.key {
+                Some(key) => key,
+                // The unpacking entries and the if entries may set any attribute.
+                None => return,
+            };
+            let name = match &key.node {
+                ast::Expr::Identifier(identifier) if identifier.names.len() == 1 => {
+                    identifier.names[0].clone()
+                }
+                ast::Expr::Identifier(identifier) => {
+                    attrs.remove(&identifier.names[0]);
+                    continue;
+                }
+                ast::Expr::StringLit(string_lit) => string_lit.value.clone(),
+                _ => return,
+            };
+            let value = fold_const_expr(&item.node.value.node, &ConstAttrs::default());
+            let is_union_with_list =
+                matches!(item.node.operation, ast::ConfigEntryOperation::Union)
+                    && matches!(attrs.get(&name), Some(ConstValue::List(_)));
+            match value {
+                Some(value)
+                    if !matches!(item.node.operation, ast::ConfigEntryOperation::Insert)
+                        && !is_union_with_list =>
+                {
+                    attrs.insert(name, value)
+                }
+                _ => attrs.remove(&name),
+            };
+        }
+        for name in &dynamic_attrs {
+            attrs.remove(name);
+        }
+        for schema_stmt in &schema_stmts {
+            for check_expr in &schema_stmt.checks {
+                if let Some(if_cond) = &check_expr.node.if_cond {
+                    match fold_const_expr(&if_cond.node, &attrs) {
+                        Some(value) if value.is_truthy() => {}
+                        _ => continue,
+                    }
+                }
+                match fold_const_expr(&check_expr.node.test.node, &attrs) {
+                    Some(value) if !value.is_truthy() => {}
+                    _ => continue,
+                }
+                let note = check_expr.node.msg.as_ref().and_then(|msg| {
+                    match fold_const_expr(&msg.node, &attrs) {
+                        Some(ConstValue::Str(msg)) => Some(msg),
+                        _ => None,
+                    }
+                });
+                self.handler.add_error(
+                    ErrorKind::SchemaCheckFailure,
+                    &[
+                        Message {
+                            pos: schema_expr.name.get_pos(),
+                            style: Style::LineAndColumn,
+                            message: "Instance check failed".to_string(),
+                            note: None,
+                        },
+                        Message {
+                            pos: check_expr.node.test.get_pos(),
+                            style: Style::LineAndColumn,
+                            message: "Check failed on the condition".to_string(),
+                            note,
+                        },
+                    ],
+                );
+            }
+        }
+    }
+
+    /// Find the schema statement by the package path and the schema name.
+    fn find_schema_stmt(&self, pkgpath: &str, name: &str) -> Option<&'ctx ast::SchemaStmt> {
+        self.program
+            .pkgs
+            .get(pkgpath)?
+            .iter()
+            .flat_map(|module| &module.body)
+            .find_map(|stmt| match &stmt.node {
+                ast::Stmt::Schema(schema_stmt) if schema_stmt.name.node == name => {
+                    Some(schema_stmt)
+                }
+                _ => None,
+            })
+    }
+}
+
+/// The package path of the `regex` system module.
+const REGEX_PKGPATH: &str = "regex";
+
+/// Collect the constant default values of the schema attributes. The attributes assigned in the
+/// schema body statements are dynamic, e.g., `value = value * 2` and the attributes in `if` blocks.
+fn collect_schema_default_attrs(
+    schema_stmt: &ast::SchemaStmt,
+    attrs: &mut ConstAttrs,
+    dynamic_attrs: &mut IndexSet<String>,
+) {
+    for stmt in &schema_stmt.body {
+        match &stmt.node {
+            ast::Stmt::SchemaAttr(schema_attr) => {
+                let name = schema_attr.name.node.clone();
+                let value = match (&schema_attr.op, &schema_attr.value) {
+                    (Some(ast::BinOrAugOp::Aug(ast::AugOp::Assign)), Some(value)) => {
+                        fold_const_expr(&value.node, &ConstAttrs::default())
+                    }
+                    // The optional attribute without a default value is None.
+                    (_, None) if schema_attr.is_optional => Some(ConstValue::None),
+                    _ => None,
+                };
+                match value {
+                    Some(value) => attrs.insert(name, value),
+                    None => attrs.remove(&name),
+                };
+            }
+            _ => {
+                let mut walker = AssignTargetWalker::default();
+                walker.walk_stmt(&stmt.node);
+                dynamic_attrs.extend(walker.names);
+            }
+        }
+    }
+}
+
+/// Collect the names assigned in a statement.
+#[derive(Default)]
+struct AssignTargetWalker {
+    names: IndexSet<String>,
+}
+
+impl MutSelfWalker for AssignTargetWalker {
+    fn walk_assign_stmt(&mut self, assign_stmt: &ast::AssignStmt) {
+        for target in &assign_stmt.targets {
+            self.names.insert(target.node.names[0].clone());
+        }
+    }
+    fn walk_aug_assign_stmt(&mut self, aug_assign_stmt: &ast::AugAssignStmt) {
+        self.names
+            .insert(aug_assign_stmt.target.node.names[0].clone());
+    }
+    fn walk_unification_stmt(&mut self, unification_stmt: &ast::UnificationStmt) {
+        self.names
+            .insert(unification_stmt.target.node.names[0].clone());
+    }
+    fn walk_schema_attr(&mut self, schema_attr: &ast::SchemaAttr) {
+        self.names.insert(schema_attr.name.node.clone());
+    }
+}
+
+impl ConstValue {
+    fn is_truthy(&self) -> bool {
+        match self {
+            ConstValue::None => false,
+            ConstValue::Bool(value) => *value,
+            ConstValue::Int(value) => *value != 0,
+            ConstValue::Float(value) => *value != 0.0,
+            ConstValue::Str(value) => !value.is_empty(),
+            ConstValue::List(values) => !values.is_empty(),
+        }
+    }
+
+    fn as_float(&self) -> Option<f64> {
+        match self {
+            ConstValue::Int(value) => Some(*value as f64),
+            ConstValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// Fold the expression into a constant value with the constant attribute values `attrs`, it returns
+/// `None` when the expression is not a compile-time constant.
+fn fold_const_expr(expr: &ast::Expr, attrs: &ConstAttrs) -> Option<ConstValue> {
+    let fold = |expr: &ast::NodeRef<ast::Expr>| fold_const_expr(&expr.node, attrs);
+    match expr {
+        ast::Expr::NameConstantLit(lit) => match lit.value {
+            ast::NameConstant::True => Some(ConstValue::Bool(true)),
+            ast::NameConstant::False => Some(ConstValue::Bool(false)),
+            ast::NameConstant::None => Some(ConstValue::None),
+            ast::NameConstant::Undefined => None,
+        },
+        ast::Expr::NumberLit(number_lit) if number_lit.binary_suffix.is_none() => {
+            match number_lit.value {
+                ast::NumberLitValue::Int(value) => Some(ConstValue::Int(value)),
+                ast::NumberLitValue::Float(value) => Some(ConstValue::Float(value)),
+            }
+        }
+        ast::Expr::StringLit(string_lit) => Some(ConstValue::Str(string_lit.value.clone())),
+        ast::Expr::List(list_expr) => list_expr
+            .elts
+            .iter()
+            .map(fold)
+            .collect::<Option<Vec<ConstValue>>>()
+            .map(ConstValue::List),
+        ast::Expr::Identifier(identifier)
+            if identifier.names.len() == 1 && identifier.pkgpath.is_empty() =>
+        {
+            attrs.get(&identifier.names[0]).cloned()
+        }
+        ast::Expr::Paren(paren_expr) => fold(&paren_expr.expr),
+        ast::Expr::Unary(unary_expr) => {
+            let operand = fold(&unary_expr.operand)?;
+            match (&unary_expr.op, operand) {
+                (ast::UnaryOp::Not, operand) => Some(ConstValue::Bool(!operand.is_truthy())),
+                (ast::UnaryOp::USub, ConstValue::Int(value)) => {
+                    value.checked_neg().map(ConstValue::Int)
+                }
+                (ast::UnaryOp::USub, ConstValue::Float(value)) => Some(ConstValue::Float(-value)),
+                (ast::UnaryOp::UAdd, operand @ (ConstValue::Int(_) | ConstValue::Float(_))) => {
+                    Some(operand)
+                }
+                _ => None,
+            }
+        }
+        ast::Expr::Binary(binary_expr) => {
+            let op = match &binary_expr.op {
+                ast::BinOrCmpOp::Bin(op) => op,
+                ast::BinOrCmpOp::Cmp(_) => return None,
+            };
+            let left = fold(&binary_expr.left)?;
+            // Short-circuit the logic operators like the runtime.
+            match op {
+                ast::BinOp::And if !left.is_truthy() => return Some(left),
+                ast::BinOp::Or if left.is_truthy() => return Some(left),
+                ast::BinOp::And | ast::BinOp::Or => return fold(&binary_expr.right),
+                _ => {}
+            }
+            fold_binary(op, left, fold(&binary_expr.right)?)
+        }
+        ast::Expr::Compare(compare) => {
+            let mut left = fold(&compare.left)?;
+            for (op, right) in compare.ops.iter().zip(&compare.comparators) {
+                let right = fold(right)?;
+                if !fold_compare(op, &left, &right)? {
+                    return Some(ConstValue::Bool(false));
+                }
+                left = right;
+            }
+            Some(ConstValue::Bool(true))
+        }
+        ast::Expr::Call(call_expr) if call_expr.keywords.is_empty() => {
+            let identifier = match &call_expr.func.node {
+                ast::Expr::Identifier(identifier) => identifier,
+                _ => return None,
+            };
+            let args = call_expr
+                .args
+                .iter()
+                .map(fold)
+                .collect::<Option<Vec<ConstValue>>>()?;
+            let names = identifier
+                .names
+                .iter()
+                .map(|name| name.as_str())
+                .collect::<Vec<&str>>();
+            match (names.as_slice(), args.as_slice()) {
+                // The builtin function `len` may be shadowed by an attribute.
+                (["len"], [value]) if !attrs.contains_key("len") => match value {
+                    ConstValue::Str(value) => Some(ConstValue::Int(value.len() as i64)),
+                    ConstValue::List(values) => Some(ConstValue::Int(values.len() as i64)),
+                    _ => None,
+                },
+                ([_, "match"], [ConstValue::Str(string), ConstValue::Str(pattern)])
+                    if identifier.pkgpath == REGEX_PKGPATH =>
+                {
+                    let re = fancy_regex::Regex::new(pattern).ok()?;
+                    re.is_match(string).ok().map(ConstValue::Bool)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn fold_binary(op: &ast::BinOp, left: ConstValue, right: ConstValue) -> Option<ConstValue> {
+    match (op, left, right) {
+        (ast::BinOp::Add, ConstValue::Int(left), ConstValue::Int(right)) => {
+            left.checked_add(right).map(ConstValue::Int)
+        }
+        (ast::BinOp::Sub, ConstValue::Int(left), ConstValue::Int(right)) => {
+            left.checked_sub(right).map(ConstValue::Int)
+        }
+        (ast::BinOp::Mul, ConstValue::Int(left), ConstValue::Int(right)) => {
+            left.checked_mul(right).map(ConstValue::Int)
+        }
+        (ast::BinOp::Add, ConstValue::Str(left), ConstValue::Str(right)) => {
+            Some(ConstValue::Str(left + &right))
+        }
+        (ast::BinOp::Add, ConstValue::List(mut left), ConstValue::List(right)) => {
+            left.extend(right);
+            Some(ConstValue::List(left))
+        }
+        (ast::BinOp::Add | ast::BinOp::Sub | ast::BinOp::Mul, left, right) => {
+            let (left, right) = (left.as_float()?, right.as_float()?);
+            let value = match op {
+                ast::BinOp::Add => left + right,
+                ast::BinOp::Sub => left - right,
+                _ => left * right,
+            };
+            Some(ConstValue::Float(value))
+        }
+        _ => None,
+    }
+}
+
+/// Compare the constant values, it returns `None` when the values are not comparable at compile time.
+fn fold_compare(op: &ast::CmpOp, left: &ConstValue, right: &ConstValue) -> Option<bool> {
+    match op {
+        ast::CmpOp::Eq => const_eq(left, right),
+        ast::CmpOp::NotEq => const_eq(left, right).map(|eq| !eq),
+        ast::CmpOp::Lt | ast::CmpOp::LtE | ast::CmpOp::Gt | ast::CmpOp::GtE => {
+            let ordering = match (left, right) {
+                (ConstValue::Int(left), ConstValue::Int(right)) => left.partial_cmp(right),
+                (ConstValue::Str(left), ConstValue::Str(right)) => left.partial_cmp(right),
+                _ => left.as_float()?.partial_cmp(&right.as_float()?),
+            }?;
+            Some(match op {
+                ast::CmpOp::Lt => ordering.is_lt(),
+                ast::CmpOp::LtE => ordering.is_le(),
+                ast::CmpOp::Gt => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            })
+        }
+        ast::CmpOp::In | ast::CmpOp::NotIn => {
+            let contains = match (left, right) {
+                (ConstValue::Str(left), ConstValue::Str(right)) => right.contains(left.as_str()),
+                (_, ConstValue::List(values)) => {
+                    let mut contains = false;
+                    for value in values {
+                        if const_eq(left, value)? {
+                            contains = true;
+                            break;
+                        }
+                    }
+                    contains
+                }
+                _ => return None,
+            };
+            Some(contains == matches!(op, ast::CmpOp::In))
+        }
+        _ => None,
+    }
+}
+
+fn const_eq(left: &ConstValue, right: &ConstValue) -> Option<bool> {
+    match (left, right) {
+        (ConstValue::None, ConstValue::None) => Some(true),
+        (ConstValue::Bool(left), ConstValue::Bool(right)) => Some(left == right),
+        (ConstValue::Str(left), ConstValue::Str(right)) => Some(left == right),
+        (ConstValue::Int(left), ConstValue::Int(right)) => Some(left == right),
+        (ConstValue::Int(_) | ConstValue::Float(_), ConstValue::Int(_) | ConstValue::Float(_)) => {
+            Some(left.as_float()? == right.as_float()?)
+        }
+        (ConstValue::List(left), ConstValue::List(right)) => {
+            if left.len() != right.len() {
+                return Some(false);
+            }
+            for (left, right) in left.iter().zip(right) {
+                if !const_eq(left, right)? {
+                    return Some(false);
+                }
+            }
+            Some(true)
+        }
+        // The booleans and the numbers are compared by the runtime value rules.
+        (ConstValue::Bool(_), ConstValue::Int(_) | ConstValue::Float(_))
+        | (ConstValue::Int(_) | ConstValue::Float(_), ConstValue::Bool(_)) => None,
+        _ => Some(false),
+    }
+}
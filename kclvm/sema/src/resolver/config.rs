@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use super::{
@@ -5,9 +6,10 @@ use super::{
     scope::{ScopeKind, ScopeObject, ScopeObjectKind},
     Resolver,
 };
-use crate::ty::sup;
 use crate::ty::SchemaType;
-use crate::ty::{Type, TypeKind};
+use crate::ty::{has_type_vars, infer_type_vars, instantiate_schema, sup};
+use crate::ty::{Type, TypeInferMethods, TypeKind};
+use indexmap::IndexMap;
 use kclvm_ast::ast;
 use kclvm_ast::pos::GetPos;
 use kclvm_error::{ErrorKind, Message, Position, Style};
@@ -354,7 +356,13 @@ impl<'ctx> Resolver<'ctx> {
         let runtime_type = kclvm_runtime::schema_runtime_type(&schema_ty.name, &schema_ty.pkgpath);
         match self.ctx.schema_mapping.get(&runtime_type) {
             Some(schema_mapping_ty) => {
-                let schema_ty = schema_mapping_ty.borrow();
+                let schema_mapping_ty = schema_mapping_ty.borrow();
+                // Instantiate the generic schema with the type arguments, e.g., `Page[Person]`.
+                let schema_ty = if schema_ty.type_args.is_empty() {
+                    Cow::Borrowed(&*schema_mapping_ty)
+                } else {
+                    Cow::Owned(instantiate_schema(&schema_mapping_ty, &schema_ty.type_args))
+                };
                 match schema_ty.get_type_of_attr(attr) {
                     Some(ty) => (true, ty),
                     None => {
@@ -396,6 +404,10 @@ impl<'ctx> Resolver<'ctx> {
             let op = &item.node.operation;
             let mut stack_depth: usize = 0;
             self.check_config_entry(key, value);
+            let config_ty = match self.ctx.config_expr_context.last() {
+                Some(Some(obj)) if obj.ty.is_schema() => Some(obj.ty.clone()),
+                _ => None,
+            };
             stack_depth += self.switch_config_expr_context_by_key(key);
             let mut has_insert_index = false;
             let val_ty = match key {
@@ -431,6 +443,21 @@ impl<'ctx> Resolver<'ctx> {
                         };
                         key_types.push(key_ty);
                         val_types.push(val_ty.clone());
+                        if let (Some(config_ty), [name]) = (&config_ty, &identifier.names[..]) {
+                            if matches!(
+                                op,
+                                ast::ConfigEntryOperation::Union
+                                    | ast::ConfigEntryOperation::Insert
+                            ) {
+                                self.check_covariant_attr_mutation(
+                                    &config_ty.into_schema_type(),
+                                    name,
+                                    val_ty.clone(),
+                                    false,
+                                    key.get_pos(),
+                                );
+                            }
+                        }
                         val_ty
                     }
                     ast::Expr::Subscript(subscript)
@@ -438,6 +465,19 @@ impl<'ctx> Resolver<'ctx> {
                     {
                         has_insert_index = true;
                         let val_ty = self.expr(value);
+                        if let (Some(config_ty), ast::Expr::Identifier(identifier)) =
+                            (&config_ty, &subscript.value.node)
+                        {
+                            if let [name] = &identifier.names[..] {
+                                self.check_covariant_attr_mutation(
+                                    &config_ty.into_schema_type(),
+                                    name,
+                                    val_ty.clone(),
+                                    !matches!(op, ast::ConfigEntryOperation::Insert),
+                                    key.get_pos(),
+                                );
+                            }
+                        }
                         key_types.push(self.str_ty());
                         val_types.push(Type::list_ref(val_ty.clone()));
                         val_ty
@@ -532,4 +572,56 @@ impl<'ctx> Resolver<'ctx> {
         let val_ty = sup(&val_types);
         Type::dict_ref(key_ty, val_ty)
     }
+
+    /// Infer the type arguments of the generic schema from the config entries
+    /// e.g., `Page {items = [1]}` -> `Page[int]`, and the type parameters which
+    /// can't be inferred are `any`.
+    pub(crate) fn infer_schema_type_args(
+        &mut self,
+        schema_ty: &SchemaType,
+        config: &'ctx ast::NodeRef<ast::Expr>,
+    ) -> Option<SchemaType> {
+        if schema_ty.type_params.is_empty() || !schema_ty.type_args.is_empty() {
+            return None;
+        }
+        let items = match &config.node {
+            ast::Expr::Config(config_expr) => &config_expr.items,
+            _ => return None,
+        };
+        let mut mapping: IndexMap<String, Vec<Rc<Type>>> = IndexMap::default();
+        for item in items {
+            let name = match &item.node.key {
+                Some(key) => match &key.node {
+                    ast::Expr::Identifier(identifier) if identifier.names.len() == 1 => {
+                        &identifier.names[0]
+                    }
+                    _ => continue,
+                },
+                None => continue,
+            };
+            let attr_ty = match schema_ty.get_type_of_attr(name) {
+                Some(ty) if has_type_vars(&ty) => ty,
+                _ => continue,
+            };
+            let init_stack_depth = self.switch_config_expr_context(None);
+            let val_ty = self.expr(&item.node.value);
+            self.clear_config_expr_context(init_stack_depth as usize, false);
+            let val_ty = self.ctx.ty_ctx.infer_to_variable_type(val_ty);
+            let attr_ty = match item.node.operation {
+                // The inserted value is the list item, e.g., `items += [1]`.
+                ast::ConfigEntryOperation::Insert if !attr_ty.is_list() => continue,
+                _ => attr_ty,
+            };
+            infer_type_vars(attr_ty, val_ty, &mut mapping);
+        }
+        let type_args: Vec<Rc<Type>> = schema_ty
+            .type_params
+            .iter()
+            .map(|param| match mapping.get(param) {
+                Some(types) => sup(types),
+                None => self.any_ty(),
+            })
+            .collect();
+        Some(instantiate_schema(schema_ty, &type_args))
+    }
 }
@@ -36,19 +36,30 @@ impl<'ctx> Resolver<'ctx> {
                     self.change_package_context(pkgpath, filename);
                     for stmt in &module.body {
                         let (start, end) = stmt.get_span_pos();
-                        let (name, doc, is_mixin, is_protocol, is_rule) = match &stmt.node {
-                            ast::Stmt::Schema(schema_stmt) => (
-                                &schema_stmt.name.node,
-                                &schema_stmt.doc,
-                                schema_stmt.is_mixin,
-                                schema_stmt.is_protocol,
-                                false,
-                            ),
-                            ast::Stmt::Rule(rule_stmt) => {
-                                (&rule_stmt.name.node, &rule_stmt.doc, false, false, true)
-                            }
-                            _ => continue,
-                        };
+                        let (name, doc, is_mixin, is_protocol, is_rule, type_params) =
+                            match &stmt.node {
+                                ast::Stmt::Schema(schema_stmt) => (
+                                    &schema_stmt.name.node,
+                                    &schema_stmt.doc,
+                                    schema_stmt.is_mixin,
+                                    schema_stmt.is_protocol,
+                                    false,
+                                    schema_stmt
+                                        .type_params
+                                        .iter()
+                                        .map(|param| param.node.clone())
+                                        .collect(),
+                                ),
+                                ast::Stmt::Rule(rule_stmt) => (
+                                    &rule_stmt.name.node,
+                                    &rule_stmt.doc,
+                                    false,
+                                    false,
+                                    true,
+                                    vec![],
+                                ),
+                                _ => continue,
+                            };
                         if self.contains_object(name) {
                             self.handler.add_error(
                                 ErrorKind::UniqueKeyError,
@@ -71,6 +82,8 @@ impl<'ctx> Resolver<'ctx> {
                             is_mixin,
                             is_protocol,
                             is_rule,
+                            type_params,
+                            type_args: vec![],
                             base: None,
                             protocol: None,
                             mixins: vec![],
@@ -528,6 +541,19 @@ impl<'ctx> Resolver<'ctx> {
             .iter()
             .map(|attr| attr.2.clone())
             .collect();
+        // Type parameters
+        let mut type_params: Vec<String> = vec![];
+        for type_param in &schema_stmt.type_params {
+            if type_params.contains(&type_param.node) {
+                self.handler.add_compile_error(
+                    &format!("duplicate type parameter '{}'", type_param.node),
+                    type_param.get_pos(),
+                );
+            } else {
+                type_params.push(type_param.node.clone());
+            }
+        }
+        let outer_type_params = std::mem::replace(&mut self.ctx.type_params, type_params.clone());
         let index_signature = if let Some(index_signature) = &schema_stmt.index_signature {
             if let Some(index_sign_name) = &index_signature.node.key_name {
                 if schema_attr_names.contains(index_sign_name) {
@@ -755,6 +781,7 @@ impl<'ctx> Resolver<'ctx> {
                 });
             }
         }
+        self.ctx.type_params = outer_type_params;
         let schema_runtime_ty = kclvm_runtime::schema_runtime_type(name, &self.ctx.pkgpath);
         if should_add_schema_ref {
             if let Some(ref parent_ty) = parent_ty {
@@ -788,6 +815,8 @@ impl<'ctx> Resolver<'ctx> {
             is_mixin: schema_stmt.is_mixin,
             is_protocol: schema_stmt.is_protocol,
             is_rule: false,
+            type_params,
+            type_args: vec![],
             base: parent_ty,
             protocol: protocol_ty,
             mixins: mixin_types,
@@ -899,6 +928,8 @@ impl<'ctx> Resolver<'ctx> {
             is_mixin: false,
             is_protocol: false,
             is_rule: true,
+            type_params: vec![],
+            type_args: vec![],
             base: None,
             protocol: protocol_ty,
             mixins: parent_types,
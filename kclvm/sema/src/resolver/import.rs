@@ -188,7 +188,9 @@ impl<'ctx> Resolver<'ctx> {
                                 );
                             }
                             // Switch pkgpath context
-                            if !self.scope_map.contains_key(&import_stmt.path) {
+                            if !self.scope_map.contains_key(&import_stmt.path)
+                                && !self.load_pkg_scope_from_cache(&import_stmt.path)
+                            {
                                 self.check(&import_stmt.path);
                             }
                             // Restore the current context
@@ -1,12 +1,15 @@
 mod arg;
 mod attr;
+mod cache;
 mod calculation;
+mod check;
 mod config;
 mod doc;
 mod format;
 pub mod global;
 mod import;
 mod r#loop;
+mod narrow;
 mod node;
 mod para;
 mod schema;
@@ -14,14 +17,15 @@ pub mod scope;
 mod ty;
 mod ty_alias;
 mod var;
+mod variance;
 
 #[cfg(test)]
 mod tests;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use std::{cell::RefCell, rc::Rc};
 
-use crate::lint::{CombinedLintPass, Linter};
+use crate::lint::{CombinedLintPass, LintLevel, Linter};
 use crate::pre_process::pre_process_program;
 use crate::resolver::scope::ScopeObject;
 use crate::resolver::ty_alias::process_program_type_alias;
@@ -33,6 +37,8 @@ use crate::ty::TypeContext;
 
 use self::scope::{builtin_scope, ProgramScope};
 
+pub use self::cache::PkgScopeCache;
+
 /// Resolver is responsible for program semantic checking, mainly
 /// including type checking and contract model checking.
 pub struct Resolver<'ctx> {
@@ -45,10 +51,13 @@ pub struct Resolver<'ctx> {
     pub options: Options,
     pub handler: Handler,
     pub linter: Linter<CombinedLintPass>,
+    pub pkg_scope_cache: PkgScopeCache,
 }
 
 impl<'ctx> Resolver<'ctx> {
-    pub fn new(program: &'ctx Program, options: Options) -> Self {
+    /// New a resolver of the program, the package scopes are loaded from and saved to
+    /// `pkg_scope_cache`, which is built by [`PkgScopeCache::new`] of the program root.
+    pub fn new(program: &'ctx Program, options: Options, pkg_scope_cache: PkgScopeCache) -> Self {
         let builtin_scope = Rc::new(RefCell::new(builtin_scope()));
         let scope = Rc::clone(&builtin_scope);
         Resolver {
@@ -61,6 +70,7 @@ impl<'ctx> Resolver<'ctx> {
             options,
             handler: Handler::default(),
             linter: Linter::<CombinedLintPass>::new(),
+            pkg_scope_cache,
         }
     }
 
@@ -91,7 +101,9 @@ impl<'ctx> Resolver<'ctx> {
 
     pub(crate) fn check_and_lint(&mut self, pkgpath: &str) -> ProgramScope {
         let mut scope = self.check(pkgpath);
+        self.mark_used_inherited_attrs();
         self.lint_check_scope_map();
+        self.lint_apply_levels();
         for diag in &self.linter.handler.diagnostics {
             scope.handler.diagnostics.insert(diag.clone());
         }
@@ -130,6 +142,18 @@ pub struct Context {
     pub ty_ctx: TypeContext,
     /// Type alias mapping
     pub type_alias_mapping: IndexMap<String, IndexMap<String, String>>,
+    /// Type parameters of the generic schema or type alias being resolved.
+    pub type_params: Vec<String>,
+    /// Type parameters of the generic type aliases, pkgpath -> alias name -> type parameters.
+    pub type_alias_params: IndexMap<String, IndexMap<String, Vec<String>>>,
+    /// Variable types narrowed by the conditions of the enclosing flow-sensitive branches.
+    pub narrowed_types: Vec<narrow::NarrowedTypes>,
+    /// Private attributes of the base schemas used in the sub schemas of the packages,
+    /// pkgpath -> (base pkgpath, base schema name) -> attribute names.
+    pub used_inherited_attrs: IndexMap<String, IndexMap<(String, String), IndexSet<String>>>,
+    /// List and dict variables referring to the values with narrower item types, which must
+    /// not be mutated with the values of the wider item types.
+    pub covariant_aliases: variance::CovariantAliases,
 }
 
 /// Resolve options
@@ -138,21 +162,45 @@ pub struct Options {
     pub raise_err: bool,
     pub config_auto_fix: bool,
     pub lint_check: bool,
+    /// Report the types which are implicitly inferred as `any`, e.g., untyped lambda parameters.
+    pub strict_type_check: bool,
+    /// The lint levels by the lint names, e.g., `unused_import = "allow"` in the `[lint]`
+    /// section of `kcl.mod`, the lints not in it are reported with their default levels.
+    pub lint_levels: IndexMap<String, LintLevel>,
 }
 
 /// Resolve program
 pub fn resolve_program(program: &mut Program) -> ProgramScope {
-    pre_process_program(program);
-    let mut resolver = Resolver::new(
+    resolve_program_with_opts(
         program,
         Options {
             raise_err: true,
             config_auto_fix: false,
             lint_check: true,
+            strict_type_check: false,
+            lint_levels: IndexMap::default(),
         },
-    );
+    )
+}
+
+/// Resolve program with the resolve options.
+pub fn resolve_program_with_opts(program: &mut Program, opts: Options) -> ProgramScope {
+    let pkg_scope_cache = PkgScopeCache::new(&program.root);
+    resolve_program_with_cache(program, opts, pkg_scope_cache)
+}
+
+/// Resolve program with the resolve options, the packages imported by the main package
+/// are loaded from `pkg_scope_cache` if they are not changed.
+pub fn resolve_program_with_cache(
+    program: &mut Program,
+    opts: Options,
+    pkg_scope_cache: PkgScopeCache,
+) -> ProgramScope {
+    pre_process_program(program);
+    let mut resolver = Resolver::new(program, opts, pkg_scope_cache);
     resolver.resolve_import();
     let scope = resolver.check_and_lint(kclvm_ast::MAIN_PKG);
+    resolver.save_pkg_scopes_to_cache(&scope);
     let type_alias_mapping = resolver.ctx.type_alias_mapping.clone();
     process_program_type_alias(program, type_alias_mapping);
     scope
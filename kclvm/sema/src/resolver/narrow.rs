@@ -0,0 +1,233 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::resolver::Resolver;
+use crate::ty::{
+    narrow, Type, BOOL_TYPE_STR, DICT_TYPE_STR, FLOAT_TYPE_STR, INT_TYPE_STR, LIST_TYPE_STR,
+    NAME_CONSTANT_NONE, STR_TYPE_STR,
+};
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_ast::pos::GetPos;
+
+use super::node::TypeRef;
+use super::scope::ScopeObject;
+
+/// The narrowed type of a variable in a flow-sensitive branch, e.g., `x` in `if x != None:`.
+#[derive(Clone, Debug)]
+pub struct NarrowedType {
+    /// The scope object of the variable when it is narrowed, a variable with
+    /// the same name in the inner scope e.g., a loop variable is not narrowed.
+    pub obj: Option<Rc<RefCell<ScopeObject>>>,
+    pub ty: TypeRef,
+}
+
+pub type NarrowedTypes = IndexMap<String, NarrowedType>;
+
+impl<'ctx> Resolver<'ctx> {
+    /// Resolve the function `f` with the variable types narrowed by the condition,
+    /// `truthy` denotes whether the condition is true or false in `f`.
+    pub(crate) fn with_narrowed_types<T>(
+        &mut self,
+        cond: &'ctx ast::NodeRef<ast::Expr>,
+        truthy: bool,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        self.enter_narrowed_types(cond, truthy);
+        let result = f(self);
+        self.ctx.narrowed_types.pop();
+        result
+    }
+
+    /// Push the variable types narrowed by the condition, it must be popped by the caller.
+    pub(crate) fn enter_narrowed_types(
+        &mut self,
+        cond: &'ctx ast::NodeRef<ast::Expr>,
+        truthy: bool,
+    ) {
+        let mut narrowed_types = NarrowedTypes::default();
+        self.narrow_types(cond, truthy, &mut narrowed_types);
+        self.ctx.narrowed_types.push(narrowed_types);
+    }
+
+    /// Lookup the narrowed type of the variable in the innermost flow-sensitive branch.
+    pub(crate) fn lookup_narrowed_type(&self, name: &str) -> Option<TypeRef> {
+        let narrowed_type = self
+            .ctx
+            .narrowed_types
+            .iter()
+            .rev()
+            .find_map(|narrowed_types| narrowed_types.get(name))?;
+        let obj = self.scope.borrow().lookup(name);
+        let is_same_obj = match (&obj, &narrowed_type.obj) {
+            (Some(obj), Some(narrowed_obj)) => Rc::ptr_eq(obj, narrowed_obj),
+            (None, None) => true,
+            _ => false,
+        };
+        if is_same_obj {
+            Some(narrowed_type.ty.clone())
+        } else {
+            None
+        }
+    }
+
+    fn narrow_types(
+        &mut self,
+        cond: &'ctx ast::NodeRef<ast::Expr>,
+        truthy: bool,
+        narrowed_types: &mut NarrowedTypes,
+    ) {
+        match &cond.node {
+            // `if x:` and `if not x:`
+            ast::Expr::Identifier(_) => {
+                if truthy {
+                    self.narrow_type(cond, narrowed_types, |ty| narrow(ty, |ty| !ty.is_none()));
+                }
+            }
+            ast::Expr::Paren(paren_expr) => {
+                self.narrow_types(&paren_expr.expr, truthy, narrowed_types)
+            }
+            ast::Expr::Unary(unary_expr) if matches!(unary_expr.op, ast::UnaryOp::Not) => {
+                self.narrow_types(&unary_expr.operand, !truthy, narrowed_types)
+            }
+            // `if a and b:` narrows both operands when it is true, and `if a or b:`
+            // narrows both operands when it is false.
+            ast::Expr::Binary(binary_expr)
+                if (matches!(binary_expr.op, ast::BinOrCmpOp::Bin(ast::BinOp::And)) && truthy)
+                    || (matches!(binary_expr.op, ast::BinOrCmpOp::Bin(ast::BinOp::Or))
+                        && !truthy) =>
+            {
+                self.narrow_types(&binary_expr.left, truthy, narrowed_types);
+                self.narrow_types(&binary_expr.right, truthy, narrowed_types);
+            }
+            ast::Expr::Compare(compare) if compare.ops.len() == 1 => {
+                let (left, right) = (&compare.left, &compare.comparators[0]);
+                let is_eq = match &compare.ops[0] {
+                    ast::CmpOp::Eq | ast::CmpOp::Is => truthy,
+                    ast::CmpOp::NotEq | ast::CmpOp::IsNot => !truthy,
+                    _ => return,
+                };
+                // `x == None` and `None == x`
+                if let Some(target) = none_guard_target(left, right) {
+                    self.narrow_type(target, narrowed_types, |ty| {
+                        narrow(ty, |ty| ty.is_none() == is_eq)
+                    });
+                }
+                // `typeof(x) == "str"` and `"str" == typeof(x)`
+                else if let Some((target, ty_str)) = typeof_guard_target(left, right) {
+                    self.narrow_type(target, narrowed_types, |ty| {
+                        narrow_with_typeof(ty, ty_str, is_eq)
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Narrow the type of the single name identifier `target`.
+    fn narrow_type(
+        &mut self,
+        target: &'ctx ast::NodeRef<ast::Expr>,
+        narrowed_types: &mut NarrowedTypes,
+        f: impl FnOnce(TypeRef) -> TypeRef,
+    ) {
+        let names = match &target.node {
+            ast::Expr::Identifier(identifier)
+                if identifier.names.len() == 1 && identifier.pkgpath.is_empty() =>
+            {
+                &identifier.names
+            }
+            _ => return,
+        };
+        let name = &names[0];
+        let ty = match narrowed_types.get(name) {
+            Some(narrowed_type) => narrowed_type.ty.clone(),
+            None => self.resolve_var(names, "", target.get_pos()),
+        };
+        let narrowed_ty = f(ty.clone());
+        if narrowed_ty != ty {
+            let obj = self.scope.borrow().lookup(name);
+            narrowed_types.insert(
+                name.to_string(),
+                NarrowedType {
+                    obj,
+                    ty: narrowed_ty,
+                },
+            );
+        }
+    }
+}
+
+fn none_guard_target<'a>(
+    left: &'a ast::NodeRef<ast::Expr>,
+    right: &'a ast::NodeRef<ast::Expr>,
+) -> Option<&'a ast::NodeRef<ast::Expr>> {
+    let is_none = |expr: &ast::NodeRef<ast::Expr>| {
+        matches!(
+            &expr.node,
+            ast::Expr::NameConstantLit(lit) if matches!(lit.value, ast::NameConstant::None)
+        )
+    };
+    if is_none(right) {
+        Some(left)
+    } else if is_none(left) {
+        Some(right)
+    } else {
+        None
+    }
+}
+
+fn typeof_guard_target<'a>(
+    left: &'a ast::NodeRef<ast::Expr>,
+    right: &'a ast::NodeRef<ast::Expr>,
+) -> Option<(&'a ast::NodeRef<ast::Expr>, &'a str)> {
+    let typeof_arg = |expr: &'a ast::NodeRef<ast::Expr>| match &expr.node {
+        ast::Expr::Call(call_expr)
+            if call_expr.args.len() == 1
+                && call_expr.keywords.is_empty()
+                && matches!(
+                    &call_expr.func.node,
+                    ast::Expr::Identifier(identifier) if identifier.get_name() == "typeof"
+                ) =>
+        {
+            Some(&call_expr.args[0])
+        }
+        _ => None,
+    };
+    let str_value = |expr: &'a ast::NodeRef<ast::Expr>| match &expr.node {
+        ast::Expr::StringLit(string_lit) => Some(string_lit.value.as_str()),
+        _ => None,
+    };
+    match (typeof_arg(left), str_value(right)) {
+        (Some(target), Some(ty_str)) => Some((target, ty_str)),
+        _ => match (typeof_arg(right), str_value(left)) {
+            (Some(target), Some(ty_str)) => Some((target, ty_str)),
+            _ => None,
+        },
+    }
+}
+
+/// Narrow the type with the result of the builtin function `typeof`. The schema types are
+/// kept when the result is not equal, because the value may be an instance of its sub schema.
+fn narrow_with_typeof(ty: TypeRef, ty_str: &str, is_eq: bool) -> TypeRef {
+    if ty.is_any() {
+        if !is_eq {
+            return ty;
+        }
+        return match ty_str {
+            NAME_CONSTANT_NONE => Rc::new(Type::NONE),
+            BOOL_TYPE_STR => Rc::new(Type::BOOL),
+            INT_TYPE_STR => Rc::new(Type::INT),
+            FLOAT_TYPE_STR => Rc::new(Type::FLOAT),
+            STR_TYPE_STR => Rc::new(Type::STR),
+            LIST_TYPE_STR => Type::list_ref(ty),
+            DICT_TYPE_STR => Type::dict_ref(ty.clone(), ty),
+            _ => ty,
+        };
+    }
+    narrow(ty, |ty| match ty.typeof_str() {
+        Some(typeof_str) if is_eq => typeof_str == ty_str,
+        Some(typeof_str) => typeof_str != ty_str || ty.is_schema(),
+        None => true,
+    })
+}
@@ -7,7 +7,8 @@ use std::rc::Rc;
 
 use crate::info::is_private_field;
 use crate::ty::{
-    sup, DecoratorTarget, Parameter, Type, TypeInferMethods, TypeKind, RESERVED_TYPE_IDENTIFIERS,
+    substitute, sup, DecoratorTarget, Parameter, Type, TypeInferMethods, TypeKind,
+    RESERVED_TYPE_IDENTIFIERS,
 };
 
 use super::format::VALID_FORMAT_SPEC_SET;
@@ -81,17 +82,38 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
 
     fn walk_type_alias_stmt(&mut self, type_alias_stmt: &'ctx ast::TypeAliasStmt) -> Self::Result {
         let (start, end) = type_alias_stmt.type_name.get_span_pos();
+        let type_params: Vec<String> = type_alias_stmt
+            .type_params
+            .iter()
+            .map(|param| param.node.clone())
+            .collect();
+        self.ctx.type_params = type_params.clone();
         let mut ty = self
             .parse_ty_with_scope(&type_alias_stmt.ty.node, start.clone())
             .as_ref()
             .clone();
+        self.ctx.type_params.clear();
         if let TypeKind::Schema(schema_ty) = &mut ty.kind {
             schema_ty.is_instance = false;
         }
         ty.is_type_alias = true;
         let ty = Rc::new(ty);
-        let ty_str = ty.into_type_annotation_str();
         let name = type_alias_stmt.type_name.node.get_name();
+        // The type parameters are erased to `any` in the runtime type string.
+        let ty_str = if type_params.is_empty() {
+            ty.into_type_annotation_str()
+        } else {
+            let mapping = type_params
+                .iter()
+                .map(|param| (param.clone(), self.any_ty()))
+                .collect();
+            self.ctx
+                .type_alias_params
+                .entry(self.ctx.pkgpath.clone())
+                .or_default()
+                .insert(name.clone(), type_params);
+            substitute(ty.clone(), &mapping).into_type_annotation_str()
+        };
         let mut mapping = IndexMap::default();
         mapping.insert(ty_str.clone(), "".to_string());
         self.ctx.import_names.insert(name.to_string(), mapping);
@@ -162,10 +184,31 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                     target.get_pos(),
                     None,
                 );
+                self.record_covariant_alias(
+                    name,
+                    &assign_stmt.value,
+                    value_ty.clone(),
+                    expected_ty.clone(),
+                    target.get_pos(),
+                );
                 if !value_ty.is_any()
                     && expected_ty.is_any()
                     && assign_stmt.type_annotation.is_none()
                 {
+                    if let (ast::Expr::Config(_), TypeKind::Dict(_, val_ty)) =
+                        (&assign_stmt.value.node, &value_ty.kind)
+                    {
+                        let val_ty = self.ctx.ty_ctx.infer_to_variable_type(val_ty.clone());
+                        if val_ty.is_union() {
+                            self.add_implicit_type_error(
+                                &format!(
+                                    "the dict literal has an implicit '{}' value type",
+                                    val_ty.ty_str()
+                                ),
+                                assign_stmt.value.get_pos(),
+                            );
+                        }
+                    }
                     self.set_type_to_scope(name, value_ty.clone(), target.get_pos());
                     if let Some(schema_ty) = &self.ctx.schema {
                         let mut schema_ty = schema_ty.borrow_mut();
@@ -224,6 +267,15 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             Ok(op) => op,
             Err(msg) => bug!("{}", msg),
         };
+        if matches!(aug_assign_stmt.op, ast::AugOp::Add | ast::AugOp::BitOr)
+            && aug_assign_stmt.target.node.names.len() == 1
+        {
+            self.check_covariant_alias_mutation(
+                &aug_assign_stmt.target.node.names[0],
+                right_ty.clone(),
+                aug_assign_stmt.target.get_pos(),
+            );
+        }
         let new_target_ty = self.binary(left_ty, right_ty, &op, aug_assign_stmt.target.get_pos());
         self.ctx.l_value = true;
         let expected_ty = self.walk_identifier_expr(&aug_assign_stmt.target);
@@ -248,8 +300,8 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
 
     fn walk_if_stmt(&mut self, if_stmt: &'ctx ast::IfStmt) -> Self::Result {
         self.expr(&if_stmt.cond);
-        self.stmts(&if_stmt.body);
-        self.stmts(&if_stmt.orelse);
+        self.with_narrowed_types(&if_stmt.cond, true, |r| r.stmts(&if_stmt.body));
+        self.with_narrowed_types(&if_stmt.cond, false, |r| r.stmts(&if_stmt.orelse));
         self.any_ty()
     }
 
@@ -323,8 +375,13 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                 iter_ty.clone(),
                 quant_expr.target.get_pos(),
             );
-            self.expr_or_any_type(&quant_expr.if_cond);
-            let item_ty = self.expr(&quant_expr.test);
+            let item_ty = match &quant_expr.if_cond {
+                Some(if_cond) => {
+                    self.expr(if_cond);
+                    self.with_narrowed_types(if_cond, true, |r| r.expr(&quant_expr.test))
+                }
+                None => self.expr(&quant_expr.test),
+            };
             self.leave_scope();
             match &quant_expr.op {
                 ast::QuantOperation::All | ast::QuantOperation::Any => self.bool_ty(),
@@ -393,7 +450,15 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                         self.must_assignable_to(value_ty, expected_ty, pos, None);
                     }
                     // Assign
-                    _ => self.must_assignable_to(value_ty, expected_ty, pos, None),
+                    _ => {
+                        self.must_assignable_to(
+                            value_ty.clone(),
+                            expected_ty.clone(),
+                            pos.clone(),
+                            None,
+                        );
+                        self.record_covariant_alias(name, value, value_ty, expected_ty, pos);
+                    }
                 },
                 None => bug!("invalid ast schema attr op kind"),
             }
@@ -404,8 +469,8 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
     /// <body> if <cond> else <orelse> -> sup([body, orelse])
     fn walk_if_expr(&mut self, if_expr: &'ctx ast::IfExpr) -> Self::Result {
         self.expr(&if_expr.cond);
-        let body_ty = self.expr(&if_expr.body);
-        let orelse_ty = self.expr(&if_expr.orelse);
+        let body_ty = self.with_narrowed_types(&if_expr.cond, true, |r| r.expr(&if_expr.body));
+        let orelse_ty = self.with_narrowed_types(&if_expr.cond, false, |r| r.expr(&if_expr.orelse));
         sup(&[body_ty, orelse_ty])
     }
 
@@ -416,7 +481,17 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
 
     fn walk_binary_expr(&mut self, binary_expr: &'ctx ast::BinaryExpr) -> Self::Result {
         let left_ty = self.expr(&binary_expr.left);
-        let mut right_ty = self.expr(&binary_expr.right);
+        // The right operand of `and` and `or` is only evaluated when the left operand is
+        // true and false respectively.
+        let mut right_ty = match &binary_expr.op {
+            ast::BinOrCmpOp::Bin(ast::BinOp::And) => {
+                self.with_narrowed_types(&binary_expr.left, true, |r| r.expr(&binary_expr.right))
+            }
+            ast::BinOrCmpOp::Bin(ast::BinOp::Or) => {
+                self.with_narrowed_types(&binary_expr.left, false, |r| r.expr(&binary_expr.right))
+            }
+            _ => self.expr(&binary_expr.right),
+        };
         let pos = binary_expr.left.get_pos();
         match &binary_expr.op {
             ast::BinOrCmpOp::Bin(bin_op) => match bin_op {
@@ -472,12 +547,24 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
     fn walk_call_expr(&mut self, call_expr: &'ctx ast::CallExpr) -> Self::Result {
         let call_ty = self.expr(&call_expr.func);
         let pos = call_expr.func.get_pos();
+        if self.is_builtin_call(call_expr, "option")
+            && !call_expr
+                .keywords
+                .iter()
+                .any(|keyword| keyword.node.arg.node.get_name() == "type")
+        {
+            self.add_implicit_type_error(
+                "the option() result has an implicit 'any' type, use the 'type' argument to specify it",
+                pos.clone(),
+            );
+        }
         if call_ty.is_any() {
             self.do_arguments_type_check(
                 &call_expr.func.node,
                 &call_expr.args,
                 &call_expr.keywords,
                 &[],
+                None,
             );
             self.any_ty()
         } else if let TypeKind::Function(func_ty) = &call_ty.kind {
@@ -486,6 +573,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                 &call_expr.args,
                 &call_expr.keywords,
                 &func_ty.params,
+                func_ty.kw_only_index,
             );
             func_ty.return_ty.clone()
         } else if let TypeKind::Schema(schema_ty) = &call_ty.kind {
@@ -501,6 +589,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                     &call_expr.args,
                     &call_expr.keywords,
                     &schema_ty.func.params,
+                    None,
                 );
                 let mut return_ty = schema_ty.clone();
                 return_ty.is_instance = true;
@@ -622,6 +711,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             None => list_comp.elt.get_end_pos(),
         };
         self.enter_scope(start.clone(), end, ScopeKind::Loop);
+        let narrowed_depth = self.ctx.narrowed_types.len();
         for comp_clause in &list_comp.generators {
             self.walk_comp_clause(&comp_clause.node);
         }
@@ -630,6 +720,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                 .add_compile_error("list unpacking cannot be used in list comprehension", start);
         }
         let item_ty = self.expr(&list_comp.elt);
+        self.ctx.narrowed_types.truncate(narrowed_depth);
         self.leave_scope();
         Type::list_ref(item_ty)
     }
@@ -642,6 +733,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             None => dict_comp.entry.value.get_end_pos(),
         };
         self.enter_scope(start.clone(), end, ScopeKind::Loop);
+        let narrowed_depth = self.ctx.narrowed_types.len();
         for comp_clause in &dict_comp.generators {
             self.walk_comp_clause(&comp_clause.node);
         }
@@ -649,6 +741,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
         // TODO: Naming both dict keys and schema attributes as `attribute`
         self.check_attr_ty(&key_ty, start);
         let val_ty = self.expr(&dict_comp.entry.value);
+        self.ctx.narrowed_types.truncate(narrowed_depth);
         self.leave_scope();
         Type::dict_ref(key_ty, val_ty)
     }
@@ -658,12 +751,16 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
         list_if_item_expr: &'ctx ast::ListIfItemExpr,
     ) -> Self::Result {
         self.expr(&list_if_item_expr.if_cond);
-        let mut or_else_ty = self.expr_or_any_type(&list_if_item_expr.orelse);
+        let mut or_else_ty = self.with_narrowed_types(&list_if_item_expr.if_cond, false, |r| {
+            r.expr_or_any_type(&list_if_item_expr.orelse)
+        });
         // `orelse` node maybe a list unpack node, use its item type instead.
         if let TypeKind::List(item_ty) = &or_else_ty.kind {
             or_else_ty = item_ty.clone();
         }
-        let exprs_ty = sup(&self.exprs(&list_if_item_expr.exprs).to_vec());
+        let exprs_ty = self.with_narrowed_types(&list_if_item_expr.if_cond, true, |r| {
+            sup(&r.exprs(&list_if_item_expr.exprs).to_vec())
+        });
         sup(&[or_else_ty, exprs_ty])
     }
 
@@ -710,9 +807,12 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
         config_if_entry_expr: &'ctx ast::ConfigIfEntryExpr,
     ) -> Self::Result {
         self.expr(&config_if_entry_expr.if_cond);
-        let dict_ty = self.walk_config_entries(&config_if_entry_expr.items);
+        let dict_ty = self.with_narrowed_types(&config_if_entry_expr.if_cond, true, |r| {
+            r.walk_config_entries(&config_if_entry_expr.items)
+        });
         if let Some(orelse) = &config_if_entry_expr.orelse {
-            let or_else_ty = self.expr(orelse);
+            let or_else_ty =
+                self.with_narrowed_types(&config_if_entry_expr.if_cond, false, |r| r.expr(orelse));
             sup(&[dict_ty, or_else_ty])
         } else {
             dict_ty
@@ -774,7 +874,12 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                 iter_ty,
                 comp_clause.iter.get_pos(),
             );
-            self.exprs(&comp_clause.ifs);
+            // The comprehension `if` clauses narrow the variable types in the following
+            // clauses and the element, which are popped by the comprehension.
+            for if_expr in &comp_clause.ifs {
+                self.expr(if_expr);
+                self.enter_narrowed_types(if_expr, true);
+            }
             self.any_ty()
         }
     }
@@ -788,6 +893,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             );
         }
         let mut pos = schema_expr.name.get_pos();
+        let mut inferred_ty = None;
         let ret_ty = match &def_ty.kind {
             TypeKind::Dict(_, _) => {
                 let obj = self.new_config_expr_context_item(
@@ -810,9 +916,18 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                     let name = schema_expr.name.node.get_name();
                     self.add_type_alias(&name, &ty_annotation_str);
                 }
+                // Check the config with the type arguments inferred from it for the raw
+                // generic schema, e.g., `Page {items = [1]}` is `Page[int]`.
+                if !schema_ty.is_instance {
+                    if let Some(schema_ty) =
+                        self.infer_schema_type_args(schema_ty, &schema_expr.config)
+                    {
+                        inferred_ty = Some(Rc::new(Type::schema(schema_ty)));
+                    }
+                }
                 let obj = self.new_config_expr_context_item(
                     &schema_ty.name,
-                    def_ty.clone(),
+                    inferred_ty.clone().unwrap_or_else(|| def_ty.clone()),
                     Position::dummy_pos(),
                     Position::dummy_pos(),
                 );
@@ -834,7 +949,9 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                         &schema_expr.args,
                         &schema_expr.kwargs,
                         &schema_ty.func.params,
+                        None,
                     );
+                    self.check_schema_config_constant(schema_ty, schema_expr);
                 }
                 self.any_ty()
             }
@@ -845,7 +962,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                 return self.any_ty();
             }
         };
-        let mut def_ty_clone = def_ty.as_ref().clone();
+        let mut def_ty_clone = inferred_ty.unwrap_or(def_ty).as_ref().clone();
         if let TypeKind::Schema(schema_ty) = &mut def_ty_clone.kind {
             schema_ty.is_instance = true;
         }
@@ -865,25 +982,38 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             self.must_be_type(msg, self.str_ty());
         }
         // Check type in if_cond expression
-        self.expr_or_any_type(&check_expr.if_cond);
-        self.expr(&check_expr.test)
+        match &check_expr.if_cond {
+            Some(if_cond) => {
+                self.expr(if_cond);
+                self.with_narrowed_types(if_cond, true, |r| r.expr(&check_expr.test))
+            }
+            None => self.expr(&check_expr.test),
+        }
     }
 
     fn walk_lambda_expr(&mut self, lambda_expr: &'ctx ast::LambdaExpr) -> Self::Result {
         let mut ret_ty = self.any_ty();
         let mut params = vec![];
+        let mut param_spans = vec![];
         self.do_parameters_check(&lambda_expr.args);
         if let Some(args) = &lambda_expr.args {
             for (i, arg) in args.node.args.iter().enumerate() {
                 let name = arg.node.get_name();
                 let arg_ty = args.node.get_arg_type(i);
                 let ty = self.parse_ty_with_scope(&arg_ty, arg.get_pos());
+                if args.node.type_annotation_list[i].is_none() {
+                    self.add_implicit_type_error(
+                        &format!("the lambda parameter '{}' has an implicit 'any' type", name),
+                        arg.get_pos(),
+                    );
+                }
+                let value = &args.node.defaults[i];
                 params.push(Parameter {
                     name,
                     ty: ty.clone(),
-                    has_default: false,
+                    has_default: value.is_some(),
                 });
-                let value = &args.node.defaults[i];
+                param_spans.push(arg.get_span_pos());
                 self.expr_or_any_type(value);
             }
         }
@@ -894,13 +1024,13 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
         self.enter_scope(start.clone(), end.clone(), ScopeKind::Lambda);
         self.ctx.in_lambda_expr.push(true);
         // Lambda parameters
-        for param in &params {
+        for (param, (param_start, param_end)) in params.iter().zip(param_spans) {
             self.insert_object(
                 &param.name,
                 ScopeObject {
                     name: param.name.clone(),
-                    start: start.clone(),
-                    end: end.clone(),
+                    start: param_start,
+                    end: param_end,
                     ty: param.ty.clone(),
                     kind: ScopeObjectKind::Parameter,
                     used: false,
@@ -1042,6 +1172,24 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
 }
 
 impl<'ctx> Resolver<'ctx> {
+    /// Whether the call expression calls the builtin function with the name, e.g., `option("key")`.
+    pub(crate) fn is_builtin_call(&self, call_expr: &ast::CallExpr, name: &str) -> bool {
+        match &call_expr.func.node {
+            ast::Expr::Identifier(identifier)
+                if identifier.pkgpath.is_empty() && identifier.get_name() == name =>
+            {
+                match (
+                    self.scope.borrow().lookup(name),
+                    self.builtin_scope.borrow().lookup(name),
+                ) {
+                    (Some(obj), Some(builtin_obj)) => Rc::ptr_eq(&obj, &builtin_obj),
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
     #[inline]
     pub fn stmts(&mut self, stmts: &'ctx [ast::NodeRef<ast::Stmt>]) -> ResolvedResult {
         let stmt_types: Vec<TypeRef> = stmts.iter().map(|stmt| self.stmt(&stmt)).collect();
@@ -34,6 +34,7 @@ impl<'ctx> Resolver<'ctx> {
             return ty;
         };
         self.ctx.schema = Some(Rc::new(RefCell::new(scope_ty.clone())));
+        self.ctx.type_params = scope_ty.type_params.clone();
         let (start, end) = schema_stmt.get_span_pos();
         self.do_parameters_check(&schema_stmt.args);
         self.enter_scope(
@@ -78,6 +79,11 @@ impl<'ctx> Resolver<'ctx> {
         let schema_attr_names = schema_stmt.get_left_identifier_list();
         for (line, column, name) in schema_attr_names {
             if !self.contains_object(&name) {
+                // The overridden attributes of the base schema are used in the base schema.
+                let used = scope_ty
+                    .base
+                    .as_ref()
+                    .map_or(false, |base| base.get_obj_of_attr(&name).is_some());
                 self.insert_object(
                     &name,
                     ScopeObject {
@@ -90,7 +96,7 @@ impl<'ctx> Resolver<'ctx> {
                         end: Position::dummy_pos(),
                         ty: self.any_ty(),
                         kind: ScopeObjectKind::Variable,
-                        used: false,
+                        used,
                         doc: None,
                     },
                 );
@@ -104,6 +110,7 @@ impl<'ctx> Resolver<'ctx> {
         }
         self.leave_scope();
         self.ctx.schema = None;
+        self.ctx.type_params.clear();
         ty
     }
 
@@ -181,6 +188,7 @@ impl<'ctx> Resolver<'ctx> {
                                 &decorator.node.args,
                                 &decorator.node.keywords,
                                 &func_ty.params,
+                                func_ty.kw_only_index,
                             );
                             decorator_objs.push(Decorator {
                                 target: target.clone(),
@@ -207,4 +215,29 @@ impl<'ctx> Resolver<'ctx> {
         }
         decorator_objs
     }
+
+    /// Mark the private schema attributes used in the sub schemas as "used", which can be
+    /// done only after all schema scopes are resolved.
+    pub(crate) fn mark_used_inherited_attrs(&mut self) {
+        for (pkgpath, scope) in &self.scope_map {
+            for child in &scope.borrow().children {
+                let child = child.borrow();
+                if let ScopeKind::Schema(name) = &child.kind {
+                    let key = (pkgpath.to_string(), name.to_string());
+                    for attrs in self
+                        .ctx
+                        .used_inherited_attrs
+                        .values()
+                        .filter_map(|attrs| attrs.get(&key))
+                    {
+                        for attr in attrs {
+                            if let Some(obj) = child.elems.get(attr) {
+                                obj.borrow_mut().used = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
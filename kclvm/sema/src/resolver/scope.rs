@@ -3,6 +3,7 @@ use compiler_base_session::Session;
 use indexmap::IndexMap;
 use kclvm_ast::{ast, MAIN_PKG};
 use kclvm_error::{Handler, Level};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::{
     cell::RefCell,
@@ -16,7 +17,7 @@ use kclvm_ast::pos::ContainsPos;
 use kclvm_error::Position;
 
 /// The object stored in the scope.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct ScopeObject {
     /// The scope object name.
     pub name: String,
@@ -48,7 +49,7 @@ impl ContainsPos for ScopeObject {
     }
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub enum ScopeObjectKind {
     Variable,
     Attribute,
@@ -62,9 +63,11 @@ pub enum ScopeObjectKind {
 /// (parent) and contained (children) scopes. Objects may be inserted
 /// and looked up by name. The zero value for Scope is a ready-to-use
 /// empty scope.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Scope {
-    /// The parent scope.
+    /// The parent scope, which is not serialized and needs to be restored
+    /// after the scope is deserialized.
+    #[serde(skip)]
     pub parent: Option<Weak<RefCell<Scope>>>,
     /// The child scope list.
     pub children: Vec<Rc<RefCell<Scope>>>,
@@ -129,7 +132,7 @@ impl ContainsPos for Scope {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ScopeKind {
     /// Package scope.
     Package(Vec<String>),
@@ -381,10 +384,14 @@ impl<'ctx> Resolver<'ctx> {
         }
     }
 
-    /// Insert object into the current scope.
+    /// Insert object into the current scope, the used flag of the replaced object
+    /// with the same name is kept, because the global types are initialized repeatedly.
     #[inline]
-    pub fn insert_object(&mut self, name: &str, obj: ScopeObject) {
+    pub fn insert_object(&mut self, name: &str, mut obj: ScopeObject) {
         let mut scope = self.scope.borrow_mut();
+        if let Some(replaced_obj) = scope.elems.get(name) {
+            obj.used |= replaced_obj.borrow().used;
+        }
         scope
             .elems
             .insert(name.to_string(), Rc::new(RefCell::new(obj)));
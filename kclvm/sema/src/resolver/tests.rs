@@ -1,15 +1,20 @@
 use super::Options;
+use super::PkgScopeCache;
 use super::Resolver;
+use crate::builtin::system_module::*;
 use crate::builtin::BUILTIN_FUNCTION_NAMES;
+use crate::lint::parse_lint_levels;
 use crate::pre_process::pre_process_program;
-use crate::resolver::resolve_program;
 use crate::resolver::scope::*;
+use crate::resolver::{resolve_program, resolve_program_with_opts};
 use crate::ty::{Type, TypeKind};
+use indexmap::IndexSet;
 use kclvm_ast::ast;
 use kclvm_ast::pos::ContainsPos;
 use kclvm_error::*;
 use kclvm_parser::ParseSession;
 use kclvm_parser::{load_program, parse_program};
+use std::collections::HashMap;
 use std::path::Path;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -98,6 +103,12 @@ fn test_resolve_program_fail() {
         "unique_key_error_0.k",
         "unique_key_error_1.k",
         "unmatched_args.k",
+        "system_module_args.k",
+        "function_type.k",
+        "generic_schema.k",
+        "narrowing.k",
+        "schema_check.k",
+        "variance.k",
     ];
     for case in cases {
         let path = Path::new(work_dir).join(case);
@@ -121,6 +132,258 @@ fn test_resolve_program_mismatch_type_fail() {
     );
 }
 
+#[test]
+fn test_resolve_system_module_args_fail() {
+    let mut program = parse_program("./src/resolver/test_fail_data/system_module_args.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let err_messages = [
+        (ErrorKind::TypeError, "expected str, got int(1)"),
+        (ErrorKind::TypeError, "expected str, got int(2)"),
+        (ErrorKind::TypeError, "expected int|float, got str(x)"),
+        (ErrorKind::TypeError, "expected int, got str(1)"),
+        (
+            ErrorKind::CompileError,
+            "\"json.encode\" takes 1 positional argument but 2 were given",
+        ),
+        (ErrorKind::TypeError, "expected int, got str(1)"),
+        (
+            ErrorKind::CompileError,
+            "\"replace\" takes 3 positional argument but 4 were given",
+        ),
+    ];
+    assert_eq!(scope.handler.diagnostics.len(), err_messages.len());
+    for (diag, (kind, msg)) in scope.handler.diagnostics.iter().zip(err_messages.iter()) {
+        assert_eq!(diag.code, Some(DiagnosticId::Error(kind.clone())));
+        assert_eq!(diag.messages[0].message, msg.to_string());
+    }
+}
+
+#[test]
+fn test_system_module_function_types() {
+    for module in STANDARD_SYSTEM_MODULES {
+        for member in get_system_module_members(module) {
+            let is_field = module == UNITS && UNITS_FIELD_NAMES.contains(&member);
+            assert_eq!(
+                get_system_member_function_ty(module, member).is_some(),
+                !is_field,
+                "{}.{}",
+                module,
+                member
+            );
+        }
+    }
+    let ty = get_system_member_function_ty(REGEX, "match").unwrap();
+    match &ty.kind {
+        TypeKind::Function(func_ty) => {
+            assert_eq!(func_ty.params.len(), 2);
+            assert!(func_ty.return_ty.is_bool());
+        }
+        _ => panic!("test failed, expect function type, got {:?}", ty),
+    }
+}
+
+#[test]
+fn test_resolve_function_type() {
+    let mut program = parse_program("./src/resolver/test_data/function_type.k").unwrap();
+    let scope = resolve_program(&mut program);
+    assert_eq!(scope.handler.diagnostics.len(), 0);
+    let main_scope = scope.main_scope().unwrap().borrow();
+    let apply = main_scope.lookup("apply").unwrap();
+    assert_eq!(apply.borrow().ty.ty_str(), "function");
+    assert_eq!(
+        apply.borrow().ty.into_function_type().ty_str(),
+        "((int) -> int, int) -> int"
+    );
+    let x0 = main_scope.lookup("x0").unwrap();
+    assert!(x0.borrow().ty.is_int());
+}
+
+#[test]
+fn test_resolve_function_type_fail() {
+    let mut program = parse_program("./src/resolver/test_fail_data/function_type.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let err_messages = [
+        "expected (int) -> int, got (int, int) -> int",
+        "expected (int) -> int, got (str) -> str",
+        "expected int, got str(2)",
+    ];
+    assert_eq!(scope.handler.diagnostics.len(), err_messages.len());
+    for (diag, msg) in scope.handler.diagnostics.iter().zip(err_messages.iter()) {
+        assert_eq!(diag.messages[0].message, msg.to_string());
+    }
+}
+
+#[test]
+fn test_resolve_generic_schema() {
+    let mut program = parse_program("./src/resolver/test_data/generic_schema.k").unwrap();
+    let scope = resolve_program(&mut program);
+    assert_eq!(scope.handler.diagnostics.len(), 0);
+    let main_scope = scope.main_scope().unwrap().borrow();
+    let page = main_scope.lookup("page").unwrap();
+    assert_eq!(page.borrow().ty.ty_str(), "Page[Person]");
+    let person = main_scope.lookup("person").unwrap();
+    assert_eq!(person.borrow().ty.ty_str(), "Person");
+    let ages = main_scope.lookup("ages").unwrap();
+    assert_eq!(ages.borrow().ty.ty_str(), "{str:int}");
+    let names = main_scope.lookup("names").unwrap();
+    assert_eq!(names.borrow().ty.ty_str(), "Page[str]");
+}
+
+#[test]
+fn test_resolve_generic_schema_fail() {
+    let mut program = parse_program("./src/resolver/test_fail_data/generic_schema.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let err_messages = [
+        "expected 2 type arguments for 'Pairs', found 1",
+        "'Person' is not a generic type",
+        "expected T, got str(Book)",
+        "expected int, got Person",
+        "expected Page[Person], got Page[int]",
+    ];
+    assert_eq!(scope.handler.diagnostics.len(), err_messages.len());
+    for (diag, msg) in scope.handler.diagnostics.iter().zip(err_messages.iter()) {
+        assert_eq!(diag.messages[0].message, msg.to_string());
+    }
+}
+
+#[test]
+fn test_resolve_narrowing() {
+    let mut program = parse_program("./src/resolver/test_data/narrowing.k").unwrap();
+    let scope = resolve_program(&mut program);
+    assert_eq!(scope.handler.diagnostics.len(), 0);
+    let main_scope = scope.main_scope().unwrap().borrow();
+    for (name, ty_str) in [("name", "str"), ("upper", "str"), ("names", "[str]")] {
+        let obj = main_scope.lookup(name).unwrap();
+        assert_eq!(obj.borrow().ty.ty_str(), ty_str, "{}", name);
+    }
+}
+
+#[test]
+fn test_resolve_narrowing_fail() {
+    let mut program = parse_program("./src/resolver/test_fail_data/narrowing.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let err_messages = [
+        "Person has no attribute age",
+        "expected int, got str",
+        "expected int, got str",
+        "expected str, got int",
+    ];
+    assert_eq!(scope.handler.diagnostics.len(), err_messages.len());
+    for (diag, msg) in scope.handler.diagnostics.iter().zip(err_messages.iter()) {
+        assert_eq!(diag.messages[0].message, msg.to_string());
+    }
+}
+
+#[test]
+fn test_resolve_schema_check_fail() {
+    let mut program = parse_program("./src/resolver/test_fail_data/schema_check.k").unwrap();
+    let scope = resolve_program(&mut program);
+    // (config line, check line, check message)
+    let failures = [
+        (26, 8, Some("port must be positive")),
+        (27, 9, None),
+        (29, 16, None),
+        (29, 17, None),
+        (31, 18, None),
+        (32, 17, None),
+    ];
+    let errors: Vec<&Diagnostic> = scope
+        .handler
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.level == Level::Error)
+        .collect();
+    assert_eq!(errors.len(), failures.len());
+    for (diag, (config_line, check_line, note)) in errors.iter().zip(failures.iter()) {
+        assert_eq!(
+            diag.code,
+            Some(DiagnosticId::Error(ErrorKind::SchemaCheckFailure))
+        );
+        assert_eq!(diag.messages.len(), 2);
+        assert_eq!(diag.messages[0].message, "Instance check failed");
+        assert_eq!(diag.messages[0].pos.line, *config_line);
+        assert_eq!(diag.messages[1].message, "Check failed on the condition");
+        assert_eq!(diag.messages[1].pos.line, *check_line);
+        assert_eq!(diag.messages[1].note.as_deref(), *note);
+    }
+}
+
+#[test]
+fn test_resolve_variance_fail() {
+    let mut program = parse_program("./src/resolver/test_fail_data/variance.k").unwrap();
+    let scope = resolve_program(&mut program);
+    // (mutation line, alias line, mutation message)
+    let failures = [
+        (
+            10,
+            8,
+            "'_bases' is mutated with the value of type '[Base]', which breaks the type '[Sub]' of '_subs'",
+        ),
+        (
+            13,
+            12,
+            "'_base_dict' is mutated with the value of type '{str(b):Base}', which breaks the type '{str:Sub}' of '_sub_dict'",
+        ),
+        (
+            20,
+            18,
+            "'members' is mutated with the value of type '[Base]', which breaks the type '[Sub]' of '_subs'",
+        ),
+        (
+            21,
+            18,
+            "'members' is mutated with the value of type 'Base', which breaks the type '[Sub]' of '_subs'",
+        ),
+    ];
+    let errors: Vec<&Diagnostic> = scope
+        .handler
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.level == Level::Error)
+        .collect();
+    assert_eq!(errors.len(), failures.len());
+    for (diag, (mutation_line, alias_line, msg)) in errors.iter().zip(failures.iter()) {
+        assert_eq!(
+            diag.code,
+            Some(DiagnosticId::Error(ErrorKind::VarianceError))
+        );
+        assert_eq!(diag.messages.len(), 2);
+        assert_eq!(diag.messages[0].message, msg.to_string());
+        assert_eq!(diag.messages[0].pos.line, *mutation_line);
+        assert_eq!(diag.messages[1].pos.line, *alias_line);
+    }
+}
+
+#[test]
+fn test_resolve_strict_type_check_fail() {
+    let path = "./src/resolver/test_fail_data/strict_type_check.k";
+    let mut program = parse_program(path).unwrap();
+    let scope = resolve_program(&mut program);
+    assert_eq!(scope.handler.diagnostics.len(), 0);
+
+    let mut program = parse_program(path).unwrap();
+    let scope = resolve_program_with_opts(
+        &mut program,
+        Options {
+            raise_err: true,
+            config_auto_fix: false,
+            lint_check: true,
+            strict_type_check: true,
+            lint_levels: Default::default(),
+        },
+    );
+    let err_messages = [
+        "the lambda parameter 'x' has an implicit 'any' type",
+        "the option() result has an implicit 'any' type, use the 'type' argument to specify it",
+        "the dict literal has an implicit 'str|int' value type",
+    ];
+    assert_eq!(scope.handler.diagnostics.len(), err_messages.len());
+    for (diag, msg) in scope.handler.diagnostics.iter().zip(err_messages.iter()) {
+        assert_eq!(diag.code, Some(DiagnosticId::Error(ErrorKind::TypeError)));
+        assert_eq!(diag.messages[0].message, msg.to_string());
+    }
+}
+
 #[test]
 fn test_resolve_program_cycle_reference_fail() {
     let sess = Arc::new(ParseSession::default());
@@ -137,6 +400,8 @@ fn test_resolve_program_cycle_reference_fail() {
         "There is a circular reference between schema SchemaSub and SchemaBase",
         "There is a circular reference between rule RuleBase and RuleSub",
         "There is a circular reference between rule RuleSub and RuleBase",
+        "The condition of the check statement is a compile-time constant",
+        "The condition of the check statement is a compile-time constant",
         "Module 'file2' imported but unused",
         "Module 'file1' imported but unused",
     ];
@@ -264,7 +529,10 @@ fn test_lint() {
             raise_err: true,
             config_auto_fix: false,
             lint_check: true,
+            strict_type_check: false,
+            lint_levels: Default::default(),
         },
+        PkgScopeCache::default(),
     );
     resolver.resolve_import();
     resolver.check_and_lint(kclvm_ast::MAIN_PKG);
@@ -325,6 +593,129 @@ fn test_lint() {
     }
 }
 
+#[test]
+fn test_lint_unused_code() {
+    let mut program = parse_program("./src/resolver/test_data/unused_code.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let warnings = [
+        (
+            WarningKind::UnusedPrivateVariableWarning,
+            "Private variable '_unused' assigned but unused",
+        ),
+        (
+            WarningKind::UnusedTypeAliasWarning,
+            "Type alias 'Port' defined but unused",
+        ),
+        (
+            WarningKind::UnusedSchemaWarning,
+            "Schema 'Unused' defined but unused",
+        ),
+        (
+            WarningKind::UnusedSchemaLocalVariableWarning,
+            "Schema local variable '_local' assigned but unused",
+        ),
+        (
+            WarningKind::UnusedLambdaParameterWarning,
+            "Lambda parameter 'y' defined but unused",
+        ),
+    ];
+    assert_eq!(scope.handler.diagnostics.len(), warnings.len());
+    for (diag, (kind, msg)) in scope.handler.diagnostics.iter().zip(warnings.iter()) {
+        assert_eq!(diag.code, Some(DiagnosticId::Warning(kind.clone())));
+        assert_eq!(diag.messages[0].message, msg.to_string());
+    }
+}
+
+#[test]
+fn test_lint_correctness() {
+    let mut program = parse_program("./src/resolver/test_data/correctness_lints.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let warnings = [
+        (
+            WarningKind::NameShadowingWarning,
+            "Variable 'len' shadows the builtin function 'len'",
+        ),
+        (
+            WarningKind::NameShadowingWarning,
+            "Lambda parameter 'math' shadows the imported module 'math'",
+        ),
+        (
+            WarningKind::SelfComparisonWarning,
+            "Comparison of an expression with itself is always True",
+        ),
+        (
+            WarningKind::ConstantConditionWarning,
+            "The condition of the if statement is a compile-time constant",
+        ),
+        (
+            WarningKind::ConstantConditionWarning,
+            "The condition of the assert statement is a compile-time constant",
+        ),
+        (
+            WarningKind::DuplicateConfigKeyWarning,
+            "Duplicate config key 'name' overrides the former value",
+        ),
+        (
+            WarningKind::MutableDefaultWarning,
+            "Mutable default value of attribute 'ports' is unioned with config values",
+        ),
+    ];
+    assert_eq!(scope.handler.diagnostics.len(), warnings.len());
+    for (diag, (kind, msg)) in scope.handler.diagnostics.iter().zip(warnings.iter()) {
+        assert_eq!(diag.code, Some(DiagnosticId::Warning(kind.clone())));
+        assert_eq!(diag.messages[0].message, msg.to_string());
+    }
+}
+
+#[test]
+fn test_lint_levels() {
+    let lint_levels = parse_lint_levels(&HashMap::from([
+        ("unused_import".to_string(), "deny".to_string()),
+        ("mutable_default".to_string(), "allow".to_string()),
+    ]))
+    .unwrap();
+    let mut program = parse_program("./src/resolver/test_data/lint_levels.k").unwrap();
+    let scope = resolve_program_with_opts(
+        &mut program,
+        Options {
+            raise_err: true,
+            config_auto_fix: false,
+            lint_check: true,
+            strict_type_check: false,
+            lint_levels,
+        },
+    );
+    let diags = [
+        (
+            Level::Warning,
+            WarningKind::NameShadowingWarning,
+            "Variable 'max' shadows the builtin function 'max'",
+        ),
+        (
+            Level::Error,
+            WarningKind::UnusedImportWarning,
+            "Module 'regex' imported but unused",
+        ),
+    ];
+    assert_eq!(scope.handler.diagnostics.len(), diags.len());
+    for (diag, (level, kind, msg)) in scope.handler.diagnostics.iter().zip(diags.iter()) {
+        assert_eq!(diag.level, *level);
+        assert_eq!(diag.code, Some(DiagnosticId::Warning(kind.clone())));
+        assert_eq!(diag.messages[0].message, msg.to_string());
+    }
+
+    let levels = HashMap::from([("unknown_lint".to_string(), "deny".to_string())]);
+    assert_eq!(
+        parse_lint_levels(&levels).unwrap_err(),
+        "unknown lint 'unknown_lint'"
+    );
+    let levels = HashMap::from([("unused_import".to_string(), "error".to_string())]);
+    assert_eq!(
+        parse_lint_levels(&levels).unwrap_err(),
+        "invalid lint level 'error', expected 'allow', 'warn' or 'deny'"
+    );
+}
+
 #[test]
 fn test_resolve_schema_doc() {
     let mut program = parse_program("./src/resolver/test_data/doc.k").unwrap();
@@ -437,3 +828,245 @@ fn test_pkg_scope() {
 
     assert!(!pkg_scope.contains_pos(&pos));
 }
+
+#[test]
+fn test_resolve_program_with_pkg_cache() {
+    let root =
+        std::env::temp_dir().join(format!("kclvm_test_sema_pkg_cache_{}", std::process::id()));
+    let pkg_dir = root.join("pkg");
+    std::fs::create_dir_all(pkg_dir.join("base")).unwrap();
+    std::fs::write(root.join("kcl.mod"), "[build]\nenable_pkg_cache = true\n").unwrap();
+    std::fs::write(root.join("main.k"), "").unwrap();
+    std::fs::write(
+        pkg_dir.join("pkg.k"),
+        "import pkg.base\n\nschema Person(base.Base):\n    age: int = 1\n    labels: {str:str} = {app = name}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        pkg_dir.join("base").join("base.k"),
+        "schema Base:\n    name: str\n",
+    )
+    .unwrap();
+    let main_file = root.join("main.k").display().to_string();
+    let load = |code: &str| {
+        let opts = kclvm_parser::LoadProgramOptions {
+            k_code_list: vec![code.to_string()],
+            ..Default::default()
+        };
+        load_program(Arc::new(ParseSession::default()), &[&main_file], Some(opts)).unwrap()
+    };
+
+    let code = "import pkg\n\nperson = pkg.Person {name = \"Alice\"}\n";
+    let mut program = load(code);
+    let scope = resolve_program(&mut program);
+    // The mutable default warning in `pkg.Person`.
+    assert_eq!(scope.handler.diagnostics.len(), 1);
+
+    // The unchanged packages are loaded from the cache without resolving.
+    let mut program = load(code);
+    pre_process_program(&mut program);
+    let opts = Options {
+        raise_err: true,
+        lint_check: true,
+        ..Default::default()
+    };
+    let mut resolver = Resolver::new(&program, opts, PkgScopeCache::new(&program.root));
+    resolver.resolve_import();
+    let scope = resolver.check_and_lint(kclvm_ast::MAIN_PKG);
+    // The warnings of the cached packages are reported again.
+    assert_eq!(scope.handler.diagnostics.len(), 1);
+    assert!(resolver.pkg_scope_cache.loaded_pkgs.contains("pkg"));
+    assert!(resolver.pkg_scope_cache.loaded_pkgs.contains("pkg.base"));
+    let main_scope = scope.main_scope().unwrap().borrow();
+    assert_eq!(
+        main_scope.lookup("person").unwrap().borrow().ty.ty_str(),
+        "Person"
+    );
+    let pkg_scope = scope.scope_map["pkg"].borrow();
+    let schema_scope = pkg_scope.search_child_scope_by_name("Person").unwrap();
+    assert!(schema_scope.borrow().get_enclosing_scope().is_some());
+    let pkg_file = pkg_dir.join("pkg.k").display().to_string();
+    assert_eq!(scope.import_names[&pkg_file]["base"], "pkg.base");
+
+    // The schema types of the cached packages are still checked.
+    let mut program = load("import pkg\n\nperson = pkg.Person {name = 1}\n");
+    let scope = resolve_program(&mut program);
+    assert_eq!(scope.handler.diagnostics.len(), 2);
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn test_resolve_program_with_pkg_cache_diagnostics() {
+    let root = std::env::temp_dir().join(format!(
+        "kclvm_test_sema_pkg_cache_diagnostics_{}",
+        std::process::id()
+    ));
+    let pkg_dir = root.join("pkg");
+    std::fs::create_dir_all(pkg_dir.join("base")).unwrap();
+    std::fs::write(root.join("kcl.mod"), "[build]\nenable_pkg_cache = true\n").unwrap();
+    std::fs::write(root.join("main.k"), "").unwrap();
+    std::fs::write(
+        pkg_dir.join("base").join("base.k"),
+        "schema Base:\n    _secret: str = \"s\"\n    name: str\n\nschema Sub(Base):\n    id: int = 1\n",
+    )
+    .unwrap();
+    std::fs::write(
+        pkg_dir.join("pkg.k"),
+        r#"import pkg.base
+
+_subs: [base.Sub] = [base.Sub {name = "a"}]
+
+schema Person(base.Base):
+    age: int = 1
+    secret: str = _secret
+    labels: {str:str} = {app = name}
+
+schema Group:
+    members: [base.Base] = _subs
+"#,
+    )
+    .unwrap();
+    let main_file = root.join("main.k").display().to_string();
+    let code = r#"import pkg
+import pkg.base
+
+person = pkg.Person {name = "Alice"}
+group = pkg.Group {members: [base.Base {name = "e"}]}
+"#;
+    let resolve = || {
+        let opts = kclvm_parser::LoadProgramOptions {
+            k_code_list: vec![code.to_string()],
+            ..Default::default()
+        };
+        let mut program =
+            load_program(Arc::new(ParseSession::default()), &[&main_file], Some(opts)).unwrap();
+        pre_process_program(&mut program);
+        let opts = Options {
+            raise_err: true,
+            lint_check: true,
+            ..Default::default()
+        };
+        let mut resolver = Resolver::new(&program, opts, PkgScopeCache::new(&program.root));
+        resolver.resolve_import();
+        let scope = resolver.check_and_lint(kclvm_ast::MAIN_PKG);
+        resolver.save_pkg_scopes_to_cache(&scope);
+        let dep_graph = &resolver.ctx.ty_ctx.dep_graph;
+        (
+            scope.handler.diagnostics,
+            resolver
+                .ctx
+                .schema_mapping
+                .keys()
+                .cloned()
+                .collect::<Vec<String>>(),
+            dep_graph
+                .raw_edges()
+                .iter()
+                .map(|edge| {
+                    (
+                        dep_graph[edge.source()].clone(),
+                        dep_graph[edge.target()].clone(),
+                    )
+                })
+                .collect::<IndexSet<(String, String)>>(),
+            resolver.pkg_scope_cache.loaded_pkgs.len(),
+        )
+    };
+
+    let (cold_diags, cold_schemas, cold_deps, cold_loaded) = resolve();
+    let (warm_diags, mut warm_schemas, warm_deps, warm_loaded) = resolve();
+    assert_eq!(cold_loaded, 0);
+    assert_eq!(warm_loaded, 2);
+    // The mutable default warning in `pkg.Person` and the variance error of `group`.
+    assert!(cold_diags.iter().any(|diag| diag.level == Level::Warning));
+    assert!(cold_diags.iter().any(|diag| diag.level == Level::Error));
+    assert_eq!(cold_diags, warm_diags);
+    // The packages are loaded in the import order instead of the resolved order.
+    warm_schemas.sort_by_key(|name| cold_schemas.iter().position(|n| n == name));
+    assert_eq!(cold_schemas, warm_schemas);
+    assert_eq!(cold_deps.len(), 5);
+    assert_eq!(cold_deps, warm_deps);
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn test_resolve_program_with_memory_cache() {
+    let root = std::env::temp_dir().join(format!(
+        "kclvm_test_sema_memory_cache_{}",
+        std::process::id()
+    ));
+    let pkg_dir = root.join("pkg");
+    std::fs::create_dir_all(pkg_dir.join("base")).unwrap();
+    std::fs::write(root.join("main.k"), "").unwrap();
+    std::fs::write(
+        pkg_dir.join("pkg.k"),
+        "import pkg.base\n\nschema Person(base.Base):\n    age: int = 1\n",
+    )
+    .unwrap();
+    std::fs::write(
+        pkg_dir.join("base").join("base.k"),
+        "schema Base:\n    name: str\n",
+    )
+    .unwrap();
+    let main_file = root.join("main.k").display().to_string();
+    let memory = kclvm_config::cache::MemoryPkgCache::default();
+    let resolve = |code: &str| {
+        let opts = kclvm_parser::LoadProgramOptions {
+            k_code_list: vec![code.to_string()],
+            ..Default::default()
+        };
+        let mut program =
+            load_program(Arc::new(ParseSession::default()), &[&main_file], Some(opts)).unwrap();
+        pre_process_program(&mut program);
+        let opts = Options {
+            raise_err: true,
+            lint_check: true,
+            ..Default::default()
+        };
+        let pkg_scope_cache = PkgScopeCache::with_memory_cache(&program.root, memory.clone());
+        let mut resolver = Resolver::new(&program, opts, pkg_scope_cache);
+        resolver.resolve_import();
+        let scope = resolver.check_and_lint(kclvm_ast::MAIN_PKG);
+        resolver.save_pkg_scopes_to_cache(&scope);
+        let mut loaded_pkgs = resolver
+            .pkg_scope_cache
+            .loaded_pkgs
+            .iter()
+            .cloned()
+            .collect::<Vec<String>>();
+        loaded_pkgs.sort();
+        (scope.handler.diagnostics.len(), loaded_pkgs)
+    };
+
+    let code = "import pkg\n\nperson = pkg.Person {name = \"Alice\"}\n";
+    // The memory cache is enabled without `kcl.mod`.
+    assert_eq!(resolve(code), (0, vec![]));
+    assert_eq!(
+        resolve(code),
+        (0, vec!["pkg".to_string(), "pkg.base".to_string()])
+    );
+    // The main package is always resolved.
+    assert_eq!(
+        resolve("import pkg\n\nperson = pkg.Person {name = 1}\n"),
+        (1, vec!["pkg".to_string(), "pkg.base".to_string()])
+    );
+
+    // The changed package and the packages importing it are resolved again.
+    std::fs::write(
+        pkg_dir.join("base").join("base.k"),
+        "schema Base:\n    name: str\n    id?: int\n",
+    )
+    .unwrap();
+    assert_eq!(resolve(code), (0, vec![]));
+    std::fs::write(
+        pkg_dir.join("pkg.k"),
+        "import pkg.base\n\nschema Person(base.Base):\n    age: int = 2\n",
+    )
+    .unwrap();
+    assert_eq!(resolve(code), (0, vec!["pkg.base".to_string()]));
+    assert_eq!(
+        resolve(code),
+        (0, vec!["pkg".to_string(), "pkg.base".to_string()])
+    );
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -1,8 +1,8 @@
 use std::rc::Rc;
 
 use crate::resolver::Resolver;
-use crate::ty::parser::parse_type_str;
-use crate::ty::{assignable_to, SchemaType, Type, TypeKind};
+use crate::ty::parser::{parse_type_str, split_generic_type_str};
+use crate::ty::{assignable_to, instantiate_schema, substitute, SchemaType, Type, TypeKind};
 use indexmap::IndexMap;
 use kclvm_ast::ast;
 use kclvm_ast::pos::GetPos;
@@ -92,10 +92,17 @@ impl<'ctx> Resolver<'ctx> {
         expected_pos: Option<Position>,
     ) {
         if !self.check_type(ty.clone(), expected_ty.clone(), &pos) {
+            // The signatures are shown when a function is not assignable to a function type.
+            let (expected_ty_str, ty_str) = match (&expected_ty.kind, &ty.kind) {
+                (TypeKind::Function(expected_func_ty), TypeKind::Function(func_ty)) => {
+                    (expected_func_ty.ty_str(), func_ty.ty_str())
+                }
+                _ => (expected_ty.ty_str(), ty.ty_str()),
+            };
             let mut msgs = vec![Message {
                 pos,
                 style: Style::LineAndColumn,
-                message: format!("expected {}, got {}", expected_ty.ty_str(), ty.ty_str(),),
+                message: format!("expected {}, got {}", expected_ty_str, ty_str),
                 note: None,
             }];
 
@@ -105,8 +112,7 @@ impl<'ctx> Resolver<'ctx> {
                     style: Style::LineAndColumn,
                     message: format!(
                         "variable is defined here, its type is {}, but got {}",
-                        expected_ty.ty_str(),
-                        ty.ty_str(),
+                        expected_ty_str, ty_str,
                     ),
                     note: None,
                 });
@@ -115,6 +121,13 @@ impl<'ctx> Resolver<'ctx> {
         }
     }
 
+    /// Report the type which is implicitly inferred by the resolver in the strict type check mode.
+    pub fn add_implicit_type_error(&mut self, msg: &str, pos: Position) {
+        if self.options.strict_type_check {
+            self.handler.add_type_error(msg, pos);
+        }
+    }
+
     /// The check type main function, returns a boolean result.
     #[inline]
     pub fn check_type(&mut self, ty: Rc<Type>, expected_ty: Rc<Type>, pos: &Position) -> bool {
@@ -184,6 +197,23 @@ impl<'ctx> Resolver<'ctx> {
                     .map(|ty| self.upgrade_named_ty_with_scope(ty.clone(), pos))
                     .collect::<Vec<Rc<Type>>>(),
             ),
+            TypeKind::Function(func_ty) => {
+                let mut func_ty = func_ty.clone();
+                for param in func_ty.params.iter_mut() {
+                    param.ty = self.upgrade_named_ty_with_scope(param.ty.clone(), pos);
+                }
+                func_ty.return_ty =
+                    self.upgrade_named_ty_with_scope(func_ty.return_ty.clone(), pos);
+                let mut ty = ty.as_ref().clone();
+                ty.kind = TypeKind::Function(func_ty);
+                Rc::new(ty)
+            }
+            TypeKind::Named(ty_str) if self.ctx.type_params.contains(ty_str) => {
+                Rc::new(Type::type_var(ty_str))
+            }
+            TypeKind::Named(ty_str) if split_generic_type_str(ty_str).is_some() => {
+                self.upgrade_generic_ty_with_scope(ty_str, pos)
+            }
             TypeKind::Named(ty_str) => {
                 let ty_str = ty_str_replace_pkgpath(ty_str, &self.ctx.pkgpath);
                 let names: Vec<&str> = if ty_str.starts_with('@') {
@@ -217,6 +247,81 @@ impl<'ctx> Resolver<'ctx> {
         }
     }
 
+    /// Instantiate the generic schema or type alias with the type arguments, e.g., `Page[Person]`.
+    fn upgrade_generic_ty_with_scope(&mut self, ty_str: &str, pos: &Position) -> ResolvedResult {
+        let (name, args) = match split_generic_type_str(ty_str) {
+            Some(v) => v,
+            None => bug!("invalid generic type string {}", ty_str),
+        };
+        let generic_ty = self.upgrade_named_ty_with_scope(Rc::new(Type::named(name)), pos);
+        let type_args = args
+            .iter()
+            .map(|arg| {
+                let ty = parse_type_str(arg);
+                self.upgrade_named_ty_with_scope(ty, pos)
+            })
+            .collect::<Vec<Rc<Type>>>();
+        let type_params = match &generic_ty.kind {
+            TypeKind::Schema(schema_ty) => schema_ty.type_params.clone(),
+            _ if generic_ty.is_type_alias => self.lookup_type_alias_params(name),
+            _ => vec![],
+        };
+        if type_params.is_empty() {
+            if !generic_ty.is_any() {
+                self.handler.add_type_error(
+                    &format!("'{}' is not a generic type", generic_ty.ty_str()),
+                    pos.clone(),
+                );
+            }
+            return self.any_ty();
+        }
+        if type_params.len() != type_args.len() {
+            self.handler.add_type_error(
+                &format!(
+                    "expected {} type arguments for '{}', found {}",
+                    type_params.len(),
+                    name,
+                    type_args.len()
+                ),
+                pos.clone(),
+            );
+            return self.any_ty();
+        }
+        match &generic_ty.kind {
+            TypeKind::Schema(schema_ty) => {
+                Rc::new(Type::schema(instantiate_schema(schema_ty, &type_args)))
+            }
+            _ => {
+                let mapping = type_params.into_iter().zip(type_args).collect();
+                substitute(generic_ty, &mapping)
+            }
+        }
+    }
+
+    /// Lookup the type parameters of the generic type alias with its name, e.g., `pkg.Pairs`.
+    fn lookup_type_alias_params(&self, name: &str) -> Vec<String> {
+        let names: Vec<&str> = name.rsplitn(2, '.').collect();
+        let (pkgpath, name) = if names.len() == 2 {
+            let pkgpath = self
+                .ctx
+                .import_names
+                .get(&self.ctx.filename)
+                .and_then(|mapping| mapping.get(names[1]))
+                .map_or(names[1].trim_start_matches('@').to_string(), |pkgpath| {
+                    pkgpath.to_string()
+                });
+            (pkgpath, names[0])
+        } else {
+            (self.ctx.pkgpath.clone(), name)
+        };
+        self.ctx
+            .type_alias_params
+            .get(&pkgpath)
+            .and_then(|mapping| mapping.get(name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
     pub fn add_type_alias(&mut self, name: &str, alias: &str) {
         if alias.starts_with('@') {
             if name == &alias[1..] {
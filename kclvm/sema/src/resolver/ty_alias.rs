@@ -1,11 +1,88 @@
+use std::rc::Rc;
+
 use indexmap::IndexMap;
 use kclvm_ast::walker::MutSelfMutWalker;
 use kclvm_ast::{ast, walk_if_mut, walk_list_mut};
 
+use crate::ty::parser::{parse_type_str, split_generic_type_str};
+use crate::ty::{Type, TypeKind};
+
 #[derive(Default)]
 struct TypeAliasTransformer {
     pub pkgpath: String,
     pub type_alias_mapping: IndexMap<String, String>,
+    /// Type parameters of the schema being walked, e.g., `T` in `schema Page[type T]`.
+    pub type_params: Vec<String>,
+}
+
+impl TypeAliasTransformer {
+    /// Replace the type alias in the type string, and erase the type parameters
+    /// and the type arguments of generic types, which are only checked at compile time,
+    /// e.g., `[T]` -> `[any]` and `Page[Person]` -> `Page`.
+    fn fix_type_str(&self, ty_str: &mut String) {
+        if let Some(type_alias) = self.type_alias_mapping.get(ty_str.as_str()) {
+            *ty_str = type_alias.clone();
+        }
+        if !ty_str.contains('[') && !self.type_params.iter().any(|p| ty_str.contains(p)) {
+            return;
+        }
+        let (ty, erased) = self.erase_ty(parse_type_str(ty_str));
+        if erased {
+            *ty_str = ty.into_type_annotation_str();
+        }
+    }
+
+    fn erase_ty(&self, ty: Rc<Type>) -> (Rc<Type>, bool) {
+        match &ty.kind {
+            TypeKind::Named(name) if self.type_params.contains(name) => (Rc::new(Type::ANY), true),
+            TypeKind::Named(name) => match split_generic_type_str(name) {
+                Some((name, _)) => {
+                    let name = self
+                        .type_alias_mapping
+                        .get(name)
+                        .map_or(name, |type_alias| type_alias.as_str());
+                    (parse_type_str(name), true)
+                }
+                None => (ty.clone(), false),
+            },
+            TypeKind::List(item_ty) => {
+                let (item_ty, erased) = self.erase_ty(item_ty.clone());
+                (Type::list_ref(item_ty), erased)
+            }
+            TypeKind::Dict(key_ty, val_ty) => {
+                let (key_ty, key_erased) = self.erase_ty(key_ty.clone());
+                let (val_ty, val_erased) = self.erase_ty(val_ty.clone());
+                (Type::dict_ref(key_ty, val_ty), key_erased || val_erased)
+            }
+            TypeKind::Union(types) => {
+                let mut erased = false;
+                let types = types
+                    .iter()
+                    .map(|ty| {
+                        let (ty, ty_erased) = self.erase_ty(ty.clone());
+                        erased |= ty_erased;
+                        ty
+                    })
+                    .collect::<Vec<Rc<Type>>>();
+                (Type::union_ref(&types), erased)
+            }
+            TypeKind::Function(func_ty) => {
+                let mut func_ty = func_ty.clone();
+                let mut erased = false;
+                for param in func_ty.params.iter_mut() {
+                    let (ty, ty_erased) = self.erase_ty(param.ty.clone());
+                    param.ty = ty;
+                    erased |= ty_erased;
+                }
+                let (return_ty, return_erased) = self.erase_ty(func_ty.return_ty.clone());
+                func_ty.return_ty = return_ty;
+                let mut ty = ty.as_ref().clone();
+                ty.kind = TypeKind::Function(func_ty);
+                (Rc::new(ty), erased || return_erased)
+            }
+            _ => (ty.clone(), false),
+        }
+    }
 }
 
 impl<'ctx> MutSelfMutWalker<'ctx> for TypeAliasTransformer {
@@ -19,40 +96,32 @@ impl<'ctx> MutSelfMutWalker<'ctx> for TypeAliasTransformer {
     fn walk_schema_stmt(&mut self, schema_stmt: &'ctx mut ast::SchemaStmt) {
         // walk_if_mut!(self, walk_identifier, schema_stmt.parent_name);
         // walk_if_mut!(self, walk_identifier, schema_stmt.for_host_name);
+        self.type_params = schema_stmt
+            .type_params
+            .iter()
+            .map(|param| param.node.clone())
+            .collect();
         walk_if_mut!(self, walk_arguments, schema_stmt.args);
         if let Some(schema_index_signature) = schema_stmt.index_signature.as_deref_mut() {
             let value = &mut schema_index_signature.node.value;
-            if let Some(type_alias) = self
-                .type_alias_mapping
-                .get(&schema_index_signature.node.key_type.node)
-            {
-                schema_index_signature.node.key_type.node = type_alias.clone();
-            }
-            if let Some(type_alias) = self
-                .type_alias_mapping
-                .get(&schema_index_signature.node.value_type.node)
-            {
-                schema_index_signature.node.value_type.node = type_alias.clone();
-            }
+            self.fix_type_str(&mut schema_index_signature.node.key_type.node);
+            self.fix_type_str(&mut schema_index_signature.node.value_type.node);
             walk_if_mut!(self, walk_expr, value);
         }
         walk_list_mut!(self, walk_identifier, schema_stmt.mixins);
         // walk_list_mut!(self, walk_call_expr, schema_stmt.decorators);
         walk_list_mut!(self, walk_stmt, schema_stmt.body);
         walk_list_mut!(self, walk_check_expr, schema_stmt.checks);
+        self.type_params.clear();
     }
     fn walk_schema_attr(&mut self, schema_attr: &'ctx mut ast::SchemaAttr) {
         // walk_list_mut!(self, walk_call_expr, schema_attr.decorators);
-        if let Some(type_alias) = self.type_alias_mapping.get(&schema_attr.type_str.node) {
-            schema_attr.type_str.node = type_alias.clone();
-        }
+        self.fix_type_str(&mut schema_attr.type_str.node);
         walk_if_mut!(self, walk_expr, schema_attr.value);
     }
     fn walk_assign_stmt(&mut self, assign_stmt: &'ctx mut ast::AssignStmt) {
         if let Some(ty_str) = &mut assign_stmt.type_annotation {
-            if let Some(type_alias) = self.type_alias_mapping.get(&ty_str.node) {
-                ty_str.node = type_alias.clone();
-            }
+            self.fix_type_str(&mut ty_str.node);
         }
         self.walk_expr(&mut assign_stmt.value.node);
     }
@@ -64,17 +133,13 @@ impl<'ctx> MutSelfMutWalker<'ctx> for TypeAliasTransformer {
         walk_if_mut!(self, walk_arguments, lambda_expr.args);
         walk_list_mut!(self, walk_stmt, lambda_expr.body);
         if let Some(ty_str) = &mut lambda_expr.return_type_str {
-            if let Some(type_alias) = self.type_alias_mapping.get(ty_str) {
-                *ty_str = type_alias.clone();
-            }
+            self.fix_type_str(ty_str);
         }
     }
     fn walk_arguments(&mut self, arguments: &'ctx mut ast::Arguments) {
         walk_list_mut!(self, walk_identifier, arguments.args);
         for type_annotation in (&mut arguments.type_annotation_list.iter_mut()).flatten() {
-            if let Some(type_alias) = self.type_alias_mapping.get(&type_annotation.node) {
-                type_annotation.node = type_alias.clone();
-            }
+            self.fix_type_str(&mut type_annotation.node);
         }
         for default in arguments.defaults.iter_mut() {
             if let Some(d) = default.as_deref_mut() {
@@ -116,6 +181,7 @@ fn fix_type_alias_identifier<'ctx>(
     let mut type_alias_transformer = TypeAliasTransformer {
         pkgpath: module.pkg.clone(),
         type_alias_mapping,
+        ..Default::default()
     };
     type_alias_transformer.walk_module(module);
 }
@@ -126,10 +192,10 @@ pub fn process_program_type_alias(
     type_alias_mapping: IndexMap<String, IndexMap<String, String>>,
 ) {
     for (pkgpath, modules) in program.pkgs.iter_mut() {
+        // The packages without type alias are also walked to erase the type parameters.
+        let type_alias_mapping = type_alias_mapping.get(pkgpath).cloned().unwrap_or_default();
         for module in modules.iter_mut() {
-            if let Some(type_alias_mapping) = type_alias_mapping.get(pkgpath) {
-                fix_type_alias_identifier(module, type_alias_mapping.clone());
-            }
+            fix_type_alias_identifier(module, type_alias_mapping.clone());
         }
     }
 }
@@ -22,12 +22,39 @@ impl<'ctx> Resolver<'ctx> {
         }
         if names.len() == 1 {
             let name = &names[0];
+            if !self.ctx.l_value {
+                // Record the variable as "used" for the unused code lints. The module is only
+                // used when its members are selected, e.g., `math.log`.
+                if let Some(obj) = self.scope.borrow().lookup(name) {
+                    if obj.borrow().kind != ScopeObjectKind::Module {
+                        obj.borrow_mut().used = true;
+                    }
+                }
+                if let Some(ty) = self.lookup_narrowed_type(name) {
+                    return ty;
+                }
+            }
             let scope_schema_ty = self.ctx.schema.clone();
             if let Some(schema_ty) = &scope_schema_ty {
                 let mut schema_ty = schema_ty.borrow_mut();
                 let ty = schema_ty.get_type_of_attr(name);
                 // Load from schema if in schema
                 if !self.ctx.l_value {
+                    // The private attributes of the base schemas are not in the current
+                    // schema scope, record them and mark them as "used" after resolving.
+                    if name.starts_with('_') && ty.is_some() {
+                        let mut base = schema_ty.base.as_ref();
+                        while let Some(base_ty) = base {
+                            self.ctx
+                                .used_inherited_attrs
+                                .entry(self.ctx.pkgpath.clone())
+                                .or_default()
+                                .entry((base_ty.pkgpath.clone(), base_ty.name.clone()))
+                                .or_default()
+                                .insert(name.to_string());
+                            base = base_ty.base.as_ref();
+                        }
+                    }
                     let scope_ty = self.find_type_in_scope(name);
                     if self.ctx.local_vars.contains(name) {
                         return scope_ty.map_or(self.any_ty(), |ty| ty);
@@ -0,0 +1,182 @@
+use crate::resolver::Resolver;
+use crate::ty::{subsume, SchemaType, TypeKind};
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_error::{ErrorKind, Message, Position, Style};
+use serde::{Deserialize, Serialize};
+
+use super::node::TypeRef;
+
+/// A list or dict variable which refers to the value of another variable with a narrower
+/// item type, e.g., `_bases` in `_bases: [Base] = _subs` where `_subs` is `[Sub]`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CovariantAlias {
+    /// The name of the referred variable.
+    pub source: String,
+    /// The type of the referred variable.
+    pub source_ty: TypeRef,
+    /// The declared type of the alias.
+    pub ty: TypeRef,
+    /// The position where the alias is assigned.
+    pub pos: Position,
+}
+
+/// The covariant aliases by the owner and the variable name, the owner is the runtime type
+/// of the schema for the schema attributes and the package path for the global variables.
+pub type CovariantAliases = IndexMap<(String, String), CovariantAlias>;
+
+impl<'ctx> Resolver<'ctx> {
+    /// Record the variable `name` as a covariant alias when the value is a variable whose
+    /// list item type or dict value type is a strict sub type of the expected one, and
+    /// forget the former alias of it.
+    pub(crate) fn record_covariant_alias(
+        &mut self,
+        name: &str,
+        value: &'ctx ast::NodeRef<ast::Expr>,
+        value_ty: TypeRef,
+        expected_ty: TypeRef,
+        pos: Position,
+    ) {
+        let key = (self.alias_owner(), name.to_string());
+        self.ctx.covariant_aliases.remove(&key);
+        let source = match &value.node {
+            ast::Expr::Identifier(identifier) => identifier.get_name(),
+            _ => return,
+        };
+        if is_strict_covariant(&value_ty, &expected_ty) {
+            self.ctx.covariant_aliases.insert(
+                key,
+                CovariantAlias {
+                    source,
+                    source_ty: value_ty,
+                    ty: expected_ty,
+                    pos,
+                },
+            );
+        }
+    }
+
+    /// Check the mutation `name += value` of the variable in the current schema or package.
+    pub(crate) fn check_covariant_alias_mutation(
+        &mut self,
+        name: &str,
+        value_ty: TypeRef,
+        pos: Position,
+    ) {
+        let mut owners = vec![self.ctx.pkgpath.clone()];
+        if let Some(schema_ty) = &self.ctx.schema {
+            owners.insert(0, schema_owner(&schema_ty.borrow()));
+        }
+        self.check_covariant_alias_mutation_in(&owners, name, value_ty, false, pos);
+    }
+
+    /// Check the mutation of the schema attribute `name` in the schema config through
+    /// the unification `:`, the insertion `+=` and the index assignment `[..]`.
+    pub(crate) fn check_covariant_attr_mutation(
+        &mut self,
+        schema_ty: &SchemaType,
+        name: &str,
+        value_ty: TypeRef,
+        is_item: bool,
+        pos: Position,
+    ) {
+        let mut owners = vec![];
+        let mut ty = Some(schema_ty);
+        while let Some(schema_ty) = ty {
+            owners.push(schema_owner(schema_ty));
+            ty = schema_ty.base.as_deref();
+        }
+        self.check_covariant_alias_mutation_in(&owners, name, value_ty, is_item, pos);
+    }
+
+    fn check_covariant_alias_mutation_in(
+        &mut self,
+        owners: &[String],
+        name: &str,
+        value_ty: TypeRef,
+        is_item: bool,
+        pos: Position,
+    ) {
+        let alias = match owners.iter().find_map(|owner| {
+            self.ctx
+                .covariant_aliases
+                .get(&(owner.to_string(), name.to_string()))
+        }) {
+            Some(alias) => alias.clone(),
+            None => return,
+        };
+        let expected_ty = if is_item {
+            match &alias.source_ty.kind {
+                TypeKind::List(item_ty) => item_ty.clone(),
+                TypeKind::Dict(_, val_ty) => val_ty.clone(),
+                _ => return,
+            }
+        } else {
+            alias.source_ty.clone()
+        };
+        if subsume(value_ty.clone(), expected_ty, false) {
+            return;
+        }
+        self.handler.add_error(
+            ErrorKind::VarianceError,
+            &[
+                Message {
+                    pos,
+                    style: Style::LineAndColumn,
+                    message: format!(
+                        "'{}' is mutated with the value of type '{}', which breaks the type '{}' of '{}'",
+                        name,
+                        value_ty.ty_str(),
+                        alias.source_ty.ty_str(),
+                        alias.source,
+                    ),
+                    note: None,
+                },
+                Message {
+                    pos: alias.pos,
+                    style: Style::LineAndColumn,
+                    message: format!(
+                        "'{}' of type '{}' refers to '{}' of type '{}'",
+                        name,
+                        alias.ty.ty_str(),
+                        alias.source,
+                        alias.source_ty.ty_str(),
+                    ),
+                    note: Some(if alias.source_ty.is_list() {
+                        format!("copy the value with '[*{}]' to mutate it", alias.source)
+                    } else {
+                        format!("copy the value with '{{**{}}}' to mutate it", alias.source)
+                    }),
+                },
+            ],
+        );
+    }
+
+    fn alias_owner(&self) -> String {
+        match &self.ctx.schema {
+            Some(schema_ty) => schema_owner(&schema_ty.borrow()),
+            None => self.ctx.pkgpath.clone(),
+        }
+    }
+}
+
+pub(crate) fn schema_owner(schema_ty: &SchemaType) -> String {
+    kclvm_runtime::schema_runtime_type(&schema_ty.name, &schema_ty.pkgpath)
+}
+
+/// Whether the list item type or the dict value type of `value_ty` is a strict sub type of
+/// the one of `expected_ty`, e.g., `[Sub]` and `[Base]`.
+fn is_strict_covariant(value_ty: &TypeRef, expected_ty: &TypeRef) -> bool {
+    let (value_item_ty, expected_item_ty) = match (&value_ty.kind, &expected_ty.kind) {
+        (TypeKind::List(value_item_ty), TypeKind::List(expected_item_ty)) => {
+            (value_item_ty, expected_item_ty)
+        }
+        (TypeKind::Dict(_, value_val_ty), TypeKind::Dict(_, expected_val_ty)) => {
+            (value_val_ty, expected_val_ty)
+        }
+        _ => return false,
+    };
+    !value_item_ty.is_any()
+        && subsume(value_item_ty.clone(), expected_item_ty.clone(), false)
+        && !subsume(expected_item_ty.clone(), value_item_ty.clone(), false)
+}
@@ -88,6 +88,15 @@ impl Type {
             is_type_alias: false,
         }
     }
+    /// Construct a type variable.
+    #[inline]
+    pub fn type_var(name: &str) -> Type {
+        Type {
+            kind: TypeKind::TypeVar(name.to_string()),
+            flags: TypeFlags::TYPE_VAR,
+            is_type_alias: false,
+        }
+    }
     /// Construct a number multiplier type.
     #[inline]
     pub fn number_multiplier(value: f64, raw_value: i64, binary_suffix: &str) -> Type {
@@ -295,6 +304,11 @@ impl Type {
     pub fn is_module(&self) -> bool {
         self.flags.contains(TypeFlags::MODULE)
     }
+    /// Whether is a type variable.
+    #[inline]
+    pub fn is_type_var(&self) -> bool {
+        self.flags.contains(TypeFlags::TYPE_VAR)
+    }
     /// Whether is an assignable type.
     #[inline]
     pub fn is_assignable_type(&self) -> bool {
@@ -314,7 +328,8 @@ impl Type {
             | TypeKind::Union(_)
             | TypeKind::Schema(_)
             | TypeKind::NumberMultiplier(_)
-            | TypeKind::Function(_) => true,
+            | TypeKind::Function(_)
+            | TypeKind::TypeVar(_) => true,
             TypeKind::Void | TypeKind::Module(_) | TypeKind::Named(_) => false,
         }
     }
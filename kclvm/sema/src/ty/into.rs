@@ -51,6 +51,14 @@ impl Type {
             _ => bug!("invalid type {} into schema type", self.ty_str()),
         }
     }
+    /// Into function type.
+    #[inline]
+    pub fn into_function_type(&self) -> FunctionType {
+        match &self.kind {
+            TypeKind::Function(func_ty) => func_ty.clone(),
+            _ => bug!("invalid type {} into function type", self.ty_str()),
+        }
+    }
     /// Into number multiplier type.
     #[inline]
     pub fn into_number_multiplier(&self) -> NumberMultiplierType {
@@ -91,7 +99,27 @@ impl Type {
                 .map(|ty| ty.into_type_annotation_str())
                 .collect::<Vec<String>>()
                 .join("|"),
+            TypeKind::Schema(schema_ty) if !schema_ty.type_args.is_empty() => format!(
+                "{}[{}]",
+                schema_ty.ty_str_with_pkgpath(),
+                schema_ty
+                    .type_args
+                    .iter()
+                    .map(|ty| ty.into_type_annotation_str())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
             TypeKind::Schema(schema_ty) => schema_ty.ty_str_with_pkgpath(),
+            TypeKind::Function(func_ty) => format!(
+                "({}) -> {}",
+                func_ty
+                    .params
+                    .iter()
+                    .map(|param| param.ty.into_type_annotation_str())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                func_ty.return_ty.into_type_annotation_str()
+            ),
             TypeKind::NumberMultiplier(number_multiplier) => {
                 if number_multiplier.is_literal {
                     format!(
@@ -120,6 +148,8 @@ impl From<ast::Type> for Type {
                 ast::BasicType::Str => Type::STR,
             },
             ast::Type::Named(identifier) => Type::named(&identifier.get_name()),
+            // The generic type is instantiated with the scope, e.g., `Page[Person]`.
+            generic_ty @ ast::Type::Generic(_) => Type::named(&generic_ty.to_string()),
             ast::Type::List(list_ty) => Type::list(
                 list_ty
                     .inner_type
@@ -156,6 +186,22 @@ impl From<ast::Type> for Type {
                 ast::LiteralType::Float(v) => Type::float_lit(v),
                 ast::LiteralType::Str(v) => Type::str_lit(&v),
             },
+            ast::Type::Function(func_ty) => Type::function(
+                None,
+                Rc::new(func_ty.ret_ty.node.into()),
+                &func_ty
+                    .params_ty
+                    .iter()
+                    .map(|ty| Parameter {
+                        name: "".to_string(),
+                        ty: Rc::new(ty.node.clone().into()),
+                        has_default: false,
+                    })
+                    .collect::<Vec<Parameter>>(),
+                "",
+                false,
+                None,
+            ),
         }
     }
 }
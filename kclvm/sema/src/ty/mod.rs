@@ -17,11 +17,12 @@ pub use unify::*;
 pub use walker::walk_type;
 
 use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Type {
     // The type kind.
     pub kind: TypeKind,
@@ -29,6 +30,10 @@ pub struct Type {
     pub is_type_alias: bool,
     /// This field provides fast access to information that is
     /// also contained in `kind`.
+    #[serde(
+        serialize_with = "serialize_flags",
+        deserialize_with = "deserialize_flags"
+    )]
     flags: TypeFlags,
 }
 
@@ -60,17 +65,34 @@ impl Type {
                 .map(|ty| ty.ty_str())
                 .collect::<Vec<String>>()
                 .join("|"),
-            TypeKind::Schema(schema_ty) => schema_ty.name.to_string(),
+            TypeKind::Schema(schema_ty) => schema_ty.ty_str(),
             TypeKind::NumberMultiplier(number_multiplier) => number_multiplier.ty_str(),
             TypeKind::Function(_) => FUNCTION_TYPE_STR.to_string(),
             TypeKind::Void => VOID_TYPE_STR.to_string(),
             TypeKind::Module(module_ty) => format!("{} '{}'", MODULE_TYPE_STR, module_ty.pkgpath),
             TypeKind::Named(name) => name.to_string(),
+            TypeKind::TypeVar(name) => name.to_string(),
+        }
+    }
+    /// Returns the result of the builtin function `typeof` for the values of the type,
+    /// or `None` if it can not be determined at compile time.
+    pub fn typeof_str(&self) -> Option<String> {
+        match &self.kind {
+            TypeKind::None => Some(NAME_CONSTANT_NONE.to_string()),
+            TypeKind::Bool | TypeKind::BoolLit(_) => Some(BOOL_TYPE_STR.to_string()),
+            TypeKind::Int | TypeKind::IntLit(_) => Some(INT_TYPE_STR.to_string()),
+            TypeKind::Float | TypeKind::FloatLit(_) => Some(FLOAT_TYPE_STR.to_string()),
+            TypeKind::Str | TypeKind::StrLit(_) => Some(STR_TYPE_STR.to_string()),
+            TypeKind::List(_) => Some(LIST_TYPE_STR.to_string()),
+            TypeKind::Dict(_, _) => Some(DICT_TYPE_STR.to_string()),
+            TypeKind::Schema(schema_ty) => Some(schema_ty.name.clone()),
+            TypeKind::Function(_) => Some(FUNCTION_TYPE_STR.to_string()),
+            _ => None,
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TypeKind {
     /// A primitive None name constant.
     None,
@@ -110,12 +132,14 @@ pub enum TypeKind {
     Module(ModuleType),
     /// A named type alias.
     Named(String),
+    /// A type variable, e.g., `T` in `schema Page[type T]`.
+    TypeVar(String),
 }
 
 bitflags::bitflags! {
     /// TypeFlags provides fast access to information that is also contained
     /// in `kind`.
-    pub struct TypeFlags: u16 {
+    pub struct TypeFlags: u32 {
         const VOID = 1 << 0;
         const INT = 1 << 1;
         const FLOAT = 1 << 2;
@@ -132,11 +156,28 @@ bitflags::bitflags! {
         const FUNCTION = 1 << 13;
         const MODULE = 1 << 14;
         const NAMED = 1 << 15;
+        const TYPE_VAR = 1 << 16;
     }
 }
 
+fn serialize_flags<S>(flags: &TypeFlags, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    flags.bits().serialize(serializer)
+}
+
+fn deserialize_flags<'de, D>(deserializer: D) -> Result<TypeFlags, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(TypeFlags::from_bits_truncate(u32::deserialize(
+        deserializer,
+    )?))
+}
+
 /// The schema type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaType {
     /// The schema name.
     pub name: String,
@@ -169,6 +210,10 @@ pub struct SchemaType {
     pub is_protocol: bool,
     /// Indicates whether it is a rule.
     pub is_rule: bool,
+    /// Type parameters of the generic schema, e.g., `T` in `schema Page[type T]`.
+    pub type_params: Vec<String>,
+    /// Type arguments of the instantiated generic schema, e.g., `Person` in `Page[Person]`.
+    pub type_args: Vec<Rc<Type>>,
     /// Base schema.
     pub base: Option<Box<SchemaType>>,
     /// Protocol schema.
@@ -186,6 +231,22 @@ pub struct SchemaType {
 }
 
 impl SchemaType {
+    /// Returns the type string of the schema including the type arguments, e.g., `Page[Person]`.
+    pub fn ty_str(&self) -> String {
+        if self.type_args.is_empty() {
+            self.name.clone()
+        } else {
+            format!(
+                "{}[{}]",
+                self.name,
+                self.type_args
+                    .iter()
+                    .map(|ty| ty.ty_str())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        }
+    }
     /// Get the object type string with pkgpath
     pub fn ty_str_with_pkgpath(&self) -> String {
         if self.pkgpath.is_empty() || self.pkgpath == MAIN_PKG {
@@ -246,7 +307,7 @@ impl SchemaType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaAttr {
     pub is_optional: bool,
     pub has_default: bool,
@@ -255,7 +316,7 @@ pub struct SchemaAttr {
     pub doc: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaIndexSignature {
     pub key_name: Option<String>,
     pub key_ty: Rc<Type>,
@@ -277,7 +338,7 @@ impl SchemaIndexSignature {
 }
 
 /// The module type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModuleType {
     pub pkgpath: String,
     pub imported: Vec<String>,
@@ -285,14 +346,14 @@ pub struct ModuleType {
 }
 
 /// The module kind.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ModuleKind {
     User,
     System,
     Plugin,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Decorator {
     pub target: DecoratorTarget,
     /// The decorator name.
@@ -301,14 +362,14 @@ pub struct Decorator {
     pub key: String,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DecoratorTarget {
     Schema,
     Attribute,
 }
 
 /// The number multiplier type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NumberMultiplierType {
     pub value: f64,
     pub raw_value: i64,
@@ -330,7 +391,7 @@ impl NumberMultiplierType {
 }
 
 /// The function type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionType {
     pub doc: String,
     pub params: Vec<Parameter>,
@@ -340,8 +401,28 @@ pub struct FunctionType {
     pub kw_only_index: Option<usize>,
 }
 
+impl FunctionType {
+    /// Returns the signature string of the function, e.g., `(int, str) -> bool`, the
+    /// function typed parameters and return type are shown with their signatures.
+    pub fn ty_str(&self) -> String {
+        let ty_str = |ty: &Rc<Type>| match &ty.kind {
+            TypeKind::Function(func_ty) => func_ty.ty_str(),
+            _ => ty.ty_str(),
+        };
+        format!(
+            "({}) -> {}",
+            self.params
+                .iter()
+                .map(|param| ty_str(&param.ty))
+                .collect::<Vec<String>>()
+                .join(", "),
+            ty_str(&self.return_ty)
+        )
+    }
+}
+
 /// The function parameter.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Parameter {
     pub name: String,
     pub ty: Rc<Type>,
@@ -11,7 +11,9 @@ pub fn parse_type_str(ty_str: &str) -> Rc<Type> {
     match TYPES_MAPPING.get(ty_str) {
         Some(ty) => Rc::new(ty.clone()),
         None => {
-            if is_union_type_str(ty_str) {
+            if is_function_type_str(ty_str) {
+                parse_function_type_str(ty_str)
+            } else if is_union_type_str(ty_str) {
                 parse_union_type_str(ty_str)
             } else if is_literal_type_str(ty_str) {
                 parse_lit_type_str(ty_str)
@@ -82,9 +84,9 @@ pub fn is_union_type_str(ty: &str) -> bool {
         let c = ty.chars().nth(i).unwrap();
         if c == '|' && stack.is_empty() {
             return true;
-        } else if c == '[' || c == '{' {
+        } else if c == '[' || c == '{' || c == '(' {
             stack.push(c);
-        } else if c == ']' || c == '}' {
+        } else if c == ']' || c == '}' || c == ')' {
             stack.pop();
         } else if c == '\"' {
             let t = &ty[i..];
@@ -126,12 +128,12 @@ pub fn split_type_union(ty_str: &str) -> Vec<&str> {
             types.push(&ty_str[s_index..i]);
             s_index = i + 1;
         }
-        // List/Dict type
-        else if c == '[' || c == '{' {
+        // List/Dict/Function type
+        else if c == '[' || c == '{' || c == '(' {
             stack.push(c);
         }
-        // List/Dict type
-        else if c == ']' || c == '}' {
+        // List/Dict/Function type
+        else if c == ']' || c == '}' || c == ')' {
             stack.pop();
         }
         // String literal type
@@ -165,6 +167,117 @@ pub fn parse_union_type_str(ty_str: &str) -> Rc<Type> {
     sup(&types)
 }
 
+/// is function type, e.g., `(int, str) -> bool`
+#[inline]
+pub fn is_function_type_str(ty_str: &str) -> bool {
+    split_function_type_str(ty_str).is_some()
+}
+
+/// Parse function type string.
+pub fn parse_function_type_str(ty_str: &str) -> Rc<Type> {
+    let (params_ty_str, ret_ty_str) = match split_function_type_str(ty_str) {
+        Some(v) => v,
+        None => bug!("invalid function type string {}", ty_str),
+    };
+    let params = params_ty_str
+        .iter()
+        .map(|ty_str| Parameter {
+            name: "".to_string(),
+            ty: parse_type_str(ty_str),
+            has_default: false,
+        })
+        .collect::<Vec<Parameter>>();
+    Rc::new(Type::function(
+        None,
+        parse_type_str(ret_ty_str),
+        &params,
+        "",
+        false,
+        None,
+    ))
+}
+
+/// split_function_type_str splits the function type string into the parameter type strings
+/// and the return type string, e.g., "(int, [str]) -> bool" -> (vec!["int", "[str]"], "bool")
+fn split_function_type_str(ty_str: &str) -> Option<(Vec<&str>, &str)> {
+    if !ty_str.starts_with('(') {
+        return None;
+    }
+    let mut depth = 0;
+    let mut s_index = 1;
+    let mut params = vec![];
+    for (i, c) in ty_str.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ']' | '}' => depth -= 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    let param = ty_str_strip(&ty_str[s_index..i]);
+                    if !param.is_empty() || !params.is_empty() {
+                        params.push(param);
+                    }
+                    let ret_ty_str = ty_str[i + 1..].trim_start().strip_prefix("->")?;
+                    return Some((params, ret_ty_str));
+                }
+            }
+            ',' if depth == 1 => {
+                params.push(ty_str_strip(&ty_str[s_index..i]));
+                s_index = i + 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// is generic type, e.g., `Page[Person]`
+#[inline]
+pub fn is_generic_type_str(ty_str: &str) -> bool {
+    split_generic_type_str(ty_str).is_some()
+}
+
+/// split_generic_type_str splits the generic type string into the name and the type argument
+/// strings, e.g., "Pair[str, [int]]" -> ("Pair", vec!["str", "[int]"])
+pub fn split_generic_type_str(ty_str: &str) -> Option<(&str, Vec<&str>)> {
+    let ty_str = ty_str_strip(ty_str);
+    let index = ty_str.find('[')?;
+    let name = &ty_str[..index];
+    if name.is_empty()
+        || !ty_str.ends_with(']')
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == '@')
+    {
+        return None;
+    }
+    let mut depth = 0;
+    let mut s_index = index + 1;
+    let mut args = vec![];
+    for (i, c) in ty_str.char_indices().skip(index) {
+        match c {
+            '[' | '{' | '(' => depth += 1,
+            '}' | ')' => depth -= 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    // The type arguments must end at the end of the type string.
+                    if i != ty_str.len() - 1 {
+                        return None;
+                    }
+                    args.push(ty_str_strip(&ty_str[s_index..i]));
+                }
+            }
+            ',' if depth == 1 => {
+                args.push(ty_str_strip(&ty_str[s_index..i]));
+                s_index = i + 1;
+            }
+            _ => {}
+        }
+    }
+    Some((name, args))
+}
+
 /// Parse literal type string.
 pub fn parse_lit_type_str(ty_str: &str) -> Rc<Type> {
     // Bool literal type.
@@ -115,3 +115,114 @@ fn test_type_walker() {
         );
     }
 }
+
+#[test]
+fn test_function_type() {
+    let func_ty = parser::parse_type_str("(int, [str]) -> str|int");
+    assert_eq!(func_ty.ty_str(), "function");
+    assert_eq!(
+        func_ty.into_function_type().ty_str(),
+        "(int, [str]) -> str|int"
+    );
+    assert_eq!(
+        func_ty.into_type_annotation_str(),
+        "(int, [str]) -> str|int"
+    );
+    assert_eq!(
+        parser::parse_type_str("() -> any").into_type_annotation_str(),
+        "() -> any"
+    );
+    assert!(parser::parse_type_str("int|(str) -> int").is_union());
+
+    let cases = [
+        // Parameters are contravariant and the return type is covariant.
+        ("(int) -> int", "(int) -> int|str", true),
+        ("(int|str) -> int", "(int) -> int", true),
+        ("(int) -> int|str", "(int) -> int", false),
+        ("(int) -> int", "(int|str) -> int", false),
+        // Arity
+        ("(int, int) -> int", "(int) -> int", false),
+        ("(int) -> int", "(int, int) -> int", false),
+        ("(any) -> any", "(int) -> str", true),
+    ];
+    for (lhs, rhs, expected) in cases {
+        assert_eq!(
+            subsume(
+                parser::parse_type_str(lhs),
+                parser::parse_type_str(rhs),
+                true
+            ),
+            expected,
+            "{} -> {}",
+            lhs,
+            rhs
+        );
+    }
+}
+
+#[test]
+fn test_generic_type() {
+    assert_eq!(
+        parser::split_generic_type_str("Pair[str, [int]]"),
+        Some(("Pair", vec!["str", "[int]"]))
+    );
+    assert_eq!(
+        parser::split_generic_type_str("pkg.Page[{str:Person}]"),
+        Some(("pkg.Page", vec!["{str:Person}"]))
+    );
+    assert_eq!(parser::split_generic_type_str("[int]"), None);
+    assert_eq!(parser::split_generic_type_str("Page"), None);
+    assert_eq!(parser::split_generic_type_str("Page[int]x"), None);
+
+    let type_var = Rc::new(Type::type_var("T"));
+    let ty = Type::union_ref(&[
+        Type::list_ref(type_var.clone()),
+        Type::dict_ref(Rc::new(Type::STR), type_var.clone()),
+    ]);
+    assert_eq!(ty.ty_str(), "[T]|{str:T}");
+    let mut mapping = IndexMap::default();
+    mapping.insert("T".to_string(), Rc::new(Type::INT));
+    assert_eq!(substitute(ty, &mapping).ty_str(), "[int]|{str:int}");
+    // Type variables are only compatible with themselves before the instantiation.
+    assert!(!subsume(Rc::new(Type::STR), type_var.clone(), true));
+    assert!(!subsume(type_var.clone(), Rc::new(Type::STR), true));
+    assert!(!subsume(
+        type_var.clone(),
+        Rc::new(Type::type_var("U")),
+        true
+    ));
+    assert!(subsume(type_var.clone(), type_var.clone(), true));
+    assert!(subsume(Rc::new(Type::NONE), type_var, true));
+
+    let mut mapping = IndexMap::default();
+    infer_type_vars(
+        Type::list_ref(Rc::new(Type::type_var("T"))),
+        Type::list_ref(Rc::new(Type::INT)),
+        &mut mapping,
+    );
+    infer_type_vars(
+        Rc::new(Type::type_var("T")),
+        Type::dict_ref(Rc::new(Type::STR), Rc::new(Type::STR)),
+        &mut mapping,
+    );
+    assert_eq!(mapping.len(), 1);
+    assert_eq!(mapping["T"], vec![Rc::new(Type::INT)]);
+}
+
+#[test]
+fn test_narrow() {
+    let ty = Type::union_ref(&[
+        Rc::new(Type::STR),
+        Type::list_ref(Rc::new(Type::STR)),
+        Rc::new(Type::NONE),
+    ]);
+    assert_eq!(narrow(ty.clone(), |ty| !ty.is_none()).ty_str(), "str|[str]");
+    assert_eq!(
+        narrow(ty.clone(), |ty| ty.typeof_str() == Some("list".to_string())).ty_str(),
+        "[str]"
+    );
+    // The type is not narrowed when no union member satisfies the guard.
+    assert_eq!(narrow(ty.clone(), |ty| ty.is_int()), ty);
+    assert_eq!(Type::int_lit(1).typeof_str(), Some("int".to_string()));
+    assert_eq!(Type::ANY.typeof_str(), None);
+}
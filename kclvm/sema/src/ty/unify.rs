@@ -1,6 +1,8 @@
 use std::{collections::HashSet, rc::Rc};
 
-use super::{SchemaType, Type, TypeKind};
+use indexmap::IndexMap;
+
+use super::{FunctionType, SchemaType, Type, TypeKind};
 
 /// The type can be assigned to the expected type.
 ///
@@ -19,6 +21,10 @@ pub fn subsume(ty_lhs: Rc<Type>, ty_rhs: Rc<Type>, check_left_any: bool) -> bool
         types
             .iter()
             .any(|ty| subsume(ty_lhs.clone(), ty.clone(), false))
+    } else if ty_lhs.is_type_var() || ty_rhs.is_type_var() {
+        // Type variables have no bounds and are substituted when the generic type
+        // is instantiated, so a type variable is only compatible with itself.
+        equal(ty_lhs, ty_rhs)
     } else if ty_lhs.is_schema() {
         match &ty_rhs.kind {
             TypeKind::Schema(ty_rhs_schema) => {
@@ -64,6 +70,8 @@ pub fn subsume(ty_lhs: Rc<Type>, ty_rhs: Rc<Type>, check_left_any: bool) -> bool
         let (ty_rhs_key, ty_rhs_val) = ty_rhs.dict_entry_ty();
         subsume(ty_lhs_key, ty_rhs_key, check_left_any)
             && subsume(ty_lhs_val, ty_rhs_val, check_left_any)
+    } else if ty_lhs.is_func() && ty_rhs.is_func() {
+        is_sub_func_of(&ty_lhs.into_function_type(), &ty_rhs.into_function_type())
     } else {
         equal(ty_lhs, ty_rhs)
     }
@@ -78,7 +86,16 @@ pub fn equal(ty_lhs: Rc<Type>, ty_rhs: Rc<Type>) -> bool {
 /// Whether the schema is sub schema of another schema.
 pub fn is_sub_schema_of(schema_ty_lhs: &SchemaType, schema_ty_rhs: &SchemaType) -> bool {
     if schema_ty_lhs.ty_str_with_pkgpath() == schema_ty_rhs.ty_str_with_pkgpath() {
-        true
+        // A generic schema without type arguments e.g., `Page` is compatible with all
+        // its instantiations, and the type arguments are covariant.
+        schema_ty_lhs.type_args.is_empty()
+            || schema_ty_rhs.type_args.is_empty()
+            || (schema_ty_lhs.type_args.len() == schema_ty_rhs.type_args.len()
+                && schema_ty_lhs
+                    .type_args
+                    .iter()
+                    .zip(schema_ty_rhs.type_args.iter())
+                    .all(|(ty_lhs, ty_rhs)| subsume(ty_lhs.clone(), ty_rhs.clone(), true)))
     } else {
         match &schema_ty_lhs.base {
             Some(base) => is_sub_schema_of(base, schema_ty_rhs),
@@ -87,6 +104,151 @@ pub fn is_sub_schema_of(schema_ty_lhs: &SchemaType, schema_ty_rhs: &SchemaType)
     }
 }
 
+/// Whether the function can be used as another function. The parameters are
+/// contravariant and the return type is covariant, e.g., `(any) -> int` is a sub
+/// function of `(str) -> int|str`, and the `any` type is compatible with all types.
+/// The function must accept all the arguments of another function, and the rest
+/// parameters of it must have default values.
+pub fn is_sub_func_of(func_ty_lhs: &FunctionType, func_ty_rhs: &FunctionType) -> bool {
+    if !func_ty_lhs.is_variadic {
+        if func_ty_lhs.params.len() < func_ty_rhs.params.len() {
+            return false;
+        }
+        if func_ty_lhs.params[func_ty_rhs.params.len()..]
+            .iter()
+            .any(|param| !param.has_default)
+        {
+            return false;
+        }
+    }
+    func_ty_lhs
+        .params
+        .iter()
+        .zip(func_ty_rhs.params.iter())
+        .all(|(param_lhs, param_rhs)| subsume(param_rhs.ty.clone(), param_lhs.ty.clone(), true))
+        && subsume(
+            func_ty_lhs.return_ty.clone(),
+            func_ty_rhs.return_ty.clone(),
+            true,
+        )
+}
+
+/// Substitute the type variables in the type with the types in the mapping,
+/// e.g., `[T]` with `{T: Person}` -> `[Person]`.
+pub fn substitute(ty: Rc<Type>, mapping: &IndexMap<String, Rc<Type>>) -> Rc<Type> {
+    match &ty.kind {
+        TypeKind::TypeVar(name) => mapping.get(name).map_or(ty.clone(), |ty| ty.clone()),
+        TypeKind::List(item_ty) => Type::list_ref(substitute(item_ty.clone(), mapping)),
+        TypeKind::Dict(key_ty, val_ty) => Type::dict_ref(
+            substitute(key_ty.clone(), mapping),
+            substitute(val_ty.clone(), mapping),
+        ),
+        TypeKind::Union(types) => Type::union_ref(
+            &types
+                .iter()
+                .map(|ty| substitute(ty.clone(), mapping))
+                .collect::<Vec<Rc<Type>>>(),
+        ),
+        TypeKind::Function(func_ty) => {
+            let mut func_ty = func_ty.clone();
+            for param in func_ty.params.iter_mut() {
+                param.ty = substitute(param.ty.clone(), mapping);
+            }
+            func_ty.return_ty = substitute(func_ty.return_ty.clone(), mapping);
+            Rc::new(Type {
+                kind: TypeKind::Function(func_ty),
+                ..ty.as_ref().clone()
+            })
+        }
+        // The instantiated schema may refer to the type variables in its type arguments,
+        // e.g., `Page[T]` in the generic schema `Book[T]`.
+        TypeKind::Schema(schema_ty) if !schema_ty.type_args.is_empty() => Rc::new(Type {
+            kind: TypeKind::Schema(substitute_schema(schema_ty, mapping)),
+            ..ty.as_ref().clone()
+        }),
+        _ => ty.clone(),
+    }
+}
+
+/// Infer the types of the type variables in the type from the value type,
+/// e.g., `[T]` with `[int]` -> `{T: [int]}`. The `None`, `any` and dict values
+/// are compatible with all type variables and do not bind them.
+pub fn infer_type_vars(
+    ty: Rc<Type>,
+    value_ty: Rc<Type>,
+    mapping: &mut IndexMap<String, Vec<Rc<Type>>>,
+) {
+    if value_ty.is_none() || value_ty.is_any() {
+        return;
+    }
+    match (&ty.kind, &value_ty.kind) {
+        (TypeKind::TypeVar(_), TypeKind::Dict(_, _)) => {}
+        (TypeKind::TypeVar(name), _) => mapping.entry(name.clone()).or_default().push(value_ty),
+        (TypeKind::List(item_ty), TypeKind::List(value_item_ty)) => {
+            infer_type_vars(item_ty.clone(), value_item_ty.clone(), mapping)
+        }
+        (TypeKind::Dict(key_ty, val_ty), TypeKind::Dict(value_key_ty, value_val_ty)) => {
+            infer_type_vars(key_ty.clone(), value_key_ty.clone(), mapping);
+            infer_type_vars(val_ty.clone(), value_val_ty.clone(), mapping);
+        }
+        (TypeKind::Schema(schema_ty), TypeKind::Schema(value_schema_ty))
+            if schema_ty.type_args.len() == value_schema_ty.type_args.len() =>
+        {
+            for (ty, value_ty) in schema_ty
+                .type_args
+                .iter()
+                .zip(value_schema_ty.type_args.iter())
+            {
+                infer_type_vars(ty.clone(), value_ty.clone(), mapping);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether the type contains type variables, e.g., `[T]`.
+pub fn has_type_vars(ty: &Type) -> bool {
+    match &ty.kind {
+        TypeKind::TypeVar(_) => true,
+        TypeKind::List(item_ty) => has_type_vars(item_ty),
+        TypeKind::Dict(key_ty, val_ty) => has_type_vars(key_ty) || has_type_vars(val_ty),
+        TypeKind::Union(types) => types.iter().any(|ty| has_type_vars(ty)),
+        TypeKind::Schema(schema_ty) => schema_ty.type_args.iter().any(|ty| has_type_vars(ty)),
+        _ => false,
+    }
+}
+
+/// Instantiate the generic schema with the type arguments, e.g., `Page[Person]`.
+pub fn instantiate_schema(schema_ty: &SchemaType, type_args: &[Rc<Type>]) -> SchemaType {
+    let mapping: IndexMap<String, Rc<Type>> = schema_ty
+        .type_params
+        .iter()
+        .cloned()
+        .zip(type_args.iter().cloned())
+        .collect();
+    let mut schema_ty = substitute_schema(schema_ty, &mapping);
+    schema_ty.type_args = type_args.to_vec();
+    schema_ty
+}
+
+fn substitute_schema(schema_ty: &SchemaType, mapping: &IndexMap<String, Rc<Type>>) -> SchemaType {
+    let mut schema_ty = schema_ty.clone();
+    for ty in schema_ty.type_args.iter_mut() {
+        *ty = substitute(ty.clone(), mapping);
+    }
+    for attr in schema_ty.attrs.values_mut() {
+        attr.ty = substitute(attr.ty.clone(), mapping);
+    }
+    if let Some(index_signature) = schema_ty.index_signature.as_mut() {
+        index_signature.key_ty = substitute(index_signature.key_ty.clone(), mapping);
+        index_signature.val_ty = substitute(index_signature.val_ty.clone(), mapping);
+    }
+    for param in schema_ty.func.params.iter_mut() {
+        param.ty = substitute(param.ty.clone(), mapping);
+    }
+    schema_ty
+}
+
 /// The type can be assigned to the expected type.
 #[inline]
 pub fn assignable_to(ty: Rc<Type>, expected_ty: Rc<Type>) -> bool {
@@ -114,6 +276,25 @@ pub fn sup(types: &[Rc<Type>]) -> Rc<Type> {
     r#typeof(types, true)
 }
 
+/// Narrow the type to the union members that satisfy the type guard, e.g., `int|str|None`
+/// with the guard `is not None` -> `int|str`. The type is not narrowed when no member
+/// satisfies the guard.
+pub fn narrow(ty: Rc<Type>, guard: impl Fn(&Rc<Type>) -> bool) -> Rc<Type> {
+    let types = match &ty.kind {
+        TypeKind::Union(types) => types.clone(),
+        _ => vec![ty.clone()],
+    };
+    let types = types
+        .into_iter()
+        .filter(|ty| guard(ty))
+        .collect::<Vec<Rc<Type>>>();
+    if types.is_empty() {
+        ty
+    } else {
+        sup(&types)
+    }
+}
+
 /// Typeof types
 pub fn r#typeof(types: &[Rc<Type>], should_remove_sub_types: bool) -> Rc<Type> {
     // 1. Initialize an ordered set to store the type array
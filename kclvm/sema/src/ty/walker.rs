@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use super::Type;
+use super::{Parameter, Type};
 
 /// Walk one type recursively and deal the type using the `walk_fn`
 pub fn walk_type(ty: &Type, walk_fn: impl Fn(&Type) -> Rc<Type> + Copy) -> Rc<Type> {
@@ -17,6 +17,22 @@ pub fn walk_type(ty: &Type, walk_fn: impl Fn(&Type) -> Rc<Type> + Copy) -> Rc<Ty
                 .map(|ty| walk_type(ty, walk_fn))
                 .collect::<Vec<Rc<Type>>>(),
         )),
+        super::TypeKind::Function(func_ty) => {
+            let mut func_ty = func_ty.clone();
+            func_ty.params = func_ty
+                .params
+                .iter()
+                .map(|param| Parameter {
+                    ty: walk_type(&param.ty, walk_fn),
+                    ..param.clone()
+                })
+                .collect();
+            func_ty.return_ty = walk_type(&func_ty.return_ty, walk_fn);
+            Rc::new(Type {
+                kind: super::TypeKind::Function(func_ty),
+                ..ty.as_ref().clone()
+            })
+        }
         _ => ty,
     }
 }
@@ -14,6 +14,8 @@ STDOUT_GOLDEN_PY = "stdout.golden.py"
 STDERR_GOLDEN_PY = "stderr.golden.py"
 SETTINGS_FILE = "settings.yaml"
 TEST_PATH = "test/grammar"
+# All the grammar test cases are run with each backend.
+BACKENDS = ["llvm", "evaluator"]
 
 # Ruamel YAML instance
 ruamel_yaml = YAML(typ="unsafe", pure=True)
@@ -106,11 +108,12 @@ test_path = pathlib.Path(__file__).parent.parent.parent.parent.parent.joinpath(
 test_dirs = find_test_dirs(str(test_path), "")
 
 
+@pytest.mark.parametrize("backend", BACKENDS)
 @pytest.mark.parametrize("test_dir", test_dirs)
-def test_grammar(test_dir):
-    print("Testing {}".format(test_dir))
+def test_grammar(test_dir, backend):
+    print("Testing {} with the {} backend".format(test_dir, backend))
     test_settings = read_settings_file(os.path.join(test_dir, SETTINGS_FILE))
-    kcl_command = ["kcl", "--target", "native", TEST_FILE]
+    kcl_command = ["kcl", "--target", "native", "--backend", backend, TEST_FILE]
     if test_settings and test_settings["kcl_options"]:
         kcl_command.extend(test_settings["kcl_options"].split())
     process = subprocess.Popen(
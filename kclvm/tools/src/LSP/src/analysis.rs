@@ -2,5 +2,5 @@ use crate::db::AnalysisDatabase;
 
 #[derive(Default)]
 pub struct Analysis {
-    _db: AnalysisDatabase,
+    pub db: AnalysisDatabase,
 }
@@ -0,0 +1,506 @@
+//! Code actions for KCL.
+//!
+//! The quick fixes are computed from the diagnostics sent back by the client in the
+//! code action context. The `code` of a diagnostic is the name of the KCL error or
+//! warning kind set by `to_lsp`, and the names used in the fix are parsed from the
+//! diagnostic message. Now supports the following quick fixes:
+//! + remove an unused import or a reimport
+//! + move an import statement to the top of the module
+//! + import a schema defined in another package of the compile unit
+//! + replace a misspelled schema attribute with the closest one
+//!
+//! Besides, the required schema attributes missing from the schema config under the
+//! cursor can be inserted with placeholder values.
+
+use std::collections::HashMap;
+
+use kclvm_ast::ast::{Module, Node, Program, SchemaExpr, Stmt};
+use kclvm_ast::walk_list;
+use kclvm_ast::walker::MutSelfWalker;
+use kclvm_error::{ErrorKind, WarningKind};
+use kclvm_sema::resolver::scope::{ProgramScope, ScopeObjectKind};
+use kclvm_sema::ty::{SchemaType, Type, TypeKind};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, NumberOrString, Position, Range,
+    TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::find_ref::resolve_schema_expr_ty;
+use crate::util::config_keys;
+
+/// Returns the quick fixes of the diagnostics and the refactorings available in the
+/// range of the file.
+pub(crate) fn code_actions(
+    file: &str,
+    src: &str,
+    range: Range,
+    diagnostics: &[Diagnostic],
+    program: &Program,
+    prog_scope: &ProgramScope,
+) -> anyhow::Result<Vec<CodeActionOrCommand>> {
+    let uri = Url::from_file_path(file)
+        .map_err(|_| anyhow::anyhow!("can't convert file to url: {}", file))?;
+    let (pkgpath, module) = match program.pkgs.iter().find_map(|(pkgpath, modules)| {
+        modules
+            .iter()
+            .find(|module| module.filename == file)
+            .map(|module| (pkgpath, module))
+    }) {
+        Some(found) => found,
+        None => return Ok(vec![]),
+    };
+    let ctx = CodeActionContext {
+        uri,
+        file,
+        src,
+        pkgpath,
+        module,
+        program,
+        prog_scope,
+    };
+    let mut actions = vec![];
+    for diagnostic in diagnostics {
+        actions.extend(ctx.quick_fixes(diagnostic));
+    }
+    actions.extend(ctx.add_missing_attrs(range));
+    Ok(actions)
+}
+
+struct CodeActionContext<'a> {
+    uri: Url,
+    file: &'a str,
+    src: &'a str,
+    pkgpath: &'a str,
+    module: &'a Module,
+    program: &'a Program,
+    prog_scope: &'a ProgramScope,
+}
+
+impl<'a> CodeActionContext<'a> {
+    fn action(
+        &self,
+        title: String,
+        kind: CodeActionKind,
+        edits: Vec<TextEdit>,
+        diagnostic: Option<&Diagnostic>,
+    ) -> CodeActionOrCommand {
+        CodeActionOrCommand::CodeAction(CodeAction {
+            title,
+            kind: Some(kind),
+            diagnostics: diagnostic.map(|diagnostic| vec![diagnostic.clone()]),
+            edit: Some(WorkspaceEdit {
+                changes: Some(HashMap::from([(self.uri.clone(), edits)])),
+                ..Default::default()
+            }),
+            command: None,
+            is_preferred: diagnostic.map(|_| true),
+            disabled: None,
+            data: None,
+        })
+    }
+
+    fn quick_fixes(&self, diagnostic: &Diagnostic) -> Option<CodeActionOrCommand> {
+        let code = match &diagnostic.code {
+            Some(NumberOrString::String(code)) => code.as_str(),
+            _ => return None,
+        };
+        let (title, edits) = if code == WarningKind::UnusedImportWarning.name() {
+            let (stmt, name) = self.import_stmt_at(diagnostic.range.start.line)?;
+            (
+                format!("Remove unused import '{}'", name),
+                vec![delete_stmt(stmt)],
+            )
+        } else if code == WarningKind::ReimportWarning.name() {
+            let (stmt, name) = self.import_stmt_at(diagnostic.range.start.line)?;
+            (
+                format!("Remove reimport of '{}'", name),
+                vec![delete_stmt(stmt)],
+            )
+        } else if code == WarningKind::ImportPositionWarning.name() {
+            let (stmt, name) = self.import_stmt_at(diagnostic.range.start.line)?;
+            (
+                format!("Move import '{}' to the top of the module", name),
+                self.move_import_to_top(stmt)?,
+            )
+        } else if code == ErrorKind::CompileError.name() {
+            if let Some(name) = parse_message(&diagnostic.message, "name '", "' is not defined") {
+                return self.import_schema(diagnostic, &name);
+            }
+            let (attr, schema) = parse_message(&diagnostic.message, "Cannot add member '", "'")
+                .and_then(|attr| {
+                    let schema = parse_message(
+                        &diagnostic.message,
+                        &format!("Cannot add member '{}' to schema '", attr),
+                        "'",
+                    )?;
+                    Some((attr, schema))
+                })?;
+            // The key is a name or a string literal, e.g., `nmae` or `"nmae"`.
+            let start = self.find_in_line(diagnostic.range.start, &attr, "")?;
+            return self.replace_attr(diagnostic, &schema, &attr, start);
+        } else if code == ErrorKind::TypeError.name() {
+            let (ty_str, attr) = diagnostic.message.split_once(" has no attribute ")?;
+            // The diagnostic is reported on the value of the selector, e.g., `p` in `p.nmae`.
+            let start = self.find_in_line(diagnostic.range.start, attr, ".")?;
+            return self.replace_attr(diagnostic, ty_str, attr, start);
+        } else {
+            return None;
+        };
+        Some(self.action(title, CodeActionKind::QUICKFIX, edits, Some(diagnostic)))
+    }
+
+    /// Returns the import statement on the line and its import name.
+    fn import_stmt_at(&self, line: u32) -> Option<(&Node<Stmt>, &str)> {
+        // The lines of AST nodes start from 1 while the lines of LSP start from 0.
+        let line = line as u64 + 1;
+        self.module.body.iter().find_map(|stmt| match &stmt.node {
+            Stmt::Import(import_stmt) if stmt.line == line => {
+                Some((stmt.as_ref(), import_stmt.name.as_str()))
+            }
+            _ => None,
+        })
+    }
+
+    /// Moves the import statement before the first statement which is not an import.
+    fn move_import_to_top(&self, stmt: &Node<Stmt>) -> Option<Vec<TextEdit>> {
+        let first_stmt = self
+            .module
+            .body
+            .iter()
+            .find(|stmt| !matches!(stmt.node, Stmt::Import(_)))?;
+        let lines: Vec<&str> = self.src.split_inclusive('\n').collect();
+        let mut text = lines
+            .get(stmt.line as usize - 1..(stmt.end_line as usize).min(lines.len()))?
+            .concat();
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        let pos = Position::new(first_stmt.line as u32 - 1, 0);
+        Some(vec![
+            TextEdit {
+                range: Range::new(pos, pos),
+                new_text: text,
+            },
+            delete_stmt(stmt),
+        ])
+    }
+
+    /// Imports the package which defines the schema and refers to the schema with the
+    /// import name.
+    fn import_schema(&self, diagnostic: &Diagnostic, name: &str) -> Option<CodeActionOrCommand> {
+        let start = self.find_in_line(diagnostic.range.start, name, "")?;
+        let end = Position::new(start.line, start.character + name.len() as u32);
+        let import_names = self.prog_scope.import_names.get(self.file);
+        let mut actions = vec![];
+        for (pkgpath, pkg_scope) in &self.prog_scope.scope_map {
+            // Only the packages of the compile unit can be imported, which excludes
+            // the builtin scope and the system modules.
+            if pkgpath == self.pkgpath || !self.program.pkgs.contains_key(pkgpath) {
+                continue;
+            }
+            let is_schema = pkg_scope.borrow().elems.get(name).map_or(false, |obj| {
+                let obj = obj.borrow();
+                obj.kind == ScopeObjectKind::Definition
+                    && matches!(obj.ty.kind, TypeKind::Schema(_))
+            });
+            if !is_schema {
+                continue;
+            }
+            let mut edits = vec![];
+            // Reuse the import name if the package has been imported.
+            let import_name = match import_names.and_then(|import_names| {
+                import_names
+                    .iter()
+                    .find(|(_, path)| *path == pkgpath)
+                    .map(|(name, _)| name.clone())
+            }) {
+                Some(import_name) => import_name,
+                None => {
+                    edits.push(self.insert_import(pkgpath));
+                    pkgpath.rsplit('.').next().unwrap_or(pkgpath).to_string()
+                }
+            };
+            edits.push(TextEdit {
+                range: Range::new(start, end),
+                new_text: format!("{}.{}", import_name, name),
+            });
+            actions.push(self.action(
+                format!("Import '{}' from '{}'", name, pkgpath),
+                CodeActionKind::QUICKFIX,
+                edits,
+                Some(diagnostic),
+            ));
+        }
+        // Only offer the fix when the schema name is not ambiguous.
+        if actions.len() == 1 {
+            actions.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Inserts the import statement after the last import statement at the top of
+    /// the module.
+    fn insert_import(&self, pkgpath: &str) -> TextEdit {
+        let line = self
+            .module
+            .body
+            .iter()
+            .take_while(|stmt| matches!(stmt.node, Stmt::Import(_)))
+            .last()
+            .map_or(0, |stmt| stmt.end_line as u32);
+        let pos = Position::new(line, 0);
+        TextEdit {
+            range: Range::new(pos, pos),
+            new_text: format!("import {}\n", pkgpath),
+        }
+    }
+
+    /// Replaces the attribute at the position with the closest attribute of the schema.
+    fn replace_attr(
+        &self,
+        diagnostic: &Diagnostic,
+        schema_name: &str,
+        attr: &str,
+        start: Position,
+    ) -> Option<CodeActionOrCommand> {
+        let schema_ty = self.find_schema(schema_name)?;
+        let mut attrs = vec![];
+        let mut ty = Some(&schema_ty);
+        while let Some(schema_ty) = ty {
+            attrs.extend(schema_ty.attrs.keys());
+            ty = schema_ty.base.as_deref();
+        }
+        let closest = closest_name(attr, attrs.into_iter())?;
+        let end = Position::new(start.line, start.character + attr.len() as u32);
+        Some(self.action(
+            format!("Change to '{}'", closest),
+            CodeActionKind::QUICKFIX,
+            vec![TextEdit {
+                range: Range::new(start, end),
+                new_text: closest,
+            }],
+            Some(diagnostic),
+        ))
+    }
+
+    /// Finds the schema type by the schema name, the schemas in the package of the file
+    /// are preferred.
+    fn find_schema(&self, name: &str) -> Option<SchemaType> {
+        let find_in_scope = |pkgpath: &str| {
+            let pkg_scope = self.prog_scope.scope_map.get(pkgpath)?.borrow();
+            let obj = pkg_scope.elems.get(name)?.borrow();
+            match &obj.ty.kind {
+                TypeKind::Schema(schema_ty) if obj.kind == ScopeObjectKind::Definition => {
+                    Some(schema_ty.clone())
+                }
+                _ => None,
+            }
+        };
+        find_in_scope(self.pkgpath).or_else(|| {
+            self.program
+                .pkgs
+                .keys()
+                .find_map(|pkgpath| find_in_scope(pkgpath))
+        })
+    }
+
+    /// Finds the name after the prefix in the line of the position, searching from the
+    /// position, and returns the start position of the name.
+    fn find_in_line(&self, pos: Position, name: &str, prefix: &str) -> Option<Position> {
+        let line = self.src.lines().nth(pos.line as usize)?;
+        let from = (pos.character as usize).min(line.len());
+        let text = line.get(from..)?;
+        let pattern = format!("{}{}", prefix, name);
+        let mut offset = 0;
+        while let Some(i) = text[offset..].find(&pattern) {
+            let start = offset + i + prefix.len();
+            let end = start + name.len();
+            let is_word_start = text[..start]
+                .chars()
+                .last()
+                .map_or(true, |c| !is_name_char(c));
+            let is_word_end = text[end..]
+                .chars()
+                .next()
+                .map_or(true, |c| !is_name_char(c));
+            if is_word_start && is_word_end {
+                return Some(Position::new(pos.line, (from + start) as u32));
+            }
+            offset = end;
+        }
+        None
+    }
+
+    /// Inserts the required attributes missing from the innermost schema config under
+    /// the range with placeholder values.
+    fn add_missing_attrs(&self, range: Range) -> Option<CodeActionOrCommand> {
+        let mut finder = SchemaExprFinder {
+            // The lines of AST nodes start from 1 while the lines of LSP start from 0.
+            line: range.start.line as u64 + 1,
+            column: range.start.character as u64,
+            schema_expr: None,
+        };
+        finder.walk_module(self.module);
+        let schema_expr = finder.schema_expr?;
+        let schema_ty = resolve_schema_expr_ty(self.program, &schema_expr, self.prog_scope)?;
+        let keys = config_keys(&schema_expr.config.node)?;
+        let mut missing: Vec<(String, String)> = vec![];
+        let mut ty = Some(&schema_ty);
+        while let Some(schema_ty) = ty {
+            for (name, attr) in &schema_ty.attrs {
+                if attr.is_optional
+                    || attr.has_default
+                    || keys.contains(name)
+                    || missing.iter().any(|(missing, _)| missing == name)
+                {
+                    continue;
+                }
+                missing.push((name.clone(), placeholder(&attr.ty)));
+            }
+            ty = schema_ty.base.as_deref();
+        }
+        if missing.is_empty() {
+            return None;
+        }
+        let config = &schema_expr.config;
+        let items: Vec<String> = missing
+            .iter()
+            .map(|(name, value)| format!("{} = {}", name, value))
+            .collect();
+        let edit = if config.line == config.end_line {
+            // Insert the attributes before the closing brace, e.g., `{name = "", age = 0}`.
+            let pos = Position::new(
+                config.end_line as u32 - 1,
+                config.end_column.saturating_sub(1) as u32,
+            );
+            let separator = if keys.is_empty() { "" } else { ", " };
+            TextEdit {
+                range: Range::new(pos, pos),
+                new_text: format!("{}{}", separator, items.join(", ")),
+            }
+        } else {
+            // Insert the attributes in new lines before the line of the closing brace,
+            // with the indentation of the config entries.
+            let lines: Vec<&str> = self.src.lines().collect();
+            let indent = match lines.get(config.line as usize) {
+                Some(line) if !line.trim().is_empty() && config.line + 1 < config.end_line => {
+                    leading_whitespace(line).to_string()
+                }
+                _ => format!(
+                    "{}    ",
+                    leading_whitespace(lines.get(config.line as usize - 1)?)
+                ),
+            };
+            let pos = Position::new(config.end_line as u32 - 1, 0);
+            TextEdit {
+                range: Range::new(pos, pos),
+                new_text: items
+                    .iter()
+                    .map(|item| format!("{}{}\n", indent, item))
+                    .collect(),
+            }
+        };
+        Some(self.action(
+            format!(
+                "Add missing required attributes of schema '{}'",
+                schema_ty.name
+            ),
+            CodeActionKind::REFACTOR_REWRITE,
+            vec![edit],
+            None,
+        ))
+    }
+}
+
+/// Finds the innermost schema expression which contains the position.
+struct SchemaExprFinder {
+    line: u64,
+    column: u64,
+    schema_expr: Option<SchemaExpr>,
+}
+
+impl MutSelfWalker for SchemaExprFinder {
+    fn walk_schema_expr(&mut self, schema_expr: &SchemaExpr) {
+        let (name, config) = (&schema_expr.name, &schema_expr.config);
+        let pos = (self.line, self.column);
+        if (name.line, name.column) <= pos && pos <= (config.end_line, config.end_column) {
+            // The walker visits the outer schema expressions first.
+            self.schema_expr = Some(schema_expr.clone());
+        }
+        walk_list!(self, walk_expr, schema_expr.args);
+        walk_list!(self, walk_keyword, schema_expr.kwargs);
+        self.walk_expr(&schema_expr.config.node);
+    }
+}
+
+/// Returns the placeholder value of the type.
+fn placeholder(ty: &Type) -> String {
+    match &ty.kind {
+        TypeKind::Bool => "False".to_string(),
+        TypeKind::BoolLit(value) => if *value { "True" } else { "False" }.to_string(),
+        TypeKind::Int | TypeKind::NumberMultiplier(_) => "0".to_string(),
+        TypeKind::IntLit(value) => value.to_string(),
+        TypeKind::Float => "0.0".to_string(),
+        TypeKind::FloatLit(value) => format!("{:?}", value),
+        TypeKind::Str => "\"\"".to_string(),
+        TypeKind::StrLit(value) => format!("{:?}", value),
+        TypeKind::List(_) => "[]".to_string(),
+        TypeKind::Dict(_, _) | TypeKind::Schema(_) => "{}".to_string(),
+        TypeKind::Union(types) => types
+            .first()
+            .map_or("None".to_string(), |ty| placeholder(ty)),
+        _ => "None".to_string(),
+    }
+}
+
+/// Returns the name with the smallest edit distance to the misspelled name, if the
+/// distance is no more than half the length of the name.
+fn closest_name<'n>(name: &str, candidates: impl Iterator<Item = &'n String>) -> Option<String> {
+    let max_distance = (name.chars().count() / 2).max(1);
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.clone())
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Returns the text between the prefix and the first suffix after it in the message.
+fn parse_message(message: &str, prefix: &str, suffix: &str) -> Option<String> {
+    let rest = message.strip_prefix(prefix)?;
+    rest.find(suffix).map(|i| rest[..i].to_string())
+}
+
+/// Deletes the whole lines of the statement.
+fn delete_stmt(stmt: &Node<Stmt>) -> TextEdit {
+    TextEdit {
+        range: Range::new(
+            Position::new(stmt.line as u32 - 1, 0),
+            Position::new(stmt.end_line as u32, 0),
+        ),
+        new_text: String::new(),
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
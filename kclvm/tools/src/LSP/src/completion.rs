@@ -7,22 +7,32 @@
 //!  + builtin function(str function)
 //!  + defitions in pkg
 //!  + system module functions
+//!
+//! Builtin decorators are completed when user enters `@`, and the attributes of the
+//! schema which are not set yet are completed in the schema config body. The items
+//! of functions and attributes carry their signatures, types and docs.
 
 use std::io;
 use std::{fs, path::Path};
 
-use indexmap::IndexSet;
-use kclvm_ast::ast::{Expr, ImportStmt, Program, Stmt};
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::ast::{Expr, ImportStmt, NodeRef, Program, Stmt};
+use kclvm_ast::pos::ContainsPos;
 use kclvm_config::modfile::KCL_FILE_EXTENSION;
 
 use kclvm_error::Position as KCLPos;
 use kclvm_sema::builtin::{
-    get_system_module_members, STANDARD_SYSTEM_MODULES, STRING_MEMBER_FUNCTIONS,
+    get_system_member_function_ty, get_system_module_members, BUILTIN_DECORATORS,
+    STANDARD_SYSTEM_MODULES, STRING_MEMBER_FUNCTIONS,
 };
 use kclvm_sema::resolver::scope::ProgramScope;
-use lsp_types::CompletionItem;
+use kclvm_sema::ty::{SchemaType, Type, TypeKind};
+use lsp_types::{CompletionItem, CompletionItemKind, Documentation};
 
+use crate::find_ref::resolve_schema_expr_ty;
 use crate::goto_def::get_identifier_last_name;
+use crate::hover::{func_doc, func_signature};
+use crate::util::config_keys;
 use crate::{goto_def::find_objs_in_program_scope, util::inner_most_expr_in_stmt};
 
 /// Computes completions at the given position.
@@ -32,11 +42,11 @@ pub(crate) fn completion(
     pos: &KCLPos,
     prog_scope: &ProgramScope,
 ) -> Option<lsp_types::CompletionResponse> {
-    if let Some('.') = trigger_character {
-        completion_dot(program, pos, prog_scope)
-    } else {
+    match trigger_character {
+        Some('.') => completion_dot(program, pos, prog_scope),
+        Some('@') => Some(completion_decorators().into()),
         // todo: Complete identifiers such as attr, variables, types, etc.
-        None
+        _ => completion_schema_config_attrs(program, pos, prog_scope),
     }
 }
 
@@ -60,7 +70,7 @@ fn completion_dot(
                 match expr {
                     Some(node) => {
                         let items = get_completion_items(&node.node, prog_scope);
-                        Some(into_completion_items(&items).into())
+                        Some(items.into_values().collect::<Vec<_>>().into())
                     }
                     None => None,
                 }
@@ -111,28 +121,133 @@ fn completion_for_import(
     Some(into_completion_items(&items).into())
 }
 
-fn get_completion_items(expr: &Expr, prog_scope: &ProgramScope) -> IndexSet<String> {
-    let mut items = IndexSet::new();
+/// Completes the builtin decorators, e.g., `deprecated` in `@deprecated`.
+fn completion_decorators() -> Vec<CompletionItem> {
+    let decorators = BUILTIN_DECORATORS;
+    decorators
+        .iter()
+        .filter_map(|(name, ty)| func_completion_item(name, &format!("@{}", name), ty))
+        .collect()
+}
+
+/// Completes the attributes which are not set yet in the schema config body, e.g.,
+/// `age` in `Person { name = "Alice" }`.
+fn completion_schema_config_attrs(
+    program: &Program,
+    pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<lsp_types::CompletionResponse> {
+    let stmt = program.pos_to_stmt(pos)?;
+    let (_, schema_def) = inner_most_expr_in_stmt(&stmt.node, pos, None);
+    let schema_expr = match schema_def?.node {
+        Expr::Schema(schema_expr) => schema_expr,
+        _ => return None,
+    };
+    if !is_in_config_body(&schema_expr.config, pos) {
+        return None;
+    }
+    let schema_ty = resolve_schema_expr_ty(program, &schema_expr, prog_scope)?;
+    let keys = config_keys(&schema_expr.config.node).unwrap_or_default();
+    let mut items = IndexMap::new();
+    collect_schema_attr_items(&schema_ty, &keys, &mut items);
+    Some(items.into_values().collect::<Vec<_>>().into())
+}
+
+/// Whether the position is directly in the config body rather than in the values of
+/// the config entries, e.g., a nested config.
+fn is_in_config_body(config: &NodeRef<Expr>, pos: &KCLPos) -> bool {
+    match &config.node {
+        Expr::Config(config_expr) => config_expr.items.iter().all(|item| {
+            !item.node.value.contains_pos(pos)
+                || matches!(item.node.value.node, Expr::Identifier(_) | Expr::Missing(_))
+        }),
+        _ => false,
+    }
+}
+
+/// Collects the attributes of the schema, its base schemas and its mixins except the keys.
+fn collect_schema_attr_items(
+    schema_ty: &SchemaType,
+    keys: &IndexSet<String>,
+    items: &mut IndexMap<String, CompletionItem>,
+) {
+    for (name, attr) in &schema_ty.attrs {
+        if name == "__settings__" || keys.contains(name) || items.contains_key(name) {
+            continue;
+        }
+        let required = !attr.is_optional && !attr.has_default;
+        let mut doc = if required { "required" } else { "optional" }.to_string();
+        if let Some(attr_doc) = &attr.doc {
+            doc.push_str("\n\n");
+            doc.push_str(attr_doc);
+        }
+        items.insert(
+            name.clone(),
+            CompletionItem {
+                label: name.clone(),
+                kind: Some(CompletionItemKind::FIELD),
+                detail: Some(format!(
+                    "{}{}: {}",
+                    name,
+                    if attr.is_optional { "?" } else { "" },
+                    attr.ty.ty_str()
+                )),
+                documentation: Some(Documentation::String(doc)),
+                ..Default::default()
+            },
+        );
+    }
+    if let Some(base) = &schema_ty.base {
+        collect_schema_attr_items(base, keys, items);
+    }
+    for mixin in &schema_ty.mixins {
+        collect_schema_attr_items(mixin, keys, items);
+    }
+}
+
+fn get_completion_items(
+    expr: &Expr,
+    prog_scope: &ProgramScope,
+) -> IndexMap<String, CompletionItem> {
+    let mut items = IndexMap::new();
     match expr {
         Expr::Identifier(id) => {
             let name = get_identifier_last_name(id);
             if !id.pkgpath.is_empty() {
                 // standard system module
                 if STANDARD_SYSTEM_MODULES.contains(&name.as_str()) {
-                    items.extend(
-                        get_system_module_members(name.as_str())
-                            .iter()
-                            .map(|s| s.to_string()),
-                    )
+                    for member in get_system_module_members(name.as_str()) {
+                        let item = match get_system_member_function_ty(&name, member) {
+                            Some(ty) => {
+                                func_completion_item(member, &format!("{}.{}", name, member), &ty)
+                            }
+                            None => Some(CompletionItem {
+                                label: member.to_string(),
+                                kind: Some(CompletionItemKind::CONSTANT),
+                                ..Default::default()
+                            }),
+                        };
+                        if let Some(item) = item {
+                            items.insert(item.label.clone(), item);
+                        }
+                    }
                 }
                 // user module
                 if let Some(scope) = prog_scope.scope_map.get(&id.pkgpath) {
                     let scope = scope.borrow();
                     for (name, obj) in &scope.elems {
-                        if obj.borrow().ty.is_module() {
+                        let obj = obj.borrow();
+                        if obj.ty.is_module() {
                             continue;
                         }
-                        items.insert(name.clone());
+                        items.insert(
+                            name.clone(),
+                            CompletionItem {
+                                label: name.clone(),
+                                detail: Some(obj.ty.ty_str()),
+                                ..Default::default()
+                            },
+                        );
                     }
                 }
                 return items;
@@ -142,17 +257,21 @@ fn get_completion_items(expr: &Expr, prog_scope: &ProgramScope) -> IndexSet<Stri
             for obj in objs {
                 match &obj.ty.kind {
                     // builtin (str) functions
-                    kclvm_sema::ty::TypeKind::Str => {
-                        let binding = STRING_MEMBER_FUNCTIONS;
-                        for k in binding.keys() {
-                            items.insert(format!("{}{}", k, "()"));
-                        }
-                    }
+                    TypeKind::Str => items.extend(string_member_items()),
                     // schema attrs
-                    kclvm_sema::ty::TypeKind::Schema(schema) => {
-                        for k in schema.attrs.keys() {
+                    TypeKind::Schema(schema) => {
+                        for (k, attr) in &schema.attrs {
                             if k != "__settings__" {
-                                items.insert(k.clone());
+                                items.insert(
+                                    k.clone(),
+                                    CompletionItem {
+                                        label: k.clone(),
+                                        kind: Some(CompletionItemKind::FIELD),
+                                        detail: Some(format!("{}: {}", k, attr.ty.ty_str())),
+                                        documentation: attr.doc.clone().map(Documentation::String),
+                                        ..Default::default()
+                                    },
+                                );
                             }
                         }
                     }
@@ -160,12 +279,7 @@ fn get_completion_items(expr: &Expr, prog_scope: &ProgramScope) -> IndexSet<Stri
                 }
             }
         }
-        Expr::StringLit(_) => {
-            let binding = STRING_MEMBER_FUNCTIONS;
-            for k in binding.keys() {
-                items.insert(format!("{}{}", k, "()"));
-            }
-        }
+        Expr::StringLit(_) => items.extend(string_member_items()),
         Expr::Selector(select_expr) => {
             let res = get_completion_items(&select_expr.value.node, prog_scope);
             items.extend(res);
@@ -175,6 +289,35 @@ fn get_completion_items(expr: &Expr, prog_scope: &ProgramScope) -> IndexSet<Stri
     items
 }
 
+/// Returns the items of the string member functions, e.g., `upper()`.
+fn string_member_items() -> Vec<(String, CompletionItem)> {
+    let functions = STRING_MEMBER_FUNCTIONS;
+    functions
+        .iter()
+        .filter_map(|(name, ty)| {
+            let item = func_completion_item(&format!("{}()", name), &format!("str.{}", name), ty)?;
+            Some((item.label.clone(), item))
+        })
+        .collect()
+}
+
+/// Returns the item of the function with its signature and doc.
+fn func_completion_item(label: &str, name: &str, ty: &Type) -> Option<CompletionItem> {
+    match &ty.kind {
+        TypeKind::Function(func_ty) => {
+            let doc = func_doc(func_ty);
+            Some(CompletionItem {
+                label: label.to_string(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: Some(func_signature(name, func_ty)),
+                documentation: (!doc.is_empty()).then_some(Documentation::String(doc)),
+                ..Default::default()
+            })
+        }
+        _ => None,
+    }
+}
+
 pub(crate) fn into_completion_items(items: &IndexSet<String>) -> Vec<CompletionItem> {
     items
         .iter()
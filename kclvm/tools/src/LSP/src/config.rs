@@ -1,3 +1,26 @@
 /// The configuration used by the language server.
 #[derive(Debug, Clone, Default)]
-pub struct Config {}
+pub struct Config {
+    /// Whether to show the default values of the schema attributes omitted from a schema
+    /// config as inlay hints.
+    pub inlay_hints_schema_attr_defaults: bool,
+    /// Whether the client supports registering the file watchers dynamically, which is
+    /// read from the client capabilities.
+    pub did_change_watched_files_dynamic_registration: bool,
+    /// The paths of the workspace folders opened by the client, in which the workspace
+    /// symbols are searched.
+    pub workspace_folders: Vec<String>,
+}
+
+impl Config {
+    /// Updates the configuration with the `initializationOptions` sent by the client, e.g.,
+    /// `{"inlayHints": {"schemaAttrDefaults": true}}`.
+    pub fn update(&mut self, options: &serde_json::Value) {
+        if let Some(enabled) = options
+            .pointer("/inlayHints/schemaAttrDefaults")
+            .and_then(serde_json::Value::as_bool)
+        {
+            self.inlay_hints_schema_attr_defaults = enabled;
+        }
+    }
+}
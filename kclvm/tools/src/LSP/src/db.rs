@@ -1,3 +1,35 @@
-/// Holds the result of the compile
+use kclvm_config::cache::MemoryPkgCache;
+use kclvm_parser::KCLModuleCache;
+
+/// Holds the results of the compile shared by all requests.
+///
+/// The parsed modules are cached by their file names, and a file is parsed again
+/// only when its source code changes, so a request on a large compile unit only
+/// re-parses the edited files.
+///
+/// The resolved scopes are built from `Rc` and can't be shared by the request threads,
+/// so the scopes of the packages imported by the compile unit are cached as JSON by
+/// their package paths. The key of a package scope is the hash of the package files
+/// and the keys of the packages it imports, so a package is resolved again only when
+/// it or one of its dependencies changes, and the other ones are loaded from the cache.
+/// The main package of the compile unit is always resolved.
 #[derive(Default, Clone)]
-pub(crate) struct AnalysisDatabase {}
+pub(crate) struct AnalysisDatabase {
+    pub module_cache: KCLModuleCache,
+    pub scope_cache: MemoryPkgCache,
+}
+
+impl AnalysisDatabase {
+    /// Drops the cached module of the file, e.g., when it is deleted. The cached scopes
+    /// are not dropped, whose keys are changed with the package files.
+    pub(crate) fn invalidate_file(&self, file: &str) {
+        self.module_cache.write().unwrap().remove(file);
+    }
+
+    /// Drops all cached modules and scopes, e.g., when `kcl.mod` changes and the
+    /// imported packages may be loaded from other files.
+    pub(crate) fn invalidate_all(&self) {
+        self.module_cache.write().unwrap().clear();
+        self.scope_cache.clear();
+    }
+}
@@ -0,0 +1,43 @@
+//! Document highlight for KCL.
+//!
+//! Highlights all occurrences in the file of the symbol under the cursor. The
+//! occurrences are resolved through the sema scopes as the references, and the
+//! declarations, assignment targets and config keys are highlighted as writes.
+
+use kclvm_ast::ast::Program;
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::resolver::scope::ProgramScope;
+use lsp_types::{DocumentHighlight, DocumentHighlightKind, Range};
+
+use crate::find_ref::find_refs::find_ref_occurrences;
+use crate::find_ref::resolve_symbol;
+use crate::to_lsp::lsp_pos;
+
+pub(crate) fn document_highlight(
+    file: &str,
+    program: &Program,
+    kcl_pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<Vec<DocumentHighlight>> {
+    let (def, _, _) = resolve_symbol(program, kcl_pos, prog_scope)?;
+    let module = program
+        .pkgs
+        .values()
+        .flatten()
+        .find(|module| module.filename == file)?;
+    let highlights = find_ref_occurrences(program, module, &def, prog_scope)
+        .iter()
+        .map(|occurrence| DocumentHighlight {
+            range: Range {
+                start: lsp_pos(&occurrence.start),
+                end: lsp_pos(&occurrence.end),
+            },
+            kind: Some(if occurrence.is_write {
+                DocumentHighlightKind::WRITE
+            } else {
+                DocumentHighlightKind::READ
+            }),
+        })
+        .collect();
+    Some(highlights)
+}
@@ -1,28 +1,87 @@
-use crate::find_ref;
-use crate::find_ref::go_to_def::go_to_def;
-use kclvm_error::Position;
+use kclvm_ast::ast::{Module, Program};
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::resolver::scope::ProgramScope;
+use lsp_types::{Location, Range, Url};
 
-/// Find all references of the item at the cursor location.
-pub fn find_refs(path: String, pos: Position) -> Vec<Position> {
-    let declaration = go_to_def(pos.clone());
-    let search = {
-        move |decl: Position| {
-            let name = find_ref::word_at_pos(pos);
-            if name.is_none() {
-                return vec![];
+use crate::find_ref::{collect_name_occurrences, resolve_symbol, Definition, NameOccurrence};
+use crate::to_lsp::lsp_pos;
+
+/// Finds all references of the symbol at the cursor position in the compile unit.
+pub(crate) fn find_refs(
+    program: &Program,
+    kcl_pos: &KCLPos,
+    prog_scope: &ProgramScope,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let (def, _, _) = resolve_symbol(program, kcl_pos, prog_scope)?;
+    let locations = find_ref_spans(program, &def, prog_scope, include_declaration)
+        .iter()
+        .filter_map(|(start, end)| {
+            Url::from_file_path(&start.filename)
+                .ok()
+                .map(|uri| Location {
+                    uri,
+                    range: Range {
+                        start: lsp_pos(start),
+                        end: lsp_pos(end),
+                    },
+                })
+        })
+        .collect();
+    Some(locations)
+}
+
+/// Returns the start and end positions of all names which resolve to the definition.
+pub(crate) fn find_ref_spans(
+    program: &Program,
+    def: &Definition,
+    prog_scope: &ProgramScope,
+    include_declaration: bool,
+) -> Vec<(KCLPos, KCLPos)> {
+    let mut spans = vec![];
+    let mut pkgpaths: Vec<&String> = program.pkgs.keys().collect();
+    pkgpaths.sort();
+    for pkgpath in pkgpaths {
+        for module in &program.pkgs[pkgpath] {
+            for occurrence in find_ref_occurrences(program, module, def, prog_scope) {
+                if !include_declaration && is_declaration(def, &occurrence) {
+                    continue;
+                }
+                spans.push((occurrence.start, occurrence.end));
             }
-            // Get identifiers with same name
-            let candidates = find_ref::match_word(path, name.unwrap());
-            // Check if the definition of candidate and declartion are the same
-            let refs: Vec<Position> = candidates
-                .into_iter()
-                .filter(|x| go_to_def(x.clone()).as_ref() == Some(&decl))
-                .collect();
-            refs
         }
-    };
-    match declaration {
-        Some(decl) => search(decl),
-        None => Vec::new(),
+    }
+    spans
+}
+
+/// Returns the occurrences of the names in the module which resolve to the definition.
+pub(crate) fn find_ref_occurrences(
+    program: &Program,
+    module: &Module,
+    def: &Definition,
+    prog_scope: &ProgramScope,
+) -> Vec<NameOccurrence> {
+    // An import name is only visible in the file that imports it.
+    if let Definition::Module { filename, .. } = def {
+        if filename != &module.filename {
+            return vec![];
+        }
+    }
+    collect_name_occurrences(module, def.name())
+        .into_iter()
+        .filter(|occurrence| {
+            resolve_symbol(program, &occurrence.start, prog_scope)
+                .map_or(false, |(candidate, _, _)| candidate.is_same(def))
+        })
+        .collect()
+}
+
+/// Whether the name occurrence is the declaration of the definition.
+fn is_declaration(def: &Definition, occurrence: &NameOccurrence) -> bool {
+    match def {
+        Definition::Object(obj) => occurrence.is_declaration || obj.start == occurrence.start,
+        // The import name is declared by the import statement, which is never
+        // contained in the name occurrences.
+        Definition::Module { .. } => false,
     }
 }
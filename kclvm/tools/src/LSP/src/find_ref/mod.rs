@@ -1,134 +1,736 @@
-#![allow(dead_code)]
+//! Find references and rename for KCL.
+//!
+//! The symbol under the cursor is resolved through the scopes built by
+//! `kclvm_sema` rather than by matching words in the source text. Every
+//! occurrence of the same name in the compile unit is then resolved the
+//! same way and kept only if it points to the same definition. Now supports
+//! the following symbols:
+//! + top-level variable
+//! + schema, mixin and rule definition
+//! + schema attr, including the keys of schema config expressions and the
+//!   attributes of selector expressions, e.g., `b` in `a().b`
+//! + import alias
 
-use anyhow::Result;
-use kclvm_driver::get_kcl_files;
-use kclvm_error::Position;
+use std::collections::HashMap;
+use std::rc::Rc;
 
-mod find_refs;
-mod go_to_def;
-mod word_map;
+use kclvm_ast::ast::{
+    Arguments, AssignStmt, ConfigEntry, ConfigExpr, ConfigIfEntryExpr, DictComp, Expr, ExprContext,
+    Identifier, ImportStmt, Keyword, LambdaExpr, Module, Node, NodeRef, Program, RuleStmt,
+    SchemaAttr, SchemaExpr, SchemaStmt, SelectorExpr, Stmt, Type, TypeAliasStmt,
+};
+use kclvm_ast::pos::{ContainsPos, GetPos};
+use kclvm_ast::walker::MutSelfWalker;
+use kclvm_ast::{walk_if, walk_list, walk_node_if, walk_node_list};
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::resolver::scope::{ProgramScope, Scope, ScopeKind, ScopeObject, ScopeObjectKind};
+use kclvm_sema::ty::{SchemaType, Type as SemaType, TypeKind};
+
+use crate::util::inner_most_expr_in_stmt;
+
+pub(crate) mod find_refs;
+pub(crate) mod rename;
 
 #[cfg(test)]
 mod tests;
 
-// LineWord describes an arbitrary word in a certain line including
-// start position, end position and the word itself.
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub struct LineWord {
-    startpos: u64,
-    endpos: u64,
-    word: String,
-}
-
-// Get the word of the position.
-pub fn word_at_pos(pos: Position) -> Option<String> {
-    let text = read_file(&pos.filename);
-    if text.is_err() {
-        return None;
-    }
-    let text = text.unwrap();
-    let lines: Vec<&str> = text.lines().collect();
-    if pos.line >= lines.len() as u64 {
-        return None;
-    }
-    pos.column?;
-    let col = pos.column.unwrap();
-    let line_words = line_to_words(lines[pos.line as usize].to_string());
-    if line_words.is_empty()
-        || col < line_words.first().unwrap().startpos
-        || col >= line_words.last().unwrap().endpos
-    {
-        return None;
-    }
-    for line_word in line_words.into_iter() {
-        if col >= line_word.startpos && col < line_word.endpos {
-            return Some(line_word.word);
-        }
-    }
-    None
-}
-
-pub fn read_file(path: &String) -> Result<String> {
-    let text = std::fs::read_to_string(path)?;
-    Ok(text)
-}
-
-// Split one line into identifier words.
-pub fn line_to_words(text: String) -> Vec<LineWord> {
-    let mut chars: Vec<char> = text.chars().collect();
-    chars.push('\n');
-    let mut start_pos = usize::MAX;
-    let mut continue_pos = usize::MAX - 1; // avoid overflow
-    let mut prev_word = false;
-    let mut words: Vec<LineWord> = vec![];
-    for (i, ch) in chars.iter().enumerate() {
-        let is_id_start = rustc_lexer::is_id_start(*ch);
-        let is_id_continue = rustc_lexer::is_id_continue(*ch);
-        // If the character is valid identfier start and the previous character is not valid identifier continue, mark the start position.
-        if is_id_start && !prev_word {
-            start_pos = i;
-        }
-        match is_id_continue {
-            true => {
-                // Continue searching for the end position.
-                if start_pos != usize::MAX {
-                    continue_pos = i;
+/// The definition which a name in the source code refers to.
+#[derive(Debug, Clone)]
+pub(crate) enum Definition {
+    /// An object recorded in the sema scopes, e.g., a variable, a schema or a schema attribute.
+    Object(ScopeObject),
+    /// A package imported into a file and referred to by its import name.
+    Module {
+        filename: String,
+        name: String,
+        pkgpath: String,
+    },
+}
+
+impl Definition {
+    /// Returns the name used to refer to the definition in the source code.
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            Definition::Object(obj) => &obj.name,
+            Definition::Module { name, .. } => name,
+        }
+    }
+
+    /// Whether two definitions denote the same symbol.
+    pub(crate) fn is_same(&self, other: &Definition) -> bool {
+        match (self, other) {
+            (Definition::Object(obj), Definition::Object(other)) => {
+                obj.name == other.name
+                    && obj.kind == other.kind
+                    && obj.start == other.start
+                    && obj.end == other.end
+            }
+            (
+                Definition::Module {
+                    filename, pkgpath, ..
+                },
+                Definition::Module {
+                    filename: other_filename,
+                    pkgpath: other_pkgpath,
+                    ..
+                },
+            ) => filename == other_filename && pkgpath == other_pkgpath,
+            _ => false,
+        }
+    }
+}
+
+/// Resolves the name on the position to its definition, returns the definition
+/// and the start and end positions of the name.
+pub(crate) fn resolve_symbol(
+    program: &Program,
+    pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<(Definition, KCLPos, KCLPos)> {
+    // The lsp position converter uses `None` for the first column.
+    let pos = &KCLPos {
+        filename: pos.filename.clone(),
+        line: pos.line,
+        column: Some(pos.column.unwrap_or(0)),
+    };
+    let stmt = program.pos_to_stmt(pos)?;
+    if let Stmt::Import(import_stmt) = &stmt.node {
+        return resolve_import_name(&stmt, import_stmt, pos);
+    }
+    if let Some(selector_expr) = selector_expr_at_pos(&stmt.node, pos) {
+        return resolve_selector_attr(program, &selector_expr, prog_scope);
+    }
+    let (expr, parent) = inner_most_expr_in_stmt(&stmt.node, pos, None);
+    let expr = expr?;
+    let id = match &expr.node {
+        Expr::Identifier(id) => id,
+        _ => return None,
+    };
+    let spans = identifier_name_spans(&expr, id);
+    let index = spans
+        .iter()
+        .position(|(start, end)| start.less_equal(pos) && pos.less_equal(end))?;
+    let def = match parent.map(|parent| parent.node) {
+        // The config key of a schema expr, e.g., `name` in `Person { name = "Alice" }`
+        Some(Expr::Schema(schema_expr)) => {
+            let schema_name = &schema_expr.name;
+            let schema_def = resolve_identifier(
+                program,
+                &schema_name.node,
+                schema_name.node.names.len().checked_sub(1)?,
+                &schema_name.get_pos(),
+                prog_scope,
+            )?;
+            match schema_def {
+                Definition::Object(obj) => {
+                    resolve_attr_chain(obj, &id.names[..=index], prog_scope)?
                 }
+                Definition::Module { .. } => return None,
+            }
+        }
+        _ => resolve_identifier(program, id, index, pos, prog_scope)?,
+    };
+    let (start, end) = spans[index].clone();
+    Some((def, start, end))
+}
+
+/// Returns the selector expr whose attribute contains the position, e.g., `a().b`
+/// when the position is on `b`.
+fn selector_expr_at_pos(stmt: &Stmt, pos: &KCLPos) -> Option<SelectorExpr> {
+    let mut finder = SelectorAttrFinder {
+        pos,
+        selector_expr: None,
+    };
+    finder.walk_stmt(stmt);
+    finder.selector_expr
+}
+
+/// Resolves the attribute of the selector expr through the schema type of its value.
+fn resolve_selector_attr(
+    program: &Program,
+    selector_expr: &SelectorExpr,
+    prog_scope: &ProgramScope,
+) -> Option<(Definition, KCLPos, KCLPos)> {
+    let schema_ty = match &expr_ty(program, &selector_expr.value, prog_scope)?.kind {
+        TypeKind::Schema(schema_ty) => schema_ty.clone(),
+        _ => return None,
+    };
+    let attr = &selector_expr.attr;
+    let obj = find_schema_attr_obj(&schema_ty, &attr.node.get_name(), prog_scope)?;
+    let (start, end) = identifier_name_spans(attr, &attr.node).first()?.clone();
+    Some((Definition::Object(obj), start, end))
+}
+
+/// Returns the type of the expression from the types of the objects in the scopes,
+/// only the expressions whose attributes can be selected are supported.
+fn expr_ty(
+    program: &Program,
+    expr: &Node<Expr>,
+    prog_scope: &ProgramScope,
+) -> Option<Rc<SemaType>> {
+    match &expr.node {
+        Expr::Identifier(id) => {
+            let index = id.names.len().checked_sub(1)?;
+            match resolve_identifier(program, id, index, &expr.get_pos(), prog_scope)? {
+                Definition::Object(obj) => Some(obj.ty),
+                Definition::Module { .. } => None,
+            }
+        }
+        Expr::Selector(selector_expr) => {
+            match resolve_selector_attr(program, selector_expr, prog_scope)? {
+                (Definition::Object(obj), _, _) => Some(obj.ty),
+                _ => None,
+            }
+        }
+        Expr::Call(call_expr) => {
+            let func_ty = expr_ty(program, &call_expr.func, prog_scope)?;
+            match &func_ty.kind {
+                TypeKind::Function(func_ty) => Some(func_ty.return_ty.clone()),
+                // Calling a schema returns its instance.
+                TypeKind::Schema(_) => Some(func_ty),
+                _ => None,
+            }
+        }
+        Expr::Paren(paren_expr) => expr_ty(program, &paren_expr.expr, prog_scope),
+        Expr::Subscript(subscript) => match &expr_ty(program, &subscript.value, prog_scope)?.kind {
+            TypeKind::List(item_ty) if subscript.index.is_some() => Some(item_ty.clone()),
+            TypeKind::Dict(_, val_ty) => Some(val_ty.clone()),
+            _ => None,
+        },
+        Expr::Schema(schema_expr) => resolve_schema_expr_ty(program, schema_expr, prog_scope)
+            .map(|schema_ty| Rc::new(SemaType::schema(schema_ty))),
+        _ => None,
+    }
+}
+
+/// Resolves the name of an import statement, e.g., `pkg` in `import pkg`
+/// or `alias` in `import pkg as alias`.
+fn resolve_import_name(
+    stmt: &Node<Stmt>,
+    import_stmt: &ImportStmt,
+    pos: &KCLPos,
+) -> Option<(Definition, KCLPos, KCLPos)> {
+    let (start, end) = import_name_span(stmt, import_stmt);
+    if start.less_equal(pos) && pos.less_equal(&end) {
+        Some((
+            Definition::Module {
+                filename: stmt.filename.clone(),
+                name: import_stmt.name.clone(),
+                pkgpath: import_stmt.path.clone(),
+            },
+            start,
+            end,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Returns the span of the import name, which is always the last token of the import statement.
+pub(crate) fn import_name_span(stmt: &Node<Stmt>, import_stmt: &ImportStmt) -> (KCLPos, KCLPos) {
+    let len = import_stmt.name.chars().count() as u64;
+    (
+        KCLPos {
+            filename: stmt.filename.clone(),
+            line: stmt.end_line,
+            column: Some(stmt.end_column.saturating_sub(len)),
+        },
+        KCLPos {
+            filename: stmt.filename.clone(),
+            line: stmt.end_line,
+            column: Some(stmt.end_column),
+        },
+    )
+}
+
+/// Resolves the name at `index` of a (possibly dotted) identifier on the position.
+pub(crate) fn resolve_identifier(
+    program: &Program,
+    id: &Identifier,
+    index: usize,
+    pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<Definition> {
+    let first = id.names.first()?;
+    let scope = scope_at_pos(program, pos, prog_scope)?;
+    let first_obj = scope.lookup(first);
+    let module_pkgpath = if !id.pkgpath.is_empty() {
+        Some(id.pkgpath.trim_start_matches('@').to_string())
+    } else if first_obj.is_none() && id.names.len() > 1 {
+        prog_scope
+            .import_names
+            .get(&pos.filename)
+            .and_then(|mapping| mapping.get(first))
+            .cloned()
+    } else {
+        None
+    };
+    match module_pkgpath {
+        Some(pkgpath) => {
+            if index == 0 {
+                let name = match first.strip_prefix('@') {
+                    Some(first_pkgpath) => import_name(prog_scope, &pos.filename, first_pkgpath)?,
+                    None => first.clone(),
+                };
+                return Some(Definition::Module {
+                    filename: pos.filename.clone(),
+                    name,
+                    pkgpath,
+                });
             }
-            false => {
-                // Find out the end position.
-                if continue_pos + 1 == i {
-                    words.push(LineWord {
-                        startpos: start_pos as u64,
-                        endpos: i as u64,
-                        word: chars[start_pos..i].iter().collect::<String>().clone(),
-                    });
+            let obj = prog_scope
+                .scope_map
+                .get(&pkgpath)?
+                .borrow()
+                .elems
+                .get(id.names.get(1)?)?
+                .borrow()
+                .clone();
+            resolve_attr_chain(obj, &id.names[2..=index], prog_scope)
+        }
+        None => {
+            let obj = match first_obj {
+                Some(obj) => obj.borrow().clone(),
+                None => lookup_enclosing_schema_attr(&scope, first, prog_scope)?,
+            };
+            resolve_attr_chain(obj, &id.names[1..=index], prog_scope)
+        }
+    }
+}
+
+/// Looks up the attribute of the schema which encloses the scope, the attributes of
+/// the base schemas and mixins are not in the schema scope.
+fn lookup_enclosing_schema_attr(
+    scope: &Scope,
+    name: &str,
+    prog_scope: &ProgramScope,
+) -> Option<ScopeObject> {
+    if let ScopeKind::Schema(schema_name) = &scope.kind {
+        let schema_obj = scope.lookup(schema_name)?;
+        let schema_ty = match &schema_obj.borrow().ty.kind {
+            TypeKind::Schema(schema_ty) => schema_ty.clone(),
+            _ => return None,
+        };
+        return find_schema_attr_obj(&schema_ty, name, prog_scope);
+    }
+    let parent = scope.parent.as_ref()?.upgrade()?;
+    let parent = parent.borrow();
+    lookup_enclosing_schema_attr(&parent, name, prog_scope)
+}
+
+/// Returns the name which the file imports the package as.
+fn import_name(prog_scope: &ProgramScope, filename: &str, pkgpath: &str) -> Option<String> {
+    prog_scope
+        .import_names
+        .get(filename)?
+        .iter()
+        .find_map(|(name, path)| (path == pkgpath).then(|| name.clone()))
+}
+
+/// Resolves the attribute names one by one starting from the schema typed object.
+fn resolve_attr_chain(
+    obj: ScopeObject,
+    names: &[String],
+    prog_scope: &ProgramScope,
+) -> Option<Definition> {
+    let mut obj = obj;
+    for name in names {
+        let schema_ty = match &obj.ty.kind {
+            TypeKind::Schema(schema_ty) => schema_ty.clone(),
+            _ => return None,
+        };
+        obj = find_schema_attr_obj(&schema_ty, name, prog_scope)?;
+    }
+    Some(Definition::Object(obj))
+}
+
+/// Resolves the schema type of the schema expression, e.g., `Person` in `Person {}`.
+pub(crate) fn resolve_schema_expr_ty(
+    program: &Program,
+    schema_expr: &SchemaExpr,
+    prog_scope: &ProgramScope,
+) -> Option<SchemaType> {
+    let (start, _) = identifier_name_spans(&schema_expr.name, &schema_expr.name.node)
+        .last()?
+        .clone();
+    match resolve_symbol(program, &start, prog_scope)? {
+        (Definition::Object(obj), _, _) => match &obj.ty.kind {
+            TypeKind::Schema(schema_ty) => Some(schema_ty.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Finds the attribute object in the schema scope, the base schemas and the mixins.
+pub(crate) fn find_schema_attr_obj(
+    schema_ty: &SchemaType,
+    attr: &str,
+    prog_scope: &ProgramScope,
+) -> Option<ScopeObject> {
+    if let Some(pkg_scope) = prog_scope.scope_map.get(&schema_ty.pkgpath) {
+        if let Some(schema_scope) = pkg_scope
+            .borrow()
+            .search_child_scope_by_name(&schema_ty.name)
+        {
+            if let Some(obj) = schema_scope.borrow().elems.get(attr) {
+                let obj = obj.borrow();
+                if matches!(
+                    obj.kind,
+                    ScopeObjectKind::Attribute | ScopeObjectKind::Variable
+                ) {
+                    return Some(obj.clone());
                 }
-                // Reset the start position.
-                start_pos = usize::MAX;
             }
         }
-        prev_word = is_id_continue;
     }
-    words
+    if let Some(base) = &schema_ty.base {
+        if let Some(obj) = find_schema_attr_obj(base, attr, prog_scope) {
+            return Some(obj);
+        }
+    }
+    schema_ty
+        .mixins
+        .iter()
+        .find_map(|mixin| find_schema_attr_obj(mixin, attr, prog_scope))
+}
+
+/// Finds the definition object of the schema in its package scope, the schemas defined
+/// outside the compile unit, e.g., the system module and plugin schemas, have no object.
+pub(crate) fn find_schema_def_obj(
+    schema_ty: &SchemaType,
+    prog_scope: &ProgramScope,
+) -> Option<ScopeObject> {
+    let pkg_scope = prog_scope.scope_map.get(&schema_ty.pkgpath)?.borrow();
+    let obj = pkg_scope.elems.get(&schema_ty.name)?.borrow();
+    (obj.kind == ScopeObjectKind::Definition).then(|| obj.clone())
 }
 
-// Get all occurrences of the word in the entire path.
-pub fn match_word(path: String, name: String) -> Vec<Position> {
-    let mut res = vec![];
-    if let Ok(files) = get_kcl_files(path, true) {
-        // Searching in all files.
-        for file in files.into_iter() {
-            let text = read_file(&file);
-            if text.is_err() {
-                continue;
+/// Returns the inner most scope on the position, or the package scope of the file
+/// when the position is not covered by any scope object.
+pub(crate) fn scope_at_pos(
+    program: &Program,
+    pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<Scope> {
+    let pkgpath = program.pkgs.iter().find_map(|(pkgpath, modules)| {
+        modules
+            .iter()
+            .any(|m| m.filename == pos.filename)
+            .then_some(pkgpath)
+    })?;
+    let pkg_scope = prog_scope.scope_map.get(pkgpath)?.borrow();
+    pkg_scope
+        .inner_most(pos)
+        .or_else(|| Some(pkg_scope.clone()))
+}
+
+/// Returns the start and end positions of each name in the identifier.
+pub(crate) fn identifier_name_spans<T>(node: &Node<T>, id: &Identifier) -> Vec<(KCLPos, KCLPos)> {
+    let mut spans = vec![];
+    let mut column = node.column;
+    for (index, name) in id.names.iter().enumerate() {
+        let len = if index == 0 && name.starts_with('@') && node.line == node.end_line {
+            // The package name is rewritten to `@pkgpath` by the resolver, the name in the
+            // source code takes the rest of the identifier span.
+            let rest: u64 = id.names[1..]
+                .iter()
+                .map(|name| name.chars().count() as u64 + 1)
+                .sum();
+            node.end_column.saturating_sub(node.column + rest)
+        } else {
+            name.chars().count() as u64
+        };
+        let end_column = column + len;
+        spans.push((
+            KCLPos {
+                filename: node.filename.clone(),
+                line: node.line,
+                column: Some(column),
+            },
+            KCLPos {
+                filename: node.filename.clone(),
+                line: node.line,
+                column: Some(end_column),
+            },
+        ));
+        // Skip the dot between names.
+        column = end_column + 1;
+    }
+    spans
+}
+
+/// A name occurrence in the source code.
+#[derive(Debug, Clone)]
+pub(crate) struct NameOccurrence {
+    pub start: KCLPos,
+    pub end: KCLPos,
+    /// Whether the name is declared by a schema, rule or schema attribute statement.
+    pub is_declaration: bool,
+    /// Whether the name is written, e.g., a declaration, the target of an assignment
+    /// or the key of a config entry.
+    pub is_write: bool,
+}
+
+/// Collects all occurrences of `name` in the module, including definitions,
+/// type annotations and config keys.
+pub(crate) fn collect_name_occurrences(module: &Module, name: &str) -> Vec<NameOccurrence> {
+    let mut collector = NameCollector {
+        name: Some(name),
+        import_names: module_import_names(module),
+        occurrences: vec![],
+    };
+    collector.walk_module(module);
+    collector.occurrences
+}
+
+/// Collects the occurrences of all names in the module.
+pub(crate) fn collect_all_name_occurrences(module: &Module) -> Vec<NameOccurrence> {
+    let mut collector = NameCollector {
+        name: None,
+        import_names: module_import_names(module),
+        occurrences: vec![],
+    };
+    collector.walk_module(module);
+    collector.occurrences
+}
+
+/// Returns the import names of the module keyed by the package paths.
+fn module_import_names(module: &Module) -> HashMap<String, String> {
+    module
+        .body
+        .iter()
+        .filter_map(|stmt| match &stmt.node {
+            Stmt::Import(import_stmt) => Some((import_stmt.path.clone(), import_stmt.name.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+struct NameCollector<'a> {
+    /// Only collects the names equal to it, or all names if it is `None`.
+    name: Option<&'a str>,
+    /// The import names keyed by the package paths, the package names of the qualified
+    /// identifiers are rewritten to `@pkgpath` by the resolver.
+    import_names: HashMap<String, String>,
+    occurrences: Vec<NameOccurrence>,
+}
+
+impl<'a> NameCollector<'a> {
+    fn matches(&self, name: &str) -> bool {
+        self.name.map_or(true, |expected| expected == name)
+    }
+
+    fn identifier<T>(&mut self, node: &Node<T>, id: &Identifier) {
+        self.identifier_with_ctx(node, id, matches!(id.ctx, ExprContext::Store));
+    }
+
+    /// Collects the names of the identifier, only the last name is written when `is_write`
+    /// is true, e.g., `b` in `a.b = 1`.
+    fn identifier_with_ctx<T>(&mut self, node: &Node<T>, id: &Identifier, is_write: bool) {
+        let last = id.names.len().saturating_sub(1);
+        for (index, (name, (start, end))) in id
+            .names
+            .iter()
+            .zip(identifier_name_spans(node, id))
+            .enumerate()
+        {
+            let name = match name.strip_prefix('@') {
+                Some(pkgpath) if index == 0 => match self.import_names.get(pkgpath) {
+                    Some(import_name) => import_name,
+                    None => continue,
+                },
+                _ => name,
+            };
+            if self.matches(name) {
+                self.occurrences.push(NameOccurrence {
+                    start,
+                    end,
+                    is_declaration: false,
+                    is_write: is_write && index == last,
+                });
+            }
+        }
+    }
+
+    fn string_name(&mut self, name: &NodeRef<String>) {
+        if self.matches(&name.node) {
+            let (start, end) = name.get_span_pos();
+            self.occurrences.push(NameOccurrence {
+                start,
+                end,
+                is_declaration: true,
+                is_write: true,
+            });
+        }
+    }
+
+    /// Collects the names in the type annotation, which are not visited by the AST walker.
+    fn ty(&mut self, ty: &NodeRef<Type>) {
+        match &ty.node {
+            Type::Named(id) => self.identifier(ty, id),
+            Type::List(list_ty) => {
+                if let Some(inner) = &list_ty.inner_type {
+                    self.ty(inner);
+                }
+            }
+            Type::Dict(dict_ty) => {
+                if let Some(key_ty) = &dict_ty.key_type {
+                    self.ty(key_ty);
+                }
+                if let Some(value_ty) = &dict_ty.value_type {
+                    self.ty(value_ty);
+                }
             }
-            let text = text.unwrap();
-            let lines: Vec<&str> = text.lines().collect();
-            for (li, line) in lines.into_iter().enumerate() {
-                // Get the matching results for each line.
-                let matched: Vec<Position> = line_to_words(line.to_string())
-                    .into_iter()
-                    .filter(|x| x.word == name)
-                    .map(|x| Position {
-                        filename: file.clone(),
-                        line: li as u64,
-                        column: Some(x.startpos),
-                    })
-                    .collect();
-                res.extend(matched);
+            Type::Union(union_ty) => {
+                for ty in &union_ty.type_elements {
+                    self.ty(ty);
+                }
             }
+            Type::Function(func_ty) => {
+                for ty in &func_ty.params_ty {
+                    self.ty(ty);
+                }
+                self.ty(&func_ty.ret_ty);
+            }
+            Type::Generic(generic_ty) => {
+                self.identifier(ty, &generic_ty.name);
+                for ty in &generic_ty.args {
+                    self.ty(ty);
+                }
+            }
+            Type::Any | Type::Basic(_) | Type::Literal(_) => {}
+        }
+    }
+
+    /// The keys of config entries are written, e.g., `name` in `{ name = "Alice" }`.
+    fn config_entry(&mut self, entry: &ConfigEntry) {
+        if let Some(key) = &entry.key {
+            match &key.node {
+                Expr::Identifier(id) => self.identifier_with_ctx(key, id, true),
+                _ => self.walk_expr_node(key),
+            }
+        }
+        self.walk_expr_node(&entry.value);
+    }
+}
+
+/// Finds the selector expr whose attribute contains the position.
+struct SelectorAttrFinder<'a> {
+    pos: &'a KCLPos,
+    selector_expr: Option<SelectorExpr>,
+}
+
+impl<'a> MutSelfWalker for SelectorAttrFinder<'a> {
+    fn walk_selector_expr(&mut self, selector_expr: &SelectorExpr) {
+        if selector_expr.attr.contains_pos(self.pos) {
+            self.selector_expr = Some(selector_expr.clone());
+        } else {
+            self.walk_expr_node(&selector_expr.value);
         }
     }
-    res
 }
 
-// Convert pos format
-// The position in lsp protocol is different with position in ast node whose line number is 1 based.
-pub fn kcl_pos_to_lsp_pos(pos: Position) -> Position {
-    Position {
-        filename: pos.filename,
-        line: pos.line - 1,
-        column: pos.column,
+impl<'a> MutSelfWalker for NameCollector<'a> {
+    fn walk_expr_node(&mut self, expr: &Node<Expr>) {
+        match &expr.node {
+            Expr::Identifier(id) => self.identifier(expr, id),
+            _ => self.walk_expr(&expr.node),
+        }
+    }
+
+    fn walk_identifier_node(&mut self, id: &Node<Identifier>) {
+        self.identifier(id, &id.node);
+    }
+
+    fn walk_type_alias_stmt(&mut self, type_alias_stmt: &TypeAliasStmt) {
+        self.walk_identifier_node(&type_alias_stmt.type_name);
+        self.ty(&type_alias_stmt.ty);
+    }
+
+    fn walk_assign_stmt(&mut self, assign_stmt: &AssignStmt) {
+        walk_node_list!(self, walk_identifier_node, assign_stmt.targets);
+        if let Some(ty) = &assign_stmt.ty {
+            self.ty(ty);
+        }
+        self.walk_expr_node(&assign_stmt.value);
+    }
+
+    fn walk_schema_attr(&mut self, schema_attr: &SchemaAttr) {
+        self.string_name(&schema_attr.name);
+        self.ty(&schema_attr.ty);
+        walk_list!(self, walk_call_expr, schema_attr.decorators);
+        walk_node_if!(self, walk_expr_node, schema_attr.value);
+    }
+
+    fn walk_schema_stmt(&mut self, schema_stmt: &SchemaStmt) {
+        self.string_name(&schema_stmt.name);
+        walk_node_if!(self, walk_identifier_node, schema_stmt.parent_name);
+        walk_node_if!(self, walk_identifier_node, schema_stmt.for_host_name);
+        walk_if!(self, walk_arguments, schema_stmt.args);
+        if let Some(index_signature) = &schema_stmt.index_signature {
+            walk_node_if!(self, walk_expr_node, index_signature.node.value);
+        }
+        walk_node_list!(self, walk_identifier_node, schema_stmt.mixins);
+        walk_list!(self, walk_call_expr, schema_stmt.decorators);
+        walk_list!(self, walk_check_expr, schema_stmt.checks);
+        walk_list!(self, walk_stmt, schema_stmt.body);
+    }
+
+    fn walk_rule_stmt(&mut self, rule_stmt: &RuleStmt) {
+        self.string_name(&rule_stmt.name);
+        walk_node_list!(self, walk_identifier_node, rule_stmt.parent_rules);
+        walk_list!(self, walk_call_expr, rule_stmt.decorators);
+        walk_list!(self, walk_check_expr, rule_stmt.checks);
+        walk_if!(self, walk_arguments, rule_stmt.args);
+        walk_node_if!(self, walk_identifier_node, rule_stmt.for_host_name);
+    }
+
+    fn walk_arguments(&mut self, arguments: &Arguments) {
+        walk_node_list!(self, walk_identifier_node, arguments.args);
+        for default in arguments.defaults.iter().flatten() {
+            self.walk_expr_node(default);
+        }
+        for ty in arguments.ty_list.iter().flatten() {
+            self.ty(ty);
+        }
+    }
+
+    fn walk_lambda_expr(&mut self, lambda_expr: &LambdaExpr) {
+        walk_if!(self, walk_arguments, lambda_expr.args);
+        if let Some(return_ty) = &lambda_expr.return_ty {
+            self.ty(return_ty);
+        }
+        walk_list!(self, walk_stmt, lambda_expr.body);
+    }
+
+    /// The attribute of a selector expr is a single name, e.g., `b` in `a().b`.
+    fn walk_selector_expr(&mut self, selector_expr: &SelectorExpr) {
+        self.walk_expr_node(&selector_expr.value);
+        self.identifier_with_ctx(&selector_expr.attr, &selector_expr.attr.node, false);
+    }
+
+    /// The keyword names of calls are parameter names, which are not collected.
+    fn walk_keyword(&mut self, keyword: &Keyword) {
+        walk_node_if!(self, walk_expr_node, keyword.value);
+    }
+
+    fn walk_config_expr(&mut self, config_expr: &ConfigExpr) {
+        for item in &config_expr.items {
+            self.config_entry(&item.node);
+        }
+    }
+
+    fn walk_config_if_entry_expr(&mut self, config_if_entry_expr: &ConfigIfEntryExpr) {
+        self.walk_expr_node(&config_if_entry_expr.if_cond);
+        for item in &config_if_entry_expr.items {
+            self.config_entry(&item.node);
+        }
+        walk_node_if!(self, walk_expr_node, config_if_entry_expr.orelse);
+    }
+
+    fn walk_dict_comp(&mut self, dict_comp: &DictComp) {
+        self.config_entry(&dict_comp.entry);
+        walk_list!(self, walk_comp_clause, dict_comp.generators);
     }
 }
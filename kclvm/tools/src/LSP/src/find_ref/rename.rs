@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use kclvm_ast::ast::{Program, Stmt};
+use kclvm_ast::token::KEYWORDS;
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::resolver::scope::ProgramScope;
+use lsp_types::{PrepareRenameResponse, Range, TextEdit, Url, WorkspaceEdit};
+
+use crate::find_ref::find_refs::find_ref_spans;
+use crate::find_ref::{import_name_span, resolve_symbol, Definition};
+use crate::to_lsp::lsp_pos;
+
+/// Checks whether the symbol at the cursor position can be renamed and returns the
+/// range of its name.
+pub(crate) fn prepare_rename(
+    program: &Program,
+    kcl_pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<PrepareRenameResponse> {
+    let (def, start, end) = resolve_symbol(program, kcl_pos, prog_scope)?;
+    if !is_renamable(&def) {
+        return None;
+    }
+    Some(PrepareRenameResponse::Range(Range {
+        start: lsp_pos(&start),
+        end: lsp_pos(&end),
+    }))
+}
+
+/// Renames the symbol at the cursor position and all its references in the compile unit.
+pub(crate) fn rename(
+    program: &Program,
+    kcl_pos: &KCLPos,
+    prog_scope: &ProgramScope,
+    new_name: &str,
+) -> anyhow::Result<Option<WorkspaceEdit>> {
+    if !is_valid_identifier(new_name) {
+        return Err(anyhow::anyhow!("'{}' is not a valid identifier", new_name));
+    }
+    let def = match resolve_symbol(program, kcl_pos, prog_scope) {
+        Some((def, _, _)) if is_renamable(&def) => def,
+        _ => return Ok(None),
+    };
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    let mut add_edit = |start: &KCLPos, end: &KCLPos, new_text: String| -> anyhow::Result<()> {
+        let uri = Url::from_file_path(&start.filename)
+            .map_err(|_| anyhow::anyhow!("can't convert file to url: {}", start.filename))?;
+        changes.entry(uri).or_default().push(TextEdit {
+            range: Range {
+                start: lsp_pos(start),
+                end: lsp_pos(end),
+            },
+            new_text,
+        });
+        Ok(())
+    };
+    for (start, end) in find_ref_spans(program, &def, prog_scope, true) {
+        add_edit(&start, &end, new_name.to_string())?;
+    }
+    // The import name is declared by the import statement, rename the alias or
+    // add one if the package is imported without an alias.
+    if let Definition::Module {
+        filename,
+        name,
+        pkgpath,
+    } = &def
+    {
+        let import_stmts = program
+            .pkgs
+            .values()
+            .flatten()
+            .filter(|module| &module.filename == filename)
+            .flat_map(|module| module.body.iter());
+        for stmt in import_stmts {
+            if let Stmt::Import(import_stmt) = &stmt.node {
+                if &import_stmt.name != name || &import_stmt.path != pkgpath {
+                    continue;
+                }
+                let (start, end) = import_name_span(stmt, import_stmt);
+                if import_stmt.asname.is_some() {
+                    add_edit(&start, &end, new_name.to_string())?;
+                } else {
+                    add_edit(&end, &end, format!(" as {}", new_name))?;
+                }
+            }
+        }
+    }
+    Ok(Some(WorkspaceEdit::new(changes)))
+}
+
+/// Builtin functions have no declaration in the source code and can't be renamed.
+fn is_renamable(def: &Definition) -> bool {
+    match def {
+        Definition::Object(obj) => obj.pos_is_valid() && !obj.start.filename.is_empty(),
+        Definition::Module { .. } => true,
+    }
+}
+
+/// Whether the name is a valid KCL identifier which is not a keyword.
+fn is_valid_identifier(name: &str) -> bool {
+    if KEYWORDS.contains(&name) {
+        return false;
+    }
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => chars.all(|c| c.is_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
@@ -1,252 +1,369 @@
-use crate::find_ref;
-use crate::find_ref::LineWord;
-use kclvm_error::Position;
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use std::path::Path;
-    use std::{collections::HashMap, hash::Hash};
-
-    fn check_line_to_words(code: &str, expect: Vec<LineWord>) {
-        assert_eq!(find_ref::line_to_words(code.to_string()), expect);
-    }
+use std::path::PathBuf;
 
-    fn test_eq_list<T>(a: &[T], b: &[T]) -> bool
-    where
-        T: Eq + Hash,
-    {
-        fn count<T>(items: &[T]) -> HashMap<&T, usize>
-        where
-            T: Eq + Hash,
-        {
-            let mut cnt = HashMap::new();
-            for i in items {
-                *cnt.entry(i).or_insert(0) += 1
-            }
-            cnt
-        }
-        count(a) == count(b)
-    }
+use kclvm_ast::ast::Program;
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::resolver::scope::ProgramScope;
+use lsp_types::{Location, Position, PrepareRenameResponse, Range, TextEdit, Url};
 
-    #[test]
-    fn test_line_to_words() {
-        let datas = vec![
-            "alice_first_name = \"alice\"",
-            "0lice_first_name = \"alic0\"",
-            "alice = p.Parent { name: \"alice\" }",
-        ];
-        let expect = vec![
-            vec![
-                LineWord {
-                    startpos: 0,
-                    endpos: 16,
-                    word: "alice_first_name".to_string(),
-                },
-                LineWord {
-                    startpos: 20,
-                    endpos: 25,
-                    word: "alice".to_string(),
-                },
-            ],
-            vec![LineWord {
-                startpos: 20,
-                endpos: 25,
-                word: "alic0".to_string(),
-            }],
-            vec![
-                LineWord {
-                    startpos: 0,
-                    endpos: 5,
-                    word: "alice".to_string(),
-                },
-                LineWord {
-                    startpos: 8,
-                    endpos: 9,
-                    word: "p".to_string(),
-                },
-                LineWord {
-                    startpos: 10,
-                    endpos: 16,
-                    word: "Parent".to_string(),
-                },
-                LineWord {
-                    startpos: 19,
-                    endpos: 23,
-                    word: "name".to_string(),
-                },
-                LineWord {
-                    startpos: 26,
-                    endpos: 31,
-                    word: "alice".to_string(),
-                },
-            ],
-        ];
-        for i in 0..datas.len() {
-            check_line_to_words(datas[i], expect[i].clone());
-        }
-    }
+use crate::find_ref::find_refs::find_refs;
+use crate::find_ref::rename::{prepare_rename, rename};
+use crate::util::{parse_param_and_compile, Param};
 
-    #[test]
-    fn test_word_at_pos() {
-        // use std::env;
-        // let parent_path = env::current_dir().unwrap();
-        // println!("The current directory is {}", parent_path.display());
-        let path_prefix = "./src/find_ref/".to_string();
-        let datas = vec![
-            Position {
-                filename: (path_prefix.clone() + "test_data/inherit.k"),
-                line: 0,
-                column: Some(0),
-            },
-            Position {
-                filename: (path_prefix.clone() + "test_data/inherit.k"),
-                line: 1,
-                column: Some(5),
-            },
-            Position {
-                filename: (path_prefix.clone() + "test_data/inherit.k"),
-                line: 3,
-                column: Some(7),
-            },
-            Position {
-                filename: (path_prefix.clone() + "test_data/inherit.k"),
-                line: 3,
-                column: Some(10),
-            },
-            Position {
-                filename: (path_prefix.clone() + "test_data/inherit.k"),
-                line: 4,
-                column: Some(8),
+fn test_data_file(dir: &str, name: &str) -> String {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("src/find_ref/test_data");
+    path.push(dir);
+    path.push(name);
+    path.canonicalize().unwrap().to_str().unwrap().to_string()
+}
+
+fn test_file(name: &str) -> String {
+    test_data_file("find_refs_test", name)
+}
+
+fn attr_test_file(name: &str) -> String {
+    test_data_file("attr_refs_test", name)
+}
+
+fn compile(file: String) -> (Program, ProgramScope) {
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file,
+            module_cache: None,
+            scope_cache: None,
+        },
+        None,
+    )
+    .unwrap();
+    (program, prog_scope)
+}
+
+fn compile_test_unit() -> (Program, ProgramScope) {
+    compile(test_file("main.k"))
+}
+
+fn compile_attr_test_unit() -> (Program, ProgramScope) {
+    compile(attr_test_file("main.k"))
+}
+
+fn location(file: &str, line: u32, start: u32, end: u32) -> Location {
+    file_location(test_file(file), line, start, end)
+}
+
+fn attr_location(file: &str, line: u32, start: u32, end: u32) -> Location {
+    file_location(attr_test_file(file), line, start, end)
+}
+
+fn file_location(file: String, line: u32, start: u32, end: u32) -> Location {
+    Location {
+        uri: Url::from_file_path(file).unwrap(),
+        range: Range {
+            start: Position {
+                line,
+                character: start,
             },
-            Position {
-                filename: (path_prefix + "test_data/inherit.k"),
-                line: 4,
-                column: Some(100),
+            end: Position {
+                line,
+                character: end,
             },
-        ];
-        let expect = vec![
-            Some("schema".to_string()),
-            Some("name".to_string()),
-            Some("Son".to_string()),
-            None,
-            None,
-            None,
-        ];
-        for i in 0..datas.len() {
-            assert_eq!(find_ref::word_at_pos(datas[i].clone()), expect[i]);
-        }
+        },
     }
+}
 
-    fn test_word_workspace() -> String {
-        Path::new(".")
-            .join("src")
-            .join("find_ref")
-            .join("test_data")
-            .join("test_word_workspace")
-            .display()
-            .to_string()
-    }
+fn sorted(mut locations: Vec<Location>) -> Vec<Location> {
+    locations.sort_by_key(|loc| {
+        (
+            loc.uri.to_string(),
+            loc.range.start.line,
+            loc.range.start.character,
+        )
+    });
+    locations
+}
 
-    #[test]
-    fn test_match_word() {
-        let path = test_word_workspace();
-        let datas = vec![String::from("Son")];
-        let except = vec![vec![
-            Position {
-                filename: Path::new(&test_word_workspace())
-                    .join("inherit_pkg.k")
-                    .display()
-                    .to_string(),
-                line: 2,
-                column: Some(7),
-            },
-            Position {
-                filename: Path::new(&test_word_workspace())
-                    .join("inherit.k")
-                    .display()
-                    .to_string(),
-                line: 3,
-                column: Some(7),
-            },
-            Position {
-                filename: Path::new(&test_word_workspace())
-                    .join("inherit.k")
-                    .display()
-                    .to_string(),
-                line: 7,
-                column: Some(16),
-            },
-        ]];
-        for i in 0..datas.len() {
-            assert!(test_eq_list(
-                &find_ref::match_word(path.clone(), datas[i].clone()),
-                &except[i]
-            ));
+#[test]
+fn find_refs_schema_attr_test() {
+    let (program, prog_scope) = compile_test_unit();
+    // `name` in `Server { name = "web" }`
+    let pos = KCLPos {
+        filename: test_file("main.k"),
+        line: 5,
+        column: Some(5),
+    };
+    let res = find_refs(&program, &pos, &prog_scope, true).unwrap();
+    assert_eq!(
+        sorted(res),
+        vec![
+            location("base.k", 4, 4, 8),
+            location("base.k", 8, 12, 16),
+            location("main.k", 4, 4, 8),
+        ]
+    );
+
+    let res = find_refs(&program, &pos, &prog_scope, false).unwrap();
+    assert_eq!(
+        sorted(res),
+        vec![location("base.k", 8, 12, 16), location("main.k", 4, 4, 8)]
+    );
+}
+
+#[test]
+fn find_refs_base_schema_attr_test() {
+    let (program, prog_scope) = compile_test_unit();
+    // `id` in `Server { id = "s1" }` is declared in the base schema.
+    let pos = KCLPos {
+        filename: test_file("main.k"),
+        line: 4,
+        column: Some(4),
+    };
+    let res = find_refs(&program, &pos, &prog_scope, true).unwrap();
+    assert_eq!(
+        sorted(res),
+        vec![location("base.k", 1, 4, 6), location("main.k", 3, 4, 6)]
+    );
+}
+
+#[test]
+fn find_refs_schema_in_other_pkg_test() {
+    let (program, prog_scope) = compile_test_unit();
+    // `Person` in `schema Person:`
+    let pos = KCLPos {
+        filename: test_file("pkg/person.k"),
+        line: 1,
+        column: Some(8),
+    };
+    let res = find_refs(&program, &pos, &prog_scope, true).unwrap();
+    assert_eq!(
+        sorted(res),
+        vec![
+            location("main.k", 7, 10, 16),
+            location("pkg/person.k", 0, 7, 13)
+        ]
+    );
+}
+
+#[test]
+fn find_refs_selected_attr_test() {
+    let (program, prog_scope) = compile_test_unit();
+    // `port` in `port = server.port`
+    let pos = KCLPos {
+        filename: test_file("main.k"),
+        line: 7,
+        column: Some(15),
+    };
+    let res = find_refs(&program, &pos, &prog_scope, true).unwrap();
+    assert_eq!(
+        sorted(res),
+        vec![location("base.k", 5, 4, 8), location("main.k", 6, 14, 18)]
+    );
+}
+
+#[test]
+fn prepare_rename_test() {
+    let (program, prog_scope) = compile_test_unit();
+    let pos = KCLPos {
+        filename: test_file("main.k"),
+        line: 3,
+        column: Some(2),
+    };
+    match prepare_rename(&program, &pos, &prog_scope).unwrap() {
+        PrepareRenameResponse::Range(range) => {
+            assert_eq!(range.start, Position::new(2, 0));
+            assert_eq!(range.end, Position::new(2, 6));
         }
+        _ => unreachable!("test error"),
     }
+    // String literals can't be renamed.
+    let pos = KCLPos {
+        filename: test_file("main.k"),
+        line: 5,
+        column: Some(13),
+    };
+    assert!(prepare_rename(&program, &pos, &prog_scope).is_none());
+}
 
-    fn test_word_workspace_map() -> String {
-        Path::new(".")
-            .join("src")
-            .join("find_ref")
-            .join("test_data")
-            .join("test_word_workspace_map")
-            .display()
-            .to_string()
-    }
+#[test]
+fn rename_variable_test() {
+    let (program, prog_scope) = compile_test_unit();
+    let pos = KCLPos {
+        filename: test_file("main.k"),
+        line: 3,
+        column: Some(2),
+    };
+    let edit = rename(&program, &pos, &prog_scope, "srv").unwrap().unwrap();
+    let changes = edit.changes.unwrap();
+    assert_eq!(changes.len(), 1);
+    let uri = Url::from_file_path(test_file("main.k")).unwrap();
+    assert_eq!(
+        changes[&uri],
+        vec![
+            TextEdit::new(
+                Range::new(Position::new(2, 0), Position::new(2, 6)),
+                "srv".to_string()
+            ),
+            TextEdit::new(
+                Range::new(Position::new(6, 7), Position::new(6, 13)),
+                "srv".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn rename_import_alias_test() {
+    let (program, prog_scope) = compile_test_unit();
+    // `p` in `alice = p.Person {`
+    let pos = KCLPos {
+        filename: test_file("main.k"),
+        line: 8,
+        column: Some(8),
+    };
+    let edit = rename(&program, &pos, &prog_scope, "person")
+        .unwrap()
+        .unwrap();
+    let changes = edit.changes.unwrap();
+    let uri = Url::from_file_path(test_file("main.k")).unwrap();
+    let mut edits = changes[&uri].clone();
+    edits.sort_by_key(|edit| (edit.range.start.line, edit.range.start.character));
+    assert_eq!(
+        edits,
+        vec![
+            TextEdit::new(
+                Range::new(Position::new(0, 14), Position::new(0, 15)),
+                "person".to_string()
+            ),
+            TextEdit::new(
+                Range::new(Position::new(7, 8), Position::new(7, 9)),
+                "person".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn rename_invalid_name_test() {
+    let (program, prog_scope) = compile_test_unit();
+    let pos = KCLPos {
+        filename: test_file("main.k"),
+        line: 3,
+        column: Some(2),
+    };
+    assert!(rename(&program, &pos, &prog_scope, "1srv").is_err());
+    assert!(rename(&program, &pos, &prog_scope, "schema").is_err());
+    assert!(rename(&program, &pos, &prog_scope, "None").is_err());
+}
+
+#[test]
+fn find_refs_selector_attr_test() {
+    let (program, prog_scope) = compile_attr_test_unit();
+    let expected = vec![
+        attr_location("inherit.k", 5, 4, 12),
+        attr_location("main.k", 4, 25, 33),
+        attr_location("main.k", 5, 32, 40),
+    ];
+    // `son_name` in `son_name: str = name`
+    let pos = KCLPos {
+        filename: attr_test_file("inherit.k"),
+        line: 6,
+        column: Some(5),
+    };
+    let res = find_refs(&program, &pos, &prog_scope, true).unwrap();
+    assert_eq!(sorted(res), expected);
+    // `son_name` in `sons[0].son_name`
+    let pos = KCLPos {
+        filename: attr_test_file("main.k"),
+        line: 5,
+        column: Some(27),
+    };
+    let res = find_refs(&program, &pos, &prog_scope, true).unwrap();
+    assert_eq!(sorted(res), expected);
+}
+
+#[test]
+fn find_refs_inherited_attr_test() {
+    let (program, prog_scope) = compile_attr_test_unit();
+    // `name` in `schema Parent:`, which is referred to in the bodies of the sub schemas.
+    let pos = KCLPos {
+        filename: attr_test_file("inherit.k"),
+        line: 2,
+        column: Some(5),
+    };
+    let res = find_refs(&program, &pos, &prog_scope, true).unwrap();
+    assert_eq!(
+        sorted(res),
+        vec![
+            attr_location("inherit.k", 1, 4, 8),
+            attr_location("inherit.k", 5, 20, 24),
+            attr_location("inherit.k", 8, 26, 30),
+            attr_location("main.k", 1, 9, 13),
+            attr_location("main.k", 6, 22, 26),
+        ]
+    );
+}
 
-    #[test]
-    fn test_word_map() {
-        let path = test_word_workspace_map();
-        let mut mp = find_ref::word_map::WorkSpaceWordMap::new(path);
-        mp.build();
-        let _res = fs::rename(
-            Path::new(&test_word_workspace_map())
-                .join("inherit_pkg.k")
-                .display()
-                .to_string(),
-            Path::new(&test_word_workspace_map())
-                .join("inherit_bak.k")
-                .display()
-                .to_string(),
-        );
-        mp.rename_file(
-            Path::new(&test_word_workspace_map())
-                .join("inherit_pkg.k")
-                .display()
-                .to_string(),
-            Path::new(&test_word_workspace_map())
-                .join("inherit_bak.k")
-                .display()
-                .to_string(),
-        );
-        mp.delete_file(
-            Path::new(&test_word_workspace_map())
-                .join("inherit.k")
-                .display()
-                .to_string(),
-        );
-        let _res = fs::rename(
-            Path::new(&test_word_workspace_map())
-                .join("inherit_bak.k")
-                .display()
-                .to_string(),
-            Path::new(&test_word_workspace_map())
-                .join("inherit_pkg.k")
-                .display()
-                .to_string(),
-        );
-
-        let except = vec![Position {
-            filename: Path::new(&test_word_workspace_map())
-                .join("inherit_bak.k")
-                .display()
-                .to_string(),
-            line: 2,
-            column: Some(7),
-        }];
-        assert_eq!(mp.get(&String::from("Son")), Some(except));
+#[test]
+fn find_refs_base_schema_test() {
+    let (program, prog_scope) = compile_attr_test_unit();
+    // `Son` in `schema Son(Parent):`
+    let pos = KCLPos {
+        filename: attr_test_file("inherit.k"),
+        line: 4,
+        column: Some(8),
+    };
+    let res = find_refs(&program, &pos, &prog_scope, true).unwrap();
+    assert_eq!(
+        sorted(res),
+        vec![
+            attr_location("inherit.k", 3, 7, 10),
+            attr_location("inherit.k", 7, 16, 19),
+            attr_location("main.k", 0, 31, 34),
+            attr_location("main.k", 1, 4, 7),
+        ]
+    );
+}
+
+#[test]
+fn find_refs_no_symbol_test() {
+    let (program, prog_scope) = compile_attr_test_unit();
+    // The keyword `schema` and the position after the end of the line.
+    for column in [2, 40] {
+        let pos = KCLPos {
+            filename: attr_test_file("inherit.k"),
+            line: 1,
+            column: Some(column),
+        };
+        assert!(find_refs(&program, &pos, &prog_scope, true).is_none());
+        assert!(prepare_rename(&program, &pos, &prog_scope).is_none());
     }
 }
+
+#[test]
+fn rename_selector_attr_test() {
+    let (program, prog_scope) = compile_attr_test_unit();
+    // `grand_son_name` in `(grand_son).grand_son_name`, the variable with the same
+    // name is not renamed.
+    let pos = KCLPos {
+        filename: attr_test_file("main.k"),
+        line: 8,
+        column: Some(30),
+    };
+    let edit = rename(&program, &pos, &prog_scope, "name_of_grand_son")
+        .unwrap()
+        .unwrap();
+    let changes = edit.changes.unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(
+        changes[&Url::from_file_path(attr_test_file("inherit.k")).unwrap()],
+        vec![TextEdit::new(
+            Range::new(Position::new(8, 4), Position::new(8, 18)),
+            "name_of_grand_son".to_string()
+        )]
+    );
+    assert_eq!(
+        changes[&Url::from_file_path(attr_test_file("main.k")).unwrap()],
+        vec![TextEdit::new(
+            Range::new(Position::new(7, 29), Position::new(7, 43)),
+            "name_of_grand_son".to_string()
+        )]
+    );
+}
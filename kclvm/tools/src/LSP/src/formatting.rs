@@ -0,0 +1,135 @@
+use kclvm_parser::parse_file;
+use kclvm_tools::format::format_source;
+use lsp_types::{FormattingOptions, Position, Range, TextEdit};
+
+/// Formats the whole source with the KCL AST printer and returns the text edits
+/// which turn the source into the formatted one.
+pub(crate) fn format(src: &str) -> anyhow::Result<Option<Vec<TextEdit>>> {
+    let (formatted_src, is_changed) = format_source(src)?;
+    if !is_changed {
+        return Ok(None);
+    }
+    Ok(Some(text_edits(src, &formatted_src, 0)))
+}
+
+/// Formats the top level statements which overlap with the range. The range is
+/// extended to the whole lines of these statements, because a part of a statement
+/// can't be printed alone.
+pub(crate) fn format_range(src: &str, range: Range) -> anyhow::Result<Option<Vec<TextEdit>>> {
+    let module = parse_file("", Some(src.to_string())).map_err(|err| anyhow::anyhow!("{}", err))?;
+    // The lines of AST nodes start from 1 while the lines of LSP start from 0.
+    let start_line = range.start.line as u64 + 1;
+    let end_line = range.end.line as u64 + 1;
+    let stmts: Vec<_> = module
+        .body
+        .iter()
+        .filter(|stmt| stmt.line <= end_line && stmt.end_line >= start_line)
+        .collect();
+    let (first, last) = match (stmts.first(), stmts.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Ok(None),
+    };
+    let lines: Vec<&str> = src.split_inclusive('\n').collect();
+    let first_line = first.line as usize - 1;
+    let last_line = (last.end_line as usize).min(lines.len());
+    let snippet = lines[first_line..last_line].concat();
+    let (mut formatted_snippet, is_changed) = format_source(&snippet)?;
+    if !is_changed {
+        return Ok(None);
+    }
+    // Keep the line ending of the last statement as it was.
+    if !snippet.ends_with('\n') && formatted_snippet.ends_with('\n') {
+        formatted_snippet.pop();
+    }
+    let edits = text_edits(&snippet, &formatted_snippet, first_line as u32);
+    if edits.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(edits))
+    }
+}
+
+/// Indents the new line after a line which ends with `:`, e.g., the schema and
+/// if statements, by one more level than the previous line.
+pub(crate) fn format_on_type(
+    src: &str,
+    pos: Position,
+    ch: &str,
+    options: &FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    if ch != "\n" || pos.line == 0 {
+        return None;
+    }
+    let lines: Vec<&str> = src.lines().collect();
+    let prev_line = lines.get(pos.line as usize - 1)?;
+    if !prev_line.trim_end().ends_with(':') {
+        return None;
+    }
+    let cur_line = lines.get(pos.line as usize).copied().unwrap_or_default();
+    let indent_unit = if options.insert_spaces {
+        " ".repeat(options.tab_size as usize)
+    } else {
+        "\t".to_string()
+    };
+    let indent = format!("{}{}", leading_whitespace(prev_line), indent_unit);
+    let cur_indent = leading_whitespace(cur_line);
+    if cur_indent == indent {
+        return None;
+    }
+    Some(vec![TextEdit {
+        range: Range {
+            start: Position::new(pos.line, 0),
+            end: Position::new(pos.line, utf16_len(cur_indent)),
+        },
+        new_text: indent,
+    }])
+}
+
+/// Returns a single text edit which replaces the changed lines between the
+/// old text and the new text, the unchanged lines at the start and the end are
+/// left untouched. The line offset is added to all positions of the edit.
+fn text_edits(old: &str, new: &str, line_offset: u32) -> Vec<TextEdit> {
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+    let prefix = old_lines
+        .iter()
+        .zip(new_lines.iter())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old_lines[prefix..]
+        .iter()
+        .rev()
+        .zip(new_lines[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let old_end = old_lines.len() - suffix;
+    let new_end = new_lines.len() - suffix;
+    if prefix == old_end && prefix == new_end {
+        return vec![];
+    }
+    let mut start = Position::new(prefix as u32, 0);
+    let mut end = end_position(&old_lines[..old_end]);
+    start.line += line_offset;
+    end.line += line_offset;
+    vec![TextEdit {
+        range: Range { start, end },
+        new_text: new_lines[prefix..new_end].concat(),
+    }]
+}
+
+/// Returns the position after the last character of the lines.
+fn end_position(lines: &[&str]) -> Position {
+    match lines.last() {
+        None => Position::new(0, 0),
+        Some(last) if last.ends_with('\n') => Position::new(lines.len() as u32, 0),
+        Some(last) => Position::new(lines.len() as u32 - 1, utf16_len(last)),
+    }
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn utf16_len(s: &str) -> u32 {
+    s.encode_utf16().count() as u32
+}
@@ -27,7 +27,8 @@ pub(crate) fn kcl_pos(file: &str, pos: Position) -> KCLPos {
     }
 }
 
-/// Converts the given lsp range to `Range`
+/// Converts the given lsp range to `Range`, the characters of the lsp positions are
+/// counted in UTF-16 code units and are converted to the byte offsets.
 pub(crate) fn text_range(text: &str, range: lsp_types::Range) -> Range<usize> {
     let mut lines_length = vec![];
     let lines_text: Vec<&str> = text.split('\n').collect();
@@ -44,11 +45,29 @@ pub(crate) fn text_range(text: &str, range: lsp_types::Range) -> Range<usize> {
         }
     }
 
-    let start =
-        lines_length.get(range.start.line as usize).unwrap() + range.start.character as usize;
-    let end = lines_length.get(range.end.line as usize).unwrap() + range.end.character as usize;
+    let offset = |pos: Position| {
+        let line_offset = lines_length.get(pos.line as usize).unwrap();
+        let character = pos.character as usize;
+        let column = match lines_text.get(pos.line as usize) {
+            Some(line) => {
+                let mut utf16_len = 0;
+                line.char_indices()
+                    .find(|(_, c)| {
+                        let found = utf16_len >= character;
+                        utf16_len += c.len_utf16();
+                        found
+                    })
+                    .map_or(line.len() + character.saturating_sub(utf16_len), |(i, _)| i)
+            }
+            None => character,
+        };
+        line_offset + column
+    };
 
-    Range { start, end }
+    Range {
+        start: offset(range.start),
+        end: offset(range.end),
+    }
 }
 
 /// Converts the specified `url` to a utf8 encoded file path string. Returns an error if the url could not be
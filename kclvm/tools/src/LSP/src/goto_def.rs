@@ -6,6 +6,10 @@
 //! + mixin definition
 //! + schema attr
 //! + attr type
+//!
+//! Goto type definition navigates from a symbol to the schemas of its type, and goto
+//! implementation navigates from a schema, mixin or protocol to all schemas which
+//! inherit, mix in or implement it.
 
 use indexmap::IndexSet;
 
@@ -14,11 +18,14 @@ use kclvm_ast::ast::{Expr, Identifier, ImportStmt, Node, Program, SchemaExpr, St
 use kclvm_error::Position as KCLPos;
 
 use kclvm_sema::resolver::scope::{ProgramScope, ScopeObject};
+use kclvm_sema::ty::{SchemaType, Type, TypeKind};
 use lsp_types::{GotoDefinitionResponse, Url};
 use lsp_types::{Location, Range};
 use std::path::Path;
 
+use crate::find_ref::{find_schema_def_obj, resolve_symbol, Definition};
 use crate::to_lsp::lsp_pos;
+use crate::type_hierarchy::{definition_schema_ty, direct_subtypes};
 use crate::util::{get_pos_from_real_path, get_real_path_from_external, inner_most_expr_in_stmt};
 
 // Navigates to the definition of an identifier.
@@ -42,6 +49,76 @@ pub(crate) fn goto_definition(
         None => None,
     }
 }
+
+/// Navigates to the schema definitions of the type of the symbol, e.g., from a variable
+/// to the schema of its inferred type. The schemas in list, dict and union types are
+/// all returned.
+pub(crate) fn goto_type_definition(
+    program: &Program,
+    kcl_pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<lsp_types::request::GotoTypeDefinitionResponse> {
+    let obj = match resolve_symbol(program, kcl_pos, prog_scope)? {
+        (Definition::Object(obj), _, _) => obj,
+        _ => return None,
+    };
+    let mut schema_tys = vec![];
+    collect_schema_types(&obj.ty, &mut schema_tys);
+    let positions = schema_tys
+        .iter()
+        .filter_map(|schema_ty| find_schema_def_obj(schema_ty, prog_scope))
+        .map(|obj| (obj.start, obj.end))
+        .collect();
+    positions_to_goto_def_resp(&positions)
+}
+
+/// Navigates from a schema, mixin or protocol to all schemas which inherit, mix in or
+/// implement it, including the indirect ones.
+pub(crate) fn goto_implementation(
+    program: &Program,
+    kcl_pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<lsp_types::request::GotoImplementationResponse> {
+    let obj = match resolve_symbol(program, kcl_pos, prog_scope)? {
+        (Definition::Object(obj), _, _) => obj,
+        _ => return None,
+    };
+    let schema_ty = definition_schema_ty(&obj)?;
+    let mut visited = IndexSet::new();
+    let mut queue = vec![schema_ty];
+    let mut positions = IndexSet::new();
+    while let Some(schema_ty) = queue.pop() {
+        for subtype in direct_subtypes(&schema_ty.pkgpath, &schema_ty.name, prog_scope) {
+            if !visited.insert((subtype.pkgpath.clone(), subtype.name.clone())) {
+                continue;
+            }
+            if let Some(obj) = find_schema_def_obj(&subtype, prog_scope) {
+                positions.insert((obj.start, obj.end));
+            }
+            queue.push(subtype);
+        }
+    }
+    positions_to_goto_def_resp(&positions)
+}
+
+/// Collects the schema types in the type, e.g., `A` and `B` in `[A] | {str:B}`.
+fn collect_schema_types(ty: &Type, schema_tys: &mut Vec<SchemaType>) {
+    match &ty.kind {
+        TypeKind::Schema(schema_ty) => schema_tys.push(schema_ty.clone()),
+        TypeKind::List(item_ty) => collect_schema_types(item_ty, schema_tys),
+        TypeKind::Dict(key_ty, val_ty) => {
+            collect_schema_types(key_ty, schema_tys);
+            collect_schema_types(val_ty, schema_tys);
+        }
+        TypeKind::Union(types) => {
+            for ty in types {
+                collect_schema_types(ty, schema_tys);
+            }
+        }
+        _ => {}
+    }
+}
+
 pub(crate) fn find_definition_objs(
     node: Node<Stmt>,
     kcl_pos: &KCLPos,
@@ -1,13 +1,23 @@
 use indexmap::IndexSet;
-use kclvm_ast::ast::Program;
+use kclvm_ast::ast::{CallExpr, Expr, Node, NodeRef, Program, Stmt};
+use kclvm_ast::pos::ContainsPos;
 use kclvm_error::Position as KCLPos;
+use kclvm_sema::builtin::{
+    get_system_member_function_ty, BUILTIN_DECORATORS, BUILTIN_FUNCTIONS, STANDARD_SYSTEM_MODULES,
+    STRING_MEMBER_FUNCTIONS,
+};
 use kclvm_sema::resolver::scope::{ProgramScope, ScopeObjectKind};
-use lsp_types::{Hover, HoverContents, MarkedString};
+use kclvm_sema::ty::{FunctionType, Type, TypeKind};
+use lsp_types::{Hover, HoverContents, LanguageString, MarkedString};
 
+use crate::find_ref::{identifier_name_spans, resolve_identifier, Definition};
 use crate::goto_def::find_definition_objs;
+use crate::util::inner_most_expr_in_stmt;
 
 /// Returns a short text describing element at position.
-/// Specifically, the doc for schema and schema attr(todo)
+/// Specifically, the doc for schema and schema attr(todo), and the signature and
+/// the doc for builtin functions, system module functions, string member functions
+/// and builtin decorators.
 pub(crate) fn hover(
     program: &Program,
     kcl_pos: &KCLPos,
@@ -15,6 +25,9 @@ pub(crate) fn hover(
 ) -> Option<lsp_types::Hover> {
     match program.pos_to_stmt(kcl_pos) {
         Some(node) => {
+            if let Some(hover) = builtin_hover(&node, program, kcl_pos, prog_scope) {
+                return Some(hover);
+            }
             let objs = find_definition_objs(node, kcl_pos, prog_scope);
             let mut docs: IndexSet<String> = IndexSet::new();
             for obj in &objs {
@@ -37,6 +50,178 @@ pub(crate) fn hover(
     }
 }
 
+/// Returns the hover of the builtin function, system module function, string member
+/// function or builtin decorator on the position.
+fn builtin_hover(
+    stmt: &Node<Stmt>,
+    program: &Program,
+    pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<Hover> {
+    let (expr, _) = inner_most_expr_in_stmt(&stmt.node, pos, None);
+    let expr = expr?;
+    let id = match &expr.node {
+        Expr::Identifier(id) => id,
+        _ => return None,
+    };
+    let index = identifier_name_spans(&expr, id)
+        .iter()
+        .position(|(start, end)| start.less_equal(pos) && pos.less_equal(end))?;
+    let name = &id.names[index];
+    // Builtin decorators, e.g., `@deprecated`.
+    if let Some(decorator) = decorator_at_pos(&stmt.node, pos) {
+        if decorator.node.func.contains_pos(pos) {
+            let decorators = BUILTIN_DECORATORS;
+            let name = id.names.join(".");
+            return func_hover(&format!("@{}", name), decorators.get(&name)?);
+        }
+    }
+    // System module functions, e.g., `match` in `regex.match`.
+    if index == 1 {
+        let pkgpath = prog_scope
+            .import_names
+            .get(&pos.filename)
+            .and_then(|mapping| mapping.get(&id.names[0]));
+        if let Some(pkgpath) = pkgpath {
+            if STANDARD_SYSTEM_MODULES.contains(&pkgpath.as_str()) {
+                let ty = get_system_member_function_ty(pkgpath, name)?;
+                return func_hover(&format!("{}.{}", pkgpath, name), &ty);
+            }
+        }
+    }
+    let def = resolve_identifier(program, id, index.saturating_sub(1), pos, prog_scope);
+    if index > 0 {
+        // String member functions, e.g., `upper` in `name.upper`.
+        match def? {
+            Definition::Object(obj) if is_str_ty(&obj.ty) => {
+                let functions = STRING_MEMBER_FUNCTIONS;
+                func_hover(&format!("str.{}", name), functions.get(name)?)
+            }
+            _ => None,
+        }
+    } else {
+        // Builtin functions, e.g., `option`, which are defined in the builtin scope
+        // without positions.
+        let is_builtin = match def {
+            Some(Definition::Object(obj)) => obj.start.filename.is_empty(),
+            Some(Definition::Module { .. }) => false,
+            None => true,
+        };
+        let functions = BUILTIN_FUNCTIONS;
+        match functions.get(name) {
+            Some(ty) if is_builtin && id.names.len() == 1 => func_hover(name, ty),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the decorator of the schema, schema attribute or rule statement on the position.
+fn decorator_at_pos<'a>(stmt: &'a Stmt, pos: &KCLPos) -> Option<&'a NodeRef<CallExpr>> {
+    let (decorators, body): (&[NodeRef<CallExpr>], &[NodeRef<Stmt>]) = match stmt {
+        Stmt::Schema(schema_stmt) => (&schema_stmt.decorators, &schema_stmt.body),
+        Stmt::SchemaAttr(schema_attr) => (&schema_attr.decorators, &[]),
+        Stmt::Rule(rule_stmt) => (&rule_stmt.decorators, &[]),
+        _ => return None,
+    };
+    decorators
+        .iter()
+        .find(|decorator| decorator.contains_pos(pos))
+        .or_else(|| {
+            body.iter()
+                .find(|stmt| stmt.contains_pos(pos))
+                .and_then(|stmt| decorator_at_pos(&stmt.node, pos))
+        })
+}
+
+fn is_str_ty(ty: &Type) -> bool {
+    matches!(ty.kind, TypeKind::Str | TypeKind::StrLit(_))
+}
+
+fn func_hover(name: &str, ty: &Type) -> Option<Hover> {
+    let func_ty = match &ty.kind {
+        TypeKind::Function(func_ty) => func_ty,
+        _ => return None,
+    };
+    let mut contents = vec![MarkedString::LanguageString(LanguageString {
+        language: "kcl".to_string(),
+        value: func_signature(name, func_ty),
+    })];
+    let doc = func_doc(func_ty);
+    if !doc.is_empty() {
+        contents.push(MarkedString::String(doc));
+    }
+    Some(Hover {
+        contents: HoverContents::Array(contents),
+        range: None,
+    })
+}
+
+/// Returns the signature of the function, e.g., `option(key: str, type: str = ...) -> any`.
+pub(crate) fn func_signature(name: &str, func_ty: &FunctionType) -> String {
+    let mut params: Vec<String> = func_ty
+        .params
+        .iter()
+        .map(|param| {
+            if param.has_default {
+                format!("{}: {} = ...", param.name, param.ty.ty_str())
+            } else {
+                format!("{}: {}", param.name, param.ty.ty_str())
+            }
+        })
+        .collect();
+    if func_ty.is_variadic {
+        params.push("...".to_string());
+    }
+    format!(
+        "{}({}) -> {}",
+        name,
+        params.join(", "),
+        func_ty.return_ty.ty_str()
+    )
+}
+
+/// Returns the doc of the function followed by the list of its parameters if the
+/// doc has no `Parameters` section, the indentation of the doc written in the raw
+/// strings of the builtin types is removed.
+pub(crate) fn func_doc(func_ty: &FunctionType) -> String {
+    let mut doc = dedent(&func_ty.doc);
+    if !func_ty.params.is_empty() && !doc.contains("Parameters\n----------") {
+        if !doc.is_empty() {
+            doc.push_str("\n\n");
+        }
+        doc.push_str("Parameters\n----------");
+        for param in &func_ty.params {
+            doc.push_str(&format!("\n{}: {}", param.name, param.ty.ty_str()));
+            if param.has_default {
+                doc.push_str(", optional");
+            }
+        }
+    }
+    doc
+}
+
+/// Removes the common indentation of the lines after the first line.
+fn dedent(doc: &str) -> String {
+    let mut lines = doc.trim().lines();
+    let first = match lines.next() {
+        Some(first) => first.trim(),
+        None => return String::new(),
+    };
+    let rest: Vec<&str> = lines.collect();
+    let indent = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut res = vec![first];
+    res.extend(
+        rest.iter()
+            .map(|line| line.get(indent..).unwrap_or_default().trim_end()),
+    );
+    res.join("\n")
+}
+
 // Convert docs to Hover. This function will convert to
 // None, Scalar or Array according to the number of positions
 fn docs_to_hover(docs: IndexSet<String>) -> Option<lsp_types::Hover> {
@@ -0,0 +1,224 @@
+use std::rc::Rc;
+
+use indexmap::IndexSet;
+use kclvm_ast::ast::{
+    AssignStmt, CompClause, Expr, Identifier, Module, Node, Program, SchemaExpr, Stmt,
+};
+use kclvm_ast::pos::GetPos;
+use kclvm_ast::walk_list;
+use kclvm_ast::walker::MutSelfWalker;
+use kclvm_ast_pretty::{print_ast_node, ASTNode};
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::resolver::scope::ProgramScope;
+use kclvm_sema::ty::{SchemaType, Type, TypeKind};
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range};
+
+use crate::find_ref::{resolve_schema_expr_ty, scope_at_pos};
+use crate::to_lsp::lsp_pos;
+use crate::util::config_keys;
+
+/// Returns the inlay hints in the range of the file, which include
+/// + the inferred types of unannotated top level assignments
+/// + the inferred return types of lambdas assigned to variables
+/// + the inferred types of comprehension variables
+/// + the default values of schema attributes omitted from schema configs, only if
+///   `show_schema_attr_defaults` is true
+pub(crate) fn inlay_hints(
+    file: &str,
+    range: Range,
+    program: &Program,
+    prog_scope: &ProgramScope,
+    show_schema_attr_defaults: bool,
+) -> Option<Vec<InlayHint>> {
+    let (pkgpath, module) = program.pkgs.iter().find_map(|(pkgpath, modules)| {
+        modules
+            .iter()
+            .find(|module| module.filename == file)
+            .map(|module| (pkgpath, module))
+    })?;
+    let mut collector = InlayHintCollector {
+        program,
+        prog_scope,
+        pkgpath,
+        show_schema_attr_defaults,
+        hints: vec![],
+    };
+    collector.walk_module(module);
+    Some(
+        collector
+            .hints
+            .into_iter()
+            .filter(|hint| range.start <= hint.position && hint.position <= range.end)
+            .collect(),
+    )
+}
+
+struct InlayHintCollector<'a> {
+    program: &'a Program,
+    prog_scope: &'a ProgramScope,
+    pkgpath: &'a str,
+    show_schema_attr_defaults: bool,
+    hints: Vec<InlayHint>,
+}
+
+impl<'a> InlayHintCollector<'a> {
+    fn push_hint(&mut self, pos: &KCLPos, label: String, kind: InlayHintKind, padding: bool) {
+        self.hints.push(InlayHint {
+            position: lsp_pos(pos),
+            label: InlayHintLabel::String(label),
+            kind: Some(kind),
+            text_edits: None,
+            tooltip: None,
+            padding_left: Some(padding),
+            padding_right: Some(padding),
+            data: None,
+        });
+    }
+
+    /// Returns the type of the top level variable declared by the assignment target.
+    fn top_level_var_ty(&self, target: &Node<Identifier>) -> Option<Rc<Type>> {
+        if target.node.names.len() != 1 {
+            return None;
+        }
+        let pkg_scope = self.prog_scope.scope_map.get(self.pkgpath)?.borrow();
+        let obj = pkg_scope.elems.get(&target.node.names[0])?.borrow();
+        // Only the first assignment declares the variable.
+        if obj.start != target.get_pos() {
+            return None;
+        }
+        Some(obj.ty.clone())
+    }
+
+    /// Finds the default value expression of the schema attribute in the schema statement.
+    fn schema_attr_default(&self, schema_ty: &SchemaType, attr: &str) -> Option<String> {
+        let module: &Module = self
+            .program
+            .pkgs
+            .get(&schema_ty.pkgpath)?
+            .iter()
+            .find(|module| module.filename == schema_ty.filename)?;
+        module.body.iter().find_map(|stmt| match &stmt.node {
+            Stmt::Schema(schema_stmt) if schema_stmt.name.node == schema_ty.name => {
+                schema_stmt.body.iter().find_map(|stmt| match &stmt.node {
+                    Stmt::SchemaAttr(schema_attr) if schema_attr.name.node == attr => schema_attr
+                        .value
+                        .as_ref()
+                        .map(|value| print_ast_node(ASTNode::Expr(value))),
+                    _ => None,
+                })
+            }
+            _ => None,
+        })
+    }
+
+    fn schema_attr_defaults(&mut self, schema_expr: &SchemaExpr) {
+        let schema_ty = match resolve_schema_expr_ty(self.program, schema_expr, self.prog_scope) {
+            Some(schema_ty) => schema_ty,
+            None => return,
+        };
+        let config_keys = match config_keys(&schema_expr.config.node) {
+            Some(config_keys) => config_keys,
+            None => return,
+        };
+        // The hints are shown before the closing brace of the config.
+        let pos = KCLPos {
+            filename: schema_expr.config.filename.clone(),
+            line: schema_expr.config.end_line,
+            column: Some(schema_expr.config.end_column.saturating_sub(1)),
+        };
+        let mut schema_ty = Some(&schema_ty);
+        let mut hinted = IndexSet::new();
+        while let Some(ty) = schema_ty {
+            for (name, attr) in &ty.attrs {
+                if !attr.has_default || config_keys.contains(name) || !hinted.insert(name) {
+                    continue;
+                }
+                if let Some(value) = self.schema_attr_default(ty, name) {
+                    self.push_hint(
+                        &pos,
+                        format!("{} = {}", name, value),
+                        InlayHintKind::PARAMETER,
+                        true,
+                    );
+                }
+            }
+            schema_ty = ty.base.as_deref();
+        }
+    }
+}
+
+impl<'a> MutSelfWalker for InlayHintCollector<'a> {
+    fn walk_assign_stmt(&mut self, assign_stmt: &AssignStmt) {
+        if assign_stmt.ty.is_none() {
+            for target in &assign_stmt.targets {
+                let ty = match self.top_level_var_ty(target) {
+                    Some(ty) => ty,
+                    None => continue,
+                };
+                match (&ty.kind, &assign_stmt.value.node) {
+                    (TypeKind::Function(func_ty), Expr::Lambda(lambda_expr))
+                        if lambda_expr.return_ty.is_none() && !func_ty.return_ty.is_any() =>
+                    {
+                        // The return type is shown after the lambda parameters.
+                        let pos = match &lambda_expr.args {
+                            Some(args) => args.get_end_pos(),
+                            None => {
+                                let mut pos = assign_stmt.value.get_pos();
+                                pos.column =
+                                    pos.column.map(|column| column + "lambda".len() as u64);
+                                pos
+                            }
+                        };
+                        self.push_hint(
+                            &pos,
+                            format!("-> {}", func_ty.return_ty.ty_str()),
+                            InlayHintKind::TYPE,
+                            true,
+                        );
+                    }
+                    (TypeKind::Any | TypeKind::Function(_), _) => {}
+                    _ => self.push_hint(
+                        &target.get_end_pos(),
+                        format!(": {}", ty.ty_str()),
+                        InlayHintKind::TYPE,
+                        false,
+                    ),
+                }
+            }
+        }
+        self.walk_expr(&assign_stmt.value.node);
+    }
+
+    fn walk_comp_clause(&mut self, comp_clause: &CompClause) {
+        for target in &comp_clause.targets {
+            let name = match target.node.names.first() {
+                Some(name) => name,
+                None => continue,
+            };
+            let ty = scope_at_pos(self.program, &target.get_pos(), self.prog_scope)
+                .and_then(|scope| scope.lookup(name))
+                .map(|obj| obj.borrow().ty.clone());
+            if let Some(ty) = ty {
+                if !ty.is_any() {
+                    self.push_hint(
+                        &target.get_end_pos(),
+                        format!(": {}", ty.ty_str()),
+                        InlayHintKind::TYPE,
+                        false,
+                    );
+                }
+            }
+        }
+        self.walk_expr(&comp_clause.iter.node);
+        walk_list!(self, walk_expr, comp_clause.ifs);
+    }
+
+    fn walk_schema_expr(&mut self, schema_expr: &SchemaExpr) {
+        if self.show_schema_attr_defaults {
+            self.schema_attr_defaults(schema_expr);
+        }
+        walk_list!(self, walk_expr, schema_expr.args);
+        walk_list!(self, walk_keyword, schema_expr.kwargs);
+        self.walk_expr(&schema_expr.config.node);
+    }
+}
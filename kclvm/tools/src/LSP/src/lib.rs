@@ -1,16 +1,24 @@
 mod analysis;
+mod code_action;
 mod completion;
 mod config;
 mod db;
 mod dispatcher;
 mod find_ref;
+mod formatting;
 mod from_lsp;
 mod notification;
 mod state;
 mod to_lsp;
 mod util;
+mod workspace_symbol;
 
+mod document_highlight;
 mod document_symbol;
 mod goto_def;
 mod hover;
+mod inlay_hints;
 mod request;
+mod semantic_tokens;
+mod signature_help;
+mod type_hierarchy;
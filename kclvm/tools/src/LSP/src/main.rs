@@ -4,19 +4,28 @@ use state::LanguageServerState;
 
 mod analysis;
 mod capabilities;
+mod code_action;
 mod completion;
 mod config;
 mod db;
 mod dispatcher;
+mod document_highlight;
 mod document_symbol;
+mod find_ref;
+mod formatting;
 mod from_lsp;
 mod goto_def;
 mod hover;
+mod inlay_hints;
 mod notification;
 mod request;
+mod semantic_tokens;
+mod signature_help;
 mod state;
 mod to_lsp;
+mod type_hierarchy;
 mod util;
+mod workspace_symbol;
 
 #[cfg(test)]
 mod tests;
@@ -46,17 +55,51 @@ pub fn run_server() -> anyhow::Result<()> {
         }),
     };
 
-    let initialize_result = serde_json::to_value(initialize_result)
+    let mut initialize_result = serde_json::to_value(initialize_result)
         .map_err(|_| anyhow::anyhow!("Initialize result error"))?;
+    // `ServerCapabilities` of lsp-types 0.93 has no type hierarchy provider field.
+    if let Some(capabilities) = initialize_result
+        .get_mut("capabilities")
+        .and_then(serde_json::Value::as_object_mut)
+    {
+        capabilities.insert(
+            "typeHierarchyProvider".to_string(),
+            serde_json::Value::Bool(true),
+        );
+    }
 
     connection.initialize_finish(initialize_id, initialize_result)?;
 
-    let config = Config::default();
+    let mut config = Config::default();
+    if let Some(options) = &initialize_params.initialization_options {
+        config.update(options);
+    }
+    config.did_change_watched_files_dynamic_registration = initialize_params
+        .capabilities
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.did_change_watched_files.as_ref())
+        .and_then(|caps| caps.dynamic_registration)
+        .unwrap_or(false);
+    config.workspace_folders = workspace_folders(&initialize_params);
     main_loop(connection, config)?;
     io_threads.join()?;
     Ok(())
 }
 
+/// Returns the paths of the workspace folders, or the root path if the client doesn't
+/// support workspace folders.
+#[allow(deprecated)]
+fn workspace_folders(initialize_params: &lsp_types::InitializeParams) -> Vec<String> {
+    let uris: Vec<lsp_types::Url> = match &initialize_params.workspace_folders {
+        Some(folders) => folders.iter().map(|folder| folder.uri.clone()).collect(),
+        None => initialize_params.root_uri.clone().into_iter().collect(),
+    };
+    uris.iter()
+        .filter_map(|uri| from_lsp::file_path_from_url(uri).ok())
+        .collect()
+}
+
 #[derive(Copy, Debug, Clone, PartialEq, Eq)]
 pub enum ExitStatus {
     Success,
@@ -1,4 +1,10 @@
-use lsp_types::notification::{DidChangeTextDocument, DidOpenTextDocument, DidSaveTextDocument};
+use std::path::Path;
+
+use kclvm_config::{modfile::KCL_MOD_FILE, settings::DEFAULT_SETTING_FILE};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
+    DidSaveTextDocument,
+};
 
 use crate::{
     dispatcher::NotificationDispatcher, from_lsp, state::LanguageServerState,
@@ -15,8 +21,8 @@ impl LanguageServerState {
             .on::<DidOpenTextDocument>(LanguageServerState::on_did_open_text_document)?
             .on::<DidChangeTextDocument>(LanguageServerState::on_did_change_text_document)?
             .on::<DidSaveTextDocument>(LanguageServerState::on_did_save_text_document)?
-            // .on::<DidCloseTextDocument>(LanguageServerState::on_did_close_text_document)?
-            // .on::<DidChangeWatchedFiles>(LanguageServerState::on_did_change_watched_files)?
+            .on::<DidCloseTextDocument>(LanguageServerState::on_did_close_text_document)?
+            .on::<DidChangeWatchedFiles>(LanguageServerState::on_did_change_watched_files)?
             .finish();
         Ok(())
     }
@@ -73,4 +79,44 @@ impl LanguageServerState {
 
         Ok(())
     }
+
+    /// Called when a `DidCloseTextDocument` notification was received.
+    fn on_did_close_text_document(
+        &mut self,
+        params: lsp_types::DidCloseTextDocumentParams,
+    ) -> anyhow::Result<()> {
+        let path = from_lsp::abs_path(&params.text_document.uri)?;
+        self.log_message(format!("on did close file: {:?}", path));
+        let file = from_lsp::file_path_from_url(&params.text_document.uri)?;
+        self.semantic_tokens.write().remove(&file);
+        // The closed file is loaded from the file system again.
+        self.vfs.write().set_file_contents(path.into(), None);
+        Ok(())
+    }
+
+    /// Called when a `DidChangeWatchedFiles` notification was received.
+    fn on_did_change_watched_files(
+        &mut self,
+        params: lsp_types::DidChangeWatchedFilesParams,
+    ) -> anyhow::Result<()> {
+        for change in params.changes {
+            let file = from_lsp::file_path_from_url(&change.uri)?;
+            self.log_message(format!("on did change watched file: {:?}", file));
+            let file_name = Path::new(&file)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+            // The compile units and the imported packages are looked up with `kcl.mod`
+            // and `kcl.yaml`, so any cached module may be stale.
+            if file_name == KCL_MOD_FILE
+                || file_name == format!("{}.lock", KCL_MOD_FILE)
+                || file_name == DEFAULT_SETTING_FILE
+            {
+                self.analysis.db.invalidate_all();
+            } else {
+                self.analysis.db.invalidate_file(&file);
+            }
+        }
+        self.refresh_diagnostics()
+    }
 }
@@ -4,14 +4,20 @@ use anyhow::Ok;
 use crossbeam_channel::Sender;
 
 use crate::{
+    code_action,
     completion::completion,
     dispatcher::RequestDispatcher,
+    document_highlight::document_highlight,
     document_symbol::document_symbol,
+    find_ref::{find_refs::find_refs, rename},
+    formatting,
     from_lsp::{file_path_from_url, kcl_pos},
-    goto_def::goto_definition,
-    hover,
+    goto_def::{goto_definition, goto_implementation, goto_type_definition},
+    hover, inlay_hints, semantic_tokens, signature_help,
     state::{log_message, LanguageServerSnapshot, LanguageServerState, Task},
-    util::{parse_param_and_compile, Param},
+    type_hierarchy,
+    util::{load_file_code_from_vfs, parse_param_and_compile, Param},
+    workspace_symbol,
 };
 
 impl LanguageServerState {
@@ -41,9 +47,29 @@ impl LanguageServerState {
                 Ok(())
             })?
             .on::<lsp_types::request::GotoDefinition>(handle_goto_definition)?
+            .on::<lsp_types::request::GotoTypeDefinition>(handle_goto_type_definition)?
+            .on::<lsp_types::request::GotoImplementation>(handle_goto_implementation)?
             .on::<lsp_types::request::Completion>(handle_completion)?
             .on::<lsp_types::request::HoverRequest>(handle_hover)?
             .on::<lsp_types::request::DocumentSymbolRequest>(handle_document_symbol)?
+            .on::<lsp_types::request::WorkspaceSymbol>(handle_workspace_symbol)?
+            .on::<lsp_types::request::References>(handle_references)?
+            .on::<lsp_types::request::DocumentHighlightRequest>(handle_document_highlight)?
+            .on::<lsp_types::request::PrepareRenameRequest>(handle_prepare_rename)?
+            .on::<lsp_types::request::Rename>(handle_rename)?
+            .on::<lsp_types::request::SemanticTokensFullRequest>(handle_semantic_tokens_full)?
+            .on::<lsp_types::request::SemanticTokensFullDeltaRequest>(
+                handle_semantic_tokens_full_delta,
+            )?
+            .on::<lsp_types::request::InlayHintRequest>(handle_inlay_hint)?
+            .on::<lsp_types::request::SignatureHelpRequest>(handle_signature_help)?
+            .on::<lsp_types::request::CodeActionRequest>(handle_code_action)?
+            .on::<type_hierarchy::TypeHierarchyPrepare>(handle_prepare_type_hierarchy)?
+            .on::<type_hierarchy::TypeHierarchySupertypes>(handle_type_hierarchy_supertypes)?
+            .on::<type_hierarchy::TypeHierarchySubtypes>(handle_type_hierarchy_subtypes)?
+            .on::<lsp_types::request::Formatting>(handle_formatting)?
+            .on::<lsp_types::request::RangeFormatting>(handle_range_formatting)?
+            .on::<lsp_types::request::OnTypeFormatting>(handle_on_type_formatting)?
             .finish();
 
         Ok(())
@@ -61,6 +87,8 @@ pub(crate) fn handle_goto_definition(
     let (program, prog_scope, _) = parse_param_and_compile(
         Param {
             file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
         },
         Some(snapshot.vfs),
     )?;
@@ -72,6 +100,54 @@ pub(crate) fn handle_goto_definition(
     Ok(res)
 }
 
+/// Called when a `GotoTypeDefinition` request was received.
+pub(crate) fn handle_goto_type_definition(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::request::GotoTypeDefinitionParams,
+    sender: Sender<Task>,
+) -> anyhow::Result<Option<lsp_types::request::GotoTypeDefinitionResponse>> {
+    let file = file_path_from_url(&params.text_document_position_params.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let kcl_pos = kcl_pos(&file, params.text_document_position_params.position);
+    let res = goto_type_definition(&program, &kcl_pos, &prog_scope);
+    if res.is_none() {
+        log_message("Type definition not found".to_string(), &sender)?;
+    }
+    Ok(res)
+}
+
+/// Called when a `GotoImplementation` request was received.
+pub(crate) fn handle_goto_implementation(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::request::GotoImplementationParams,
+    sender: Sender<Task>,
+) -> anyhow::Result<Option<lsp_types::request::GotoImplementationResponse>> {
+    let file = file_path_from_url(&params.text_document_position_params.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let kcl_pos = kcl_pos(&file, params.text_document_position_params.position);
+    let res = goto_implementation(&program, &kcl_pos, &prog_scope);
+    if res.is_none() {
+        log_message("Implementation not found".to_string(), &sender)?;
+    }
+    Ok(res)
+}
+
 /// Called when a `Completion` request was received.
 pub(crate) fn handle_completion(
     snapshot: LanguageServerSnapshot,
@@ -83,6 +159,8 @@ pub(crate) fn handle_completion(
     let (program, prog_scope, _) = parse_param_and_compile(
         Param {
             file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
         },
         Some(snapshot.vfs),
     )?;
@@ -119,6 +197,8 @@ pub(crate) fn handle_hover(
     let (program, prog_scope, _) = parse_param_and_compile(
         Param {
             file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
         },
         Some(snapshot.vfs),
     )?;
@@ -146,6 +226,8 @@ pub(crate) fn handle_document_symbol(
     let (program, prog_scope, _) = parse_param_and_compile(
         Param {
             file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
         },
         Some(snapshot.vfs),
     )?;
@@ -156,3 +238,361 @@ pub(crate) fn handle_document_symbol(
     }
     Ok(res)
 }
+
+/// Called when a `WorkspaceSymbol` request was received.
+pub(crate) fn handle_workspace_symbol(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::WorkspaceSymbolParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<lsp_types::SymbolInformation>>> {
+    let symbols = workspace_symbol::workspace_symbols(
+        &params.query,
+        &snapshot.config.workspace_folders,
+        snapshot.vfs,
+        &snapshot.db.module_cache,
+    )?;
+    Ok(Some(symbols))
+}
+
+/// Called when a `References` request was received.
+pub(crate) fn handle_references(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::ReferenceParams,
+    sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<lsp_types::Location>>> {
+    let file = file_path_from_url(&params.text_document_position.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let kcl_pos = kcl_pos(&file, params.text_document_position.position);
+    let res = find_refs(
+        &program,
+        &kcl_pos,
+        &prog_scope,
+        params.context.include_declaration,
+    );
+    if res.is_none() {
+        log_message("References not found".to_string(), &sender)?;
+    }
+    Ok(res)
+}
+
+/// Called when a `DocumentHighlight` request was received.
+pub(crate) fn handle_document_highlight(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::DocumentHighlightParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<lsp_types::DocumentHighlight>>> {
+    let file = file_path_from_url(&params.text_document_position_params.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let kcl_pos = kcl_pos(&file, params.text_document_position_params.position);
+    Ok(document_highlight(&file, &program, &kcl_pos, &prog_scope))
+}
+
+/// Called when a `PrepareRename` request was received.
+pub(crate) fn handle_prepare_rename(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::TextDocumentPositionParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<lsp_types::PrepareRenameResponse>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let kcl_pos = kcl_pos(&file, params.position);
+    Ok(rename::prepare_rename(&program, &kcl_pos, &prog_scope))
+}
+
+/// Called when a `Rename` request was received.
+pub(crate) fn handle_rename(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::RenameParams,
+    sender: Sender<Task>,
+) -> anyhow::Result<Option<lsp_types::WorkspaceEdit>> {
+    let file = file_path_from_url(&params.text_document_position.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let kcl_pos = kcl_pos(&file, params.text_document_position.position);
+    let res = rename::rename(&program, &kcl_pos, &prog_scope, &params.new_name)?;
+    if res.is_none() {
+        log_message("Rename target not found".to_string(), &sender)?;
+    }
+    Ok(res)
+}
+
+/// Called when a `Formatting` request was received.
+pub(crate) fn handle_formatting(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::DocumentFormattingParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<lsp_types::TextEdit>>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+    let src = load_file_code_from_vfs(&file, snapshot.vfs)?;
+    formatting::format(&src)
+}
+
+/// Called when a `RangeFormatting` request was received.
+pub(crate) fn handle_range_formatting(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::DocumentRangeFormattingParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<lsp_types::TextEdit>>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+    let src = load_file_code_from_vfs(&file, snapshot.vfs)?;
+    formatting::format_range(&src, params.range)
+}
+
+/// Called when a `OnTypeFormatting` request was received.
+pub(crate) fn handle_on_type_formatting(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::DocumentOnTypeFormattingParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<lsp_types::TextEdit>>> {
+    let file = file_path_from_url(&params.text_document_position.text_document.uri)?;
+    let src = load_file_code_from_vfs(&file, snapshot.vfs)?;
+    Ok(formatting::format_on_type(
+        &src,
+        params.text_document_position.position,
+        &params.ch,
+        &params.options,
+    ))
+}
+
+/// Called when a `SemanticTokensFull` request was received.
+pub(crate) fn handle_semantic_tokens_full(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::SemanticTokensParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<lsp_types::SemanticTokensResult>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let res = semantic_tokens::semantic_tokens(&file, &program, &prog_scope);
+    if let Some(tokens) = &res {
+        snapshot
+            .semantic_tokens
+            .write()
+            .insert(file.clone(), tokens.clone());
+    }
+    Ok(res.map(lsp_types::SemanticTokensResult::Tokens))
+}
+
+/// Called when a `SemanticTokensFullDelta` request was received.
+pub(crate) fn handle_semantic_tokens_full_delta(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::SemanticTokensDeltaParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<lsp_types::SemanticTokensFullDeltaResult>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let tokens = match semantic_tokens::semantic_tokens(&file, &program, &prog_scope) {
+        Some(tokens) => tokens,
+        None => return Ok(None),
+    };
+    let previous = snapshot
+        .semantic_tokens
+        .write()
+        .insert(file.clone(), tokens.clone());
+    // Send the full tokens if the previous tokens known by the client are not cached.
+    let res = match previous {
+        Some(previous) if previous.result_id.as_ref() == Some(&params.previous_result_id) => {
+            lsp_types::SemanticTokensFullDeltaResult::TokensDelta(lsp_types::SemanticTokensDelta {
+                result_id: tokens.result_id,
+                edits: semantic_tokens::semantic_tokens_edits(&previous.data, &tokens.data),
+            })
+        }
+        _ => lsp_types::SemanticTokensFullDeltaResult::Tokens(tokens),
+    };
+    Ok(Some(res))
+}
+
+/// Called when a `InlayHint` request was received.
+pub(crate) fn handle_inlay_hint(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::InlayHintParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<lsp_types::InlayHint>>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    Ok(inlay_hints::inlay_hints(
+        &file,
+        params.range,
+        &program,
+        &prog_scope,
+        snapshot.config.inlay_hints_schema_attr_defaults,
+    ))
+}
+
+/// Called when a `SignatureHelp` request was received.
+pub(crate) fn handle_signature_help(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::SignatureHelpParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<lsp_types::SignatureHelp>> {
+    let file = file_path_from_url(&params.text_document_position_params.text_document.uri)?;
+    let src = load_file_code_from_vfs(&file, snapshot.vfs.clone())?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    Ok(signature_help::signature_help(
+        &file,
+        &src,
+        params.text_document_position_params.position,
+        &program,
+        &prog_scope,
+    ))
+}
+
+/// Called when a `CodeAction` request was received.
+pub(crate) fn handle_code_action(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::CodeActionParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<lsp_types::CodeActionResponse>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+    let src = load_file_code_from_vfs(&file, snapshot.vfs.clone())?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let actions = code_action::code_actions(
+        &file,
+        &src,
+        params.range,
+        &params.context.diagnostics,
+        &program,
+        &prog_scope,
+    )?;
+    Ok((!actions.is_empty()).then_some(actions))
+}
+
+/// Called when a `PrepareTypeHierarchy` request was received.
+pub(crate) fn handle_prepare_type_hierarchy(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::TextDocumentPositionParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<type_hierarchy::TypeHierarchyItem>>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    let kcl_pos = kcl_pos(&file, params.position);
+    Ok(type_hierarchy::prepare_type_hierarchy(
+        &program,
+        &kcl_pos,
+        &prog_scope,
+    ))
+}
+
+/// Called when a `TypeHierarchySupertypes` request was received.
+pub(crate) fn handle_type_hierarchy_supertypes(
+    snapshot: LanguageServerSnapshot,
+    params: type_hierarchy::TypeHierarchyItemParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<type_hierarchy::TypeHierarchyItem>>> {
+    let file = file_path_from_url(&params.item.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    Ok(type_hierarchy::supertypes(
+        &params.item,
+        &program,
+        &prog_scope,
+    ))
+}
+
+/// Called when a `TypeHierarchySubtypes` request was received.
+pub(crate) fn handle_type_hierarchy_subtypes(
+    snapshot: LanguageServerSnapshot,
+    params: type_hierarchy::TypeHierarchyItemParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<type_hierarchy::TypeHierarchyItem>>> {
+    let file = file_path_from_url(&params.item.uri)?;
+
+    let (program, prog_scope, _) = parse_param_and_compile(
+        Param {
+            file: file.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs),
+    )?;
+    Ok(type_hierarchy::subtypes(
+        &params.item,
+        &program,
+        &prog_scope,
+    ))
+}
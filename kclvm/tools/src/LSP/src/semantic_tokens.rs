@@ -0,0 +1,318 @@
+//! Semantic tokens for KCL.
+//!
+//! Every name in the file is resolved through the `ProgramScope` the same way as
+//! find references, so a schema type can be told from a variable, and a system
+//! module from a local package. The import names and the decorators are
+//! highlighted from the AST directly.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use kclvm_ast::ast::{Expr, NodeRef, Program, Stmt};
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::builtin::system_module::STANDARD_SYSTEM_MODULES;
+use kclvm_sema::resolver::scope::{ProgramScope, ScopeKind, ScopeObject, ScopeObjectKind};
+use kclvm_sema::ty::TypeKind;
+use lsp_types::{
+    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens, SemanticTokensEdit,
+    SemanticTokensLegend,
+};
+
+use crate::find_ref::{
+    collect_all_name_occurrences, identifier_name_spans, import_name_span, resolve_symbol,
+    Definition,
+};
+
+/// The token types of KCL, the index of a type in the list is used as the token type.
+pub(crate) const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::new("rule"),
+    SemanticTokenType::new("mixin"),
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::new("decorator"),
+    SemanticTokenType::TYPE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PARAMETER,
+];
+
+/// The token modifiers of KCL, the bit of a modifier is `1 << index`.
+pub(crate) const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+    SemanticTokenModifier::new("optional"),
+];
+
+const NAMESPACE: u32 = 0;
+const SCHEMA: u32 = 1;
+const RULE: u32 = 2;
+const MIXIN: u32 = 3;
+const PROTOCOL: u32 = 4;
+const DECORATOR: u32 = 5;
+const TYPE_ALIAS: u32 = 6;
+const ATTRIBUTE: u32 = 7;
+const FUNCTION: u32 = 8;
+const VARIABLE: u32 = 9;
+const PARAMETER: u32 = 10;
+
+const READONLY: u32 = 1 << 0;
+const DEFAULT_LIBRARY: u32 = 1 << 1;
+const OPTIONAL: u32 = 1 << 2;
+
+/// The result id of the last computed semantic tokens.
+static RESULT_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// A semantic token with absolute positions, the positions are encoded relative to
+/// the previous token when returned to the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AbsoluteToken {
+    start: KCLPos,
+    length: u32,
+    token_type: u32,
+    modifiers: u32,
+}
+
+/// Computes the semantic tokens of the file and assigns a new result id to them.
+pub(crate) fn semantic_tokens(
+    file: &str,
+    program: &Program,
+    prog_scope: &ProgramScope,
+) -> Option<SemanticTokens> {
+    let module = program
+        .pkgs
+        .values()
+        .flatten()
+        .find(|module| module.filename == file)?;
+    let mut tokens = vec![];
+    for stmt in &module.body {
+        if let Stmt::Import(import_stmt) = &stmt.node {
+            let (start, end) = import_name_span(stmt, import_stmt);
+            let modifiers = if STANDARD_SYSTEM_MODULES.contains(&import_stmt.path.as_str()) {
+                DEFAULT_LIBRARY
+            } else {
+                0
+            };
+            push_token(&mut tokens, start, end, NAMESPACE, modifiers);
+        }
+    }
+    collect_decorators(&module.body, &mut tokens);
+    for occurrence in collect_all_name_occurrences(module) {
+        if let Some((def, start, end)) = resolve_symbol(program, &occurrence.start, prog_scope) {
+            if let Some((token_type, modifiers)) = classify(&def, prog_scope) {
+                push_token(&mut tokens, start, end, token_type, modifiers);
+            }
+        }
+    }
+    tokens.sort_by_key(|token| (token.start.line, token.start.column));
+    tokens.dedup_by(|token, prev| token.start == prev.start);
+    Some(SemanticTokens {
+        result_id: Some(next_result_id()),
+        data: encode_tokens(&tokens),
+    })
+}
+
+/// Returns the edits which turn the previous tokens into the current tokens. The
+/// tokens are compared as a whole, a single edit replaces the changed tokens between
+/// the unchanged prefix and suffix.
+pub(crate) fn semantic_tokens_edits(
+    previous: &[SemanticToken],
+    current: &[SemanticToken],
+) -> Vec<SemanticTokensEdit> {
+    let prefix = previous
+        .iter()
+        .zip(current.iter())
+        .take_while(|(previous, current)| previous == current)
+        .count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(previous, current)| previous == current)
+        .count();
+    let previous_end = previous.len() - suffix;
+    let current_end = current.len() - suffix;
+    if prefix == previous_end && prefix == current_end {
+        return vec![];
+    }
+    // Each token is encoded as 5 integers in the data array.
+    vec![SemanticTokensEdit {
+        start: 5 * prefix as u32,
+        delete_count: 5 * (previous_end - prefix) as u32,
+        data: Some(current[prefix..current_end].to_vec()),
+    }]
+}
+
+fn next_result_id() -> String {
+    (RESULT_ID.fetch_add(1, Ordering::SeqCst) + 1).to_string()
+}
+
+fn push_token(
+    tokens: &mut Vec<AbsoluteToken>,
+    start: KCLPos,
+    end: KCLPos,
+    token_type: u32,
+    modifiers: u32,
+) {
+    let length = end
+        .column
+        .unwrap_or(0)
+        .saturating_sub(start.column.unwrap_or(0)) as u32;
+    if start.line != end.line || length == 0 {
+        return;
+    }
+    tokens.push(AbsoluteToken {
+        start,
+        length,
+        token_type,
+        modifiers,
+    });
+}
+
+/// Collects the decorator names of schemas and schema attributes.
+fn collect_decorators(stmts: &[NodeRef<Stmt>], tokens: &mut Vec<AbsoluteToken>) {
+    for stmt in stmts {
+        let decorators = match &stmt.node {
+            Stmt::Schema(schema_stmt) => {
+                collect_decorators(&schema_stmt.body, tokens);
+                &schema_stmt.decorators
+            }
+            Stmt::SchemaAttr(schema_attr) => &schema_attr.decorators,
+            Stmt::If(if_stmt) => {
+                collect_decorators(&if_stmt.body, tokens);
+                collect_decorators(&if_stmt.orelse, tokens);
+                continue;
+            }
+            _ => continue,
+        };
+        for decorator in decorators {
+            if let Expr::Identifier(id) = &decorator.node.func.node {
+                for (start, end) in identifier_name_spans(&decorator.node.func, id) {
+                    push_token(tokens, start, end, DECORATOR, DEFAULT_LIBRARY);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the token type and modifiers of the definition.
+fn classify(def: &Definition, prog_scope: &ProgramScope) -> Option<(u32, u32)> {
+    let obj = match def {
+        Definition::Module { pkgpath, .. } => {
+            let modifiers = if STANDARD_SYSTEM_MODULES.contains(&pkgpath.as_str()) {
+                DEFAULT_LIBRARY
+            } else {
+                0
+            };
+            return Some((NAMESPACE, modifiers));
+        }
+        Definition::Object(obj) => obj,
+    };
+    match obj.kind {
+        ScopeObjectKind::Definition => match &obj.ty.kind {
+            TypeKind::Schema(schema_ty) => {
+                let token_type = if schema_ty.is_rule {
+                    RULE
+                } else if schema_ty.is_mixin {
+                    MIXIN
+                } else if schema_ty.is_protocol {
+                    PROTOCOL
+                } else {
+                    SCHEMA
+                };
+                Some((token_type, 0))
+            }
+            // Builtin functions are the only definitions without positions.
+            TypeKind::Function(_) if !obj.pos_is_valid() || obj.start.filename.is_empty() => {
+                Some((FUNCTION, DEFAULT_LIBRARY))
+            }
+            _ => None,
+        },
+        ScopeObjectKind::Attribute => {
+            let modifiers = if is_optional_attr(obj, prog_scope) {
+                OPTIONAL
+            } else {
+                0
+            };
+            Some((ATTRIBUTE, modifiers))
+        }
+        ScopeObjectKind::Variable => {
+            // The exported top level variables are immutable.
+            let modifiers = if !obj.name.starts_with('_') && is_top_level(obj, prog_scope) {
+                READONLY
+            } else {
+                0
+            };
+            Some((VARIABLE, modifiers))
+        }
+        ScopeObjectKind::Parameter => Some((PARAMETER, 0)),
+        ScopeObjectKind::TypeAlias => Some((TYPE_ALIAS, 0)),
+        ScopeObjectKind::Module => Some((NAMESPACE, 0)),
+    }
+}
+
+/// Whether the object is declared in the package scope.
+fn is_top_level(obj: &ScopeObject, prog_scope: &ProgramScope) -> bool {
+    prog_scope.scope_map.values().any(|scope| {
+        scope
+            .borrow()
+            .elems
+            .get(&obj.name)
+            .map_or(false, |elem| elem.borrow().start == obj.start)
+    })
+}
+
+/// Whether the schema attribute object is declared as optional, e.g., `name?: str`.
+fn is_optional_attr(obj: &ScopeObject, prog_scope: &ProgramScope) -> bool {
+    for pkg_scope in prog_scope.scope_map.values() {
+        let pkg_scope = pkg_scope.borrow();
+        let schema_name = match pkg_scope.inner_most(&obj.start).map(|scope| scope.kind) {
+            Some(ScopeKind::Schema(name)) => name,
+            _ => continue,
+        };
+        if let Some(schema_obj) = pkg_scope.elems.get(&schema_name) {
+            if let TypeKind::Schema(schema_ty) = &schema_obj.borrow().ty.kind {
+                return schema_ty
+                    .attrs
+                    .get(&obj.name)
+                    .map_or(false, |attr| attr.is_optional);
+            }
+        }
+    }
+    false
+}
+
+/// Encodes the tokens with the positions relative to the previous token.
+fn encode_tokens(tokens: &[AbsoluteToken]) -> Vec<SemanticToken> {
+    let mut data = vec![];
+    let mut prev_line = 0;
+    let mut prev_start = 0;
+    for token in tokens {
+        // The lines of KCL positions start from 1 while the lines of LSP start from 0.
+        let line = token.start.line as u32 - 1;
+        let start = token.start.column.unwrap_or(0) as u32;
+        let delta_line = line - prev_line;
+        let delta_start = if delta_line == 0 {
+            start - prev_start
+        } else {
+            start
+        };
+        data.push(SemanticToken {
+            delta_line,
+            delta_start,
+            length: token.length,
+            token_type: token.token_type,
+            token_modifiers_bitset: token.modifiers,
+        });
+        prev_line = line;
+        prev_start = start;
+    }
+    data
+}
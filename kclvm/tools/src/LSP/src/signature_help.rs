@@ -0,0 +1,242 @@
+use std::rc::Rc;
+
+use kclvm_ast::ast::{ExprContext, Identifier, Program};
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::builtin::{get_system_member_function_ty, STANDARD_SYSTEM_MODULES};
+use kclvm_sema::resolver::scope::ProgramScope;
+use kclvm_sema::ty::{FunctionType, Type, TypeKind};
+use lsp_types::{
+    Documentation, ParameterInformation, ParameterLabel, Position, SignatureHelp,
+    SignatureInformation,
+};
+
+use crate::find_ref::{resolve_identifier, Definition};
+use crate::from_lsp::{kcl_pos, text_range};
+
+/// The call surrounding the cursor position, found by scanning the source code
+/// because the code being typed is usually incomplete, e.g., `option(`.
+#[derive(Debug, PartialEq, Eq)]
+struct CallSite {
+    /// The dotted name of the called function or schema, e.g., `regex.match`.
+    callee: String,
+    /// The number of arguments before the cursor.
+    arg_index: usize,
+    /// The keyword of the argument under the cursor, e.g., `type` in `option("key", type=`.
+    keyword: Option<String>,
+}
+
+/// Returns the signature of the builtin function, system module function, lambda or
+/// schema called at the position, with the active parameter under the cursor.
+pub(crate) fn signature_help(
+    file: &str,
+    src: &str,
+    pos: Position,
+    program: &Program,
+    prog_scope: &ProgramScope,
+) -> Option<SignatureHelp> {
+    let offset = text_range(src, lsp_types::Range::new(pos, pos)).start;
+    let call_site = find_call_site(src, offset)?;
+    let kcl_pos = kcl_pos(file, pos);
+    let (func_ty, doc, param_docs) =
+        resolve_callee(&call_site.callee, &kcl_pos, program, prog_scope)?;
+    let mut param_labels: Vec<String> = func_ty
+        .params
+        .iter()
+        .map(|param| format!("{}: {}", param.name, param.ty.ty_str()))
+        .collect();
+    if func_ty.is_variadic {
+        param_labels.push("...".to_string());
+    }
+    let active_parameter = match &call_site.keyword {
+        Some(keyword) => func_ty
+            .params
+            .iter()
+            .position(|param| &param.name == keyword)?,
+        None => call_site.arg_index,
+    };
+    // The variadic arguments are all mapped to the last label.
+    let active_parameter = active_parameter.min(param_labels.len().saturating_sub(1)) as u32;
+    let label = format!(
+        "{}({}) -> {}",
+        call_site.callee,
+        param_labels.join(", "),
+        func_ty.return_ty.ty_str()
+    );
+    let parameters = param_labels
+        .iter()
+        .enumerate()
+        .map(|(i, param_label)| ParameterInformation {
+            label: ParameterLabel::Simple(param_label.clone()),
+            documentation: param_docs
+                .get(i)
+                .cloned()
+                .flatten()
+                .map(Documentation::String),
+        })
+        .collect();
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: (!doc.is_empty()).then_some(Documentation::String(doc)),
+            parameters: Some(parameters),
+            active_parameter: Some(active_parameter),
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter),
+    })
+}
+
+/// Resolves the callee to its function type, its doc and the docs of its parameters.
+fn resolve_callee(
+    callee: &str,
+    pos: &KCLPos,
+    program: &Program,
+    prog_scope: &ProgramScope,
+) -> Option<(FunctionType, String, Vec<Option<String>>)> {
+    let names: Vec<String> = callee.split('.').map(|name| name.to_string()).collect();
+    // System module functions, e.g., `regex.match`.
+    if names.len() == 2 {
+        let pkgpath = prog_scope
+            .import_names
+            .get(&pos.filename)
+            .and_then(|mapping| mapping.get(&names[0]));
+        if let Some(pkgpath) = pkgpath {
+            if STANDARD_SYSTEM_MODULES.contains(&pkgpath.as_str()) {
+                let ty = get_system_member_function_ty(pkgpath, &names[1])?;
+                return function_ty(&ty).map(|func_ty| {
+                    let doc = func_ty.doc.clone();
+                    (func_ty, doc, vec![])
+                });
+            }
+        }
+    }
+    let id = Identifier {
+        names: names.clone(),
+        pkgpath: "".to_string(),
+        ctx: ExprContext::Load,
+    };
+    let obj = match resolve_identifier(program, &id, names.len() - 1, pos, prog_scope)? {
+        Definition::Object(obj) => obj,
+        Definition::Module { .. } => return None,
+    };
+    match &obj.ty.kind {
+        // Schemas are called with the schema arguments, e.g., `Person("alice", 18)`.
+        TypeKind::Schema(schema_ty) => {
+            let func_ty = schema_ty.func.as_ref().clone();
+            let param_docs = func_ty
+                .params
+                .iter()
+                .map(|param| {
+                    schema_ty
+                        .get_obj_of_attr(&param.name)
+                        .and_then(|attr| attr.doc.clone())
+                })
+                .collect();
+            let func_ty = FunctionType {
+                return_ty: Rc::new(Type::schema(schema_ty.clone())),
+                ..func_ty
+            };
+            Some((func_ty, schema_ty.doc.clone(), param_docs))
+        }
+        _ => function_ty(&obj.ty).map(|func_ty| {
+            let doc = func_ty.doc.clone();
+            (func_ty, doc, vec![])
+        }),
+    }
+}
+
+fn function_ty(ty: &Type) -> Option<FunctionType> {
+    match &ty.kind {
+        TypeKind::Function(func_ty) => Some(func_ty.clone()),
+        _ => None,
+    }
+}
+
+/// Scans the source code before the offset and returns the innermost unclosed call.
+/// Brackets in strings and comments are skipped.
+fn find_call_site(src: &str, offset: usize) -> Option<CallSite> {
+    let mut offset = offset.min(src.len());
+    while !src.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let text = &src[..offset];
+    // The open brackets with their offsets, the number of commas in them and the start
+    // offsets of their last arguments.
+    let mut brackets: Vec<(char, usize, usize, usize)> = vec![];
+    let mut chars = text.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '#' => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '"' | '\'' => {
+                let is_triple = text[i..].starts_with(&c.to_string().repeat(3));
+                if is_triple {
+                    chars.next();
+                    chars.next();
+                }
+                let mut quotes = 0;
+                while let Some((_, next)) = chars.next() {
+                    if next == '\\' {
+                        chars.next();
+                        continue;
+                    }
+                    if next == c {
+                        quotes += 1;
+                        if !is_triple || quotes == 3 {
+                            break;
+                        }
+                    } else {
+                        quotes = 0;
+                    }
+                    if next == '\n' && !is_triple {
+                        break;
+                    }
+                }
+            }
+            '(' | '[' | '{' => brackets.push((c, i, 0, i + 1)),
+            ')' | ']' | '}' => {
+                brackets.pop();
+            }
+            ',' => {
+                if let Some(last) = brackets.last_mut() {
+                    last.2 += 1;
+                    last.3 = i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    // The cursor may be in a list or dict argument of the call.
+    let index = brackets.iter().rposition(|(c, _, _, _)| *c == '(')?;
+    let (_, open, commas, arg_start) = brackets[index];
+    let callee: String = text[..open]
+        .trim_end()
+        .chars()
+        .rev()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+        .collect::<Vec<char>>()
+        .into_iter()
+        .rev()
+        .collect();
+    if callee.is_empty() || callee.starts_with('.') || callee.ends_with('.') {
+        return None;
+    }
+    let arg = text[arg_start..].trim_start();
+    let keyword = arg.find('=').and_then(|i| {
+        let name = arg[..i].trim_end();
+        let is_keyword = !arg[i + 1..].starts_with('=')
+            && !name.is_empty()
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+        is_keyword.then(|| name.to_string())
+    });
+    Some(CallSite {
+        callee,
+        arg_index: commas,
+        keyword,
+    })
+}
@@ -1,15 +1,19 @@
+use crate::analysis::Analysis;
 use crate::config::Config;
+use crate::db::AnalysisDatabase;
 use crate::to_lsp::{kcl_diag_to_lsp_diags, url};
 use crate::util::{get_file_name, parse_param_and_compile, to_json, Param};
 use crossbeam_channel::{select, unbounded, Receiver, Sender};
 use lsp_server::{ReqQueue, Response};
 use lsp_types::{
-    notification::{Notification, PublishDiagnostics},
-    Diagnostic, PublishDiagnosticsParams,
+    notification::{DidChangeWatchedFiles, Notification, PublishDiagnostics},
+    request::{RegisterCapability, Request},
+    Diagnostic, DidChangeWatchedFilesRegistrationOptions, FileSystemWatcher,
+    PublishDiagnosticsParams, Registration, RegistrationParams, SemanticTokens, Url,
 };
 use parking_lot::RwLock;
-use ra_ap_vfs::Vfs;
-use std::{sync::Arc, time::Instant};
+use ra_ap_vfs::{ChangeKind, FileId, Vfs};
+use std::{collections::HashMap, sync::Arc, time::Instant};
 
 pub(crate) type RequestHandler = fn(&mut LanguageServerState, lsp_server::Response);
 
@@ -37,7 +41,7 @@ pub(crate) struct LanguageServerState {
     pub(crate) request_queue: lsp_server::ReqQueue<(String, Instant), RequestHandler>,
 
     /// The configuration passed by the client
-    pub _config: Config,
+    pub config: Config,
 
     /// Thread pool for async execution
     pub thread_pool: threadpool::ThreadPool,
@@ -51,6 +55,12 @@ pub(crate) struct LanguageServerState {
     /// The virtual filesystem that holds all the file contents
     pub vfs: Arc<RwLock<Vfs>>,
 
+    /// Holds the state of the analysis process
+    pub analysis: Analysis,
+
+    /// The last semantic tokens sent to the client for each file, used to compute the delta
+    pub semantic_tokens: Arc<RwLock<HashMap<String, SemanticTokens>>>,
+
     /// True if the client requested that we shut down
     pub shutdown_requested: bool,
 }
@@ -60,6 +70,12 @@ pub(crate) struct LanguageServerState {
 pub(crate) struct LanguageServerSnapshot {
     /// The virtual filesystem that holds all the file contents
     pub vfs: Arc<RwLock<Vfs>>,
+    /// Holds the results of the compile shared by all requests
+    pub db: AnalysisDatabase,
+    /// The last semantic tokens sent to the client for each file
+    pub semantic_tokens: Arc<RwLock<HashMap<String, SemanticTokens>>>,
+    /// The configuration passed by the client
+    pub config: Config,
 }
 
 #[allow(unused)]
@@ -69,8 +85,10 @@ impl LanguageServerState {
         LanguageServerState {
             sender,
             request_queue: ReqQueue::default(),
-            _config: config,
+            config,
             vfs: Arc::new(RwLock::new(Default::default())),
+            analysis: Analysis::default(),
+            semantic_tokens: Arc::new(RwLock::new(HashMap::new())),
             thread_pool: threadpool::ThreadPool::default(),
             task_sender,
             task_receiver,
@@ -89,6 +107,9 @@ impl LanguageServerState {
 
     /// Runs the language server to completion
     pub fn run(mut self, receiver: Receiver<lsp_server::Message>) -> anyhow::Result<()> {
+        if self.config.did_change_watched_files_dynamic_registration {
+            self.register_file_watchers()?;
+        }
         while let Some(event) = self.next_event(&receiver) {
             if let Event::Lsp(lsp_server::Message::Notification(notification)) = &event {
                 if notification.method == lsp_types::notification::Exit::METHOD {
@@ -109,8 +130,7 @@ impl LanguageServerState {
             Event::Lsp(msg) => match msg {
                 lsp_server::Message::Request(req) => self.on_request(req, start_time)?,
                 lsp_server::Message::Notification(not) => self.on_notification(not)?,
-                // lsp_server::Message::Response(resp) => self.complete_request(resp),
-                _ => {}
+                lsp_server::Message::Response(resp) => self.complete_request(resp),
             },
         };
 
@@ -173,6 +193,61 @@ impl LanguageServerState {
         Ok(())
     }
 
+    /// Handles a response to a request sent to the client.
+    fn complete_request(&mut self, response: lsp_server::Response) {
+        if let Some(handler) = self.request_queue.outgoing.complete(response.id.clone()) {
+            handler(self, response)
+        }
+    }
+
+    /// Registers the file watchers, so that the client notifies the server of the changes
+    /// to the KCL files, `kcl.mod` and `kcl.yaml` on disk.
+    fn register_file_watchers(&mut self) -> anyhow::Result<()> {
+        let watchers = ["**/*.k", "**/kcl.mod", "**/kcl.mod.lock", "**/kcl.yaml"]
+            .iter()
+            .map(|glob_pattern| FileSystemWatcher {
+                glob_pattern: glob_pattern.to_string(),
+                kind: None,
+            })
+            .collect();
+        let registration = Registration {
+            id: DidChangeWatchedFiles::METHOD.to_string(),
+            method: DidChangeWatchedFiles::METHOD.to_string(),
+            register_options: Some(to_json(DidChangeWatchedFilesRegistrationOptions {
+                watchers,
+            })?),
+        };
+        let request = self.request_queue.outgoing.register(
+            RegisterCapability::METHOD.to_string(),
+            RegistrationParams {
+                registrations: vec![registration],
+            },
+            |_, _| {},
+        );
+        self.send(request.into())
+    }
+
+    /// Recomputes the diagnostics of all opened files in the thread pool, e.g., when a
+    /// file imported by them or the compile unit config changes on disk.
+    pub(crate) fn refresh_diagnostics(&mut self) -> anyhow::Result<()> {
+        let snapshot = self.snapshot();
+        let file_ids: Vec<FileId> = self.vfs.read().iter().map(|(file_id, _)| file_id).collect();
+        let files = file_ids
+            .into_iter()
+            .map(|file_id| {
+                let filename = get_file_name(self.vfs.read(), file_id)?;
+                Ok((filename, url(&snapshot, file_id)?))
+            })
+            .collect::<anyhow::Result<Vec<(String, Url)>>>()?;
+        let task_sender = self.task_sender.clone();
+        self.thread_pool.execute(move || {
+            for (filename, uri) in files {
+                let _result = publish_diagnostics(&snapshot, &filename, uri, &task_sender);
+            }
+        });
+        Ok(())
+    }
+
     /// Sends a message to the client
     pub(crate) fn send(&mut self, message: lsp_server::Message) -> anyhow::Result<()> {
         self.sender.send(message)?;
@@ -196,6 +271,9 @@ impl LanguageServerState {
     pub fn snapshot(&self) -> LanguageServerSnapshot {
         LanguageServerSnapshot {
             vfs: self.vfs.clone(),
+            db: self.analysis.db.clone(),
+            semantic_tokens: self.semantic_tokens.clone(),
+            config: self.config.clone(),
         }
     }
 
@@ -225,30 +303,54 @@ fn handle_diagnostics(
             let uri = url(&snapshot, file.file_id)?;
             (filename, uri)
         };
-        let (_, _, diags) = parse_param_and_compile(
-            Param {
-                file: filename.clone(),
-            },
-            Some(snapshot.vfs.clone()),
-        )
-        .unwrap();
-
-        let diagnostics = diags
-            .iter()
-            .flat_map(|diag| kcl_diag_to_lsp_diags(diag, filename.as_str()))
-            .collect::<Vec<Diagnostic>>();
-        sender.send(Task::Notify(lsp_server::Notification {
-            method: PublishDiagnostics::METHOD.to_owned(),
-            params: to_json(PublishDiagnosticsParams {
-                uri,
-                diagnostics,
-                version: None,
-            })?,
-        }))?;
+        // The diagnostics of the closed files are cleared.
+        if file.change_kind == ChangeKind::Delete {
+            send_diagnostics(uri, vec![], &sender)?;
+        } else {
+            publish_diagnostics(&snapshot, &filename, uri, &sender)?;
+        }
     }
     Ok(())
 }
 
+/// Compiles the compile unit of the file and publishes the diagnostics of the file.
+fn publish_diagnostics(
+    snapshot: &LanguageServerSnapshot,
+    filename: &str,
+    uri: Url,
+    sender: &Sender<Task>,
+) -> anyhow::Result<()> {
+    let (_, _, diags) = parse_param_and_compile(
+        Param {
+            file: filename.to_string(),
+            module_cache: Some(snapshot.db.module_cache.clone()),
+            scope_cache: Some(snapshot.db.scope_cache.clone()),
+        },
+        Some(snapshot.vfs.clone()),
+    )?;
+    let diagnostics = diags
+        .iter()
+        .flat_map(|diag| kcl_diag_to_lsp_diags(diag, filename))
+        .collect::<Vec<Diagnostic>>();
+    send_diagnostics(uri, diagnostics, sender)
+}
+
+fn send_diagnostics(
+    uri: Url,
+    diagnostics: Vec<Diagnostic>,
+    sender: &Sender<Task>,
+) -> anyhow::Result<()> {
+    sender.send(Task::Notify(lsp_server::Notification {
+        method: PublishDiagnostics::METHOD.to_owned(),
+        params: to_json(PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        })?,
+    }))?;
+    Ok(())
+}
+
 pub(crate) fn log_message(message: String, sender: &Sender<Task>) -> anyhow::Result<()> {
     let typ = lsp_types::MessageType::INFO;
     sender.send(Task::Notify(lsp_server::Notification::new(
@@ -1,6 +1,7 @@
 use std::env;
 use std::path::PathBuf;
 use std::process::Command;
+use std::sync::Arc;
 
 use indexmap::IndexSet;
 use kclvm_ast::ast::Program;
@@ -9,20 +10,37 @@ use kclvm_error::Position as KCLPos;
 use kclvm_sema::builtin::MATH_FUNCTION_NAMES;
 use kclvm_sema::builtin::STRING_MEMBER_FUNCTIONS;
 use kclvm_sema::resolver::scope::ProgramScope;
+use lsp_types::CodeActionOrCommand;
 use lsp_types::CompletionResponse;
+use lsp_types::DocumentHighlightKind;
 use lsp_types::DocumentSymbol;
 use lsp_types::DocumentSymbolResponse;
+use lsp_types::GotoDefinitionResponse;
+use lsp_types::InlayHintLabel;
 use lsp_types::MarkedString;
+use lsp_types::SemanticToken;
 use lsp_types::SymbolKind;
 use lsp_types::Url;
-use lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+use lsp_types::{FormattingOptions, Position, Range, TextDocumentContentChangeEvent, TextEdit};
+use parking_lot::RwLock;
+use ra_ap_vfs::Vfs;
 
+use crate::code_action::code_actions;
+use crate::db::AnalysisDatabase;
+use crate::document_highlight::document_highlight;
 use crate::document_symbol::document_symbol;
+use crate::formatting::{format, format_on_type, format_range};
 use crate::from_lsp::file_path_from_url;
 use crate::hover::hover;
+use crate::inlay_hints::inlay_hints;
+use crate::semantic_tokens::{semantic_tokens, semantic_tokens_edits};
+use crate::signature_help::signature_help;
+use crate::to_lsp::kcl_diag_to_lsp_diags;
+use crate::type_hierarchy::{prepare_type_hierarchy, subtypes, supertypes, TypeHierarchyItem};
+use crate::workspace_symbol::workspace_symbols;
 use crate::{
     completion::{completion, into_completion_items},
-    goto_def::goto_definition,
+    goto_def::{goto_definition, goto_implementation, goto_type_definition},
     util::{apply_document_changes, parse_param_and_compile, Param},
 };
 
@@ -33,8 +51,15 @@ fn compile_test_file(testfile: &str) -> (String, Program, ProgramScope, IndexSet
 
     let file = test_file.to_str().unwrap().to_string();
 
-    let (program, prog_scope, diags) =
-        parse_param_and_compile(Param { file: file.clone() }, None).unwrap();
+    let (program, prog_scope, diags) = parse_param_and_compile(
+        Param {
+            file: file.clone(),
+            module_cache: None,
+            scope_cache: None,
+        },
+        None,
+    )
+    .unwrap();
     (file, program, prog_scope, diags)
 }
 
@@ -48,6 +73,8 @@ fn diagnostics_test() {
     let (_, _, diags) = parse_param_and_compile(
         Param {
             file: file.to_string(),
+            module_cache: None,
+            scope_cache: None,
         },
         None,
     )
@@ -501,14 +528,21 @@ fn test_apply_document_changes() {
     apply_document_changes(&mut text, change![0, 0; 0, 0 => "a"]);
     assert_eq!(text, "a❤️");
 
-    // todo: Non-ASCII char
-    // text = String::from("a\nb");
-    // apply_document_changes(&mut text, change![0, 1; 1, 0 => "\nțc", 0, 1; 1, 1 => "d"]);
-    // assert_eq!(text, "adcb");
+    // Non-ASCII char
+    text = String::from("a\nb");
+    apply_document_changes(&mut text, change![0, 1; 1, 0 => "\nțc", 0, 1; 1, 1 => "d"]);
+    assert_eq!(text, "adcb");
 
-    // text = String::from("a\nb");
-    // apply_document_changes(&mut text, change![0, 1; 1, 0 => "ț\nc", 0, 2; 0, 2 => "c"]);
-    // assert_eq!(text, "ațc\ncb");
+    text = String::from("a\nb");
+    apply_document_changes(&mut text, change![0, 1; 1, 0 => "ț\nc", 0, 2; 0, 2 => "c"]);
+    assert_eq!(text, "ațc\ncb");
+}
+
+fn completion_labels(res: CompletionResponse) -> IndexSet<String> {
+    match res {
+        CompletionResponse::Array(items) => items.into_iter().map(|item| item.label).collect(),
+        CompletionResponse::List(list) => list.items.into_iter().map(|item| item.label).collect(),
+    }
 }
 
 #[test]
@@ -528,9 +562,7 @@ fn completion_test() {
     items.insert("name".to_string());
     items.insert("age".to_string());
 
-    let expect: CompletionResponse = into_completion_items(&items).into();
-
-    assert_eq!(got, expect);
+    assert_eq!(completion_labels(got), items);
     items.clear();
 
     let pos = KCLPos {
@@ -545,9 +577,7 @@ fn completion_test() {
     for k in binding.keys() {
         items.insert(format!("{}{}", k, "()"));
     }
-    let expect: CompletionResponse = into_completion_items(&items).into();
-
-    assert_eq!(got, expect);
+    assert_eq!(completion_labels(got), items);
     items.clear();
 
     // test completion for import pkg path
@@ -574,8 +604,7 @@ fn completion_test() {
 
     let got = completion(Some('.'), &program, &pos, &prog_scope).unwrap();
     items.insert("Person1".to_string());
-    let expect: CompletionResponse = into_completion_items(&items).into();
-    assert_eq!(got, expect);
+    assert_eq!(completion_labels(got), items);
     items.clear();
 
     let pos = KCLPos {
@@ -585,8 +614,7 @@ fn completion_test() {
     };
     let got = completion(Some('.'), &program, &pos, &prog_scope).unwrap();
     items.extend(MATH_FUNCTION_NAMES.iter().map(|s| s.to_string()));
-    let expect: CompletionResponse = into_completion_items(&items).into();
-    assert_eq!(got, expect);
+    assert_eq!(completion_labels(got), items);
     items.clear();
 
     // test completion for literal str builtin function
@@ -601,9 +629,7 @@ fn completion_test() {
     for k in binding.keys() {
         items.insert(format!("{}{}", k, "()"));
     }
-    let expect: CompletionResponse = into_completion_items(&items).into();
-
-    assert_eq!(got, expect);
+    assert_eq!(completion_labels(got), items);
 }
 
 #[test]
@@ -755,6 +781,8 @@ fn goto_import_external_file_test() {
     let (program, prog_scope, diags) = parse_param_and_compile(
         Param {
             file: path.to_string(),
+            module_cache: None,
+            scope_cache: None,
         },
         None,
     )
@@ -771,3 +799,710 @@ fn goto_import_external_file_test() {
     let res = goto_definition(&program, &pos, &prog_scope);
     assert!(res.is_some());
 }
+
+#[test]
+fn formatting_test() {
+    let src = "a = 1\nb=2\nc = 3\n";
+    let edits = format(src).unwrap().unwrap();
+    assert_eq!(
+        edits,
+        vec![TextEdit {
+            range: Range::new(Position::new(1, 0), Position::new(2, 0)),
+            new_text: "b = 2\n".to_string(),
+        }]
+    );
+    // The formatted source needs no edits.
+    assert!(format("a = 1\n").unwrap().is_none());
+    // The source with syntax errors can't be formatted.
+    assert!(format("a = \n").is_err());
+}
+
+#[test]
+fn range_formatting_test() {
+    let src = "a=1\nb=2\nc=3\n";
+    let range = Range::new(Position::new(1, 0), Position::new(1, 3));
+    let edits = format_range(src, range).unwrap().unwrap();
+    assert_eq!(
+        edits,
+        vec![TextEdit {
+            range: Range::new(Position::new(1, 0), Position::new(2, 0)),
+            new_text: "b = 2\n".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn on_type_formatting_test() {
+    let options = FormattingOptions {
+        tab_size: 4,
+        insert_spaces: true,
+        ..Default::default()
+    };
+    let src = "schema Person:\n\n";
+    let edits = format_on_type(src, Position::new(1, 0), "\n", &options).unwrap();
+    assert_eq!(
+        edits,
+        vec![TextEdit {
+            range: Range::new(Position::new(1, 0), Position::new(1, 0)),
+            new_text: "    ".to_string(),
+        }]
+    );
+    // Only the new line after `:` is indented.
+    let src = "a = 1\n\n";
+    assert!(format_on_type(src, Position::new(1, 0), "\n", &options).is_none());
+}
+
+#[test]
+fn semantic_tokens_test() {
+    let (file, program, prog_scope, _) =
+        compile_test_file("src/test_data/semantic_tokens_test/main.k");
+    let tokens = semantic_tokens(&file, &program, &prog_scope).unwrap();
+    assert!(tokens.result_id.is_some());
+
+    // Decode the tokens to (line, start, length, token type, token modifiers).
+    let mut line = 0;
+    let mut start = 0;
+    let mut got = vec![];
+    for token in tokens.data {
+        if token.delta_line > 0 {
+            start = 0;
+        }
+        line += token.delta_line;
+        start += token.delta_start;
+        got.push((
+            line,
+            start,
+            token.length,
+            token.token_type,
+            token.token_modifiers_bitset,
+        ));
+    }
+    let expect = vec![
+        (0, 7, 4, 0, 2),  // math: namespace, defaultLibrary
+        (2, 7, 4, 1, 0),  // Base: class
+        (3, 4, 2, 7, 4),  // id: property, optional
+        (5, 7, 6, 1, 0),  // Server: class
+        (5, 14, 4, 1, 0), // Base: class
+        (6, 5, 10, 5, 2), // deprecated: decorator, defaultLibrary
+        (7, 4, 4, 7, 0),  // name: property
+        (9, 0, 6, 9, 1),  // server: variable, readonly
+        (9, 9, 6, 1, 0),  // Server: class
+        (10, 4, 4, 7, 0), // name: property
+        (12, 0, 5, 9, 0), // _port: variable
+        (12, 8, 4, 0, 2), // math: namespace, defaultLibrary
+    ];
+    assert_eq!(got, expect);
+}
+
+#[test]
+fn semantic_tokens_edits_test() {
+    let token = |delta_line| SemanticToken {
+        delta_line,
+        delta_start: 0,
+        length: 1,
+        token_type: 0,
+        token_modifiers_bitset: 0,
+    };
+    let previous = vec![token(0), token(1), token(2)];
+    let current = vec![token(0), token(3), token(4), token(2)];
+    let edits = semantic_tokens_edits(&previous, &current);
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].start, 5);
+    assert_eq!(edits[0].delete_count, 5);
+    assert_eq!(edits[0].data, Some(vec![token(3), token(4)]));
+    assert!(semantic_tokens_edits(&previous, &previous).is_empty());
+}
+
+#[test]
+fn inlay_hints_test() {
+    let (file, program, prog_scope, _) = compile_test_file("src/test_data/inlay_hints_test/main.k");
+    let range = Range::new(Position::new(0, 0), Position::new(12, 0));
+    let hints = inlay_hints(&file, range, &program, &prog_scope, true).unwrap();
+    let got: Vec<(u32, String)> = hints
+        .iter()
+        .map(|hint| {
+            let label = match &hint.label {
+                InlayHintLabel::String(label) => label.clone(),
+                InlayHintLabel::LabelParts(_) => unreachable!("test error"),
+            };
+            (hint.position.line, label)
+        })
+        .collect();
+    let expect: Vec<(u32, String)> = vec![
+        (4, ": Server".to_string()),
+        (6, "port = 8080".to_string()),
+        (7, ": [str]".to_string()),
+        (8, ": [str]".to_string()),
+        (8, ": str".to_string()),
+        (9, "-> int".to_string()),
+    ];
+    assert_eq!(got, expect);
+    assert_eq!(hints[0].position, Position::new(4, 6));
+    assert_eq!(hints[4].position, Position::new(8, 16));
+
+    // The default values of schema attributes are hidden unless enabled.
+    let hints = inlay_hints(&file, range, &program, &prog_scope, false).unwrap();
+    assert_eq!(hints.len(), 5);
+
+    // Only the hints in the range are returned.
+    let range = Range::new(Position::new(0, 0), Position::new(5, 0));
+    let hints = inlay_hints(&file, range, &program, &prog_scope, true).unwrap();
+    assert_eq!(hints.len(), 1);
+}
+
+#[test]
+fn signature_help_test() {
+    let (file, program, prog_scope, _) =
+        compile_test_file("src/test_data/signature_help_test/main.k");
+    let src = std::fs::read_to_string(&file).unwrap();
+    let help = |line, character| {
+        signature_help(
+            &file,
+            &src,
+            Position::new(line, character),
+            &program,
+            &prog_scope,
+        )
+    };
+
+    // builtin function
+    let res = help(9, 11).unwrap();
+    assert!(res.signatures[0]
+        .label
+        .starts_with("option(key: str, type: str"));
+    assert_eq!(res.active_parameter, Some(0));
+    assert!(res.signatures[0].documentation.is_some());
+    // keyword argument
+    let res = help(9, 23).unwrap();
+    assert_eq!(res.active_parameter, Some(1));
+
+    // lambda
+    let res = help(10, 11).unwrap();
+    assert_eq!(res.signatures[0].label, "add(x: int, y: int) -> int");
+    assert_eq!(res.active_parameter, Some(1));
+
+    // schema arguments
+    let res = help(11, 20).unwrap();
+    assert_eq!(
+        res.signatures[0].label,
+        "Person(name: str, age: int) -> Person"
+    );
+    assert_eq!(res.active_parameter, Some(1));
+
+    // system module function
+    let res = help(12, 16).unwrap();
+    assert_eq!(
+        res.signatures[0].label,
+        "regex.match(string: str, pattern: str) -> bool"
+    );
+    assert_eq!(res.active_parameter, Some(0));
+
+    // not in a call
+    assert!(help(6, 3).is_none());
+}
+
+#[test]
+fn code_action_test() {
+    let (file, program, prog_scope, diags) =
+        compile_test_file("src/test_data/code_action_test/main.k");
+    let src = std::fs::read_to_string(&file).unwrap();
+    let uri = Url::from_file_path(&file).unwrap();
+    let diagnostics: Vec<lsp_types::Diagnostic> = diags
+        .iter()
+        .flat_map(|diag| kcl_diag_to_lsp_diags(diag, &file))
+        .collect();
+    let actions = |range: Range, diagnostics: &[lsp_types::Diagnostic]| {
+        code_actions(&file, &src, range, diagnostics, &program, &prog_scope)
+            .unwrap()
+            .into_iter()
+            .map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) => {
+                    let edits = action.edit.unwrap().changes.unwrap()[&uri].clone();
+                    (action.title, edits)
+                }
+                CodeActionOrCommand::Command(_) => unreachable!(),
+            })
+            .collect::<Vec<(String, Vec<TextEdit>)>>()
+    };
+    let quick_fix = |message: &str| {
+        let diagnostic = diagnostics
+            .iter()
+            .find(|diagnostic| diagnostic.message == message)
+            .unwrap();
+        actions(diagnostic.range, &[diagnostic.clone()]).remove(0)
+    };
+    let edit = |start: (u32, u32), end: (u32, u32), new_text: &str| TextEdit {
+        range: Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1)),
+        new_text: new_text.to_string(),
+    };
+
+    // unused import
+    let (title, edits) = quick_fix("Module 'math' imported but unused");
+    assert_eq!(title, "Remove unused import 'math'");
+    assert_eq!(edits[0].range.end.line, edits[0].range.start.line + 1);
+    assert!(edits[0].new_text.is_empty());
+
+    // reimport
+    let (title, edits) = quick_fix("Module 'math' is reimported multiple times");
+    assert_eq!(title, "Remove reimport of 'math'");
+    assert_eq!(edits, vec![edit((1, 0), (2, 0), "")]);
+
+    // import position
+    let (title, edits) = quick_fix("Importstmt should be placed at the top of the module");
+    assert_eq!(title, "Move import 'regex' to the top of the module");
+    assert_eq!(
+        edits,
+        vec![
+            edit((3, 0), (3, 0), "import regex\n"),
+            edit((14, 0), (15, 0), "")
+        ]
+    );
+
+    // name not defined
+    let (title, edits) = quick_fix("name 'Person' is not defined");
+    assert_eq!(title, "Import 'Person' from 'pkg'");
+    assert_eq!(
+        edits,
+        vec![
+            edit((2, 0), (2, 0), "import pkg\n"),
+            edit((13, 8), (13, 14), "pkg.Person")
+        ]
+    );
+
+    // misspelled schema config attribute
+    let (title, edits) = quick_fix("Cannot add member 'nmae' to schema 'Server'");
+    assert_eq!(title, "Change to 'name'");
+    assert_eq!(edits, vec![edit((10, 4), (10, 8), "name")]);
+
+    // misspelled schema attribute
+    let (title, edits) = quick_fix("Server has no attribute prot");
+    assert_eq!(title, "Change to 'port'");
+    assert_eq!(edits, vec![edit((12, 14), (12, 18), "port")]);
+
+    // missing required schema attributes
+    let pos = Position::new(9, 16);
+    let res = actions(Range::new(pos, pos), &[]);
+    assert_eq!(
+        res,
+        vec![(
+            "Add missing required attributes of schema 'Server'".to_string(),
+            vec![edit((11, 0), (11, 0), "    name = \"\"\n    port = 0\n")]
+        )]
+    );
+    let pos = Position::new(13, 15);
+    assert_eq!(
+        actions(Range::new(pos, pos), &[]),
+        vec![],
+        "the schema of the config is not defined"
+    );
+}
+
+#[test]
+fn module_cache_test() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let file = path
+        .join("src/test_data/code_action_test/main.k")
+        .to_str()
+        .unwrap()
+        .to_string();
+    let db = AnalysisDatabase::default();
+    let compile = || {
+        parse_param_and_compile(
+            Param {
+                file: file.clone(),
+                module_cache: Some(db.module_cache.clone()),
+                scope_cache: Some(db.scope_cache.clone()),
+            },
+            None,
+        )
+        .unwrap()
+    };
+
+    let (_, scope, diags) = compile();
+    let cached_files: Vec<String> = db.module_cache.read().unwrap().keys().cloned().collect();
+    assert!(cached_files.contains(&file));
+    assert!(cached_files.iter().any(|file| file.ends_with("other.k")));
+    assert!(cached_files.iter().any(|file| file.ends_with("person.k")));
+
+    // The cached modules and package scopes give the same scopes and diagnostics.
+    let (_, cached_scope, cached_diags) = compile();
+    let mut pkgpaths = scope.pkgpaths();
+    let mut cached_pkgpaths = cached_scope.pkgpaths();
+    pkgpaths.sort();
+    cached_pkgpaths.sort();
+    assert_eq!(cached_pkgpaths, pkgpaths);
+    assert_eq!(cached_diags, diags);
+
+    db.invalidate_file(&file);
+    assert!(!db.module_cache.read().unwrap().contains_key(&file));
+    db.invalidate_all();
+    assert!(db.module_cache.read().unwrap().is_empty());
+}
+
+#[test]
+fn workspace_symbol_test() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let folder = path
+        .join("src/test_data/type_hierarchy_test")
+        .to_str()
+        .unwrap()
+        .to_string();
+    let db = AnalysisDatabase::default();
+    let vfs = Arc::new(RwLock::new(Vfs::default()));
+    let query = |query: &str| {
+        workspace_symbols(query, &[folder.clone()], vfs.clone(), &db.module_cache)
+            .unwrap()
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.kind, symbol.container_name))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        query(""),
+        vec![
+            ("Person".to_string(), SymbolKind::STRUCT, None),
+            ("Student".to_string(), SymbolKind::STRUCT, None),
+            ("PersonType".to_string(), SymbolKind::TYPE_PARAMETER, None),
+            ("person".to_string(), SymbolKind::VARIABLE, None),
+            ("student".to_string(), SymbolKind::VARIABLE, None),
+            (
+                "Base".to_string(),
+                SymbolKind::STRUCT,
+                Some("pkg".to_string())
+            ),
+            (
+                "NameProtocol".to_string(),
+                SymbolKind::STRUCT,
+                Some("pkg".to_string())
+            ),
+            (
+                "NameMixin".to_string(),
+                SymbolKind::STRUCT,
+                Some("pkg".to_string())
+            ),
+        ]
+    );
+    // The query is matched case-insensitively as a subsequence of the name.
+    assert_eq!(
+        query("per")
+            .into_iter()
+            .map(|(name, ..)| name)
+            .collect::<Vec<_>>(),
+        vec!["Person", "PersonType", "person"]
+    );
+
+    let symbols = workspace_symbols("Student", &[folder.clone()], vfs, &db.module_cache).unwrap();
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(
+        symbols[0].location.range,
+        Range {
+            start: Position::new(6, 7),
+            end: Position::new(6, 14),
+        }
+    );
+}
+
+#[test]
+fn type_hierarchy_test() {
+    let (file, program, prog_scope, _) =
+        compile_test_file("src/test_data/type_hierarchy_test/main.k");
+    let names =
+        |items: Vec<TypeHierarchyItem>| items.into_iter().map(|item| item.name).collect::<Vec<_>>();
+
+    // schema Person(pkg.Base):
+    //         ^
+    let pos = KCLPos {
+        filename: file.clone(),
+        line: 3,
+        column: Some(8),
+    };
+    let items = prepare_type_hierarchy(&program, &pos, &prog_scope).unwrap();
+    assert_eq!(items.len(), 1);
+    let person = items[0].clone();
+    assert_eq!(person.name, "Person");
+    assert_eq!(person.kind, SymbolKind::STRUCT);
+    assert_eq!(
+        person.selection_range,
+        Range {
+            start: Position::new(2, 7),
+            end: Position::new(2, 13),
+        }
+    );
+
+    let parents = supertypes(&person, &program, &prog_scope).unwrap();
+    assert_eq!(names(parents.clone()), vec!["Base", "NameMixin"]);
+    assert!(parents
+        .iter()
+        .all(|item| item.uri.path().ends_with("pkg/base.k")));
+    assert_eq!(
+        names(subtypes(&person, &program, &prog_scope).unwrap()),
+        vec!["Student"]
+    );
+
+    // The protocol is a supertype of the mixin.
+    let protocols = supertypes(&parents[1], &program, &prog_scope).unwrap();
+    assert_eq!(names(protocols.clone()), vec!["NameProtocol"]);
+    assert_eq!(protocols[0].kind, SymbolKind::INTERFACE);
+    assert_eq!(
+        names(subtypes(&protocols[0], &program, &prog_scope).unwrap()),
+        vec!["NameMixin"]
+    );
+
+    // student = Student {
+    //           ^
+    let pos = KCLPos {
+        filename: file,
+        line: 17,
+        column: Some(16),
+    };
+    let items = prepare_type_hierarchy(&program, &pos, &prog_scope).unwrap();
+    assert_eq!(names(items.clone()), vec!["Student"]);
+    assert!(subtypes(&items[0], &program, &prog_scope)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn goto_type_definition_test() {
+    let (file, program, prog_scope, _) =
+        compile_test_file("src/test_data/type_hierarchy_test/main.k");
+
+    // person = Person {
+    // ^
+    let pos = KCLPos {
+        filename: file.clone(),
+        line: 12,
+        column: Some(0),
+    };
+    match goto_type_definition(&program, &pos, &prog_scope).unwrap() {
+        GotoDefinitionResponse::Scalar(loc) => {
+            assert_eq!(loc.uri, Url::from_file_path(&file).unwrap());
+            assert_eq!(loc.range.start, Position::new(2, 0));
+        }
+        _ => unreachable!("test error"),
+    }
+
+    // The type of a literal has no schema.
+    let pos = KCLPos {
+        filename: file,
+        line: 13,
+        column: Some(4),
+    };
+    let res = goto_type_definition(&program, &pos, &prog_scope);
+    assert!(res.is_none());
+}
+
+#[test]
+fn goto_implementation_test() {
+    let (file, program, prog_scope, _) =
+        compile_test_file("src/test_data/type_hierarchy_test/main.k");
+
+    // schema Person(pkg.Base):
+    //                   ^
+    let pos = KCLPos {
+        filename: file.clone(),
+        line: 3,
+        column: Some(19),
+    };
+    // Both the direct and the indirect subtypes are returned.
+    match goto_implementation(&program, &pos, &prog_scope).unwrap() {
+        GotoDefinitionResponse::Array(locs) => {
+            let starts: Vec<(Url, Position)> = locs
+                .into_iter()
+                .map(|loc| (loc.uri, loc.range.start))
+                .collect();
+            let uri = Url::from_file_path(&file).unwrap();
+            assert_eq!(
+                starts,
+                vec![
+                    (uri.clone(), Position::new(2, 0)),
+                    (uri, Position::new(6, 0))
+                ]
+            );
+        }
+        _ => unreachable!("test error"),
+    }
+
+    // schema Student(Person):
+    //         ^
+    let pos = KCLPos {
+        filename: file,
+        line: 7,
+        column: Some(8),
+    };
+    assert!(goto_implementation(&program, &pos, &prog_scope).is_none());
+}
+
+#[test]
+fn document_highlight_test() {
+    let (file, program, prog_scope, _) =
+        compile_test_file("src/test_data/type_hierarchy_test/main.k");
+    let highlight = |line: u64, column: u64| {
+        let pos = KCLPos {
+            filename: file.clone(),
+            line,
+            column: Some(column),
+        };
+        document_highlight(&file, &program, &pos, &prog_scope)
+            .unwrap()
+            .into_iter()
+            .map(|highlight| (highlight.range.start, highlight.kind.unwrap()))
+            .collect::<Vec<_>>()
+    };
+
+    // person = Person {
+    //          ^
+    assert_eq!(
+        highlight(12, 10),
+        vec![
+            (Position::new(2, 7), DocumentHighlightKind::WRITE),
+            (Position::new(6, 15), DocumentHighlightKind::READ),
+            (Position::new(9, 18), DocumentHighlightKind::READ),
+            (Position::new(11, 9), DocumentHighlightKind::READ),
+        ]
+    );
+    // The config keys of the attribute inherited from the base schema are writes.
+    assert_eq!(
+        highlight(13, 5),
+        vec![
+            (Position::new(12, 4), DocumentHighlightKind::WRITE),
+            (Position::new(17, 8), DocumentHighlightKind::WRITE),
+        ]
+    );
+}
+
+#[test]
+fn builtin_hover_test() {
+    let (file, program, prog_scope, _) = compile_test_file("src/test_data/hover_test/builtin.k");
+    let hover_contents = |line: u64, column: u64| {
+        let pos = KCLPos {
+            filename: file.clone(),
+            line,
+            column: Some(column),
+        };
+        match hover(&program, &pos, &prog_scope).unwrap().contents {
+            lsp_types::HoverContents::Array(contents) => contents,
+            _ => unreachable!("test error"),
+        }
+    };
+    let signature = |contents: &[MarkedString]| match &contents[0] {
+        MarkedString::LanguageString(s) => s.value.clone(),
+        _ => unreachable!("test error"),
+    };
+
+    // @deprecated(version="v1")
+    let contents = hover_contents(3, 2);
+    assert_eq!(
+        signature(&contents),
+        "@deprecated(version: str = ..., reason: str = ..., strict: bool = ...) -> any"
+    );
+    match &contents[1] {
+        MarkedString::String(doc) => {
+            assert!(doc.starts_with("This decorator is used"));
+            assert!(
+                doc.contains("\nParameters\n----------\nversion: str, optional\n    The version")
+            );
+            assert!(doc.contains("\nExamples\n--------\n@deprecated("));
+        }
+        _ => unreachable!("test error"),
+    }
+    // n = math.log(10)
+    let contents = hover_contents(13, 10);
+    assert_eq!(
+        signature(&contents),
+        "math.log(x: int|float, e: float = ...) -> int|float"
+    );
+    match &contents[1] {
+        MarkedString::String(doc) => {
+            assert!(doc.contains("\ne: float, optional\n    The base of the logarithm"));
+            assert!(doc.contains("\nExamples\n--------\nimport math\n"));
+        }
+        _ => unreachable!("test error"),
+    }
+    // upper = name.upper()
+    let contents = hover_contents(14, 14);
+    assert_eq!(signature(&contents), "str.upper() -> str");
+    match &contents[1] {
+        MarkedString::String(doc) => {
+            assert!(doc.ends_with("Examples\n--------\n\"kcl\".upper()  # \"KCL\""))
+        }
+        _ => unreachable!("test error"),
+    }
+    // value = option("key")
+    assert!(signature(&hover_contents(15, 9)).starts_with("option(key: str, type: str = ..."));
+    // words = name.split(",", 1)
+    assert_eq!(
+        signature(&hover_contents(20, 14)),
+        "str.split(sep: str = ..., maxsplit: int = ...) -> [str]"
+    );
+}
+
+#[test]
+fn builtin_completion_test() {
+    let (file, program, prog_scope, _) = compile_test_file("src/test_data/hover_test/builtin.k");
+
+    // Builtin decorators
+    let pos = KCLPos {
+        filename: file.clone(),
+        line: 3,
+        column: Some(1),
+    };
+    let got = completion(Some('@'), &program, &pos, &prog_scope).unwrap();
+    match got {
+        CompletionResponse::Array(items) => {
+            let deprecated = items
+                .iter()
+                .find(|item| item.label == "deprecated")
+                .unwrap();
+            assert_eq!(
+                deprecated.detail,
+                Some(
+                    "@deprecated(version: str = ..., reason: str = ..., strict: bool = ...) -> any"
+                        .to_string()
+                )
+            );
+            assert!(deprecated.documentation.is_some());
+            assert!(items.iter().any(|item| item.label == "info"));
+        }
+        _ => unreachable!("test error"),
+    }
+
+    // The attributes not set yet in the schema config body, including the attributes
+    // of the base schema.
+    let pos = KCLPos {
+        filename: file,
+        line: 18,
+        column: Some(4),
+    };
+    let got = completion(None, &program, &pos, &prog_scope).unwrap();
+    match got {
+        CompletionResponse::Array(items) => {
+            let mut attrs: Vec<(String, Option<String>, Option<lsp_types::Documentation>)> = items
+                .into_iter()
+                .map(|item| (item.label, item.detail, item.documentation))
+                .collect();
+            attrs.sort_by(|a, b| a.0.cmp(&b.0));
+            assert_eq!(
+                attrs,
+                vec![
+                    (
+                        "age".to_string(),
+                        Some("age?: int".to_string()),
+                        Some(lsp_types::Documentation::String("optional".to_string()))
+                    ),
+                    (
+                        "id".to_string(),
+                        Some("id: int".to_string()),
+                        Some(lsp_types::Documentation::String("required".to_string()))
+                    ),
+                    (
+                        "labels".to_string(),
+                        Some("labels: {str:str}".to_string()),
+                        Some(lsp_types::Documentation::String("optional".to_string()))
+                    ),
+                ]
+            );
+        }
+        _ => unreachable!("test error"),
+    }
+}
@@ -1,4 +1,5 @@
 use kclvm_error::Diagnostic as KCLDiagnostic;
+use kclvm_error::DiagnosticId;
 use kclvm_error::Level;
 use kclvm_error::Message;
 use kclvm_error::Position as KCLPos;
@@ -21,7 +22,11 @@ pub fn lsp_pos(pos: &KCLPos) -> Position {
 }
 
 /// Convert KCL Message to LSP Diagnostic
-fn kcl_msg_to_lsp_diags(msg: &Message, severity: DiagnosticSeverity) -> Diagnostic {
+fn kcl_msg_to_lsp_diags(
+    msg: &Message,
+    severity: DiagnosticSeverity,
+    code: Option<NumberOrString>,
+) -> Diagnostic {
     let kcl_pos = msg.pos.clone();
     let start_position = lsp_pos(&kcl_pos);
     let end_position = lsp_pos(&kcl_pos);
@@ -29,7 +34,7 @@ fn kcl_msg_to_lsp_diags(msg: &Message, severity: DiagnosticSeverity) -> Diagnost
     Diagnostic {
         range: Range::new(start_position, end_position),
         severity: Some(severity),
-        code: None,
+        code,
         code_description: None,
         source: None,
         message: msg.message.clone(),
@@ -47,13 +52,29 @@ fn kcl_err_level_to_severity(level: Level) -> DiagnosticSeverity {
     }
 }
 
+/// Convert the KCL diagnostic id to the LSP diagnostic code, which is the name of the
+/// error or warning kind, e.g., `UnusedImportWarning`. Code actions use it to find
+/// the quick fixes of a diagnostic.
+fn kcl_diag_id_to_code(id: &DiagnosticId) -> NumberOrString {
+    match id {
+        DiagnosticId::Error(kind) => NumberOrString::String(kind.name()),
+        DiagnosticId::Warning(kind) => NumberOrString::String(kind.name()),
+    }
+}
+
 /// Convert KCL Diagnostic to LSP Diagnostics.
 /// Because the diagnostic of KCL contains multiple messages, and each messages corresponds to a diagnostic of LSP, the return value is a vec
 pub fn kcl_diag_to_lsp_diags(diag: &KCLDiagnostic, file_name: &str) -> Vec<Diagnostic> {
     diag.messages
         .iter()
         .filter(|msg| msg.pos.filename == file_name)
-        .map(|msg| kcl_msg_to_lsp_diags(msg, kcl_err_level_to_severity(diag.level)))
+        .map(|msg| {
+            kcl_msg_to_lsp_diags(
+                msg,
+                kcl_err_level_to_severity(diag.level),
+                diag.code.as_ref().map(kcl_diag_id_to_code),
+            )
+        })
         .collect()
 }
 
@@ -0,0 +1,216 @@
+//! Type hierarchy for KCL schemas.
+//!
+//! The supertypes of a schema are its base schema, mixins and protocol recorded in
+//! the [`SchemaType`], and the subtypes are the schemas in the compile unit which
+//! inherit, mix in or implement it.
+//!
+//! `lsp-types` 0.93 predates the type hierarchy of LSP 3.17, so the requests and
+//! the item are declared here.
+
+use kclvm_ast::ast::{Program, Stmt};
+use kclvm_ast::pos::GetPos;
+use kclvm_error::Position as KCLPos;
+use kclvm_sema::resolver::scope::{ProgramScope, ScopeObject, ScopeObjectKind};
+use kclvm_sema::ty::{SchemaType, TypeKind};
+use lsp_types::request::Request;
+use lsp_types::{Range, SymbolKind, SymbolTag, TextDocumentPositionParams, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::find_ref::{find_schema_def_obj, resolve_symbol, Definition};
+use crate::to_lsp::lsp_pos;
+
+/// The `textDocument/prepareTypeHierarchy` request.
+pub(crate) enum TypeHierarchyPrepare {}
+
+impl Request for TypeHierarchyPrepare {
+    type Params = TextDocumentPositionParams;
+    type Result = Option<Vec<TypeHierarchyItem>>;
+    const METHOD: &'static str = "textDocument/prepareTypeHierarchy";
+}
+
+/// The `typeHierarchy/supertypes` request.
+pub(crate) enum TypeHierarchySupertypes {}
+
+impl Request for TypeHierarchySupertypes {
+    type Params = TypeHierarchyItemParams;
+    type Result = Option<Vec<TypeHierarchyItem>>;
+    const METHOD: &'static str = "typeHierarchy/supertypes";
+}
+
+/// The `typeHierarchy/subtypes` request.
+pub(crate) enum TypeHierarchySubtypes {}
+
+impl Request for TypeHierarchySubtypes {
+    type Params = TypeHierarchyItemParams;
+    type Result = Option<Vec<TypeHierarchyItem>>;
+    const METHOD: &'static str = "typeHierarchy/subtypes";
+}
+
+/// The params of the supertypes and subtypes requests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TypeHierarchyItemParams {
+    pub item: TypeHierarchyItem,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TypeHierarchyItem {
+    pub name: String,
+    pub kind: SymbolKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<SymbolTag>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub uri: Url,
+    pub range: Range,
+    pub selection_range: Range,
+    /// The schema which the item denotes, which is preserved between the requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<TypeHierarchyData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct TypeHierarchyData {
+    pub pkgpath: String,
+    pub name: String,
+}
+
+/// Returns the schema on the position, including the schema name in the definition
+/// and the references of the schema.
+pub(crate) fn prepare_type_hierarchy(
+    program: &Program,
+    pos: &KCLPos,
+    prog_scope: &ProgramScope,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let obj = match resolve_symbol(program, pos, prog_scope)? {
+        (Definition::Object(obj), _, _) => obj,
+        _ => return None,
+    };
+    let schema_ty = definition_schema_ty(&obj)?;
+    Some(vec![type_hierarchy_item(program, &schema_ty, prog_scope)?])
+}
+
+/// Returns the base schema, the mixins and the protocol of the schema.
+pub(crate) fn supertypes(
+    item: &TypeHierarchyItem,
+    program: &Program,
+    prog_scope: &ProgramScope,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let schema_ty = lookup_schema_ty(item.data.as_ref()?, prog_scope)?;
+    let items = schema_ty
+        .base
+        .iter()
+        .map(|base| base.as_ref())
+        .chain(schema_ty.mixins.iter())
+        .chain(schema_ty.protocol.iter().map(|protocol| protocol.as_ref()))
+        .filter_map(|ty| type_hierarchy_item(program, ty, prog_scope))
+        .collect();
+    Some(items)
+}
+
+/// Returns the schemas which directly inherit, mix in or implement the schema.
+pub(crate) fn subtypes(
+    item: &TypeHierarchyItem,
+    program: &Program,
+    prog_scope: &ProgramScope,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let data = item.data.as_ref()?;
+    let items = direct_subtypes(&data.pkgpath, &data.name, prog_scope)
+        .iter()
+        .filter_map(|schema_ty| type_hierarchy_item(program, schema_ty, prog_scope))
+        .collect();
+    Some(items)
+}
+
+/// Returns the schema types defined in the compile unit which directly inherit, mix in
+/// or implement the schema denoted by the package path and the name.
+pub(crate) fn direct_subtypes(
+    pkgpath: &str,
+    name: &str,
+    prog_scope: &ProgramScope,
+) -> Vec<SchemaType> {
+    let is_target = |ty: &SchemaType| ty.pkgpath == pkgpath && ty.name == name;
+    let mut subtypes = vec![];
+    for pkg_scope in prog_scope.scope_map.values() {
+        for obj in pkg_scope.borrow().elems.values() {
+            let schema_ty = match definition_schema_ty(&obj.borrow()) {
+                Some(schema_ty) => schema_ty,
+                None => continue,
+            };
+            let is_subtype = schema_ty.base.as_deref().map_or(false, is_target)
+                || schema_ty.mixins.iter().any(is_target)
+                || schema_ty.protocol.as_deref().map_or(false, is_target);
+            if is_subtype {
+                subtypes.push(schema_ty);
+            }
+        }
+    }
+    subtypes
+}
+
+/// Returns the schema type of the schema definition object.
+pub(crate) fn definition_schema_ty(obj: &ScopeObject) -> Option<SchemaType> {
+    match (&obj.kind, &obj.ty.kind) {
+        (ScopeObjectKind::Definition, TypeKind::Schema(schema_ty)) => Some(schema_ty.clone()),
+        _ => None,
+    }
+}
+
+/// Looks up the schema type in the package scope by the item data, the schema type in
+/// the item is not used because the supertypes may be changed since the item is prepared.
+fn lookup_schema_ty(data: &TypeHierarchyData, prog_scope: &ProgramScope) -> Option<SchemaType> {
+    let pkg_scope = prog_scope.scope_map.get(&data.pkgpath)?.borrow();
+    let obj = pkg_scope.elems.get(&data.name)?.borrow();
+    definition_schema_ty(&obj)
+}
+
+/// Builds the item of the schema, the schemas defined outside the compile unit have no item.
+fn type_hierarchy_item(
+    program: &Program,
+    schema_ty: &SchemaType,
+    prog_scope: &ProgramScope,
+) -> Option<TypeHierarchyItem> {
+    let obj = find_schema_def_obj(schema_ty, prog_scope)?;
+    let range = Range {
+        start: lsp_pos(&obj.start),
+        end: lsp_pos(&obj.end),
+    };
+    let selection_range = program
+        .pos_to_stmt(&obj.start)
+        .and_then(|stmt| match &stmt.node {
+            Stmt::Schema(schema_stmt) => Some(schema_stmt.name.get_span_pos()),
+            Stmt::Rule(rule_stmt) => Some(rule_stmt.name.get_span_pos()),
+            _ => None,
+        })
+        .map_or(range, |(start, end)| Range {
+            start: lsp_pos(&start),
+            end: lsp_pos(&end),
+        });
+    let keyword = if schema_ty.is_rule {
+        "rule"
+    } else if schema_ty.is_protocol {
+        "protocol"
+    } else if schema_ty.is_mixin {
+        "mixin"
+    } else {
+        "schema"
+    };
+    Some(TypeHierarchyItem {
+        name: schema_ty.name.clone(),
+        kind: if schema_ty.is_protocol {
+            SymbolKind::INTERFACE
+        } else {
+            SymbolKind::STRUCT
+        },
+        tags: None,
+        detail: Some(format!("{} in {}", keyword, schema_ty.pkgpath)),
+        uri: Url::from_file_path(&obj.start.filename).ok()?,
+        range,
+        selection_range,
+        data: Some(TypeHierarchyData {
+            pkgpath: schema_ty.pkgpath.clone(),
+            name: schema_ty.name.clone(),
+        }),
+    })
+}
@@ -4,13 +4,16 @@ use std::{fs, sync::Arc};
 use indexmap::IndexSet;
 use kclvm_ast::ast::{ConfigEntry, Expr, Identifier, Node, NodeRef, Program, Stmt, Type};
 use kclvm_ast::pos::ContainsPos;
+use kclvm_config::cache::MemoryPkgCache;
 use kclvm_config::modfile::KCL_FILE_EXTENSION;
 use kclvm_driver::kpm_metadata::fetch_metadata;
 use kclvm_driver::{get_kcl_files, lookup_compile_unit};
 use kclvm_error::Diagnostic;
 use kclvm_error::Position as KCLPos;
-use kclvm_parser::{load_program, ParseSession};
-use kclvm_sema::resolver::{resolve_program, scope::ProgramScope};
+use kclvm_parser::{load_program, KCLModuleCache, ParseSession};
+use kclvm_sema::resolver::{
+    resolve_program, resolve_program_with_cache, scope::ProgramScope, Options, PkgScopeCache,
+};
 use kclvm_utils::pkgpath::rm_external_pkg_name;
 use lsp_types::Url;
 use parking_lot::{RwLock, RwLockReadGuard};
@@ -51,6 +54,11 @@ pub fn get_file_name(vfs: RwLockReadGuard<Vfs>, file_id: FileId) -> anyhow::Resu
 
 pub(crate) struct Param {
     pub file: String,
+    /// The module cache of the analysis database, the files are always parsed if it is `None`.
+    pub module_cache: Option<KCLModuleCache>,
+    /// The package scope cache of the analysis database, the packages imported by the
+    /// compile unit are always resolved if it is `None`.
+    pub scope_cache: Option<MemoryPkgCache>,
 }
 
 pub(crate) fn parse_param_and_compile(
@@ -61,6 +69,7 @@ pub(crate) fn parse_param_and_compile(
     let files: Vec<&str> = files.iter().map(|s| s.as_str()).collect();
     let mut opt = opt.unwrap_or_default();
     opt.load_plugins = true;
+    opt.module_cache = param.module_cache;
 
     // update opt.k_code_list
     if let Some(vfs) = vfs {
@@ -69,7 +78,18 @@ pub(crate) fn parse_param_and_compile(
     }
     let sess = Arc::new(ParseSession::default());
     let mut program = load_program(sess.clone(), &files, Some(opt)).unwrap();
-    let prog_scope = resolve_program(&mut program);
+    let prog_scope = match param.scope_cache {
+        Some(scope_cache) => {
+            let opts = Options {
+                raise_err: true,
+                lint_check: true,
+                ..Default::default()
+            };
+            let pkg_scope_cache = PkgScopeCache::with_memory_cache(&program.root, scope_cache);
+            resolve_program_with_cache(&mut program, opts, pkg_scope_cache)
+        }
+        None => resolve_program(&mut program),
+    };
     sess.append_diagnostic(prog_scope.handler.diagnostics.clone());
     let diags = sess.1.borrow().diagnostics.clone();
     Ok((program, prog_scope, diags))
@@ -93,6 +113,14 @@ pub(crate) fn apply_document_changes(
     }
 }
 
+/// Loads the code of the file from vfs, the unsaved changes of the opened file
+/// are included.
+pub(crate) fn load_file_code_from_vfs(file: &str, vfs: Arc<RwLock<Vfs>>) -> anyhow::Result<String> {
+    load_files_code_from_vfs(&[file], vfs)?
+        .pop()
+        .ok_or_else(|| anyhow::anyhow!("can't load the code of file: {}", file))
+}
+
 fn load_files_code_from_vfs(files: &[&str], vfs: Arc<RwLock<Vfs>>) -> anyhow::Result<Vec<String>> {
     let mut res = vec![];
     let vfs = &mut vfs.read();
@@ -171,25 +199,10 @@ pub(crate) fn inner_most_expr_in_stmt(
 ) -> (Option<Node<Expr>>, Option<Node<Expr>>) {
     match stmt {
         Stmt::Assign(assign_stmt) => {
-            if let Some(ty) = &assign_stmt.type_annotation {
-                // build a temp identifier with string
-                return (
-                    Some(Node::node_with_pos(
-                        Expr::Identifier(Identifier {
-                            names: vec![ty.node.clone()],
-                            pkgpath: "".to_string(),
-                            ctx: kclvm_ast::ast::ExprContext::Load,
-                        }),
-                        (
-                            ty.filename.clone(),
-                            ty.line,
-                            ty.column,
-                            ty.end_line,
-                            ty.end_column,
-                        ),
-                    )),
-                    schema_def,
-                );
+            if let Some(ty) = &assign_stmt.ty {
+                if ty.contains_pos(pos) {
+                    return (build_identifier_from_ty_string(ty, pos), schema_def);
+                }
             }
             walk_if_contains!(assign_stmt.value, pos, schema_def);
 
@@ -205,6 +218,12 @@ pub(crate) fn inner_most_expr_in_stmt(
                 schema_def,
                 Expr::Identifier
             );
+            if type_alias_stmt.ty.contains_pos(pos) {
+                return (
+                    build_identifier_from_ty_string(&type_alias_stmt.ty, pos),
+                    schema_def,
+                );
+            }
             (None, schema_def)
         }
         Stmt::Expr(expr_stmt) => {
@@ -295,6 +314,12 @@ pub(crate) fn inner_most_expr_in_stmt(
             (None, schema_def)
         }
         Stmt::SchemaAttr(schema_attr_expr) => {
+            if schema_attr_expr.name.contains_pos(pos) {
+                return (
+                    Some(build_identifier_from_string(&schema_attr_expr.name)),
+                    schema_def,
+                );
+            }
             if schema_attr_expr.ty.contains_pos(pos) {
                 return (
                     build_identifier_from_ty_string(&schema_attr_expr.ty, pos),
@@ -308,6 +333,12 @@ pub(crate) fn inner_most_expr_in_stmt(
             (None, schema_def)
         }
         Stmt::Rule(rule_stmt) => {
+            if rule_stmt.name.contains_pos(pos) {
+                return (
+                    Some(build_identifier_from_string(&rule_stmt.name)),
+                    schema_def,
+                );
+            }
             for parent_id in &rule_stmt.parent_rules {
                 walk_if_contains_with_new_expr!(parent_id, pos, schema_def, Expr::Identifier);
             }
@@ -446,6 +477,11 @@ pub(crate) fn inner_most_expr(
             if let Some(args) = &lambda_expr.args {
                 walk_if_contains_with_new_expr!(args, pos, schema_def, Expr::Arguments);
             }
+            if let Some(ty) = &lambda_expr.return_ty {
+                if ty.contains_pos(pos) {
+                    return (build_identifier_from_ty_string(ty, pos), schema_def);
+                }
+            }
             for stmt in &lambda_expr.body {
                 if stmt.contains_pos(pos) {
                     return inner_most_expr_in_stmt(&stmt.node, pos, schema_def);
@@ -518,6 +554,24 @@ fn inner_most_expr_in_config_entry(
     }
 }
 
+/// Returns the keys of the config expression, e.g., `name` in `{name = "alice"}`.
+pub(crate) fn config_keys(config: &Expr) -> Option<IndexSet<String>> {
+    match config {
+        Expr::Config(config_expr) => Some(
+            config_expr
+                .items
+                .iter()
+                .filter_map(|item| match &item.node.key.as_ref()?.node {
+                    Expr::Identifier(id) => id.names.first().cloned(),
+                    Expr::StringLit(string_lit) => Some(string_lit.value.clone()),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
 /// Build a temp identifier expr with string
 fn build_identifier_from_string(s: &NodeRef<String>) -> Node<Expr> {
     Node::node_with_pos(
@@ -584,6 +638,31 @@ fn build_identifier_from_ty_string(ty: &NodeRef<Type>, pos: &KCLPos) -> Option<N
             None
         }
         Type::Literal(_) => None,
+        Type::Function(func_ty) => {
+            for ty in func_ty.params_ty.iter().chain(Some(&func_ty.ret_ty)) {
+                if ty.contains_pos(pos) {
+                    return build_identifier_from_ty_string(ty, pos);
+                }
+            }
+            None
+        }
+        Type::Generic(generic_ty) => {
+            for ty in &generic_ty.args {
+                if ty.contains_pos(pos) {
+                    return build_identifier_from_ty_string(ty, pos);
+                }
+            }
+            Some(Node::node_with_pos(
+                Expr::Identifier(generic_ty.name.clone()),
+                (
+                    ty.filename.clone(),
+                    ty.line,
+                    ty.column,
+                    ty.end_line,
+                    ty.end_column,
+                ),
+            ))
+        }
     }
 }
 
@@ -0,0 +1,198 @@
+//! Workspace symbol search for KCL.
+//!
+//! The KCL files in the workspace folders are parsed rather than compiled, because
+//! they may belong to different compile units. The parsed modules are shared with
+//! the other requests by the module cache, so only the changed files are parsed
+//! again. Now supports the following symbols:
+//! + schema, mixin, protocol and rule definition
+//! + type alias
+//! + top-level variable
+
+use std::path::Path;
+use std::sync::Arc;
+
+use indexmap::IndexSet;
+use kclvm_ast::ast::{Identifier, Module, NodeRef, Stmt};
+use kclvm_ast::pos::GetPos;
+use kclvm_driver::get_kcl_files;
+use kclvm_error::Position as KCLPos;
+use kclvm_parser::{parse_file_with_cache, KCLModuleCache};
+use lsp_types::{Location, Range, SymbolInformation, SymbolKind, Url};
+use parking_lot::RwLock;
+use ra_ap_vfs::Vfs;
+
+use crate::{to_lsp::lsp_pos, util::load_file_code_from_vfs};
+
+/// Returns the symbols defined in the KCL files of the workspace folders whose
+/// names match the query.
+pub(crate) fn workspace_symbols(
+    query: &str,
+    folders: &[String],
+    vfs: Arc<RwLock<Vfs>>,
+    module_cache: &KCLModuleCache,
+) -> anyhow::Result<Vec<SymbolInformation>> {
+    let mut symbols = vec![];
+    for folder in folders {
+        for file in get_kcl_files(folder, true)? {
+            let code = load_file_code_from_vfs(&file, vfs.clone())?;
+            let module = match parse_file_with_cache(&file, Some(code), module_cache) {
+                Ok(module) => module,
+                Err(_) => continue,
+            };
+            let uri = match Url::from_file_path(&file) {
+                Ok(uri) => uri,
+                Err(_) => continue,
+            };
+            let container_name = container_name(folder, &file);
+            symbols.extend(
+                module_symbols(&module)
+                    .into_iter()
+                    .filter(|symbol| fuzzy_match(query, &symbol.name))
+                    .map(|symbol| to_symbol_information(symbol, &uri, &container_name)),
+            );
+        }
+    }
+    Ok(symbols)
+}
+
+/// A symbol defined in a KCL module.
+#[derive(Debug, Clone)]
+pub(crate) struct ModuleSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub start: KCLPos,
+    pub end: KCLPos,
+}
+
+/// Collects the schemas, rules, type aliases and top-level variables defined in the module.
+pub(crate) fn module_symbols(module: &Module) -> Vec<ModuleSymbol> {
+    let mut symbols = vec![];
+    let mut variables = IndexSet::new();
+    collect_stmt_symbols(&module.body, &mut symbols, &mut variables);
+    symbols
+}
+
+fn collect_stmt_symbols(
+    stmts: &[NodeRef<Stmt>],
+    symbols: &mut Vec<ModuleSymbol>,
+    variables: &mut IndexSet<String>,
+) {
+    for stmt in stmts {
+        match &stmt.node {
+            Stmt::Schema(schema_stmt) => {
+                let (start, end) = schema_stmt.name.get_span_pos();
+                symbols.push(ModuleSymbol {
+                    name: schema_stmt.name.node.clone(),
+                    kind: SymbolKind::STRUCT,
+                    start,
+                    end,
+                });
+            }
+            Stmt::Rule(rule_stmt) => {
+                let (start, end) = rule_stmt.name.get_span_pos();
+                symbols.push(ModuleSymbol {
+                    name: rule_stmt.name.node.clone(),
+                    kind: SymbolKind::STRUCT,
+                    start,
+                    end,
+                });
+            }
+            Stmt::TypeAlias(type_alias_stmt) => {
+                let (start, end) = type_alias_stmt.type_name.get_span_pos();
+                symbols.push(ModuleSymbol {
+                    name: type_alias_stmt.type_name.node.get_name(),
+                    kind: SymbolKind::TYPE_PARAMETER,
+                    start,
+                    end,
+                });
+            }
+            Stmt::Assign(assign_stmt) => {
+                for target in &assign_stmt.targets {
+                    collect_variable_symbol(target, symbols, variables);
+                }
+            }
+            Stmt::Unification(unification_stmt) => {
+                collect_variable_symbol(&unification_stmt.target, symbols, variables);
+            }
+            Stmt::AugAssign(aug_assign_stmt) => {
+                collect_variable_symbol(&aug_assign_stmt.target, symbols, variables);
+            }
+            Stmt::If(if_stmt) => {
+                collect_stmt_symbols(&if_stmt.body, symbols, variables);
+                collect_stmt_symbols(&if_stmt.orelse, symbols, variables);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Collects the variable defined by the assignment target, a variable assigned
+/// more than once is only collected on its first assignment.
+fn collect_variable_symbol(
+    target: &NodeRef<Identifier>,
+    symbols: &mut Vec<ModuleSymbol>,
+    variables: &mut IndexSet<String>,
+) {
+    let name = match target.node.names.first() {
+        Some(name) => name,
+        None => return,
+    };
+    if variables.insert(name.clone()) {
+        let start = target.get_pos();
+        let end = KCLPos {
+            column: start
+                .column
+                .map(|column| column + name.chars().count() as u64),
+            ..start.clone()
+        };
+        symbols.push(ModuleSymbol {
+            name: name.clone(),
+            kind: SymbolKind::VARIABLE,
+            start,
+            end,
+        });
+    }
+}
+
+#[allow(deprecated)]
+fn to_symbol_information(
+    symbol: ModuleSymbol,
+    uri: &Url,
+    container_name: &Option<String>,
+) -> SymbolInformation {
+    SymbolInformation {
+        name: symbol.name,
+        kind: symbol.kind,
+        tags: None,
+        deprecated: None,
+        location: Location {
+            uri: uri.clone(),
+            range: Range {
+                start: lsp_pos(&symbol.start),
+                end: lsp_pos(&symbol.end),
+            },
+        },
+        container_name: container_name.clone(),
+    }
+}
+
+/// Returns the package path of the file relative to the workspace folder, e.g.,
+/// `pkg.sub` for `<folder>/pkg/sub/main.k`, or `None` for the files in the folder.
+fn container_name(folder: &str, file: &str) -> Option<String> {
+    let dir = Path::new(file).parent()?.strip_prefix(folder).ok()?;
+    let names: Vec<String> = dir
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect();
+    (!names.is_empty()).then(|| names.join("."))
+}
+
+/// Whether all characters of the query appear in the name in order, ignoring case.
+/// An empty query matches all names.
+pub(crate) fn fuzzy_match(query: &str, name: &str) -> bool {
+    let mut name_chars = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| name_chars.any(|c| c == q))
+}
@@ -1,10 +1,13 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use indexmap::IndexSet;
+use kclvm_config::modfile::{get_pkg_root_from_paths, load_mod_lint_levels};
 use kclvm_error::{Diagnostic, Handler};
 use kclvm_parser::{load_program, LoadProgramOptions, ParseSession};
 use kclvm_runtime::PanicInfo;
-use kclvm_sema::resolver::resolve_program;
+use kclvm_sema::lint::parse_lint_levels;
+use kclvm_sema::resolver::{resolve_program_with_opts, Options};
 #[cfg(test)]
 mod tests;
 
@@ -36,6 +39,8 @@ mod tests;
 /// import math
 /// schema Person:
 ///     age: int
+///
+/// person = Person {age = 1}
 /// ```
 ///
 /// - return
@@ -62,6 +67,46 @@ pub fn lint_files(
     files: &[&str],
     opts: Option<LoadProgramOptions>,
 ) -> (IndexSet<Diagnostic>, IndexSet<Diagnostic>) {
+    lint_files_with_levels(files, opts, &HashMap::new())
+}
+
+/// KCL Lint tools API with the lint levels, e.g., `unused_import = "allow"`. The lint levels
+/// in the `[lint]` section of `kcl.mod` are used for the lints not set in `lint_levels`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::collections::HashMap;
+/// use kclvm_tools::lint::lint_files_with_levels;
+/// let lint_levels = HashMap::from([("unused_import".to_string(), "deny".to_string())]);
+/// let (errors, warnings) = lint_files_with_levels(&["test.k"], None, &lint_levels);
+/// ```
+pub fn lint_files_with_levels(
+    files: &[&str],
+    opts: Option<LoadProgramOptions>,
+    lint_levels: &HashMap<String, String>,
+) -> (IndexSet<Diagnostic>, IndexSet<Diagnostic>) {
+    let file_paths = files.iter().map(|f| f.to_string()).collect::<Vec<String>>();
+    let mut levels = match get_pkg_root_from_paths(&file_paths) {
+        Ok(root) if !root.is_empty() => match load_mod_lint_levels(&root) {
+            Ok(levels) => levels,
+            Err(err) => {
+                return Handler::default()
+                    .add_panic_info(&PanicInfo::from(err.to_string()))
+                    .classification();
+            }
+        },
+        _ => HashMap::new(),
+    };
+    levels.extend(lint_levels.clone());
+    let lint_levels = match parse_lint_levels(&levels) {
+        Ok(lint_levels) => lint_levels,
+        Err(err_str) => {
+            return Handler::default()
+                .add_panic_info(&PanicInfo::from(err_str))
+                .classification();
+        }
+    };
     // Parse AST program.
     let sess = Arc::new(ParseSession::default());
     let mut program = match load_program(sess.clone(), files, opts) {
@@ -72,6 +117,16 @@ pub fn lint_files(
                 .classification();
         }
     };
-    sess.append_diagnostic(resolve_program(&mut program).handler.diagnostics)
+    let scope = resolve_program_with_opts(
+        &mut program,
+        Options {
+            raise_err: true,
+            config_auto_fix: false,
+            lint_check: true,
+            strict_type_check: false,
+            lint_levels,
+        },
+    );
+    sess.append_diagnostic(scope.handler.diagnostics)
         .classification()
 }
@@ -9,6 +9,7 @@ fn test_lint() {
         "Module 'a' is reimported multiple times",
         "Module 'import_test.a' imported but unused",
         "Module 'abc' imported but unused",
+        "Schema 'Person' defined but unused",
     ];
     assert_eq!(warnings.len(), msgs.len());
     for (diag, m) in warnings.iter().zip(msgs.iter()) {